@@ -71,46 +71,68 @@ where
     Ok(flags_vec)
 }
 
+/// The representations a transaction `flags` field can have. Either the raw `u32` bit-flags
+/// or a `Vec<Flag>` of the flags defined for the transaction type.
+pub trait TxnFlags: Sized {
+    fn serialize_txn_flags<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_txn_flags<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Self>, D::Error>;
+}
+
+impl TxnFlags for u32 {
+    fn serialize_txn_flags<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_u32(*self)
+    }
+
+    fn deserialize_txn_flags<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Self>, D::Error> {
+        Ok(Some(u32::deserialize(d)?))
+    }
+}
+
+impl<F> TxnFlags for Vec<F>
+where
+    F: Serialize + IntoEnumIterator + Debug,
+{
+    fn serialize_txn_flags<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_flag(self, s)
+    }
+
+    fn deserialize_txn_flags<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Self>, D::Error> {
+        let flags_vec = deserialize_flags(d)?;
+        if flags_vec.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(flags_vec))
+        }
+    }
+}
+
 /// A `mod` to be used on transaction `flags` fields. It serializes the `Vec<Flag>` into a `u32`,
 /// representing the bit-flags, and deserializes the `u32` back into `Vec<Flag>` for internal uses.
+/// Raw `u32` flags are passed through.
 pub(crate) mod txn_flags {
-    use core::fmt::Debug;
+    use crate::_serde::TxnFlags;
 
-    use crate::_serde::{deserialize_flags, serialize_flag};
-    use alloc::vec::Vec;
-
-    use serde::{Deserializer, Serialize, Serializer};
+    use serde::{Deserializer, Serializer};
 
-    use strum::IntoEnumIterator;
-
-    pub fn serialize<F, S>(flags: &Option<Vec<F>>, s: S) -> Result<S::Ok, S::Error>
+    pub fn serialize<T, S>(flags: &Option<T>, s: S) -> Result<S::Ok, S::Error>
     where
-        F: Serialize,
+        T: TxnFlags,
         S: Serializer,
     {
         if let Some(f) = flags {
-            serialize_flag(f, s)
+            f.serialize_txn_flags(s)
         } else {
             s.serialize_u32(0)
         }
     }
 
-    pub fn deserialize<'de, F, D>(d: D) -> Result<Option<Vec<F>>, D::Error>
+    pub fn deserialize<'de, T, D>(d: D) -> Result<Option<T>, D::Error>
     where
-        F: Serialize + IntoEnumIterator + Debug,
+        T: TxnFlags,
         D: Deserializer<'de>,
     {
-        let flags_vec_result: Result<Vec<F>, D::Error> = deserialize_flags(d);
-        match flags_vec_result {
-            Ok(flags_vec) => {
-                if flags_vec.is_empty() {
-                    Ok(None)
-                } else {
-                    Ok(Some(flags_vec))
-                }
-            }
-            Err(error) => Err(error),
-        }
+        T::deserialize_txn_flags(d)
     }
 }
 
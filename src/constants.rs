@@ -11,6 +11,9 @@ pub const HEX_CURRENCY_REGEX: &str = r"^[A-F0-9]{40}$";
 
 /// Length of an account id.
 pub const ACCOUNT_ID_LENGTH: usize = 20;
+/// The address of the account with the ID zero, used as the
+/// `Account` of pseudo-transactions.
+pub const ACCOUNT_ZERO: &str = "rrrrrrrrrrrrrrrrrrrrrhoLvTp";
 
 pub const MAX_TICK_SIZE: u32 = 15;
 pub const MIN_TICK_SIZE: u32 = 3;
@@ -25,6 +28,57 @@ pub const MAX_URI_LENGTH: usize = 512;
 
 pub const MAX_DOMAIN_LENGTH: usize = 256;
 
+pub const MAX_MEMOS_SIZE: usize = 1024;
+
+/// Universal flag requiring a fully-canonical signature. Set
+/// on most transactions in the ledger, but not part of the flag
+/// enums of the transaction types.
+pub const TF_FULLY_CANONICAL_SIG: u32 = 0x80000000;
+
+pub const MAX_MPT_AMOUNT: u64 = 0x7FFFFFFFFFFFFFFF;
+pub const MAX_MPTOKEN_METADATA_LENGTH: usize = 1024;
+
+/// Prefixes of the data hashed for the identifying hashes and
+/// signatures of the XRP Ledger. Each prefix is three uppercase
+/// ASCII characters followed by a zero byte.
+///
+/// See Hash Prefixes:
+/// `<https://xrpl.org/basic-data-types.html#hash-prefixes>`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, EnumIter)]
+#[repr(u32)]
+pub enum HashPrefix {
+    /// `TXN\0`, the identifying hash of a signed transaction.
+    TransactionId = 0x54584E00,
+    /// `SND\0`, a transaction with its metadata in the ledger's
+    /// transaction tree.
+    TransactionNode = 0x534E4400,
+    /// `MLN\0`, a leaf node of the ledger's state tree.
+    LeafNode = 0x4D4C4E00,
+    /// `MIN\0`, an inner node of a ledger tree.
+    InnerNode = 0x4D494E00,
+    /// `LWR\0`, the header of a ledger.
+    LedgerMaster = 0x4C575200,
+    /// `STX\0`, a transaction signed by a single account.
+    TransactionSign = 0x53545800,
+    /// `SMT\0`, a transaction signed by multiple accounts.
+    TransactionMultiSign = 0x534D5400,
+    /// `VAL\0`, a validation vote.
+    Validation = 0x56414C00,
+    /// `PRP\0`, a consensus proposal.
+    Proposal = 0x50525000,
+    /// `MAN\0`, a manifest of a validator.
+    Manifest = 0x4D414E00,
+    /// `CLM\0`, a claim of a payment channel.
+    PaymentChannelClaim = 0x434C4D00,
+}
+
+impl HashPrefix {
+    /// Returns the four bytes of the prefix.
+    pub const fn bytes(self) -> [u8; 4] {
+        (self as u32).to_be_bytes()
+    }
+}
+
 /// Represents the supported cryptography algorithms.
 #[derive(Debug, PartialEq, Eq, Clone, EnumIter, Display, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -33,3 +87,34 @@ pub enum CryptoAlgorithm {
     ED25519,
     SECP256K1,
 }
+
+/// New seeds and wallets use Ed25519 unless specified otherwise.
+impl Default for CryptoAlgorithm {
+    fn default() -> Self {
+        CryptoAlgorithm::ED25519
+    }
+}
+
+#[cfg(test)]
+mod test_hash_prefix {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        for (prefix, expected) in [
+            (HashPrefix::TransactionId, b"TXN\0"),
+            (HashPrefix::TransactionNode, b"SND\0"),
+            (HashPrefix::LeafNode, b"MLN\0"),
+            (HashPrefix::InnerNode, b"MIN\0"),
+            (HashPrefix::LedgerMaster, b"LWR\0"),
+            (HashPrefix::TransactionSign, b"STX\0"),
+            (HashPrefix::TransactionMultiSign, b"SMT\0"),
+            (HashPrefix::Validation, b"VAL\0"),
+            (HashPrefix::Proposal, b"PRP\0"),
+            (HashPrefix::Manifest, b"MAN\0"),
+            (HashPrefix::PaymentChannelClaim, b"CLM\0"),
+        ] {
+            assert_eq!(&prefix.bytes(), expected);
+        }
+    }
+}
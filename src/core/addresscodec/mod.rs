@@ -9,7 +9,9 @@ use crate::constants::CryptoAlgorithm;
 use crate::core::addresscodec::exceptions::XRPLAddressCodecException;
 use crate::core::addresscodec::utils::*;
 use crate::skip_err;
+use crate::utils::hex::from_hex;
 use alloc::string::String;
+use alloc::string::ToString;
 use alloc::vec::Vec;
 use core::convert::TryInto;
 use strum::IntoEnumIterator;
@@ -51,7 +53,7 @@ fn _get_tag_from_buffer(buffer: &[u8]) -> Result<Option<u64>, XRPLAddressCodecEx
         // inverse of what happens in encode
     } else if flag != &0 {
         Err(XRPLAddressCodecException::InvalidXAddressZeroNoTag)
-    } else if hex::decode("0000000000000000")? != buffer[1..9] {
+    } else if from_hex("0000000000000000")? != buffer[1..9] {
         Err(XRPLAddressCodecException::InvalidXAddressZeroRemain)
     } else {
         Ok(None)
@@ -543,6 +545,42 @@ pub fn is_valid_xaddress(xaddress: &str) -> bool {
     xaddress_to_classic_address(xaddress).is_ok()
 }
 
+/// Returns the classic address and the tag of an address,
+/// which can be a classic address or an X-Address. Classic
+/// addresses are returned unchanged without a tag.
+///
+/// See X-Address format:
+/// `<https://xrpaddress.info>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::addresscodec::derive_address_from_x_address;
+///
+/// let xaddress: &str = "X7AcgcsBL6XDcUb289X4mJ8djcdyKaB5hJDWMArnXr61cqZ";
+///
+/// assert_eq!(
+///     derive_address_from_x_address(xaddress),
+///     Ok(("r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59".to_string(), None)),
+/// );
+/// assert_eq!(
+///     derive_address_from_x_address("r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59"),
+///     Ok(("r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59".to_string(), None)),
+/// );
+/// ```
+pub fn derive_address_from_x_address(
+    address: &str,
+) -> Result<(String, Option<u64>), XRPLAddressCodecException> {
+    if is_valid_classic_address(address) {
+        Ok((address.to_string(), None))
+    } else {
+        let (classic_address, tag, _is_test_network) = xaddress_to_classic_address(address)?;
+        Ok((classic_address, tag))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -584,13 +622,13 @@ mod test {
 
     #[test]
     fn test_decode_seed() {
-        let hex_bytes = hex::decode(SECP256K1_HEX_TEST).expect("");
+        let hex_bytes = from_hex(SECP256K1_HEX_TEST).expect("");
         let (decode_result, encoding_type) = decode_seed(SECP256K1_ENCODED_SEED_TEST).unwrap();
 
         assert_eq!(hex_bytes, decode_result);
         assert_eq!(CryptoAlgorithm::SECP256K1, encoding_type);
 
-        let hex_bytes = hex::decode(ED25519_HEX_TEST).expect("");
+        let hex_bytes = from_hex(ED25519_HEX_TEST).expect("");
         let (decode_result, encoding_type) = decode_seed(ED25519_ENCODED_SEED_TEST).unwrap();
 
         assert_eq!(hex_bytes, decode_result);
@@ -633,7 +671,7 @@ mod test {
 
     #[test]
     fn test_encode_node_public_key() {
-        let bytes = hex::decode(NODE_PUBLIC_KEY_HEX_TEST).expect("");
+        let bytes = from_hex(NODE_PUBLIC_KEY_HEX_TEST).expect("");
         assert_eq!(
             encode_node_public_key(&bytes),
             Ok(NODE_PUBLIC_KEY_TEST.to_string()),
@@ -644,14 +682,14 @@ mod test {
     fn test_decode_node_public_key() {
         assert_eq!(
             decode_node_public_key(NODE_PUBLIC_KEY_TEST),
-            Ok(hex::decode(NODE_PUBLIC_KEY_HEX_TEST).expect("")),
+            Ok(from_hex(NODE_PUBLIC_KEY_HEX_TEST).expect("")),
         );
     }
 
     #[test]
     fn test_encode_account_public_key() {
         assert_eq!(
-            encode_account_public_key(&hex::decode(ACCOUNT_PUBLIC_KEY_HEX_TEST).expect("")),
+            encode_account_public_key(&from_hex(ACCOUNT_PUBLIC_KEY_HEX_TEST).expect("")),
             Ok(ACCOUNT_PUBLIC_KEY_TEST.to_string()),
         );
     }
@@ -660,7 +698,7 @@ mod test {
     fn test_decode_account_public_key() {
         assert_eq!(
             decode_account_public_key(ACCOUNT_PUBLIC_KEY_TEST),
-            Ok(hex::decode(ACCOUNT_PUBLIC_KEY_HEX_TEST).expect("")),
+            Ok(from_hex(ACCOUNT_PUBLIC_KEY_HEX_TEST).expect("")),
         );
     }
 
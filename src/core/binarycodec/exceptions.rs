@@ -2,6 +2,7 @@
 
 use crate::utils::exceptions::ISOCodeException;
 use crate::utils::exceptions::XRPRangeException;
+use alloc::string::String;
 use strum_macros::Display;
 
 #[derive(Debug, Clone, PartialEq, Display)]
@@ -13,6 +14,7 @@ pub enum XRPLBinaryCodecException {
     UnexpectedFieldCodeRange { min: usize, max: usize },
     UnexpectedFieldIdByteRange { min: usize, max: usize },
     UnknownFieldName,
+    UnsupportedFieldType,
     InvalidReadFromBytesValue,
     InvalidVariableLengthTooLarge { max: usize },
     InvalidHashLength { expected: usize, found: usize },
@@ -22,6 +24,10 @@ pub enum XRPLBinaryCodecException {
     FromUtf8Error,
     ParseIntError,
     FromHexError,
+    XAddressTagMismatch { field: String },
+    UnknownTransactionType { found: String },
+    UnknownField { field: String },
+    DeliverMaxMismatch,
     XRPRangeError(XRPRangeException),
     SerdeJsonError(serde_json::error::Category),
     DecimalError(rust_decimal::Error),
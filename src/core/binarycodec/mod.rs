@@ -4,16 +4,29 @@ pub mod exceptions;
 pub(crate) mod test_cases;
 pub mod utils;
 
+use crate::constants::HashPrefix;
+use crate::core::addresscodec::{is_valid_xaddress, xaddress_to_classic_address};
 use crate::core::binarycodec::exceptions::XRPLBinaryCodecException;
 use crate::core::binarycodec::utils::*;
 use crate::core::definitions::*;
-use crate::core::types::TryFromParser;
+use crate::core::keypairs::utils::sha512_first_half;
+use crate::core::types::exceptions::{XRPLHashException, XRPLTypeException};
+use crate::core::types::{
+    AccountId, Amount, Hash128, Hash160, Hash256, PathSet, TryFromParser, Vector256,
+};
+use crate::utils::exceptions::JSONParseException;
+use crate::utils::hex::{from_hex, to_hex};
 use crate::utils::ToBytes;
 use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
 use alloc::vec;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::convert::TryFrom;
 use core::convert::TryInto;
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::{Map, Value};
 
 /// Serializes JSON to XRPL binary format.
 pub type BinarySerializer = Vec<u8>;
@@ -380,20 +393,20 @@ pub trait Serialization {
     /// use xrpl::core::definitions::FieldHeader;
     ///
     /// let field_header: FieldHeader = FieldHeader {
-    ///     type_code: -2,
-    ///     field_code: 0,
+    ///     type_code: 7,
+    ///     field_code: 3,
     /// };
     ///
     /// let field_info: FieldInfo = FieldInfo {
-    ///     nth: 0,
-    ///     is_vl_encoded: false,
-    ///     is_serialized: false,
-    ///     is_signing_field: false,
-    ///     r#type: "Unknown".to_string(),
+    ///     nth: 3,
+    ///     is_vl_encoded: true,
+    ///     is_serialized: true,
+    ///     is_signing_field: true,
+    ///     r#type: "Blob".to_string(),
     /// };
     ///
-    /// let field_instance = FieldInstance::new(&field_info, "Generic", field_header);
-    /// let expected: Vec<u8> = [255, 224, 0, 17, 34].to_vec();
+    /// let field_instance = FieldInstance::new(&field_info, "SigningPubKey", field_header);
+    /// let expected: Vec<u8> = [115, 3, 0, 17, 34].to_vec();
     /// let test_bytes: Vec<u8> = [0, 17, 34].to_vec();
     /// let mut serializer: BinarySerializer = BinarySerializer::new();
     ///
@@ -455,10 +468,10 @@ impl Parser for BinaryParser {
     }
 
     fn read(&mut self, n: usize) -> Result<Vec<u8>, XRPLBinaryCodecException> {
-        let first_n_bytes = self.0[..n].to_owned();
+        let first_n_bytes = self.0.get(..n).map(<[u8]>::to_vec);
 
         self.skip_bytes(n)?;
-        Ok(first_n_bytes)
+        Ok(first_n_bytes.unwrap_or_default())
     }
 
     fn read_uint8(&mut self) -> Result<u8, XRPLBinaryCodecException> {
@@ -598,7 +611,7 @@ impl TryFrom<&str> for BinaryParser {
     type Error = XRPLBinaryCodecException;
 
     fn try_from(hex_bytes: &str) -> Result<Self, Self::Error> {
-        Ok(BinaryParser(hex::decode(hex_bytes)?))
+        Ok(BinaryParser(from_hex(hex_bytes)?))
     }
 }
 
@@ -632,6 +645,634 @@ impl Iterator for BinaryParser {
     }
 }
 
+/// Serializes a model to JSON with its fields in the XRP
+/// Ledger's canonical order, so equivalent models always
+/// produce byte-identical JSON.
+///
+/// Fields known to the definitions are ordered by type code
+/// and then field code, like in the binary format. Any other
+/// keys, e.g. those of issued currency amounts, follow in
+/// lexicographical order. Nested objects are ordered the same
+/// way and array elements keep their order.
+///
+/// See Canonical Field Order:
+/// `<https://xrpl.org/serialization.html#canonical-field-order>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::to_canonical_json;
+/// use serde_json::json;
+///
+/// let tx = json!({
+///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     "Sequence": 2,
+///     "TransactionType": "AccountSet",
+/// });
+///
+/// assert_eq!(
+///     to_canonical_json(&tx).unwrap(),
+///     r#"{"TransactionType":"AccountSet","Sequence":2,"Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"}"#,
+/// );
+/// ```
+pub fn to_canonical_json<T: Serialize>(tx: &T) -> Result<String, XRPLBinaryCodecException> {
+    let mut canonical_json = String::new();
+    _write_canonical_json(&serde_json::to_value(tx)?, &mut canonical_json)?;
+
+    Ok(canonical_json)
+}
+
+/// Orders two field names by their canonical field order.
+fn _cmp_canonical_field_order(field1: &str, field2: &str) -> Ordering {
+    let ordinal = |field_name: &str| {
+        get_field_instance(field_name)
+            .filter(|field_instance| field_instance.is_serialized)
+            .map(|field_instance| field_instance.ordinal)
+    };
+
+    match (ordinal(field1), ordinal(field2)) {
+        (Some(ordinal1), Some(ordinal2)) => ordinal1.cmp(&ordinal2),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => field1.cmp(field2),
+    }
+}
+
+fn _write_canonical_json(
+    value: &Value,
+    canonical_json: &mut String,
+) -> Result<(), XRPLBinaryCodecException> {
+    match value {
+        Value::Object(object) => {
+            let mut fields: Vec<&String> = object.keys().collect();
+            fields.sort_by(|field1, field2| _cmp_canonical_field_order(field1, field2));
+
+            canonical_json.push('{');
+            for (index, field) in fields.into_iter().enumerate() {
+                if index > 0 {
+                    canonical_json.push(',');
+                }
+                canonical_json.push_str(&serde_json::to_string(field)?);
+                canonical_json.push(':');
+                _write_canonical_json(&object[field], canonical_json)?;
+            }
+            canonical_json.push('}');
+        }
+        Value::Array(array) => {
+            canonical_json.push('[');
+            for (index, element) in array.iter().enumerate() {
+                if index > 0 {
+                    canonical_json.push(',');
+                }
+                _write_canonical_json(element, canonical_json)?;
+            }
+            canonical_json.push(']');
+        }
+        _ => canonical_json.push_str(&serde_json::to_string(value)?),
+    }
+
+    Ok(())
+}
+
+/// Marks the end of a nested object in the binary format.
+const OBJECT_END_MARKER: u8 = 0xE1;
+/// Marks the end of an array in the binary format.
+const ARRAY_END_MARKER: u8 = 0xF1;
+
+/// Encode a transaction or ledger object into the XRP Ledger's
+/// canonical binary format, as hex string.
+///
+/// Fields are written in canonical field order. Keys that are
+/// not serialized fields, like `hash`, are skipped. An `Account`
+/// or `Destination` may be given as X-address, its tag is then
+/// encoded as `SourceTag` or `DestinationTag`.
+///
+/// See Serialization Format:
+/// `<https://xrpl.org/serialization.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::encode;
+/// use serde_json::json;
+///
+/// let tx = json!({
+///     "TransactionType": "AccountSet",
+///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     "Sequence": 2,
+/// });
+///
+/// assert_eq!(
+///     encode(&tx).unwrap(),
+///     "120003240000000281144B4E9C06F24296074F7BC48F92A97916C6DC5EA9",
+/// );
+/// ```
+pub fn encode<T: Serialize>(object: &T) -> Result<String, XRPLTypeException> {
+    let value = serde_json::to_value(object).map_err(XRPLBinaryCodecException::from)?;
+
+    match value.as_object() {
+        Some(object) => Ok(to_hex(_serialize_object(&_prepare_object(object)?)?)),
+        None => Err(_invalid_value("Object", &value)),
+    }
+}
+
+/// Encode a transaction into the bytes that are signed, as a
+/// hex string. Only signing fields are serialized and the
+/// result is prefixed with the `TransactionSign` hash prefix.
+///
+/// See Signing Data:
+/// `<https://xrpl.org/serialization.html#hash-prefixes>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::encode_for_signing;
+/// use serde_json::json;
+///
+/// let tx = json!({
+///     "TransactionType": "AccountSet",
+///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     "Sequence": 2,
+///     "TxnSignature": "00",
+/// });
+///
+/// assert_eq!(
+///     encode_for_signing(&tx).unwrap(),
+///     "53545800120003240000000281144B4E9C06F24296074F7BC48F92A97916C6DC5EA9",
+/// );
+/// ```
+pub fn encode_for_signing<T: Serialize>(object: &T) -> Result<String, XRPLTypeException> {
+    let value = serde_json::to_value(object).map_err(XRPLBinaryCodecException::from)?;
+    let object = match value.as_object() {
+        Some(object) => _prepare_object(object)?,
+        None => return Err(_invalid_value("Object", &value)),
+    };
+    let mut data = HashPrefix::TransactionSign.bytes().to_vec();
+    data.extend_from_slice(&_serialize_object(&_signing_fields(object))?);
+
+    Ok(to_hex(data))
+}
+
+/// Encode a transaction into the bytes that are signed by one
+/// signer of a multi-signed transaction, as a hex string. Only
+/// signing fields are serialized, `SigningPubKey` is blanked,
+/// the result is prefixed with the `TransactionMultiSign` hash
+/// prefix and suffixed with the AccountID of `signer_account`.
+///
+/// See Multi-Signing:
+/// `<https://xrpl.org/multi-signing.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::encode_for_multisigning;
+/// use serde_json::json;
+///
+/// let tx = json!({
+///     "TransactionType": "AccountSet",
+///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     "Sequence": 2,
+///     "SigningPubKey": "",
+/// });
+///
+/// assert_eq!(
+///     encode_for_multisigning(&tx, "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap(),
+///     "534D5400120003240000000273008114\
+///     4B4E9C06F24296074F7BC48F92A97916C6DC5EA9\
+///     4B4E9C06F24296074F7BC48F92A97916C6DC5EA9",
+/// );
+/// ```
+pub fn encode_for_multisigning<T: Serialize>(
+    object: &T,
+    signer_account: &str,
+) -> Result<String, XRPLTypeException> {
+    let value = serde_json::to_value(object).map_err(XRPLBinaryCodecException::from)?;
+    let mut object = match value.as_object() {
+        Some(object) => _prepare_object(object)?,
+        None => return Err(_invalid_value("Object", &value)),
+    };
+    object.insert("SigningPubKey".to_string(), Value::from(""));
+
+    let mut data = HashPrefix::TransactionMultiSign.bytes().to_vec();
+    data.extend_from_slice(&_serialize_object(&_signing_fields(object))?);
+    data.extend_from_slice(AccountId::try_from(signer_account)?.as_ref());
+
+    Ok(to_hex(data))
+}
+
+/// Get the identifying hash of a signed transaction from its
+/// hex encoded binary format, e.g. the `tx_blob` returned by
+/// `sign`.
+///
+/// See Identifying Hash:
+/// `<https://xrpl.org/basic-data-types.html#hashes>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::get_hash;
+///
+/// let tx_blob = "120003240000000281144B4E9C06F24296074F7BC48F92A97916C6DC5EA9";
+///
+/// assert_eq!(get_hash(tx_blob).unwrap().len(), 64);
+/// ```
+pub fn get_hash(tx_blob: &str) -> Result<String, XRPLBinaryCodecException> {
+    let mut data = HashPrefix::TransactionId.bytes().to_vec();
+    data.extend_from_slice(&from_hex(tx_blob)?);
+
+    Ok(to_hex(sha512_first_half(&data)))
+}
+
+/// Decode a hex string in the XRP Ledger's canonical binary
+/// format, like a `tx_blob` or the `data` of a ledger object,
+/// into its JSON representation.
+///
+/// See Serialization Format:
+/// `<https://xrpl.org/serialization.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::decode;
+/// use serde_json::json;
+///
+/// let blob = "120003240000000281144B4E9C06F24296074F7BC48F92A97916C6DC5EA9";
+///
+/// assert_eq!(
+///     decode(blob).unwrap(),
+///     json!({
+///         "TransactionType": "AccountSet",
+///         "Sequence": 2,
+///         "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     }),
+/// );
+/// ```
+pub fn decode(hex_string: &str) -> Result<Value, XRPLTypeException> {
+    let mut parser = BinaryParser::try_from(hex_string)?;
+
+    Ok(Value::Object(_deserialize_object(&mut parser, false)?))
+}
+
+/// Deserializes the fields of an object until the end of the
+/// parser or, for nested objects, the object end marker.
+fn _deserialize_object(
+    parser: &mut BinaryParser,
+    nested: bool,
+) -> Result<Map<String, Value>, XRPLTypeException> {
+    let mut object = Map::new();
+
+    while !parser.is_end(None) {
+        if nested && parser.peek() == Some([OBJECT_END_MARKER]) {
+            parser.skip_bytes(1)?;
+            break;
+        }
+
+        let field = parser.read_field()?;
+        let value = _deserialize_field_value(parser, &field)?;
+        object.insert(field.name, value);
+    }
+
+    Ok(object)
+}
+
+/// Deserializes the value of a field according to its type.
+fn _deserialize_field_value(
+    parser: &mut BinaryParser,
+    field: &FieldInstance,
+) -> Result<Value, XRPLTypeException> {
+    let length = if field.is_vl_encoded {
+        Some(parser.read_length_prefix()?)
+    } else {
+        None
+    };
+
+    let value = match field.associated_type.as_str() {
+        "UInt8" => {
+            let number = parser.read_uint8()?;
+            match field.name.as_str() {
+                "TransactionResult" => get_transaction_result_name(&(number as i16))
+                    .map(|name| Value::from(name.as_str()))
+                    .unwrap_or_else(|| Value::from(number)),
+                _ => Value::from(number),
+            }
+        }
+        "UInt16" => {
+            let number = parser.read_uint16()?;
+            let code = number as i16;
+            let name = match field.name.as_str() {
+                "TransactionType" => get_transaction_type_name(&code),
+                "LedgerEntryType" => get_ledger_entry_type_name(&code),
+                _ => None,
+            };
+            match name {
+                Some(name) => Value::from(name.as_str()),
+                None => Value::from(number),
+            }
+        }
+        "UInt32" => Value::from(parser.read_uint32()?),
+        "UInt64" => {
+            let bytes: [u8; 8] = parser.read(8)?.try_into().map_err(|_| {
+                XRPLBinaryCodecException::UnexpectedParserSkipOverflow {
+                    max: parser.len(),
+                    found: 8,
+                }
+            })?;
+            Value::from(to_hex(bytes))
+        }
+        "Hash128" => Value::from(Hash128::from_parser(parser, length)?.to_string()),
+        "Hash160" => Value::from(Hash160::from_parser(parser, length)?.to_string()),
+        "Hash256" => Value::from(Hash256::from_parser(parser, length)?.to_string()),
+        "Amount" => serde_json::to_value(Amount::from_parser(parser, length)?)
+            .map_err(XRPLBinaryCodecException::from)?,
+        "Blob" => Value::from(to_hex(parser.read(length.unwrap_or(0))?)),
+        "AccountID" => Value::from(AccountId::from_parser(parser, length)?.to_string()),
+        "PathSet" => serde_json::to_value(PathSet::from_parser(parser, length)?)
+            .map_err(XRPLBinaryCodecException::from)?,
+        "Vector256" => serde_json::to_value(Vector256::from_parser(parser, length)?)
+            .map_err(XRPLBinaryCodecException::from)?,
+        "SerializedDict" => Value::Object(_deserialize_object(parser, true)?),
+        "SerializedList" => {
+            let mut array = Vec::new();
+            while !parser.is_end(None) {
+                if parser.peek() == Some([ARRAY_END_MARKER]) {
+                    parser.skip_bytes(1)?;
+                    break;
+                }
+                let inner_field = parser.read_field()?;
+                let inner_value = _deserialize_field_value(parser, &inner_field)?;
+                let mut wrapper = Map::new();
+                wrapper.insert(inner_field.name, inner_value);
+                array.push(Value::Object(wrapper));
+            }
+            Value::Array(array)
+        }
+        _ => return Err(XRPLBinaryCodecException::UnsupportedFieldType.into()),
+    };
+
+    Ok(value)
+}
+
+fn _invalid_value(expected: &str, found: &Value) -> XRPLTypeException {
+    XRPLTypeException::JSONParseError(JSONParseException::InvalidSerdeValue {
+        expected: expected.to_owned(),
+        found: found.clone(),
+    })
+}
+
+/// AccountID fields that may be given as X-address, with the
+/// field their tag is moved to.
+const XADDRESS_TAG_FIELDS: [(&str, &str); 2] =
+    [("Account", "SourceTag"), ("Destination", "DestinationTag")];
+
+/// Prepares the JSON of an object for serialization, see
+/// `_handle_xaddresses` and `_handle_deliver_max`.
+fn _prepare_object(object: &Map<String, Value>) -> Result<Map<String, Value>, XRPLTypeException> {
+    let mut object = _handle_xaddresses(object)?;
+    _handle_deliver_max(&mut object)?;
+
+    Ok(object)
+}
+
+/// Replaces `DeliverMax`, the API v2 name of the `Amount` of a
+/// payment that has no binary field, with `Amount`. Errors if
+/// both are set to different amounts.
+///
+/// See Payment Fields:
+/// `<https://xrpl.org/payment.html#payment-fields>`
+fn _handle_deliver_max(object: &mut Map<String, Value>) -> Result<(), XRPLTypeException> {
+    if let Some(deliver_max) = object.remove("DeliverMax").filter(|value| !value.is_null()) {
+        match object.get("Amount") {
+            Some(amount) if !amount.is_null() && amount != &deliver_max => {
+                return Err(XRPLBinaryCodecException::DeliverMaxMismatch.into());
+            }
+            _ => {
+                object.insert("Amount".to_string(), deliver_max);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Replaces X-addresses of the `Account` and `Destination`
+/// fields with their classic address and moves their tag into
+/// the `SourceTag` or `DestinationTag` field. Errors if that field
+/// is set to a different tag.
+///
+/// See X-Address Format:
+/// `<https://xrpaddress.info>`
+fn _handle_xaddresses(
+    object: &Map<String, Value>,
+) -> Result<Map<String, Value>, XRPLTypeException> {
+    let mut object = object.clone();
+
+    for (field, tag_field) in XADDRESS_TAG_FIELDS.iter() {
+        let xaddress = match object.get(*field).and_then(Value::as_str) {
+            Some(address) if is_valid_xaddress(address) => address.to_owned(),
+            _ => continue,
+        };
+        let (classic_address, tag, _) =
+            xaddress_to_classic_address(&xaddress).map_err(XRPLHashException::from)?;
+
+        if let Some(tag) = tag {
+            match object.get(*tag_field) {
+                Some(existing_tag) if existing_tag.as_u64() != Some(tag) => {
+                    return Err(XRPLBinaryCodecException::XAddressTagMismatch {
+                        field: field.to_string(),
+                    }
+                    .into());
+                }
+                _ => {
+                    object.insert(tag_field.to_string(), Value::from(tag));
+                }
+            }
+        }
+        object.insert(field.to_string(), Value::from(classic_address));
+    }
+
+    Ok(object)
+}
+
+/// Keeps only the fields that are part of the signed data,
+/// dropping `TxnSignature`, `Signers` and other non-signing
+/// fields.
+fn _signing_fields(object: Map<String, Value>) -> Map<String, Value> {
+    object
+        .into_iter()
+        .filter(|(field_name, _)| {
+            get_field_instance(field_name).is_some_and(|field| field.is_signing)
+        })
+        .collect()
+}
+
+/// Serializes the fields of an object in canonical field order.
+/// Fields set to `null`, like the unset fields of a `Memo`, are
+/// treated as absent. Lowercase fields like `hash` or
+/// `ledger_index` are API metadata and skipped, other fields
+/// missing from the definitions error instead of being dropped
+/// from the binary format.
+fn _serialize_object(object: &Map<String, Value>) -> Result<Vec<u8>, XRPLTypeException> {
+    let mut fields: Vec<FieldInstance> = Vec::new();
+
+    for (field_name, _) in object.iter().filter(|(_, value)| !value.is_null()) {
+        match get_field_instance(field_name) {
+            Some(field_instance) if field_instance.is_serialized => fields.push(field_instance),
+            Some(_) => (),
+            None if field_name.starts_with(char::is_lowercase) => (),
+            None => {
+                return Err(XRPLBinaryCodecException::UnknownField {
+                    field: field_name.to_owned(),
+                }
+                .into())
+            }
+        }
+    }
+    fields.sort_by_key(|field_instance| field_instance.ordinal);
+
+    let mut serializer = BinarySerializer::new();
+    for field in fields {
+        let value = _serialize_field_value(&field, &object[&field.name])?;
+        serializer.write_field_and_value(field, &value);
+    }
+
+    Ok(serializer)
+}
+
+/// Serializes the value of a field according to its type.
+fn _serialize_field_value(
+    field: &FieldInstance,
+    value: &Value,
+) -> Result<Vec<u8>, XRPLTypeException> {
+    let as_str = || {
+        value
+            .as_str()
+            .ok_or_else(|| _invalid_value("String", value))
+    };
+
+    match field.associated_type.as_str() {
+        "UInt8" => {
+            let number = match field.name.as_str() {
+                "TransactionResult" => value
+                    .as_str()
+                    .and_then(get_transaction_result_code)
+                    .map(|code| *code as u64),
+                _ => value.as_u64(),
+            };
+            let number: u8 = number
+                .ok_or_else(|| _invalid_value("UInt8", value))?
+                .try_into()
+                .map_err(XRPLBinaryCodecException::from)?;
+            Ok(number.to_be_bytes().to_vec())
+        }
+        "UInt16" => {
+            let number = match field.name.as_str() {
+                "TransactionType" => match value.as_str() {
+                    Some(name) => match get_transaction_type_code(name) {
+                        Some(code) => Some(*code as u64),
+                        None => {
+                            return Err(XRPLBinaryCodecException::UnknownTransactionType {
+                                found: name.to_owned(),
+                            }
+                            .into())
+                        }
+                    },
+                    None => None,
+                },
+                "LedgerEntryType" => value
+                    .as_str()
+                    .and_then(get_ledger_entry_type_code)
+                    .map(|code| *code as u64),
+                _ => value.as_u64(),
+            };
+            let number: u16 = number
+                .ok_or_else(|| _invalid_value("UInt16", value))?
+                .try_into()
+                .map_err(XRPLBinaryCodecException::from)?;
+            Ok(number.to_be_bytes().to_vec())
+        }
+        "UInt32" => {
+            let number = match value {
+                Value::String(number) => number.parse::<u64>().ok(),
+                _ => value.as_u64(),
+            };
+            let number: u32 = number
+                .ok_or_else(|| _invalid_value("UInt32", value))?
+                .try_into()
+                .map_err(XRPLBinaryCodecException::from)?;
+            Ok(number.to_be_bytes().to_vec())
+        }
+        "UInt64" => {
+            let number =
+                u64::from_str_radix(as_str()?, 16).map_err(XRPLBinaryCodecException::from)?;
+            Ok(number.to_be_bytes().to_vec())
+        }
+        "Hash128" => Ok(Hash128::try_from(as_str()?)?.as_ref().to_vec()),
+        "Hash160" => Ok(Hash160::try_from(as_str()?)?.as_ref().to_vec()),
+        "Hash256" => Ok(Hash256::try_from(as_str()?)?.as_ref().to_vec()),
+        "Amount" => Ok(Amount::try_from(value.clone())?.as_ref().to_vec()),
+        "Blob" => Ok(from_hex(as_str()?)?),
+        "AccountID" => Ok(AccountId::try_from(as_str()?)?.as_ref().to_vec()),
+        "PathSet" => {
+            let path_set: Vec<Vec<IndexMap<String, String>>> =
+                serde_json::from_value(value.clone())
+                    .map_err(|_| _invalid_value("PathSet", value))?;
+            Ok(PathSet::try_from(path_set)?.as_ref().to_vec())
+        }
+        "Vector256" => {
+            let hashes = value
+                .as_array()
+                .and_then(|hashes| {
+                    hashes
+                        .iter()
+                        .map(Value::as_str)
+                        .collect::<Option<Vec<&str>>>()
+                })
+                .ok_or_else(|| _invalid_value("Array of Strings", value))?;
+            Ok(Vector256::try_from(hashes)?.as_ref().to_vec())
+        }
+        "SerializedDict" => {
+            let object = value
+                .as_object()
+                .ok_or_else(|| _invalid_value("Object", value))?;
+            let mut bytes = _serialize_object(object)?;
+            bytes.push(OBJECT_END_MARKER);
+            Ok(bytes)
+        }
+        "SerializedList" => {
+            let array = value
+                .as_array()
+                .ok_or_else(|| _invalid_value("Array", value))?;
+            let mut serializer = BinarySerializer::new();
+            for element in array {
+                let wrapper = element
+                    .as_object()
+                    .filter(|wrapper| wrapper.len() == 1)
+                    .ok_or_else(|| _invalid_value("Object with a single field", element))?;
+                let (field_name, inner_value) = wrapper.iter().next().expect("one field");
+                let inner_field = get_field_instance(field_name)
+                    .ok_or(XRPLBinaryCodecException::UnknownFieldName)?;
+                let inner_bytes = _serialize_field_value(&inner_field, inner_value)?;
+                serializer.write_field_and_value(inner_field, &inner_bytes);
+            }
+            serializer.push(ARRAY_END_MARKER);
+            Ok(serializer)
+        }
+        _ => Err(XRPLBinaryCodecException::UnsupportedFieldType.into()),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -642,7 +1283,7 @@ mod test {
 
     #[test]
     fn test_binaryparser_from() {
-        let test_bytes: Vec<u8> = hex::decode(TEST_HEX).expect("");
+        let test_bytes: Vec<u8> = from_hex(TEST_HEX).expect("");
         let ref_bytes: &[u8] = test_bytes.as_ref();
         let slice_parser = BinaryParser::from(ref_bytes);
         let vec_parser = BinaryParser::from(test_bytes.to_owned());
@@ -653,7 +1294,7 @@ mod test {
 
     #[test]
     fn test_binaryparser_try_from() {
-        let test_bytes: Vec<u8> = hex::decode(TEST_HEX).expect("");
+        let test_bytes: Vec<u8> = from_hex(TEST_HEX).expect("");
         let string_parser = BinaryParser::try_from(TEST_HEX).unwrap();
 
         assert_eq!(string_parser, test_bytes[..]);
@@ -661,7 +1302,7 @@ mod test {
 
     #[test]
     fn test_peek() {
-        let test_bytes: Vec<u8> = hex::decode(TEST_HEX).expect("");
+        let test_bytes: Vec<u8> = from_hex(TEST_HEX).expect("");
         let binary_parser = BinaryParser::from(test_bytes.as_ref());
 
         assert_eq!(binary_parser.peek(), Some([test_bytes[0]; 1]));
@@ -669,7 +1310,7 @@ mod test {
 
     #[test]
     fn test_skip_bytes() {
-        let test_bytes: Vec<u8> = hex::decode(TEST_HEX).expect("");
+        let test_bytes: Vec<u8> = from_hex(TEST_HEX).expect("");
         let mut binary_parser = BinaryParser::from(test_bytes.as_ref());
 
         assert!(binary_parser.skip_bytes(4).is_ok());
@@ -678,7 +1319,7 @@ mod test {
 
     #[test]
     fn test_read() {
-        let test_bytes: Vec<u8> = hex::decode(TEST_HEX).expect("");
+        let test_bytes: Vec<u8> = from_hex(TEST_HEX).expect("");
         let mut binary_parser = BinaryParser::from(test_bytes.as_ref());
         let result = binary_parser.read(5);
 
@@ -689,7 +1330,7 @@ mod test {
     #[test]
     fn test_read_uint8() {
         let test_hex: &str = "01000200000003";
-        let test_bytes: Vec<u8> = hex::decode(test_hex).expect("");
+        let test_bytes: Vec<u8> = from_hex(test_hex).expect("");
         let mut binary_parser = BinaryParser::from(test_bytes.as_ref());
         let result = binary_parser.read_uint8();
 
@@ -700,7 +1341,7 @@ mod test {
     #[test]
     fn test_read_uint16() {
         let test_hex: &str = "000200000003";
-        let test_bytes: Vec<u8> = hex::decode(test_hex).expect("");
+        let test_bytes: Vec<u8> = from_hex(test_hex).expect("");
         let mut binary_parser = BinaryParser::from(test_bytes.as_ref());
         let result = binary_parser.read_uint16();
 
@@ -711,7 +1352,7 @@ mod test {
     #[test]
     fn test_read_uint32() {
         let test_hex: &str = "00000003";
-        let test_bytes: Vec<u8> = hex::decode(test_hex).expect("");
+        let test_bytes: Vec<u8> = from_hex(test_hex).expect("");
         let mut binary_parser = BinaryParser::from(test_bytes.as_ref());
         let result = binary_parser.read_uint32();
 
@@ -721,7 +1362,7 @@ mod test {
 
     #[test]
     fn test_read_length_prefix() {
-        let test_bytes: Vec<u8> = hex::decode(TEST_HEX).expect("");
+        let test_bytes: Vec<u8> = from_hex(TEST_HEX).expect("");
         let mut binary_parser = BinaryParser::from(test_bytes.as_ref());
         let result = binary_parser.read_length_prefix();
 
@@ -744,7 +1385,7 @@ mod test {
 
     #[test]
     fn accept_peek_skip_read() {
-        let test_bytes: Vec<u8> = hex::decode(TEST_HEX).expect("");
+        let test_bytes: Vec<u8> = from_hex(TEST_HEX).expect("");
         let mut binary_parser = BinaryParser::from(test_bytes.as_ref());
 
         assert_eq!(binary_parser.peek(), Some([test_bytes[0]; 1]));
@@ -760,20 +1401,20 @@ mod test {
     #[test]
     fn test_binaryserializer_write_field_and_value() {
         let field_header = FieldHeader {
-            type_code: -2,
-            field_code: 0,
+            type_code: 7,
+            field_code: 3,
         };
 
         let field_info = FieldInfo {
-            nth: 0,
-            is_vl_encoded: false,
-            is_serialized: false,
-            is_signing_field: false,
-            r#type: "Unknown".to_string(),
+            nth: 3,
+            is_vl_encoded: true,
+            is_serialized: true,
+            is_signing_field: true,
+            r#type: "Blob".to_string(),
         };
 
-        let field_instance = FieldInstance::new(&field_info, "Generic", field_header);
-        let expected: Vec<u8> = [255, 224, 0, 17, 34].to_vec();
+        let field_instance = FieldInstance::new(&field_info, "SigningPubKey", field_header);
+        let expected: Vec<u8> = [115, 3, 0, 17, 34].to_vec();
         let test_bytes: Vec<u8> = [0, 17, 34].to_vec();
         let mut serializer: BinarySerializer = BinarySerializer::new();
 
@@ -790,7 +1431,7 @@ mod test {
             let blob = (0..case).map(|_| "A2").collect::<String>();
             let mut binary_serializer: BinarySerializer = BinarySerializer::new();
 
-            binary_serializer.write_length_encoded(&hex::decode(blob).expect(""));
+            binary_serializer.write_length_encoded(&from_hex(blob).expect(""));
 
             let mut binary_parser: BinaryParser = BinaryParser::from(binary_serializer.as_ref());
             let decoded_length = binary_parser.read_length_prefix();
@@ -799,4 +1440,294 @@ mod test {
             assert_eq!(decoded_length, Ok(case));
         }
     }
+
+    #[test]
+    fn test_to_canonical_json() {
+        let tx = serde_json::json!({
+            "Memos": [{"Memo": {"MemoType": "72656e74", "MemoData": "72656e74"}}],
+            "Amount": {"value": "1", "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn", "currency": "USD"},
+            "Flags": 0,
+            "TransactionType": "Payment",
+        });
+
+        assert_eq!(
+            to_canonical_json(&tx).unwrap(),
+            r#"{"TransactionType":"Payment","Flags":0,"Amount":{"currency":"USD","issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","value":"1"},"Memos":[{"Memo":{"MemoType":"72656e74","MemoData":"72656e74"}}]}"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_encode {
+    use super::*;
+    use crate::core::addresscodec::classic_address_to_xaddress;
+    use crate::core::binarycodec::test_cases::{load_codec_fixtures, load_sign_fixtures};
+    use crate::models::amount::XRPAmount;
+    use crate::models::transactions::{CommonFields, MPTokenIssuanceCreate, Memo, Payment};
+
+    #[test]
+    fn test_encode_codec_fixtures() {
+        let fixtures = load_codec_fixtures();
+
+        for fixture in fixtures
+            .account_state
+            .iter()
+            .chain(fixtures.transactions.iter())
+        {
+            assert_eq!(encode(&fixture.json).unwrap(), fixture.binary);
+        }
+    }
+
+    #[test]
+    fn test_encode_sign_fixtures() {
+        for fixture in load_sign_fixtures() {
+            let tx_blob = encode(&fixture.tx_json).unwrap();
+
+            assert_eq!(tx_blob, fixture.tx_blob);
+            assert_eq!(get_hash(&tx_blob).unwrap(), fixture.hash);
+            assert_eq!(fixture.tx_json["hash"], fixture.hash.as_str());
+        }
+    }
+
+    #[test]
+    fn test_encode_for_signing_sign_fixtures() {
+        for fixture in load_sign_fixtures() {
+            let mut unsigned = fixture.tx_json.clone();
+            unsigned.as_object_mut().unwrap().remove("TxnSignature");
+
+            assert_eq!(
+                encode_for_signing(&fixture.tx_json).unwrap(),
+                alloc::format!("53545800{}", encode(&unsigned).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn test_sign_sign_fixtures() {
+        let (_, private_key) =
+            crate::core::keypairs::derive_keypair("snoPBrXtMeMyMHUVTgbuqAfg1SUTb", false).unwrap();
+
+        for fixture in load_sign_fixtures() {
+            let signing_blob = from_hex(&encode_for_signing(&fixture.tx_json).unwrap()).unwrap();
+
+            assert_eq!(
+                crate::core::keypairs::sign(&signing_blob, &private_key).unwrap(),
+                fixture.tx_json["TxnSignature"]
+            );
+        }
+    }
+
+    /// The signing vectors of `ripple-binary-codec`.
+    fn signing_vector_tx() -> Value {
+        serde_json::json!({
+            "Account": "r9LqNeG6qHxjeUocjvVki2XR35weJ9mZgQ",
+            "Amount": "1000",
+            "Destination": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            "Fee": "10",
+            "Flags": 2147483648u32,
+            "Sequence": 1,
+            "TransactionType": "Payment",
+            "TxnSignature": "30440220718D264EF05CAED7C781FF6DE298DCAC68D002562C9BF3A07C1\
+                E721B420C0DAB02203A5A4779EF4D2CCC7BC3EF886676D803A9981B928D3B8ACA483B80\
+                ECA3CD7B9B",
+            "SigningPubKey": "ED5F5AC8B98974A3CA843326D9B88CEBD0560177B973EE0B149F782CFAA06DC66A",
+        })
+    }
+
+    #[test]
+    fn test_encode_for_signing_vector() {
+        assert_eq!(
+            encode_for_signing(&signing_vector_tx()).unwrap(),
+            "53545800120000228000000024000000016140000000000003E868400000000000000A\
+            7321ED5F5AC8B98974A3CA843326D9B88CEBD0560177B973EE0B149F782CFAA06DC66A\
+            81145B812C9D57731E27A2DA8B1830195F88EF32A3B68314B5F762798A53D543A014CAF8\
+            B297CFF8F2F937E8"
+        );
+    }
+
+    #[test]
+    fn test_encode_for_multisigning_vector() {
+        let mut tx = signing_vector_tx();
+        tx["Signers"] = serde_json::json!([{
+            "Signer": {
+                "Account": "rJZdUusLDtY9NEsGea7ijqhVrXv98rYBYN",
+                "SigningPubKey": "ED5F5AC8B98974A3CA843326D9B88CEBD0560177B973EE0B149F782CFAA06DC66A",
+                "TxnSignature": "00",
+            }
+        }]);
+
+        assert_eq!(
+            encode_for_multisigning(&tx, "rJZdUusLDtY9NEsGea7ijqhVrXv98rYBYN").unwrap(),
+            "534D5400120000228000000024000000016140000000000003E868400000000000000A\
+            730081145B812C9D57731E27A2DA8B1830195F88EF32A3B68314B5F762798A53D543A014\
+            CAF8B297CFF8F2F937E8C0A5ABEF242802EFED4B041E8F2D4A8CC86AE3D1"
+        );
+        assert!(encode_for_multisigning(&tx, "not an address").is_err());
+    }
+
+    #[test]
+    fn test_encode_memo_order() {
+        let first = Memo::new(Some("6669727374".into()), None, None);
+        let second = Memo::new(Some("7365636F6E64".into()), None, None);
+        let payment = |memos| Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                sequence: Some(1),
+                memos: Some(memos),
+                ..Default::default()
+            },
+            amount: XRPAmount::from("1000000").into(),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        };
+        let tx_blob = encode(&payment(vec![first.clone(), second.clone()])).unwrap();
+        let reversed_tx_blob = encode(&payment(vec![second, first])).unwrap();
+        let memos = &decode(&tx_blob).unwrap()["Memos"];
+
+        assert_eq!(memos[0]["Memo"]["MemoData"], "6669727374");
+        assert_eq!(memos[1]["Memo"]["MemoData"], "7365636F6E64");
+        assert_ne!(
+            get_hash(&tx_blob).unwrap(),
+            get_hash(&reversed_tx_blob).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_encode_xaddress() {
+        let destination = "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK";
+        let xaddress = classic_address_to_xaddress(destination, Some(12345), false).unwrap();
+        let payment = |destination: &str, destination_tag: Option<u32>| Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                sequence: Some(1),
+                ..Default::default()
+            },
+            amount: XRPAmount::from("1000000").into(),
+            destination: destination.to_owned().into(),
+            destination_tag,
+            ..Default::default()
+        };
+        let tx_blob = encode(&payment(destination, Some(12345))).unwrap();
+
+        assert_eq!(encode(&payment(&xaddress, None)).unwrap(), tx_blob);
+        assert_eq!(encode(&payment(&xaddress, Some(12345))).unwrap(), tx_blob);
+        assert_eq!(decode(&tx_blob).unwrap()["Destination"], destination);
+        assert_eq!(decode(&tx_blob).unwrap()["DestinationTag"], 12345);
+        assert_eq!(
+            encode(&payment(&xaddress, Some(1))),
+            Err(XRPLBinaryCodecException::XAddressTagMismatch {
+                field: "Destination".to_string(),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn test_encode_error() {
+        assert!(encode(&"not an object").is_err());
+        assert!(encode(&serde_json::json!({ "Sequence": "abc" })).is_err());
+    }
+
+    #[test]
+    fn test_encode_unknown_definitions() {
+        let mptoken_issuance_create = MPTokenIssuanceCreate {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            encode(&mptoken_issuance_create),
+            Err(XRPLBinaryCodecException::UnknownTransactionType {
+                found: "MPTokenIssuanceCreate".to_string(),
+            }
+            .into())
+        );
+        assert_eq!(
+            encode(&serde_json::json!({
+                "TransactionType": "Payment",
+                "MaximumAmount": "100",
+            })),
+            Err(XRPLBinaryCodecException::UnknownField {
+                field: "MaximumAmount".to_string(),
+            }
+            .into())
+        );
+        assert!(encode(&serde_json::json!({
+            "TransactionType": "Payment",
+            "hash": "00",
+            "ledger_index": 1,
+        }))
+        .is_ok());
+    }
+
+    #[test]
+    fn test_encode_deliver_max() {
+        let payment = |amount: Option<&str>, deliver_max: Option<&str>| {
+            serde_json::json!({
+                "TransactionType": "Payment",
+                "Account": "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
+                "Destination": "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK",
+                "Amount": amount,
+                "DeliverMax": deliver_max,
+            })
+        };
+        let tx_blob = encode(&payment(Some("1000000"), None)).unwrap();
+
+        assert_eq!(encode(&payment(None, Some("1000000"))).unwrap(), tx_blob);
+        assert_eq!(
+            encode(&payment(Some("1000000"), Some("1000000"))).unwrap(),
+            tx_blob
+        );
+        assert_eq!(
+            encode(&payment(Some("1000000"), Some("2000000"))),
+            Err(XRPLBinaryCodecException::DeliverMaxMismatch.into())
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_decode {
+    use super::*;
+    use crate::core::binarycodec::test_cases::{load_codec_fixtures, load_sign_fixtures};
+
+    #[test]
+    fn test_decode_codec_fixtures() {
+        let fixtures = load_codec_fixtures();
+
+        for fixture in fixtures
+            .account_state
+            .iter()
+            .chain(fixtures.transactions.iter())
+        {
+            let decoded = decode(&fixture.binary).unwrap();
+
+            assert_eq!(encode(&decoded).unwrap(), fixture.binary);
+            for (field, value) in decoded.as_object().unwrap() {
+                assert_eq!(&fixture.json[field], value, "{}", field);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_sign_fixtures() {
+        for fixture in load_sign_fixtures() {
+            let decoded = decode(&fixture.tx_blob).unwrap();
+
+            assert_eq!(
+                decoded["TransactionType"],
+                fixture.tx_json["TransactionType"]
+            );
+            assert_eq!(decoded["TxnSignature"], fixture.tx_json["TxnSignature"]);
+            assert_eq!(encode(&decoded).unwrap(), fixture.tx_blob);
+        }
+    }
+
+    #[test]
+    fn test_decode_error() {
+        assert!(decode("not hex").is_err());
+        assert!(decode("12").is_err());
+    }
 }
@@ -38,6 +38,38 @@ pub struct ValueTest {
     pub error: Option<String>,
 }
 
+/// An object together with its binary format, as serialized
+/// by rippled.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CodecFixture {
+    pub binary: String,
+    pub json: Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CodecFixtures {
+    pub account_state: Vec<CodecFixture>,
+    pub transactions: Vec<CodecFixture>,
+}
+
+/// A transaction of the genesis account signed with its
+/// `masterpassphrase` seed, with its `tx_blob` and identifying
+/// hash.
+///
+/// The fixtures are not captured from rippled. They are generated
+/// by `test_data/generate-sign-fixtures.py`, an independent Python
+/// implementation of the binary format and of secp256k1 signing,
+/// and are unverified against rippled. The signatures are
+/// deterministic (RFC 6979), so they can be compared with
+/// `rippled sign snoPBrXtMeMyMHUVTgbuqAfg1SUTb '<tx_json>' offline`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SignFixture {
+    pub tx_json: Value,
+    pub tx_blob: String,
+    pub hash: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TestDefinitions {
     pub types: Vec<Type>,
@@ -48,7 +80,6 @@ pub struct TestDefinitions {
 
 fn _load_tests() -> &'static Option<TestDefinitions> {
     pub const DATA_DRIVEN_TESTS: &str = include_str!("../test_data/data-driven-tests.json");
-    pub const CODEC_TEST_FIXTURES: &str = include_str!("../test_data/codec-fixtures.json");
     pub const X_CODEC_TEST_FIXTURES: &str = include_str!("../test_data/x-codec-fixtures.json");
 
     lazy_static! {
@@ -81,3 +112,18 @@ pub fn load_data_tests(test_type: Option<&str>) -> Vec<ValueTest> {
         defintions.values_tests.clone()
     }
 }
+
+/// Retrieve the ledger objects and transactions with their
+/// binary format.
+pub fn load_codec_fixtures() -> CodecFixtures {
+    pub const CODEC_TEST_FIXTURES: &str = include_str!("../test_data/codec-fixtures.json");
+
+    serde_json::from_str(CODEC_TEST_FIXTURES).expect("load_codec_fixtures")
+}
+
+/// Retrieve the transactions signed by the genesis account.
+pub fn load_sign_fixtures() -> Vec<SignFixture> {
+    pub const SIGN_TEST_FIXTURES: &str = include_str!("../test_data/sign-fixtures.json");
+
+    serde_json::from_str(SIGN_TEST_FIXTURES).expect("load_sign_fixtures")
+}
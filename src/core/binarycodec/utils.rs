@@ -6,6 +6,7 @@ use crate::core::definitions::DefinitionHandler;
 use crate::core::definitions::FieldHeader;
 use crate::core::definitions::CODE_MAX_VALUE;
 use crate::core::definitions::CODE_MIN_VALUE;
+use crate::utils::hex::from_hex;
 use alloc::vec;
 use alloc::vec::Vec;
 
@@ -92,7 +93,7 @@ fn _encode_field_id(field_header: &FieldHeader) -> Result<Vec<u8>, XRPLBinaryCod
 
 /// See: `<https://xrpl.org/serialization.html#field-ids>`
 fn _decode_field_id(field_id: &str) -> Result<FieldHeader, XRPLBinaryCodecException> {
-    let bytes = hex::decode(field_id)?;
+    let bytes = from_hex(field_id)?;
 
     match bytes.len() {
         1 => {
@@ -232,11 +233,12 @@ pub fn decode_field_name(field_id: &str) -> Result<&str, XRPLBinaryCodecExceptio
 mod test {
     use super::*;
     use crate::core::binarycodec::test_cases::load_field_tests;
+    use crate::utils::hex::to_hex;
 
     #[test]
     fn test_encode_field_name() {
         for test in load_field_tests() {
-            let result = hex::encode_upper(encode_field_name(&test.name).expect(""));
+            let result = to_hex(encode_field_name(&test.name).expect(""));
             assert_eq!(test.expected_hex, result)
         }
     }
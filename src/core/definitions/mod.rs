@@ -141,28 +141,23 @@ impl ToString for FieldHeader {
 
 impl ToBytes for FieldHeader {
     /// Convert the FieldHeader to a Vec<u8>.
+    ///
+    /// See Field IDs:
+    /// `<https://xrpl.org/serialization.html#field-ids>`
     fn to_bytes(&self) -> Vec<u8> {
-        let mut header_bytes = vec![];
+        let type_code = self.type_code as u8;
+        let field_code = self.field_code as u8;
 
-        if self.type_code < 16 {
-            if self.field_code < 16 {
-                let shift = self.type_code << 4 | self.field_code;
-                header_bytes.extend_from_slice(&shift.to_be_bytes());
+        if type_code < 16 {
+            if field_code < 16 {
+                vec![type_code << 4 | field_code]
             } else {
-                let shift = self.type_code << 4;
-
-                header_bytes.extend_from_slice(&shift.to_be_bytes());
-                header_bytes.extend_from_slice(&self.field_code.to_be_bytes());
+                vec![type_code << 4, field_code]
             }
-        } else if self.field_code < 16 {
-            header_bytes.extend_from_slice(&self.field_code.to_be_bytes());
-            header_bytes.extend_from_slice(&self.type_code.to_be_bytes());
+        } else if field_code < 16 {
+            vec![field_code, type_code]
         } else {
-            header_bytes.extend_from_slice(&[0]);
-            header_bytes.extend_from_slice(&self.type_code.to_be_bytes());
-            header_bytes.extend_from_slice(&self.field_code.to_be_bytes());
+            vec![0, type_code, field_code]
         }
-
-        header_bytes
     }
 }
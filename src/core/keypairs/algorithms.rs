@@ -11,6 +11,7 @@ use crate::constants::CryptoAlgorithm;
 use crate::core::keypairs::exceptions::XRPLKeypairsException;
 use crate::core::keypairs::utils::*;
 use crate::core::keypairs::CryptoImplementation;
+use crate::utils::hex::{from_hex, to_hex};
 use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -32,12 +33,12 @@ pub struct Ed25519;
 impl Secp256k1 {
     /// Hex encode the private key.
     fn _private_key_to_str(key: secp256k1::SecretKey) -> String {
-        hex::encode_upper(key.as_ref())
+        to_hex(key.as_ref())
     }
 
     /// Hex encode the public key.
     fn _public_key_to_str(key: secp256k1::PublicKey) -> String {
-        hex::encode_upper(key.serialize())
+        to_hex(key.serialize())
     }
 
     /// Format a provided key.
@@ -139,12 +140,12 @@ impl Secp256k1 {
 impl Ed25519 {
     /// Hex encode the private key.
     fn _private_key_to_str(key: ed25519_dalek::SecretKey) -> String {
-        hex::encode(key)
+        to_hex(key)
     }
 
     /// Hex encode the public key.
     fn _public_key_to_str(key: ed25519_dalek::PublicKey) -> String {
-        hex::encode(key.as_ref())
+        to_hex(key.as_ref())
     }
 
     /// Format a provided key.
@@ -266,7 +267,17 @@ impl CryptoImplementation for Secp256k1 {
     ) -> Result<Vec<u8>, XRPLKeypairsException> {
         let secp = secp256k1::Secp256k1::<secp256k1::SignOnly>::signing_only();
         let message = Self::_get_message(message_bytes)?;
-        let trimmed_key = private_key.trim_start_matches(SECP256K1_PREFIX);
+        // Only strip the padding, the key itself may start with zeros.
+        let padding = private_key
+            .len()
+            .saturating_sub(secp256k1::constants::SECRET_KEY_SIZE * 2);
+        let trimmed_key = match private_key.as_bytes()[..padding]
+            .iter()
+            .all(|byte| *byte == SECP256K1_PREFIX as u8)
+        {
+            true => &private_key[padding..],
+            false => private_key,
+        };
         let private = secp256k1::SecretKey::from_str(trimmed_key)?;
         let signature = secp.sign_ecdsa(&message, &private);
 
@@ -303,7 +314,7 @@ impl CryptoImplementation for Secp256k1 {
         let secp = secp256k1::Secp256k1::<secp256k1::VerifyOnly>::verification_only();
         let msg = Self::_get_message(message_bytes);
 
-        if let Ok(value) = hex::decode(signature) {
+        if let Ok(value) = from_hex(signature) {
             let sig = ecdsa::Signature::from_der(&value);
             let public = secp256k1::PublicKey::from_str(public_key);
 
@@ -412,7 +423,7 @@ impl CryptoImplementation for Ed25519 {
     /// assert_eq!(Some(signature), signing);
     /// ```
     fn sign(&self, message: &[u8], private_key: &str) -> Result<Vec<u8>, XRPLKeypairsException> {
-        let raw_private = hex::decode(&private_key[ED25519_PREFIX.len()..])?;
+        let raw_private = from_hex(&private_key[ED25519_PREFIX.len()..])?;
         let private = ed25519_dalek::SecretKey::from_bytes(&raw_private)?;
         let expanded_private = ed25519_dalek::ExpandedSecretKey::from(&private);
         let public = ed25519_dalek::PublicKey::from(&private);
@@ -447,8 +458,8 @@ impl CryptoImplementation for Ed25519 {
     /// ));
     /// ```
     fn is_valid_message(&self, message: &[u8], signature: &str, public_key: &str) -> bool {
-        let raw_public = hex::decode(&public_key[ED25519_PREFIX.len()..]);
-        let decoded_sig = hex::decode(signature);
+        let raw_public = from_hex(&public_key[ED25519_PREFIX.len()..]);
+        let decoded_sig = from_hex(signature);
 
         if raw_public.is_err() || decoded_sig.is_err() {
             return false;
@@ -501,9 +512,18 @@ mod test {
         assert!(error.is_err());
     }
 
+    #[test]
+    fn test_secp256k1_sign_leading_zero_key() {
+        let private_key = format!("000F{}", "1".repeat(62));
+
+        assert!(Secp256k1
+            .sign(TEST_MESSAGE.as_bytes(), &private_key)
+            .is_ok());
+    }
+
     #[test]
     fn test_secp256k1_is_valid_message() {
-        let signature: &str = &hex::encode_upper(SIGNATURE_SECP256K1);
+        let signature: &str = &to_hex(SIGNATURE_SECP256K1);
         let message: &[u8] = TEST_MESSAGE.as_bytes();
 
         assert!(Secp256k1.is_valid_message(message, signature, PUBLIC_SECP256K1));
@@ -531,7 +551,7 @@ mod test {
 
     #[test]
     fn test_ed25519_is_valid_message() {
-        let signature: &str = &hex::encode_upper(SIGNATURE_ED25519);
+        let signature: &str = &to_hex(SIGNATURE_ED25519);
         let message: &[u8] = TEST_MESSAGE.as_bytes();
 
         assert!(Ed25519.is_valid_message(message, signature, PUBLIC_ED25519));
@@ -9,7 +9,14 @@ use strum_macros::Display;
 pub enum XRPLKeypairsException {
     InvalidSignature,
     InvalidSecret,
-    UnsupportedValidatorAlgorithm { expected: CryptoAlgorithm },
+    InvalidKey,
+    UnsupportedValidatorAlgorithm {
+        expected: CryptoAlgorithm,
+    },
+    AlgorithmMismatch {
+        expected: CryptoAlgorithm,
+        found: CryptoAlgorithm,
+    },
     ED25519Error,
     SECP256K1Error,
     FromHexError,
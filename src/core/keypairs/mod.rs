@@ -15,6 +15,7 @@ use crate::core::addresscodec::utils::SEED_LENGTH;
 use crate::core::addresscodec::*;
 use crate::core::keypairs::exceptions::XRPLKeypairsException;
 use crate::core::keypairs::utils::*;
+use crate::utils::hex::{from_hex, to_hex};
 use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -29,11 +30,30 @@ const fn _get_algorithm_sig_length(algo: CryptoAlgorithm) -> usize {
     }
 }
 
-/// Return the CryptoAlgorithm from a key.
-fn _get_algorithm_from_key(key: &str) -> CryptoAlgorithm {
-    match &key[..2] {
-        ED25519_PREFIX => CryptoAlgorithm::ED25519,
-        _ => CryptoAlgorithm::SECP256K1,
+/// Return the CryptoAlgorithm of a hex encoded public or
+/// private key. Ed25519 keys are prefixed with `ED`, every
+/// other key is treated as a SECP256K1 key. Errors if the
+/// key is too short to carry a prefix.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::keypairs::get_algorithm_from_key;
+/// use xrpl::constants::CryptoAlgorithm;
+///
+/// let public_key: &str = "ED01FA53FA5A7E77798F882ECE20B1ABC00\
+///                         BB358A9E55A202D0D0676BD0CE37A63";
+///
+/// assert_eq!(Ok(CryptoAlgorithm::ED25519), get_algorithm_from_key(public_key));
+/// assert!(get_algorithm_from_key("").is_err());
+/// ```
+pub fn get_algorithm_from_key(key: &str) -> Result<CryptoAlgorithm, XRPLKeypairsException> {
+    match key.get(..2) {
+        Some(ED25519_PREFIX) => Ok(CryptoAlgorithm::ED25519),
+        Some(_) => Ok(CryptoAlgorithm::SECP256K1),
+        None => Err(XRPLKeypairsException::InvalidKey),
     }
 }
 
@@ -46,17 +66,9 @@ fn _get_algorithm_engine(algo: CryptoAlgorithm) -> Box<dyn CryptoImplementation>
     }
 }
 
-/// Return the trait implementation based on the
-/// provided key.
-fn _get_algorithm_engine_from_key(key: &str) -> Box<dyn CryptoImplementation> {
-    match &key[..2] {
-        ED25519_PREFIX => _get_algorithm_engine(CryptoAlgorithm::ED25519),
-        _ => _get_algorithm_engine(CryptoAlgorithm::SECP256K1),
-    }
-}
-
 /// Generate a seed value that cryptographic keys
-/// can be derived from.
+/// can be derived from. If no algorithm is provided
+/// the seed is encoded for Ed25519.
 ///
 /// # Examples
 ///
@@ -94,11 +106,7 @@ pub fn generate_seed(
 ) -> Result<String, XRPLAddressCodecException> {
     let mut random_bytes: [u8; SEED_LENGTH] = [0u8; SEED_LENGTH];
 
-    let algo: CryptoAlgorithm = if let Some(value) = algorithm {
-        value
-    } else {
-        CryptoAlgorithm::ED25519
-    };
+    let algo: CryptoAlgorithm = algorithm.unwrap_or_default();
 
     if let Some(value) = entropy {
         random_bytes = value;
@@ -148,9 +156,46 @@ pub fn derive_keypair(
     validator: bool,
 ) -> Result<(String, String), XRPLKeypairsException> {
     let (decoded_seed, algorithm) = decode_seed(seed)?;
-    let module = _get_algorithm_engine(algorithm);
-    let (public, private) = module.derive_keypair(&decoded_seed, validator)?;
-    let signature = sign(SIGNATURE_VERIFICATION_MESSAGE, &private)?;
+    _derive_keypair(&decoded_seed, validator, algorithm)
+}
+
+/// Derive the public and private keys of `algorithm` from a
+/// given seed value, regardless of the algorithm the seed is
+/// encoded for.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::constants::CryptoAlgorithm;
+/// use xrpl::core::keypairs::{derive_keypair_with_algorithm, get_algorithm_from_key};
+///
+/// let seed: &str = "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r";
+/// let (public_key, _) =
+///     derive_keypair_with_algorithm(seed, false, CryptoAlgorithm::SECP256K1).unwrap();
+///
+/// assert_eq!(Ok(CryptoAlgorithm::SECP256K1), get_algorithm_from_key(&public_key));
+/// ```
+pub fn derive_keypair_with_algorithm(
+    seed: &str,
+    validator: bool,
+    algorithm: CryptoAlgorithm,
+) -> Result<(String, String), XRPLKeypairsException> {
+    let (decoded_seed, _) = decode_seed(seed)?;
+    _derive_keypair(&decoded_seed, validator, algorithm)
+}
+
+/// Derive the keys of `algorithm` from a decoded seed and
+/// verify that they sign and verify messages.
+fn _derive_keypair(
+    decoded_seed: &[u8],
+    validator: bool,
+    algorithm: CryptoAlgorithm,
+) -> Result<(String, String), XRPLKeypairsException> {
+    let module = _get_algorithm_engine(algorithm.clone());
+    let (public, private) = module.derive_keypair(decoded_seed, validator)?;
+    let signature = sign_with_algorithm(SIGNATURE_VERIFICATION_MESSAGE, &private, algorithm)?;
 
     if module.is_valid_message(SIGNATURE_VERIFICATION_MESSAGE, &signature, &public) {
         Ok((public, private))
@@ -191,7 +236,7 @@ pub fn derive_keypair(
 /// assert_eq!(Some(address), derivation);
 /// ```
 pub fn derive_classic_address(public_key: &str) -> Result<String, XRPLAddressCodecException> {
-    let account_id = get_account_id(&hex::decode(public_key)?);
+    let account_id = get_account_id(&from_hex(public_key)?);
     encode_classic_address(&account_id)
 }
 
@@ -227,8 +272,43 @@ pub fn derive_classic_address(public_key: &str) -> Result<String, XRPLAddressCod
 /// assert_eq!(Some(signature), signing);
 /// ```
 pub fn sign(message: &[u8], private_key: &str) -> Result<String, XRPLKeypairsException> {
-    let module = _get_algorithm_engine_from_key(private_key);
-    Ok(hex::encode_upper(module.sign(message, private_key)?))
+    sign_with_algorithm(message, private_key, get_algorithm_from_key(private_key)?)
+}
+
+/// Sign a message using a given private key of `algorithm`.
+/// Errors if the key belongs to a different algorithm.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::constants::CryptoAlgorithm;
+/// use xrpl::core::keypairs::sign_with_algorithm;
+///
+/// let message: &[u8] = "test message".as_bytes();
+/// let private_key: &str = "EDB4C4E046826BD26190D09715FC31F4E\
+///                          6A728204EADD112905B08B14B7F15C4F3";
+///
+/// assert!(sign_with_algorithm(message, private_key, CryptoAlgorithm::ED25519).is_ok());
+/// assert!(sign_with_algorithm(message, private_key, CryptoAlgorithm::SECP256K1).is_err());
+/// ```
+pub fn sign_with_algorithm(
+    message: &[u8],
+    private_key: &str,
+    algorithm: CryptoAlgorithm,
+) -> Result<String, XRPLKeypairsException> {
+    let found = get_algorithm_from_key(private_key)?;
+
+    if found != algorithm {
+        return Err(XRPLKeypairsException::AlgorithmMismatch {
+            expected: algorithm,
+            found,
+        });
+    }
+
+    let module = _get_algorithm_engine(algorithm);
+    Ok(to_hex(module.sign(message, private_key)?))
 }
 
 /// Verifies the signature on a given message.
@@ -254,7 +334,49 @@ pub fn sign(message: &[u8], private_key: &str) -> Result<String, XRPLKeypairsExc
 /// ));
 /// ```
 pub fn is_valid_message(message: &[u8], signature: &str, public_key: &str) -> bool {
-    let module = _get_algorithm_engine_from_key(public_key);
+    match get_algorithm_from_key(public_key) {
+        Ok(algorithm) => is_valid_message_with_algorithm(message, signature, public_key, algorithm),
+        Err(_) => false,
+    }
+}
+
+/// Verifies the signature on a given message with a public
+/// key of `algorithm`. Returns false if the key belongs to a
+/// different algorithm.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::constants::CryptoAlgorithm;
+/// use xrpl::core::keypairs::is_valid_message_with_algorithm;
+///
+/// let message: &[u8] = "test message".as_bytes();
+/// let signature: &str = "CB199E1BFD4E3DAA105E4832EEDFA36413E1F44205E4EFB9\
+///                        E27E826044C21E3E2E848BBC8195E8959BADF887599B7310\
+///                        AD1B7047EF11B682E0D068F73749750E";
+/// let public_key: &str = "ED01FA53FA5A7E77798F882ECE20B1ABC00\
+///                         BB358A9E55A202D0D0676BD0CE37A63";
+///
+/// assert!(is_valid_message_with_algorithm(
+///     message,
+///     signature,
+///     public_key,
+///     CryptoAlgorithm::ED25519,
+/// ));
+/// ```
+pub fn is_valid_message_with_algorithm(
+    message: &[u8],
+    signature: &str,
+    public_key: &str,
+    algorithm: CryptoAlgorithm,
+) -> bool {
+    if get_algorithm_from_key(public_key) != Ok(algorithm.clone()) {
+        return false;
+    }
+
+    let module = _get_algorithm_engine(algorithm);
     module.is_valid_message(message, signature, public_key)
 }
 
@@ -300,6 +422,103 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_seed_algorithm_round_trip() {
+        let seed_ed25519 = generate_seed(Some(TEST_BYTES), None).unwrap();
+        let seed_secp256k1 =
+            generate_seed(Some(TEST_BYTES), Some(CryptoAlgorithm::SECP256K1)).unwrap();
+
+        assert!(seed_ed25519.starts_with("sEd"));
+        assert!(seed_secp256k1.starts_with('s') && !seed_secp256k1.starts_with("sEd"));
+        assert_eq!(
+            decode_seed(&seed_ed25519),
+            Ok((TEST_BYTES, CryptoAlgorithm::ED25519))
+        );
+        assert_eq!(
+            decode_seed(&seed_secp256k1),
+            Ok((TEST_BYTES, CryptoAlgorithm::SECP256K1))
+        );
+
+        let (public_ed25519, private_ed25519) = derive_keypair(&seed_ed25519, false).unwrap();
+        let (public_secp256k1, private_secp256k1) = derive_keypair(&seed_secp256k1, false).unwrap();
+
+        assert_eq!(
+            get_algorithm_from_key(&public_ed25519),
+            Ok(CryptoAlgorithm::ED25519)
+        );
+        assert_eq!(
+            get_algorithm_from_key(&private_ed25519),
+            Ok(CryptoAlgorithm::ED25519)
+        );
+        assert_eq!(
+            get_algorithm_from_key(&public_secp256k1),
+            Ok(CryptoAlgorithm::SECP256K1)
+        );
+        assert_eq!(
+            get_algorithm_from_key(&private_secp256k1),
+            Ok(CryptoAlgorithm::SECP256K1)
+        );
+    }
+
+    #[test]
+    fn test_get_algorithm_from_invalid_key() {
+        let message = TEST_MESSAGE.as_bytes();
+
+        for key in ["", "E", "D\u{e9}"] {
+            assert_eq!(
+                get_algorithm_from_key(key),
+                Err(XRPLKeypairsException::InvalidKey)
+            );
+            assert_eq!(sign(message, key), Err(XRPLKeypairsException::InvalidKey));
+            assert!(!is_valid_message(message, "", key));
+        }
+    }
+
+    #[test]
+    fn test_algorithm_round_trip() {
+        for algorithm in [CryptoAlgorithm::ED25519, CryptoAlgorithm::SECP256K1] {
+            let other = match algorithm {
+                CryptoAlgorithm::ED25519 => CryptoAlgorithm::SECP256K1,
+                CryptoAlgorithm::SECP256K1 => CryptoAlgorithm::ED25519,
+            };
+            let seed = generate_seed(Some(TEST_BYTES), Some(algorithm.clone())).unwrap();
+            let (public_key, private_key) =
+                derive_keypair_with_algorithm(&seed, false, algorithm.clone()).unwrap();
+            let message = TEST_MESSAGE.as_bytes();
+            let signature = sign_with_algorithm(message, &private_key, algorithm.clone()).unwrap();
+
+            assert_eq!(
+                derive_keypair(&seed, false),
+                Ok((public_key.clone(), private_key.clone()))
+            );
+            assert_eq!(get_algorithm_from_key(&public_key), Ok(algorithm.clone()));
+            assert!(is_valid_message_with_algorithm(
+                message,
+                &signature,
+                &public_key,
+                algorithm.clone(),
+            ));
+            assert!(!is_valid_message_with_algorithm(
+                message,
+                &signature,
+                &public_key,
+                other.clone(),
+            ));
+            assert_eq!(
+                sign_with_algorithm(message, &private_key, other.clone()),
+                Err(XRPLKeypairsException::AlgorithmMismatch {
+                    expected: other.clone(),
+                    found: algorithm.clone(),
+                })
+            );
+
+            let (other_public_key, _) =
+                derive_keypair_with_algorithm(&seed, false, other.clone()).unwrap();
+
+            assert_eq!(get_algorithm_from_key(&other_public_key), Ok(other));
+        }
+    }
+
     #[test]
     fn test_derive_keypair() {
         let (public_ed25519, private_ed25519) = derive_keypair(SEED_ED25519, false).unwrap();
@@ -328,20 +547,20 @@ mod test {
     fn test_sign() {
         assert_eq!(
             sign(TEST_MESSAGE.as_bytes(), PRIVATE_ED25519),
-            Ok(hex::encode_upper(SIGNATURE_ED25519)),
+            Ok(to_hex(SIGNATURE_ED25519)),
         );
 
         assert_eq!(
             sign(TEST_MESSAGE.as_bytes(), PRIVATE_SECP256K1),
-            Ok(hex::encode_upper(SIGNATURE_SECP256K1)),
+            Ok(to_hex(SIGNATURE_SECP256K1)),
         );
     }
 
     #[test]
     fn test_is_valid_message() {
         let message: &[u8] = TEST_MESSAGE.as_bytes();
-        let sig_ed25519: &str = &hex::encode_upper(SIGNATURE_ED25519);
-        let sig_secp256k1: &str = &hex::encode_upper(SIGNATURE_SECP256K1);
+        let sig_ed25519: &str = &to_hex(SIGNATURE_ED25519);
+        let sig_secp256k1: &str = &to_hex(SIGNATURE_SECP256K1);
 
         assert!(is_valid_message(message, sig_ed25519, PUBLIC_ED25519));
         assert!(is_valid_message(message, sig_secp256k1, PUBLIC_SECP256K1));
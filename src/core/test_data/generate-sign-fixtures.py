@@ -0,0 +1,235 @@
+#!/usr/bin/env python3
+"""Generates sign-fixtures.json.
+
+The fixtures are NOT captured from rippled. This script is an
+independent implementation of the parts of the XRPL binary format
+the fixtures use, of the secp256k1 key derivation of family seeds and
+of deterministic (RFC 6979) low-S ECDSA signing, written without
+looking at the crate's binary codec. The fixtures are unverified
+against rippled until they are compared with the output of
+
+    rippled sign snoPBrXtMeMyMHUVTgbuqAfg1SUTb '<tx_json>' offline
+
+Requires the `cryptography` package (>= 42 for deterministic ECDSA).
+
+Usage, from this directory:
+
+    python3 generate-sign-fixtures.py > sign-fixtures.json
+"""
+
+import hashlib
+import json
+import os
+from decimal import Decimal
+
+from cryptography.hazmat.primitives import hashes, serialization
+from cryptography.hazmat.primitives.asymmetric import ec, utils
+
+DEFINITIONS = os.path.join(
+    os.path.dirname(os.path.abspath(__file__)), "..", "definitions", "definitions.json"
+)
+
+ALPHABET = b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz"
+CURVE_ORDER = 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141
+
+# The genesis account of a standalone rippled and its seed.
+GENESIS_ACCOUNT = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"
+GENESIS_SEED = "snoPBrXtMeMyMHUVTgbuqAfg1SUTb"
+USD = {"currency": "USD", "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"}
+
+TRANSACTIONS = [
+    {
+        "TransactionType": "Payment",
+        "Account": GENESIS_ACCOUNT,
+        "Amount": "1000000000",
+        "Destination": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+        "Fee": "12",
+        "Flags": 0,
+        "Sequence": 4,
+    },
+    {
+        "TransactionType": "OfferCreate",
+        "Account": GENESIS_ACCOUNT,
+        "Fee": "12",
+        "Flags": 0,
+        "LastLedgerSequence": 84803100,
+        "Sequence": 5,
+        "TakerGets": "15000000000",
+        "TakerPays": dict(USD, value="7072.8"),
+    },
+    {
+        "TransactionType": "TrustSet",
+        "Account": GENESIS_ACCOUNT,
+        "Fee": "12",
+        "Flags": 262144,
+        "LimitAmount": dict(USD, value="100"),
+        "Sequence": 6,
+    },
+    {
+        "TransactionType": "EscrowFinish",
+        "Account": GENESIS_ACCOUNT,
+        "Condition": "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100",
+        "Fee": "330",
+        "Flags": 0,
+        "Fulfillment": "A0028000",
+        "OfferSequence": 7,
+        "Owner": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+        "Sequence": 7,
+    },
+]
+
+
+def base58_decode(value):
+    number = 0
+    for char in value.encode():
+        number = number * 58 + ALPHABET.index(char)
+    raw = number.to_bytes((number.bit_length() + 7) // 8, "big")
+    raw = b"\0" * (len(value) - len(value.lstrip("r"))) + raw
+    body, checksum = raw[:-4], raw[-4:]
+    assert hashlib.sha256(hashlib.sha256(body).digest()).digest()[:4] == checksum
+    return body
+
+
+def sha512_half(data):
+    return hashlib.sha512(data).digest()[:32]
+
+
+def public_key_of(private_key):
+    return (
+        ec.derive_private_key(private_key, ec.SECP256K1())
+        .public_key()
+        .public_bytes(serialization.Encoding.X962, serialization.PublicFormat.CompressedPoint)
+    )
+
+
+def derive_keypair(seed):
+    """Derives the secp256k1 account keypair of a family seed."""
+    raw = base58_decode(seed)
+    assert raw[0] == 0x21
+    entropy = raw[1:]
+
+    sequence = 0
+    while True:
+        root = int.from_bytes(sha512_half(entropy + sequence.to_bytes(4, "big")), "big")
+        if 0 < root < CURVE_ORDER:
+            break
+        sequence += 1
+    root_public_key = public_key_of(root)
+
+    sequence = 0
+    while True:
+        tweak = int.from_bytes(
+            sha512_half(root_public_key + bytes(4) + sequence.to_bytes(4, "big")), "big"
+        )
+        if 0 < tweak < CURVE_ORDER:
+            break
+        sequence += 1
+    private_key = (root + tweak) % CURVE_ORDER
+    return private_key, public_key_of(private_key)
+
+
+with open(DEFINITIONS) as definitions_file:
+    DEFINITIONS_JSON = json.load(definitions_file)
+TYPES = DEFINITIONS_JSON["TYPES"]
+FIELDS = {name: info for name, info in DEFINITIONS_JSON["FIELDS"]}
+TRANSACTION_TYPES = DEFINITIONS_JSON["TRANSACTION_TYPES"]
+
+
+def field_header(type_code, field_code):
+    if type_code < 16 and field_code < 16:
+        return bytes([type_code << 4 | field_code])
+    if type_code < 16:
+        return bytes([type_code << 4, field_code])
+    if field_code < 16:
+        return bytes([field_code, type_code])
+    return bytes([0, type_code, field_code])
+
+
+def variable_length(data):
+    assert len(data) <= 192
+    return bytes([len(data)]) + data
+
+
+def currency_code(currency):
+    assert len(currency) == 3
+    return bytes(12) + currency.encode() + bytes(5)
+
+
+def amount(value):
+    if isinstance(value, str):
+        return (0x4000000000000000 | int(value)).to_bytes(8, "big")
+
+    decimal = Decimal(value["value"])
+    if decimal == 0:
+        word = 0x8000000000000000
+    else:
+        sign, digits, exponent = decimal.as_tuple()
+        mantissa = int("".join(map(str, digits)))
+        while mantissa < 10**15:
+            mantissa *= 10
+            exponent -= 1
+        while mantissa >= 10**16:
+            assert mantissa % 10 == 0
+            mantissa //= 10
+            exponent += 1
+        word = (
+            0x8000000000000000
+            | (0 if sign else 0x4000000000000000)
+            | ((exponent + 97) << 54)
+            | mantissa
+        )
+    return (
+        word.to_bytes(8, "big")
+        + currency_code(value["currency"])
+        + base58_decode(value["issuer"])[1:]
+    )
+
+
+def field_bytes(name, value):
+    info = FIELDS[name]
+    header = field_header(TYPES[info["type"]], info["nth"])
+    if info["type"] == "UInt16":
+        return header + TRANSACTION_TYPES[value].to_bytes(2, "big")
+    if info["type"] == "UInt32":
+        return header + int(value).to_bytes(4, "big")
+    if info["type"] == "Amount":
+        return header + amount(value)
+    if info["type"] == "Blob":
+        return header + variable_length(bytes.fromhex(value))
+    if info["type"] == "AccountID":
+        return header + variable_length(base58_decode(value)[1:])
+    raise ValueError("unsupported field " + name)
+
+
+def serialize(tx, signing):
+    names = [
+        name
+        for name in tx
+        if name in FIELDS
+        and FIELDS[name]["isSerialized"]
+        and (not signing or FIELDS[name]["isSigningField"])
+    ]
+    names.sort(key=lambda name: (TYPES[FIELDS[name]["type"]], FIELDS[name]["nth"]))
+    return b"".join(field_bytes(name, tx[name]) for name in names)
+
+
+def sign(tx, private_key, public_key):
+    tx = dict(tx, SigningPubKey=public_key.hex().upper())
+    digest = sha512_half(b"STX\0" + serialize(tx, True))
+    signature = ec.derive_private_key(private_key, ec.SECP256K1()).sign(
+        digest, ec.ECDSA(utils.Prehashed(hashes.SHA256()), deterministic_signing=True)
+    )
+    r, s = utils.decode_dss_signature(signature)
+    if s > CURVE_ORDER // 2:
+        s = CURVE_ORDER - s
+    tx["TxnSignature"] = utils.encode_dss_signature(r, s).hex().upper()
+
+    tx_blob = serialize(tx, False)
+    tx_hash = sha512_half(b"TXN\0" + tx_blob).hex().upper()
+    tx = dict(sorted(tx.items()), hash=tx_hash)
+    return {"hash": tx_hash, "tx_blob": tx_blob.hex().upper(), "tx_json": tx}
+
+
+if __name__ == "__main__":
+    private_key, public_key = derive_keypair(GENESIS_SEED)
+    print(json.dumps([sign(tx, private_key, public_key) for tx in TRANSACTIONS], indent=2))
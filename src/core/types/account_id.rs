@@ -7,6 +7,7 @@ use crate::core::addresscodec::*;
 use crate::core::types::exceptions::XRPLHashException;
 use crate::core::types::*;
 use crate::core::BinaryParser;
+use crate::utils::hex::from_hex;
 use crate::utils::is_hex_address;
 use alloc::string::String;
 use alloc::string::ToString;
@@ -72,7 +73,7 @@ impl TryFrom<&str> for AccountId {
     /// a base58 r-Address.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         if is_hex_address(value) {
-            Self::new(Some(&hex::decode(value)?))
+            Self::new(Some(&from_hex(value)?))
         } else if is_valid_classic_address(value) {
             Self::new(Some(&decode_classic_address(value)?))
         } else if is_valid_xaddress(value) {
@@ -103,6 +104,7 @@ impl AsRef<[u8]> for AccountId {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::utils::hex::to_hex;
     use alloc::format;
 
     const HEX_ENCODING: &str = "5E7B112523F68D2F5E879DB4EAC51C6698A69304";
@@ -110,15 +112,15 @@ mod test {
 
     #[test]
     fn test_accountid_new() {
-        let hex = hex::decode(HEX_ENCODING).expect("");
+        let hex = from_hex(HEX_ENCODING).expect("");
         let account = AccountId::new(Some(&hex)).unwrap();
-        assert_eq!(HEX_ENCODING, hex::encode_upper(account));
+        assert_eq!(HEX_ENCODING, to_hex(account));
     }
 
     #[test]
     fn test_accountid_try_from() {
         let account = AccountId::try_from(BASE58_ENCODING).unwrap();
-        assert_eq!(HEX_ENCODING, hex::encode_upper(account));
+        assert_eq!(HEX_ENCODING, to_hex(account));
     }
 
     #[test]
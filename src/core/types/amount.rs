@@ -10,6 +10,7 @@ use crate::core::BinaryParser;
 use crate::core::Parser;
 use crate::utils::exceptions::JSONParseException;
 use crate::utils::exceptions::XRPRangeException;
+use crate::utils::hex::to_hex;
 use crate::utils::*;
 use alloc::string::String;
 use alloc::string::ToString;
@@ -56,6 +57,20 @@ fn _contains_decimal(string: &str) -> bool {
     string.contains('.')
 }
 
+/// Parses the value of an issued currency amount given in decimal
+/// or scientific notation into its canonical form without
+/// insignificant zeros, so that equal values serialize to the
+/// same bytes.
+fn _normalize_issued_currency_value(value: &str) -> Result<Decimal, XRPRangeException> {
+    let decimal = if value.contains(['e', 'E']) {
+        Decimal::from_scientific(value)?
+    } else {
+        Decimal::from_str(value)?
+    };
+
+    Ok(decimal.normalize())
+}
+
 /// Serializes the value field of an issued currency amount
 /// to its bytes representation.
 fn _serialize_issued_currency_value(decimal: Decimal) -> Result<[u8; 8], XRPRangeException> {
@@ -118,6 +133,10 @@ fn _serialize_xrp_amount(value: &str) -> Result<[u8; 8], XRPRangeException> {
 
     let decimal = Decimal::from_str(value)?.normalize();
 
+    if !decimal.fract().is_zero() {
+        return Err(XRPRangeException::InvalidValueContainsDecimal);
+    }
+
     if let Some(result) = decimal.to_i64() {
         let value_with_pos_bit = result | _POS_SIGN_BIT_MASK;
         Ok(value_with_pos_bit.to_be_bytes())
@@ -184,7 +203,7 @@ impl IssuedCurrency {
         if exp < MIN_IOU_EXPONENT {
             value = Decimal::ZERO;
         } else {
-            let hex_mantissa = hex::encode([&[bytes[1] & 0x3F], &bytes[2..]].concat());
+            let hex_mantissa = to_hex([&[bytes[1] & 0x3F], &bytes[2..]].concat());
             let int_mantissa = i128::from_str_radix(&hex_mantissa, 16)?;
             value = Decimal::from_i128_with_scale(int_mantissa, exp.unsigned_abs());
 
@@ -222,9 +241,11 @@ impl TryFromParser for Amount {
         _length: Option<usize>,
     ) -> Result<Amount, Self::Error> {
         let parser_first_byte = parser.peek();
+        // The most significant bit is set for issued currency
+        // amounts and unset for XRP amounts.
         let num_bytes = match parser_first_byte {
-            None => _CURRENCY_AMOUNT_BYTE_LENGTH,
-            Some(_) => _NATIVE_AMOUNT_BYTE_LENGTH,
+            Some([byte]) if byte & 0x80 != 0 => _CURRENCY_AMOUNT_BYTE_LENGTH,
+            _ => _NATIVE_AMOUNT_BYTE_LENGTH,
         };
 
         Ok(Amount(parser.read(num_bytes as usize)?))
@@ -319,7 +340,7 @@ impl TryFrom<serde_json::Value> for IssuedCurrency {
 
     /// Construct an IssuedCurrency object from a Serde JSON Value.
     fn try_from(json: serde_json::Value) -> Result<Self, Self::Error> {
-        let value = Decimal::from_str(
+        let value = _normalize_issued_currency_value(
             json["value"]
                 .as_str()
                 .ok_or(XRPLTypeException::InvalidNoneValue)?,
@@ -346,7 +367,7 @@ impl TryFrom<serde_json::Value> for IssuedCurrency {
 impl ToString for Amount {
     /// Get the hex representation of the Amount bytes.
     fn to_string(&self) -> String {
-        hex::encode_upper(self.as_ref())
+        to_hex(self.as_ref())
     }
 }
 
@@ -363,10 +384,33 @@ mod test {
     use crate::core::binarycodec::test_cases::load_data_tests;
     use crate::core::types::test_cases::IOUCase;
     use crate::core::types::test_cases::TEST_XRP_CASES;
+    use crate::utils::hex::from_hex;
     use alloc::format;
 
     const IOU_TEST: &str = include_str!("../test_data/iou-tests.json");
 
+    #[test]
+    fn test_normalized_values() {
+        let issued_currency = |value: &str| {
+            Amount::try_from(serde_json::json!({
+                "currency": "USD",
+                "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "value": value,
+            }))
+            .unwrap()
+            .to_string()
+        };
+
+        assert_eq!(issued_currency("0.30"), issued_currency("0.3"));
+        assert_eq!(issued_currency("3e-1"), issued_currency("0.3"));
+        assert_eq!(issued_currency("300.000"), issued_currency("3E2"));
+        assert_eq!(
+            Amount::try_from("1000000.0").unwrap().to_string(),
+            Amount::try_from("1000000").unwrap().to_string()
+        );
+        assert!(Amount::try_from("1000000.5").is_err());
+    }
+
     #[test]
     fn test_contains_decimal() {
         assert!(_contains_decimal("1.00"));
@@ -378,10 +422,10 @@ mod test {
         let json: Vec<IOUCase> = serde_json::from_str(IOU_TEST).expect("");
 
         for case in json {
-            let bytes = hex::decode(case.1).expect("");
+            let bytes = from_hex(case.1).expect("");
             let amount: Amount = Amount::new(Some(&bytes)).unwrap();
 
-            assert_eq!(hex::encode_upper(bytes), amount.to_string())
+            assert_eq!(to_hex(bytes), amount.to_string())
         }
     }
 
@@ -409,7 +453,7 @@ mod test {
 
         for case in json {
             let expect = serde_json::to_string(&case.0).expect("");
-            let bytes = hex::decode(case.1).expect("");
+            let bytes = from_hex(case.1).expect("");
             let amount: Amount = Amount::new(Some(&bytes)).unwrap();
             let serialize = serde_json::to_string(&amount).unwrap();
 
@@ -417,7 +461,7 @@ mod test {
         }
 
         for (xrp, result) in TEST_XRP_CASES {
-            let bytes = hex::decode(result).expect("");
+            let bytes = from_hex(result).expect("");
             let amount: Amount = Amount::new(Some(&bytes)).unwrap();
             let serialize = serde_json::to_string(&amount).unwrap();
 
@@ -5,6 +5,7 @@
 
 use crate::core::binarycodec::exceptions::XRPLBinaryCodecException;
 use crate::core::types::*;
+use crate::utils::hex::{from_hex, to_hex};
 use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec;
@@ -38,7 +39,7 @@ impl Serialize for Blob {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&hex::encode_upper(self.as_ref()))
+        serializer.serialize_str(&to_hex(self.as_ref()))
     }
 }
 
@@ -47,14 +48,14 @@ impl TryFrom<&str> for Blob {
 
     /// Construct a Blob from a hex string.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Self::new(Some(&hex::decode(value)?))
+        Self::new(Some(&from_hex(value)?))
     }
 }
 
 impl ToString for Blob {
     /// Get the hex representation of the Blob bytes.
     fn to_string(&self) -> String {
-        hex::encode_upper(self.as_ref())
+        to_hex(self.as_ref())
     }
 }
 
@@ -73,7 +74,7 @@ mod test {
 
     #[test]
     fn test_blob_new() {
-        let bytes = hex::decode(TEST_HEX).unwrap();
+        let bytes = from_hex(TEST_HEX).unwrap();
         let blob = Blob::new(Some(&bytes));
 
         assert!(blob.is_ok());
@@ -82,7 +83,7 @@ mod test {
 
     #[test]
     fn test_blob_try_from() {
-        let bytes = hex::decode(TEST_HEX).unwrap();
+        let bytes = from_hex(TEST_HEX).unwrap();
         let blob = Blob::try_from(TEST_HEX);
 
         assert!(blob.is_ok());
@@ -6,6 +6,7 @@ use crate::core::types::utils::CURRENCY_CODE_LENGTH;
 use crate::core::types::*;
 use crate::core::BinaryParser;
 use crate::utils::exceptions::ISOCodeException;
+use crate::utils::hex::{from_hex, to_hex};
 use crate::utils::*;
 use alloc::string::String;
 use alloc::string::ToString;
@@ -106,7 +107,7 @@ impl TryFrom<&str> for Currency {
             let hash160 = Hash160::new(Some(&iso_bytes))?;
             Ok(Currency(hash160))
         } else if is_iso_hex(value) {
-            Ok(Currency(Hash160::new(Some(&hex::decode(value)?))?))
+            Ok(Currency(Hash160::new(Some(&from_hex(value)?))?))
         } else {
             Err(XRPLHashException::ISOCodeError(
                 ISOCodeException::UnsupportedCurrencyRepresentation,
@@ -120,15 +121,15 @@ impl ToString for Currency {
     fn to_string(&self) -> String {
         let buffer = self.0.as_ref();
 
-        if hex::encode_upper(buffer) == NATIVE_HEX_CODE {
+        if to_hex(buffer) == NATIVE_HEX_CODE {
             NATIVE_CODE.to_string()
         } else {
             let iso = _iso_code_from_hex(buffer);
 
             if let Ok(code) = iso {
-                code.or_else(|| Some(hex::encode_upper(buffer))).unwrap()
+                code.or_else(|| Some(to_hex(buffer))).unwrap()
             } else {
-                hex::encode_upper(buffer)
+                to_hex(buffer)
             }
         }
     }
@@ -160,16 +161,16 @@ mod test {
         // Error case
         let invalid_iso = "INVALID";
 
-        assert_eq!(USD_HEX_CODE, hex::encode_upper(usd_iso_bytes));
-        assert_eq!(NATIVE_HEX_CODE, hex::encode_upper(xrp_iso_bytes));
+        assert_eq!(USD_HEX_CODE, to_hex(usd_iso_bytes));
+        assert_eq!(NATIVE_HEX_CODE, to_hex(xrp_iso_bytes));
         assert!(_iso_to_bytes(invalid_iso).is_err());
     }
 
     #[test]
     fn test_currency_new() {
-        let hex = hex::decode(USD_HEX_CODE).expect("");
+        let hex = from_hex(USD_HEX_CODE).expect("");
         let currency = Currency::new(Some(&hex));
-        assert_eq!(USD_HEX_CODE, hex::encode_upper(currency.unwrap()))
+        assert_eq!(USD_HEX_CODE, to_hex(currency.unwrap()))
     }
 
     #[test]
@@ -182,9 +183,9 @@ mod test {
 
         assert_eq!(NATIVE_CODE, from_hex_xrp.to_string());
         assert_eq!(USD_ISO, from_hex_ic.to_string());
-        assert_eq!(NATIVE_HEX_CODE, hex::encode_upper(from_iso_xrp));
-        assert_eq!(USD_HEX_CODE, hex::encode_upper(from_iso_ic));
-        assert_eq!(NONSTANDARD_HEX_CODE, hex::encode_upper(from_ns));
+        assert_eq!(NATIVE_HEX_CODE, to_hex(from_iso_xrp));
+        assert_eq!(USD_HEX_CODE, to_hex(from_iso_ic));
+        assert_eq!(NONSTANDARD_HEX_CODE, to_hex(from_ns));
     }
 
     #[test]
@@ -17,6 +17,7 @@ pub enum XRPLTypeException {
     XRPLRangeError(XRPRangeException),
     DecimalError(rust_decimal::Error),
     JSONParseError(JSONParseException),
+    XRPLVectorError(XRPLVectorException),
 }
 
 #[derive(Debug, Clone, PartialEq, Display)]
@@ -38,6 +39,12 @@ pub enum XRPLVectorException {
     XRPLHashError(XRPLHashException),
 }
 
+impl From<XRPLVectorException> for XRPLTypeException {
+    fn from(err: XRPLVectorException) -> Self {
+        XRPLTypeException::XRPLVectorError(err)
+    }
+}
+
 impl From<XRPLHashException> for XRPLTypeException {
     fn from(err: XRPLHashException) -> Self {
         XRPLTypeException::XRPLHashError(err)
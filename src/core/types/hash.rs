@@ -8,6 +8,7 @@ use crate::core::types::utils::*;
 use crate::core::types::*;
 use crate::core::BinaryParser;
 use crate::core::Parser;
+use crate::utils::hex::{from_hex, to_hex};
 use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec::Vec;
@@ -258,7 +259,7 @@ impl TryFrom<&str> for Hash128 {
 
     /// Construct a Hash object from a hex string.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Hash128::new(Some(&hex::decode(value)?))
+        Hash128::new(Some(&from_hex(value)?))
     }
 }
 
@@ -267,7 +268,7 @@ impl TryFrom<&str> for Hash160 {
 
     /// Construct a Hash object from a hex string.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Hash160::new(Some(&hex::decode(value)?))
+        Hash160::new(Some(&from_hex(value)?))
     }
 }
 
@@ -276,28 +277,28 @@ impl TryFrom<&str> for Hash256 {
 
     /// Construct a Hash object from a hex string.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Hash256::new(Some(&hex::decode(value)?))
+        Hash256::new(Some(&from_hex(value)?))
     }
 }
 
 impl ToString for Hash128 {
     /// Get the hex representation of the Hash128 bytes.
     fn to_string(&self) -> String {
-        hex::encode_upper(self.as_ref())
+        to_hex(self.as_ref())
     }
 }
 
 impl ToString for Hash160 {
     /// Get the hex representation of the Hash160 bytes.
     fn to_string(&self) -> String {
-        hex::encode_upper(self.as_ref())
+        to_hex(self.as_ref())
     }
 }
 
 impl ToString for Hash256 {
     /// Get the hex representation of the Hash256 bytes.
     fn to_string(&self) -> String {
-        hex::encode_upper(self.as_ref())
+        to_hex(self.as_ref())
     }
 }
 
@@ -333,9 +334,9 @@ mod test {
 
     #[test]
     fn test_hash_new() {
-        let hex128 = hex::decode(HASH128_HEX_TEST).unwrap();
-        let hex160 = hex::decode(HASH160_HEX_TEST).unwrap();
-        let hex256 = hex::decode(HASH256_HEX_TEST).unwrap();
+        let hex128 = from_hex(HASH128_HEX_TEST).unwrap();
+        let hex160 = from_hex(HASH160_HEX_TEST).unwrap();
+        let hex256 = from_hex(HASH256_HEX_TEST).unwrap();
 
         assert_eq!(HASH128_HEX_TEST, Hash128(hex128).to_string());
         assert_eq!(HASH160_HEX_TEST, Hash160(hex160).to_string());
@@ -344,21 +345,21 @@ mod test {
 
     #[test]
     fn test_hash_try_from_parser() {
-        let hex = hex::decode(HASH128_HEX_TEST).expect("");
+        let hex = from_hex(HASH128_HEX_TEST).expect("");
         let mut parser = BinaryParser::from(hex);
         let result = Hash128::from_parser(&mut parser, None);
 
         assert!(result.is_ok());
         assert_eq!(HASH128_HEX_TEST, result.unwrap().to_string());
 
-        let hex = hex::decode(HASH160_HEX_TEST).expect("");
+        let hex = from_hex(HASH160_HEX_TEST).expect("");
         let mut parser = BinaryParser::from(hex);
         let result = Hash160::from_parser(&mut parser, None);
 
         assert!(result.is_ok());
         assert_eq!(HASH160_HEX_TEST, result.unwrap().to_string());
 
-        let hex = hex::decode(HASH256_HEX_TEST).expect("");
+        let hex = from_hex(HASH256_HEX_TEST).expect("");
         let mut parser = BinaryParser::from(hex);
         let result = Hash256::from_parser(&mut parser, None);
 
@@ -474,6 +474,7 @@ mod test {
     use crate::core::types::test_cases::TEST_PATH_BUFFER;
     use crate::core::types::test_cases::TEST_PATH_SET_BUFFER;
     use crate::core::types::test_cases::TEST_PATH_STEP_BUFFER;
+    use crate::utils::hex::{from_hex, to_hex};
 
     pub const PATH_SET_TEST: &str = include_str!("../test_data/path-set-test.json");
     pub const PATH_TEST: &str = include_str!("../test_data/path-test.json");
@@ -500,7 +501,7 @@ mod test {
     #[test]
     fn test_pathstep_new() {
         for data in TEST_PATH_STEP_BUFFER {
-            let hex: Vec<u8> = hex::decode(data).expect("");
+            let hex: Vec<u8> = from_hex(data).expect("");
             let pathstep = PathStep::new(Some(&hex)).unwrap();
 
             assert_eq!(pathstep.as_ref(), hex);
@@ -509,24 +510,24 @@ mod test {
 
     #[test]
     fn test_path_new() {
-        let hex: Vec<u8> = hex::decode(TEST_PATH_BUFFER).expect("");
+        let hex: Vec<u8> = from_hex(TEST_PATH_BUFFER).expect("");
         let path = Path::new(Some(&hex)).unwrap();
 
-        assert_eq!(hex::encode_upper(path.as_ref()), TEST_PATH_BUFFER);
+        assert_eq!(to_hex(path.as_ref()), TEST_PATH_BUFFER);
     }
 
     #[test]
     fn test_pathset_new() {
-        let hex: Vec<u8> = hex::decode(TEST_PATH_SET_BUFFER).expect("");
+        let hex: Vec<u8> = from_hex(TEST_PATH_SET_BUFFER).expect("");
         let pathset = PathSet::new(Some(&hex)).unwrap();
 
-        assert_eq!(hex::encode_upper(pathset.as_ref()), TEST_PATH_SET_BUFFER);
+        assert_eq!(to_hex(pathset.as_ref()), TEST_PATH_SET_BUFFER);
     }
 
     #[test]
     fn test_pathstep_from_parser() {
         for data in TEST_PATH_STEP_BUFFER {
-            let hex = hex::decode(data).expect("");
+            let hex = from_hex(data).expect("");
             let mut parser = BinaryParser::from(hex.clone());
             let pathset = PathStep::from_parser(&mut parser, None).unwrap();
 
@@ -536,7 +537,7 @@ mod test {
 
     #[test]
     fn test_path_from_parser() {
-        let hex = hex::decode(TEST_PATH_BUFFER).expect("");
+        let hex = from_hex(TEST_PATH_BUFFER).expect("");
         let mut parser = BinaryParser::from(hex.clone());
         let pathset = Path::from_parser(&mut parser, None).unwrap();
 
@@ -545,7 +546,7 @@ mod test {
 
     #[test]
     fn test_pathset_from_parser() {
-        let hex = hex::decode(TEST_PATH_SET_BUFFER).expect("");
+        let hex = from_hex(TEST_PATH_SET_BUFFER).expect("");
         let mut parser = BinaryParser::from(hex.clone());
         let pathset = PathSet::from_parser(&mut parser, None).unwrap();
 
@@ -561,12 +562,12 @@ mod test {
             pathsteps.extend_from_slice(PathStep::try_from(map.clone()).unwrap().as_ref());
         }
 
-        assert_eq!(hex::encode_upper(pathsteps), TEST_PATH_BUFFER);
+        assert_eq!(to_hex(pathsteps), TEST_PATH_BUFFER);
     }
 
     #[test]
     fn test_path_try_from() {
-        let hex = hex::decode(TEST_PATH_BUFFER).expect("");
+        let hex = from_hex(TEST_PATH_BUFFER).expect("");
         let path = Path::try_from(PATH_TEST).unwrap();
 
         assert_eq!(path.as_ref(), hex)
@@ -574,7 +575,7 @@ mod test {
 
     #[test]
     fn test_pathset_try_from() {
-        let hex = hex::decode(TEST_PATH_SET_BUFFER).expect("");
+        let hex = from_hex(TEST_PATH_SET_BUFFER).expect("");
         let pathset = PathSet::try_from(PATH_SET_TEST).unwrap();
 
         assert_eq!(pathset.as_ref(), hex)
@@ -595,7 +596,7 @@ mod test {
 
     #[test]
     fn test_path_to_json() {
-        let hex: Vec<u8> = hex::decode(TEST_PATH_BUFFER).unwrap();
+        let hex: Vec<u8> = from_hex(TEST_PATH_BUFFER).unwrap();
         let path = Path::new(Some(&hex)).unwrap();
         let compact: serde_json::Value = serde_json::from_str(PATH_TEST).unwrap();
 
@@ -607,7 +608,7 @@ mod test {
 
     #[test]
     fn test_pathset_to_json() {
-        let hex: Vec<u8> = hex::decode(TEST_PATH_SET_BUFFER).expect("");
+        let hex: Vec<u8> = from_hex(TEST_PATH_SET_BUFFER).expect("");
         let compact: serde_json::Value = serde_json::from_str(PATH_SET_TEST).expect("");
         let pathset = PathSet::new(Some(&hex)).unwrap();
 
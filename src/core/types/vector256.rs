@@ -5,6 +5,7 @@ use crate::core::types::exceptions::XRPLVectorException;
 use crate::core::types::hash::Hash256;
 use crate::core::types::*;
 use crate::core::BinaryParser;
+use crate::utils::hex::to_hex;
 use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec;
@@ -72,7 +73,7 @@ impl Serialize for Vector256 {
             let mut sequence = serializer.serialize_seq(None)?;
 
             for i in (0..self.0.len()).step_by(_HASH_LENGTH_BYTES) {
-                let encoded = hex::encode_upper(&self.0[i..i + _HASH_LENGTH_BYTES]);
+                let encoded = to_hex(&self.0[i..i + _HASH_LENGTH_BYTES]);
                 sequence.serialize_element(&encoded)?;
             }
 
@@ -99,7 +100,7 @@ impl TryFrom<Vec<&str>> for Vector256 {
 impl ToString for Vector256 {
     /// Get the hex representation of the Vector256 bytes.
     fn to_string(&self) -> String {
-        hex::encode_upper(self.as_ref())
+        to_hex(self.as_ref())
     }
 }
 
@@ -113,6 +114,7 @@ impl AsRef<[u8]> for Vector256 {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::utils::hex::from_hex;
     use alloc::format;
 
     const SERIALIZED: &str = "42426C4D4F1009EE67080A9B7965B44656D7714D104A72F9B4369F97ABF044EE4C97EBA926031A7CF7D7B36FDE3ED66DDA5421192D63DE53FFB46E43B9DC8373";
@@ -121,13 +123,13 @@ mod test {
 
     #[test]
     fn test_vector256_new() {
-        let bytes = hex::decode(HASH1).unwrap();
+        let bytes = from_hex(HASH1).unwrap();
         assert_eq!(HASH1, Vector256(bytes).to_string());
     }
 
     #[test]
     fn test_vector256_try_from_parser() {
-        let hex = hex::decode(SERIALIZED).expect("");
+        let hex = from_hex(SERIALIZED).expect("");
         let mut parser = BinaryParser::from(hex);
         let result = Vector256::from_parser(&mut parser, None);
 
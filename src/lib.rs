@@ -31,6 +31,7 @@ pub mod core;
 pub mod macros;
 #[cfg(feature = "models")]
 pub mod models;
+pub mod prelude;
 #[cfg(feature = "utils")]
 pub mod utils;
 pub mod wallet;
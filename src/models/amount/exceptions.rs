@@ -1,10 +1,27 @@
+use alloc::string::String;
 use thiserror_no_std::Error;
 
 #[derive(Debug, Clone, PartialEq, Error)]
 pub enum XRPLAmountException {
     #[error("Unable to convert amount `value` into `Decimal`.")]
     ToDecimalError(#[from] rust_decimal::Error),
+    #[error("The drops amount `{found}` is not a non-negative integer.")]
+    InvalidDropsFormat { found: String },
+    #[error("The drops amount `{found}` exceeds the maximum of {max} drops.")]
+    DropsAmountTooLarge { max: u64, found: String },
+    #[error(
+        "The currency code `{found}` is reserved for XRP and can not be used by issued currencies."
+    )]
+    InvalidIssuedCurrencyCode { found: String },
+    #[error("The MPT amount of the issuance `{mpt_issuance_id}` has no currency.")]
+    MPTAmountHasNoCurrency { mpt_issuance_id: String },
+    #[error("The balance of {balance} drops is below the required reserve of {reserve} drops.")]
+    BalanceBelowReserve { balance: String, reserve: String },
 }
 
+/// `rust_decimal::Error` only lacks `Eq` because it is not derived,
+/// it holds no floating point values.
+impl Eq for XRPLAmountException {}
+
 #[cfg(feature = "std")]
 impl alloc::error::Error for XRPLAmountException {}
@@ -1,19 +1,58 @@
 use crate::models::amount::exceptions::XRPLAmountException;
+use crate::models::amount::{hash_value, value_eq, Amount};
 use crate::models::Model;
+use crate::Err;
 use alloc::borrow::Cow;
+use alloc::string::ToString;
+use anyhow::Result;
 use core::convert::TryInto;
+use core::fmt::{Display, Formatter};
+use core::hash::{Hash, Hasher};
 use core::str::FromStr;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Default)]
+/// Two issued currency amounts are equal only if their currency,
+/// issuer and value match. Values are compared as normalized
+/// decimals, so `"1.50"` equals `"1.5"`.
+#[derive(Debug, Eq, Clone, Serialize, Deserialize, Default)]
 pub struct IssuedCurrencyAmount<'a> {
     pub currency: Cow<'a, str>,
     pub issuer: Cow<'a, str>,
     pub value: Cow<'a, str>,
 }
 
-impl<'a> Model for IssuedCurrencyAmount<'a> {}
+impl<'a> Model for IssuedCurrencyAmount<'a> {
+    fn get_errors(&self) -> Result<()> {
+        match self._get_currency_error() {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
+
+/// Displays the amount as `value/currency.issuer`.
+impl<'a> Display for IssuedCurrencyAmount<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/{}.{}", self.value, self.currency, self.issuer)
+    }
+}
+
+impl<'a> PartialEq for IssuedCurrencyAmount<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.currency == other.currency
+            && self.issuer == other.issuer
+            && value_eq(&self.value, &other.value)
+    }
+}
+
+impl<'a> Hash for IssuedCurrencyAmount<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.currency.hash(state);
+        self.issuer.hash(state);
+        hash_value(&self.value, state);
+    }
+}
 
 impl<'a> IssuedCurrencyAmount<'a> {
     pub fn new(currency: Cow<'a, str>, issuer: Cow<'a, str>, value: Cow<'a, str>) -> Self {
@@ -23,6 +62,52 @@ impl<'a> IssuedCurrencyAmount<'a> {
             value,
         }
     }
+
+    /// Wraps the amount into an `Amount`.
+    pub fn into_amount(self) -> Amount<'a> {
+        Amount::IssuedCurrencyAmount(self)
+    }
+
+    /// Returns the amount without borrowed data.
+    pub fn into_owned(self) -> IssuedCurrencyAmount<'static> {
+        IssuedCurrencyAmount {
+            currency: Cow::Owned(self.currency.into_owned()),
+            issuer: Cow::Owned(self.issuer.into_owned()),
+            value: Cow::Owned(self.value.into_owned()),
+        }
+    }
+
+    /// Returns whether the value is below zero. Balances of trust
+    /// lines are negative if the low account owes the high account,
+    /// while limits and transferred amounts are never negative.
+    /// The sign is read from the string, so values outside the
+    /// range of `Decimal` like `-1e-30` are negative as well.
+    pub fn is_negative(&self) -> bool {
+        match self.value.strip_prefix('-') {
+            Some(unsigned) => {
+                let mantissa = unsigned.split(['e', 'E']).next().unwrap_or(unsigned);
+                mantissa.bytes().any(|byte| matches!(byte, b'1'..=b'9'))
+            }
+            None => false,
+        }
+    }
+}
+
+impl<'a> IssuedCurrencyAmountError for IssuedCurrencyAmount<'a> {
+    fn _get_currency_error(&self) -> Result<(), XRPLAmountException> {
+        if self.currency.eq_ignore_ascii_case("XRP") {
+            Err(XRPLAmountException::InvalidIssuedCurrencyCode {
+                found: self.currency.to_string(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+pub trait IssuedCurrencyAmountError {
+    /// The currency code `XRP` is reserved, in any case.
+    fn _get_currency_error(&self) -> Result<(), XRPLAmountException>;
 }
 
 impl<'a> TryInto<Decimal> for IssuedCurrencyAmount<'a> {
@@ -35,3 +120,78 @@ impl<'a> TryInto<Decimal> for IssuedCurrencyAmount<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test_display {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let amount = IssuedCurrencyAmount::new(
+            "USD".into(),
+            "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq".into(),
+            "31.5".into(),
+        );
+
+        assert_eq!(
+            amount.to_string(),
+            "31.5/USD.rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_issued_currency_amount_error {
+    use super::*;
+
+    #[test]
+    fn test_currency_error() {
+        for currency in ["XRP", "xrp", "Xrp"] {
+            let issued_currency_amount = IssuedCurrencyAmount::new(
+                currency.into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                "10".into(),
+            );
+
+            assert_eq!(
+                issued_currency_amount.validate().unwrap_err().to_string(),
+                alloc::format!(
+                    "The currency code `{}` is reserved for XRP and can not be used by issued currencies.",
+                    currency
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_negative() {
+        let amount = |value: &'static str| {
+            IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                value.into(),
+            )
+        };
+
+        assert!(amount("-10").is_negative());
+        assert!(amount("-1e-5").is_negative());
+        assert!(amount("-1e-30").is_negative());
+        assert!(amount("-9999999999999999e80").is_negative());
+        assert!(!amount("10").is_negative());
+        assert!(!amount("0").is_negative());
+        assert!(!amount("-0").is_negative());
+        assert!(!amount("-0.0e-30").is_negative());
+        assert!(!amount("1e-30").is_negative());
+    }
+
+    #[test]
+    fn test_valid_currency() {
+        let issued_currency_amount = IssuedCurrencyAmount::new(
+            "USD".into(),
+            "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+            "10".into(),
+        );
+
+        assert!(issued_currency_amount.is_valid());
+    }
+}
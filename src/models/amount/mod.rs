@@ -1,36 +1,213 @@
 pub mod exceptions;
 pub mod issued_currency_amount;
+pub mod mpt_amount;
 pub mod xrp_amount;
 
 use core::convert::TryInto;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 pub use issued_currency_amount::*;
+pub use mpt_amount::*;
 use rust_decimal::Decimal;
 pub use xrp_amount::*;
 
 use crate::models::amount::exceptions::XRPLAmountException;
 use crate::models::Model;
-use serde::{Deserialize, Serialize};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{Error, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
 use strum_macros::Display;
 
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Display)]
+/// Amounts compare and hash by their normalized value, so they
+/// can be used as map keys. See the individual amount types for
+/// the exact equality semantics.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Display)]
 #[serde(untagged)]
 pub enum Amount<'a> {
     IssuedCurrencyAmount(IssuedCurrencyAmount<'a>),
+    MPTAmount(MPTAmount<'a>),
     XRPAmount(XRPAmount<'a>),
 }
 
+/// Picks the variant by the shape of the JSON instead of trying
+/// them in order: a string is an XRP amount, an object with
+/// `mpt_issuance_id` an MPT amount and an object with `currency`
+/// an issued currency amount. Errors name the malformed variant.
+/// Strings are borrowed from the input where the deserializer
+/// allows it.
+impl<'de: 'a, 'a> Deserialize<'de> for Amount<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(AmountVisitor(PhantomData))
+    }
+}
+
+struct AmountVisitor<'a>(PhantomData<Amount<'a>>);
+
+impl<'de: 'a, 'a> Visitor<'de> for AmountVisitor<'a> {
+    type Value = Amount<'a>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string of drops or an object")
+    }
+
+    fn visit_borrowed_str<E: Error>(self, drops: &'de str) -> Result<Self::Value, E> {
+        Ok(Amount::XRPAmount(drops.into()))
+    }
+
+    fn visit_str<E: Error>(self, drops: &str) -> Result<Self::Value, E> {
+        Ok(Amount::XRPAmount(drops.to_string().into()))
+    }
+
+    fn visit_string<E: Error>(self, drops: String) -> Result<Self::Value, E> {
+        Ok(Amount::XRPAmount(drops.into()))
+    }
+
+    fn visit_map<M: MapAccess<'de>>(self, mut map: M) -> Result<Self::Value, M::Error> {
+        let mut currency = None;
+        let mut issuer = None;
+        let mut mpt_issuance_id = None;
+        let mut value = None;
+        while let Some(key) = map.next_key::<AmountField>()? {
+            let (name, slot) = match key {
+                AmountField::Currency => ("currency", &mut currency),
+                AmountField::Issuer => ("issuer", &mut issuer),
+                AmountField::MPTIssuanceID => ("mpt_issuance_id", &mut mpt_issuance_id),
+                AmountField::Value => ("value", &mut value),
+                AmountField::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                    continue;
+                }
+            };
+            if slot.is_some() {
+                return Err(M::Error::duplicate_field(name));
+            }
+            *slot = Some(map.next_value::<AmountString>()?.0);
+        }
+
+        let missing = |variant: &str, field: &str| {
+            M::Error::custom(format!("invalid {}: missing field `{}`", variant, field))
+        };
+        match (mpt_issuance_id, currency) {
+            (Some(mpt_issuance_id), None) => Ok(Amount::MPTAmount(MPTAmount::new(
+                mpt_issuance_id,
+                value.ok_or_else(|| missing("MPT amount", "value"))?,
+            ))),
+            (None, Some(currency)) => Ok(Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                currency,
+                issuer.ok_or_else(|| missing("issued currency amount", "issuer"))?,
+                value.ok_or_else(|| missing("issued currency amount", "value"))?,
+            ))),
+            (Some(_), Some(_)) => Err(M::Error::custom(
+                "ambiguous amount with both `mpt_issuance_id` and `currency`",
+            )),
+            (None, None) => Err(M::Error::custom(
+                "invalid amount object without `mpt_issuance_id` or `currency`",
+            )),
+        }
+    }
+}
+
+/// The fields of issued currency and MPT amounts. Other fields are
+/// ignored, like the derived implementations of the variants do.
+enum AmountField {
+    Currency,
+    Issuer,
+    MPTIssuanceID,
+    Value,
+    Other,
+}
+
+impl<'de> Deserialize<'de> for AmountField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AmountFieldVisitor;
+
+        impl<'de> Visitor<'de> for AmountFieldVisitor {
+            type Value = AmountField;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an amount field")
+            }
+
+            fn visit_str<E: Error>(self, field: &str) -> Result<Self::Value, E> {
+                Ok(match field {
+                    "currency" => AmountField::Currency,
+                    "issuer" => AmountField::Issuer,
+                    "mpt_issuance_id" => AmountField::MPTIssuanceID,
+                    "value" => AmountField::Value,
+                    _ => AmountField::Other,
+                })
+            }
+        }
+
+        deserializer.deserialize_identifier(AmountFieldVisitor)
+    }
+}
+
+/// A string field of an amount object, borrowed from the input
+/// where possible.
+struct AmountString<'a>(Cow<'a, str>);
+
+impl<'de: 'a, 'a> Deserialize<'de> for AmountString<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AmountStringVisitor<'a>(PhantomData<AmountString<'a>>);
+
+        impl<'de: 'a, 'a> Visitor<'de> for AmountStringVisitor<'a> {
+            type Value = AmountString<'a>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E: Error>(self, field: &'de str) -> Result<Self::Value, E> {
+                Ok(AmountString(Cow::Borrowed(field)))
+            }
+
+            fn visit_str<E: Error>(self, field: &str) -> Result<Self::Value, E> {
+                Ok(AmountString(Cow::Owned(field.to_string())))
+            }
+
+            fn visit_string<E: Error>(self, field: String) -> Result<Self::Value, E> {
+                Ok(AmountString(Cow::Owned(field)))
+            }
+        }
+
+        deserializer.deserialize_str(AmountStringVisitor(PhantomData))
+    }
+}
+
 impl<'a> TryInto<Decimal> for Amount<'a> {
     type Error = XRPLAmountException;
 
     fn try_into(self) -> Result<Decimal, Self::Error> {
         match self {
             Amount::IssuedCurrencyAmount(amount) => amount.try_into(),
+            Amount::MPTAmount(amount) => amount.try_into(),
             Amount::XRPAmount(amount) => amount.try_into(),
         }
     }
 }
 
-impl<'a> Model for Amount<'a> {}
+impl<'a> Model for Amount<'a> {
+    fn get_errors(&self) -> anyhow::Result<()> {
+        match self {
+            Amount::IssuedCurrencyAmount(amount) => amount.get_errors(),
+            _ => Ok(()),
+        }
+    }
+}
 
 impl<'a> Default for Amount<'a> {
     fn default() -> Self {
@@ -39,15 +216,81 @@ impl<'a> Default for Amount<'a> {
 }
 
 impl<'a> Amount<'a> {
-    pub fn is_xrp(&self) -> bool {
+    /// Creates an amount of an issued currency.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage
+    ///
+    /// ```
+    /// use xrpl::models::amount::Amount;
+    ///
+    /// let amount = Amount::issued("USD", "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B", "10");
+    ///
+    /// assert!(amount.is_issued_currency());
+    /// ```
+    pub fn issued(
+        currency: impl Into<Cow<'a, str>>,
+        issuer: impl Into<Cow<'a, str>>,
+        value: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+            currency.into(),
+            issuer.into(),
+            value.into(),
+        ))
+    }
+
+    /// Creates an amount of XRP in drops.
+    pub fn xrp(drops: impl Into<Cow<'a, str>>) -> Self {
+        Amount::XRPAmount(XRPAmount::from(drops.into()))
+    }
+
+    /// Returns the amount without borrowed data.
+    pub fn into_owned(self) -> Amount<'static> {
         match self {
-            Amount::IssuedCurrencyAmount(_) => false,
-            Amount::XRPAmount(_) => true,
+            Amount::IssuedCurrencyAmount(amount) => {
+                Amount::IssuedCurrencyAmount(amount.into_owned())
+            }
+            Amount::MPTAmount(amount) => Amount::MPTAmount(amount.into_owned()),
+            Amount::XRPAmount(amount) => Amount::XRPAmount(amount.into_owned()),
         }
     }
 
+    pub fn is_xrp(&self) -> bool {
+        matches!(self, Amount::XRPAmount(_))
+    }
+
     pub fn is_issued_currency(&self) -> bool {
-        !self.is_xrp()
+        matches!(self, Amount::IssuedCurrencyAmount(_))
+    }
+
+    pub fn is_mpt(&self) -> bool {
+        matches!(self, Amount::MPTAmount(_))
+    }
+
+    /// Returns the value of the amount. XRP amounts are in drops.
+    pub fn value(&self) -> &str {
+        match self {
+            Amount::IssuedCurrencyAmount(amount) => &amount.value,
+            Amount::MPTAmount(amount) => &amount.value,
+            Amount::XRPAmount(amount) => &amount.0,
+        }
+    }
+
+    /// Returns whether both amounts are denominated in the same
+    /// currency. Issued currencies also need to share the issuer.
+    pub fn is_same_currency(&self, other: &Amount) -> bool {
+        match (self, other) {
+            (Amount::XRPAmount(_), Amount::XRPAmount(_)) => true,
+            (Amount::IssuedCurrencyAmount(amount), Amount::IssuedCurrencyAmount(other)) => {
+                amount.currency == other.currency && amount.issuer == other.issuer
+            }
+            (Amount::MPTAmount(amount), Amount::MPTAmount(other)) => {
+                amount.mpt_issuance_id == other.mpt_issuance_id
+            }
+            _ => false,
+        }
     }
 }
 
@@ -57,8 +300,275 @@ impl<'a> From<IssuedCurrencyAmount<'a>> for Amount<'a> {
     }
 }
 
+impl<'a> From<MPTAmount<'a>> for Amount<'a> {
+    fn from(value: MPTAmount<'a>) -> Self {
+        Self::MPTAmount(value)
+    }
+}
+
 impl<'a> From<XRPAmount<'a>> for Amount<'a> {
     fn from(value: XRPAmount<'a>) -> Self {
         Self::XRPAmount(value)
     }
 }
+
+/// Parses an amount value into a normalized decimal. Returns `None`
+/// if the value is not representable as a `Decimal`.
+pub(crate) fn normalized_value(value: &str) -> Option<Decimal> {
+    Decimal::from_str(value)
+        .or_else(|_| Decimal::from_scientific(value))
+        .ok()
+        .map(|decimal| decimal.normalize())
+}
+
+/// Compares two amount values by their normalized decimal, falling
+/// back to the raw strings if either one is not a valid decimal.
+fn value_eq(value: &str, other: &str) -> bool {
+    match (normalized_value(value), normalized_value(other)) {
+        (Some(value), Some(other)) => value == other,
+        _ => value == other,
+    }
+}
+
+/// Hashes an amount value consistently with `value_eq`.
+fn hash_value<H: Hasher>(value: &str, state: &mut H) {
+    match normalized_value(value) {
+        Some(decimal) => decimal.hash(state),
+        None => value.hash(state),
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_mpt_amount_round_trip() {
+        let mpt_amount_json = r#"{"mpt_issuance_id":"00002403C84A0A28E0190E208E982C352BBD5006600555CF","value":"100"}"#;
+        let amount: Amount = serde_json::from_str(mpt_amount_json).unwrap();
+
+        assert_eq!(
+            amount,
+            Amount::MPTAmount(MPTAmount::new(
+                "00002403C84A0A28E0190E208E982C352BBD5006600555CF".into(),
+                "100".into()
+            ))
+        );
+        assert!(amount.is_mpt() && !amount.is_xrp() && !amount.is_issued_currency());
+        assert_eq!(amount.value(), "100");
+        assert_eq!(serde_json::to_string(&amount).unwrap(), mpt_amount_json);
+    }
+
+    #[test]
+    fn test_serialize_constructed() {
+        let issued_currency = Amount::issued("USD", "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B", "10");
+        let xrp = Amount::xrp("1000");
+
+        assert_eq!(
+            issued_currency,
+            IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                "10".into()
+            )
+            .into_amount()
+        );
+        assert_eq!(
+            serde_json::to_string(&issued_currency).unwrap(),
+            r#"{"currency":"USD","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","value":"10"}"#
+        );
+        assert_eq!(serde_json::to_string(&xrp).unwrap(), r#""1000""#);
+    }
+
+    #[test]
+    fn test_amount_variants() {
+        let xrp: Amount = serde_json::from_str(r#""1000""#).unwrap();
+        let issued_currency: Amount = serde_json::from_str(
+            r#"{"currency":"USD","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","value":"10"}"#,
+        )
+        .unwrap();
+
+        assert!(xrp.is_xrp());
+        assert_eq!(xrp.value(), "1000");
+        assert!(issued_currency.is_issued_currency());
+        assert_eq!(issued_currency.value(), "10");
+    }
+}
+
+#[cfg(test)]
+mod test_deserialize {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_deserialize_variants() {
+        assert_eq!(
+            serde_json::from_str::<Amount>(r#""1000""#).unwrap(),
+            Amount::XRPAmount("1000".into())
+        );
+        assert_eq!(
+            serde_json::from_str::<Amount>(
+                r#"{"currency":"USD","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","value":"10"}"#
+            )
+            .unwrap(),
+            Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                "10".into(),
+            ))
+        );
+        assert_eq!(
+            serde_json::from_str::<Amount>(
+                r#"{"value":"100","mpt_issuance_id":"00002403C84A0A28E0190E208E982C352BBD5006600555CF"}"#
+            )
+            .unwrap(),
+            Amount::MPTAmount(MPTAmount::new(
+                "00002403C84A0A28E0190E208E982C352BBD5006600555CF".into(),
+                "100".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_deserialize_borrowed() {
+        let is_borrowed = |value: &Cow<str>| matches!(value, Cow::Borrowed(_));
+
+        match serde_json::from_str::<Amount>(r#""1000""#).unwrap() {
+            Amount::XRPAmount(amount) => assert!(is_borrowed(&amount.0)),
+            amount => panic!("expected an XRP amount, found {:?}", amount),
+        }
+        match serde_json::from_str::<Amount>(
+            r#"{"currency":"USD","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","value":"10"}"#,
+        )
+        .unwrap()
+        {
+            Amount::IssuedCurrencyAmount(amount) => {
+                assert!(is_borrowed(&amount.currency));
+                assert!(is_borrowed(&amount.issuer));
+                assert!(is_borrowed(&amount.value));
+            }
+            amount => panic!("expected an issued currency amount, found {:?}", amount),
+        }
+        match serde_json::from_str::<Amount>(
+            r#"{"mpt_issuance_id":"00002403C84A0A28E0190E208E982C352BBD5006600555CF","value":"100"}"#,
+        )
+        .unwrap()
+        {
+            Amount::MPTAmount(amount) => {
+                assert!(is_borrowed(&amount.mpt_issuance_id));
+                assert!(is_borrowed(&amount.value));
+            }
+            amount => panic!("expected an MPT amount, found {:?}", amount),
+        }
+        // Escaped strings can not be borrowed from the input.
+        match serde_json::from_str::<Amount>(r#""10\u0030""#).unwrap() {
+            Amount::XRPAmount(amount) => {
+                assert!(!is_borrowed(&amount.0));
+                assert_eq!(amount.0, "100");
+            }
+            amount => panic!("expected an XRP amount, found {:?}", amount),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_malformed() {
+        let missing_issuer =
+            serde_json::from_str::<Amount>(r#"{"currency":"USD","value":"10"}"#).unwrap_err();
+
+        assert!(missing_issuer
+            .to_string()
+            .starts_with("invalid issued currency amount: missing field `issuer`"));
+        assert!(serde_json::from_str::<Amount>(r#"{"value":"10"}"#).is_err());
+        assert!(serde_json::from_str::<Amount>(
+            r#"{"currency":"USD","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","mpt_issuance_id":"00002403C84A0A28E0190E208E982C352BBD5006600555CF","value":"10"}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Amount>("1000").is_err());
+        assert!(serde_json::from_str::<Amount>(
+            r#"{"currency":"USD","currency":"EUR","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","value":"10"}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Amount>(
+            r#"{"currency":"USD","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","value":10}"#
+        )
+        .is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_hash {
+    use super::*;
+    use crate::_serde::HashMap;
+
+    #[test]
+    fn test_amount_as_map_key() {
+        let mut balances: HashMap<Amount, &str> = HashMap::default();
+        balances.insert(
+            Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                "10".into(),
+            )),
+            "usd",
+        );
+        balances.insert(Amount::XRPAmount("1000".into()), "xrp");
+
+        assert_eq!(
+            balances.get(&Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                "10.00".into(),
+            ))),
+            Some(&"usd")
+        );
+        assert_eq!(
+            balances.get(&Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                "1e1".into(),
+            ))),
+            Some(&"usd")
+        );
+        assert_eq!(
+            balances.get(&Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh".into(),
+                "10".into(),
+            ))),
+            None
+        );
+        assert_eq!(
+            balances.get(&Amount::XRPAmount("1000".into())),
+            Some(&"xrp")
+        );
+        assert_eq!(balances.get(&Amount::XRPAmount("1001".into())), None);
+    }
+
+    #[test]
+    fn test_value_equality() {
+        let amount = IssuedCurrencyAmount::new(
+            "USD".into(),
+            "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+            "1.50".into(),
+        );
+
+        assert_eq!(
+            amount,
+            IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                "1.5".into(),
+            )
+        );
+        assert_ne!(
+            amount,
+            IssuedCurrencyAmount::new(
+                "EUR".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                "1.5".into(),
+            )
+        );
+        assert_ne!(XRPAmount::from("abc"), XRPAmount::from("abd"));
+        assert_eq!(XRPAmount::from("abc"), XRPAmount::from("abc"));
+    }
+}
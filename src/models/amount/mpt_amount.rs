@@ -0,0 +1,96 @@
+use crate::models::amount::exceptions::XRPLAmountException;
+use crate::models::amount::{hash_value, value_eq};
+use crate::models::Model;
+use alloc::borrow::Cow;
+use core::convert::TryInto;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// An amount of a Multi-Purpose Token, identified by
+/// the ID of its issuance.
+///
+/// See MPT Amounts:
+/// `<https://xrpl.org/docs/references/protocol/data-types/currency-formats#mpt-amounts>`
+///
+/// Two MPT amounts are equal if their issuance ID and normalized
+/// value match.
+#[derive(Debug, Eq, Clone, Serialize, Deserialize, Default)]
+pub struct MPTAmount<'a> {
+    pub mpt_issuance_id: Cow<'a, str>,
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> Model for MPTAmount<'a> {}
+
+impl<'a> PartialEq for MPTAmount<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.mpt_issuance_id == other.mpt_issuance_id && value_eq(&self.value, &other.value)
+    }
+}
+
+impl<'a> Hash for MPTAmount<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.mpt_issuance_id.hash(state);
+        hash_value(&self.value, state);
+    }
+}
+
+impl<'a> MPTAmount<'a> {
+    pub fn new(mpt_issuance_id: Cow<'a, str>, value: Cow<'a, str>) -> Self {
+        Self {
+            mpt_issuance_id,
+            value,
+        }
+    }
+
+    /// Returns the amount without borrowed data.
+    pub fn into_owned(self) -> MPTAmount<'static> {
+        MPTAmount {
+            mpt_issuance_id: Cow::Owned(self.mpt_issuance_id.into_owned()),
+            value: Cow::Owned(self.value.into_owned()),
+        }
+    }
+}
+
+impl<'a> TryInto<Decimal> for MPTAmount<'a> {
+    type Error = XRPLAmountException;
+
+    fn try_into(self) -> Result<Decimal, Self::Error> {
+        match Decimal::from_str(&self.value) {
+            Ok(decimal) => Ok(decimal),
+            Err(decimal_error) => Err(XRPLAmountException::ToDecimalError(decimal_error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let mpt_amount = MPTAmount::new(
+            "00002403C84A0A28E0190E208E982C352BBD5006600555CF".into(),
+            "100".into(),
+        );
+        let mpt_amount_json = serde_json::to_string(&mpt_amount).unwrap();
+        let actual = mpt_amount_json.as_str();
+        let expected = r#"{"mpt_issuance_id":"00002403C84A0A28E0190E208E982C352BBD5006600555CF","value":"100"}"#;
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let mpt_amount_json = r#"{"mpt_issuance_id":"00002403C84A0A28E0190E208E982C352BBD5006600555CF","value":"100"}"#;
+        let actual: MPTAmount = serde_json::from_str(mpt_amount_json).unwrap();
+        let expected = MPTAmount::new(
+            "00002403C84A0A28E0190E208E982C352BBD5006600555CF".into(),
+            "100".into(),
+        );
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,28 +1,101 @@
 use crate::models::amount::exceptions::XRPLAmountException;
+use crate::models::amount::{hash_value, value_eq};
 use crate::models::Model;
+use crate::utils::xrpl_conversion::MAX_DROPS;
 use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
 use core::convert::TryInto;
+use core::fmt::{Display, Formatter};
+use core::hash::{Hash, Hasher};
 use core::str::FromStr;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Default)]
+/// Two XRP amounts are equal if they represent the same number of
+/// drops, regardless of their string representation.
+#[derive(Debug, Eq, Clone, Serialize, Deserialize, Default)]
 pub struct XRPAmount<'a>(pub Cow<'a, str>);
 
 impl<'a> Model for XRPAmount<'a> {}
 
+impl<'a> PartialEq for XRPAmount<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        value_eq(&self.0, &other.0)
+    }
+}
+
+impl<'a> Hash for XRPAmount<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_value(&self.0, state);
+    }
+}
+
+/// Displays the amount in drops.
+impl<'a> Display for XRPAmount<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'a> XRPAmount<'a> {
+    /// Formats the amount of drops as decimal XRP value with
+    /// up to 6 decimal places and no trailing zeros.
+    pub fn to_xrp_string(&self) -> Result<String, XRPLAmountException> {
+        XRPAmount::from_str(&self.0)?;
+        let mut xrp = Decimal::from_str(&self.0)?;
+        xrp.set_scale(6)?;
+
+        Ok(xrp.normalize().to_string())
+    }
+
+    /// Returns the amount without borrowed data.
+    pub fn into_owned(self) -> XRPAmount<'static> {
+        XRPAmount(Cow::Owned(self.0.into_owned()))
+    }
+}
+
 impl<'a> From<Cow<'a, str>> for XRPAmount<'a> {
     fn from(value: Cow<'a, str>) -> Self {
         Self(value)
     }
 }
 
+/// Does not validate the amount. Use `str::parse` to get an
+/// `XRPAmount` with validated drops.
 impl<'a> From<&'a str> for XRPAmount<'a> {
     fn from(value: &'a str) -> Self {
         Self(value.into())
     }
 }
 
+impl<'a> From<String> for XRPAmount<'a> {
+    fn from(value: String) -> Self {
+        Self(value.into())
+    }
+}
+
+impl<'a> FromStr for XRPAmount<'a> {
+    type Err = XRPLAmountException;
+
+    /// Parses an amount of drops, which has to be a non-negative
+    /// integer not exceeding `MAX_DROPS`.
+    fn from_str(drops: &str) -> Result<Self, Self::Err> {
+        if drops.is_empty() || !drops.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(XRPLAmountException::InvalidDropsFormat {
+                found: drops.to_string(),
+            });
+        }
+
+        match drops.parse::<u64>() {
+            Ok(value) if value <= MAX_DROPS => Ok(Self(drops.to_string().into())),
+            _ => Err(XRPLAmountException::DropsAmountTooLarge {
+                max: MAX_DROPS,
+                found: drops.to_string(),
+            }),
+        }
+    }
+}
+
 impl<'a> TryInto<Decimal> for XRPAmount<'a> {
     type Error = XRPLAmountException;
 
@@ -33,3 +106,78 @@ impl<'a> TryInto<Decimal> for XRPAmount<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test_display {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(XRPAmount::from("1000000").to_string(), "1000000");
+        assert_eq!(alloc::format!("{}", XRPAmount::from("12")), "12");
+    }
+
+    #[test]
+    fn test_to_xrp_string() {
+        assert_eq!(XRPAmount::from("1000000").to_xrp_string(), Ok("1".into()));
+        assert_eq!(XRPAmount::from("1").to_xrp_string(), Ok("0.000001".into()));
+        assert_eq!(
+            XRPAmount::from("1234500").to_xrp_string(),
+            Ok("1.2345".into())
+        );
+        assert_eq!(XRPAmount::from("0").to_xrp_string(), Ok("0".into()));
+        assert_eq!(
+            XRPAmount::from("100000000000000000").to_xrp_string(),
+            Ok("100000000000".into())
+        );
+        assert_eq!(
+            XRPAmount::from("1.5").to_xrp_string(),
+            Err(XRPLAmountException::InvalidDropsFormat {
+                found: "1.5".to_string()
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_xrp_amount_error {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(XRPAmount::from_str("200000"), Ok(XRPAmount::from("200000")));
+        assert_eq!(
+            "100000000000000000".parse::<XRPAmount>(),
+            Ok(XRPAmount::from("100000000000000000"))
+        );
+    }
+
+    #[test]
+    fn test_from_str_error() {
+        assert_eq!(
+            XRPAmount::from_str("-1"),
+            Err(XRPLAmountException::InvalidDropsFormat {
+                found: "-1".to_string()
+            })
+        );
+        assert_eq!(
+            XRPAmount::from_str("1.5"),
+            Err(XRPLAmountException::InvalidDropsFormat {
+                found: "1.5".to_string()
+            })
+        );
+        assert_eq!(
+            XRPAmount::from_str("abc"),
+            Err(XRPLAmountException::InvalidDropsFormat {
+                found: "abc".to_string()
+            })
+        );
+        assert_eq!(
+            XRPAmount::from_str("100000000000000001"),
+            Err(XRPLAmountException::DropsAmountTooLarge {
+                max: 100000000000000000,
+                found: "100000000000000001".to_string()
+            })
+        );
+    }
+}
@@ -1,8 +1,12 @@
 pub mod issued_currency;
 pub mod xrp;
 
+use crate::models::amount::exceptions::XRPLAmountException;
+use crate::models::amount::Amount;
 use crate::models::Model;
 use alloc::borrow::Cow;
+use alloc::string::ToString;
+use core::convert::TryFrom;
 pub use issued_currency::*;
 use serde::{Deserialize, Serialize};
 use strum_macros::Display;
@@ -38,3 +42,74 @@ impl<'a> From<XRP<'a>> for Currency<'a> {
         Self::XRP(value)
     }
 }
+
+impl<'a> ToAmount<'a, Amount<'a>> for Currency<'a> {
+    fn to_amount(&self, value: Cow<'a, str>) -> Amount<'a> {
+        match self {
+            Currency::IssuedCurrency(issued_currency) => {
+                Amount::IssuedCurrencyAmount(issued_currency.to_amount(value))
+            }
+            Currency::XRP(xrp) => Amount::XRPAmount(xrp.to_amount(value)),
+        }
+    }
+}
+
+/// Returns the currency of the amount without its value. MPT
+/// amounts are not denominated in a currency and fail to convert.
+impl<'a> TryFrom<Amount<'a>> for Currency<'a> {
+    type Error = XRPLAmountException;
+
+    fn try_from(value: Amount<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Amount::IssuedCurrencyAmount(amount) => Ok(Currency::IssuedCurrency(amount.into())),
+            Amount::XRPAmount(amount) => Ok(Currency::XRP(amount.into())),
+            Amount::MPTAmount(amount) => Err(XRPLAmountException::MPTAmountHasNoCurrency {
+                mpt_issuance_id: amount.mpt_issuance_id.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_amount_currency_conversion {
+    use super::*;
+    use crate::models::amount::{IssuedCurrencyAmount, MPTAmount, XRPAmount};
+
+    #[test]
+    fn test_xrp() {
+        let currency = Currency::XRP(XRP::new());
+        let amount = currency.to_amount("1000000".into());
+
+        assert_eq!(amount, Amount::XRPAmount(XRPAmount::from("1000000")));
+        assert_eq!(Currency::try_from(amount), Ok(currency));
+    }
+
+    #[test]
+    fn test_issued_currency() {
+        let currency = Currency::IssuedCurrency(IssuedCurrency::new(
+            "USD".into(),
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+        ));
+        let amount = currency.to_amount("1.5".into());
+
+        assert_eq!(
+            amount,
+            Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+                "1.5".into(),
+            ))
+        );
+        assert_eq!(Currency::try_from(amount), Ok(currency));
+    }
+
+    #[test]
+    fn test_mpt() {
+        let amount = Amount::MPTAmount(MPTAmount::new(
+            "00000001A407AF5856CCF3C42619DAA925813FC955C72983".into(),
+            "100".into(),
+        ));
+
+        assert!(Currency::try_from(amount).is_err());
+    }
+}
@@ -4,21 +4,80 @@ use crate::models::requests::XRPLRequestException;
 use crate::models::transactions::XRPLTransactionException;
 use alloc::string::String;
 use serde::{Deserialize, Serialize};
-use strum_macros::Display;
+use thiserror_no_std::Error;
 
-#[derive(Debug, PartialEq, Display)]
+#[derive(Debug, PartialEq, Error)]
 #[non_exhaustive]
 pub enum XRPLModelException<'a> {
+    /// An issued currency cannot be defined as XRP.
+    #[error("An issued currency cannot be defined as XRP")]
     InvalidICCannotBeXRP,
+    #[error("{0}")]
     XRPLTransactionError(XRPLTransactionException<'a>),
+    #[error("{0}")]
     XRPLRequestError(XRPLRequestException<'a>),
 }
 
+impl<'a> From<XRPLTransactionException<'a>> for XRPLModelException<'a> {
+    fn from(err: XRPLTransactionException<'a>) -> Self {
+        XRPLModelException::XRPLTransactionError(err)
+    }
+}
+
+impl<'a> From<XRPLRequestException<'a>> for XRPLModelException<'a> {
+    fn from(err: XRPLRequestException<'a>) -> Self {
+        XRPLModelException::XRPLRequestError(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> alloc::error::Error for XRPLModelException<'a> {}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct JSONRPCException {
     code: i32,
     message: String,
 }
 
-#[cfg(feature = "std")]
-impl<'a> alloc::error::Error for XRPLModelException<'a> {}
+#[cfg(test)]
+mod test_exception_conversions {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::models::transactions::XRPLAccountSetException;
+
+    fn get_account_set_error<'a>() -> Result<(), XRPLAccountSetException<'a>> {
+        Err(XRPLAccountSetException::ValueTooHigh {
+            field: "tick_size",
+            max: 15,
+            found: 16,
+            resource: "",
+        })
+    }
+
+    fn get_transaction_error<'a>() -> Result<(), XRPLTransactionException<'a>> {
+        get_account_set_error()?;
+
+        Ok(())
+    }
+
+    fn get_model_error<'a>() -> Result<(), XRPLModelException<'a>> {
+        get_transaction_error()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_account_set_exception_into_model_exception() {
+        let account_set_error = get_account_set_error().unwrap_err();
+        let model_error = get_model_error().unwrap_err();
+
+        assert_eq!(
+            model_error,
+            XRPLModelException::XRPLTransactionError(
+                XRPLTransactionException::XRPLAccountSetError(account_set_error.clone())
+            )
+        );
+        assert_eq!(model_error.to_string(), account_set_error.to_string());
+    }
+}
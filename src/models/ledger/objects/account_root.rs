@@ -1,7 +1,9 @@
 use crate::_serde::lgr_obj_flags;
+use crate::models::amount::exceptions::XRPLAmountException;
 use crate::models::ledger::LedgerEntryType;
 use crate::models::{amount::XRPAmount, Model};
 use alloc::borrow::Cow;
+use alloc::string::ToString;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
@@ -149,6 +151,82 @@ impl<'a> Default for AccountRoot<'a> {
 impl<'a> Model for AccountRoot<'a> {}
 
 impl<'a> AccountRoot<'a> {
+    /// Returns the ledger object without borrowed data.
+    pub fn into_owned(self) -> AccountRoot<'static> {
+        AccountRoot {
+            ledger_entry_type: self.ledger_entry_type,
+            flags: self.flags,
+            index: Cow::Owned(self.index.into_owned()),
+            account: Cow::Owned(self.account.into_owned()),
+            owner_count: self.owner_count,
+            previous_txn_id: Cow::Owned(self.previous_txn_id.into_owned()),
+            previous_txn_lgr_seq: self.previous_txn_lgr_seq,
+            sequence: self.sequence,
+            account_txn_id: self
+                .account_txn_id
+                .map(|account_txn_id| Cow::Owned(account_txn_id.into_owned())),
+            balance: self.balance.map(XRPAmount::into_owned),
+            burned_nftokens: self.burned_nftokens,
+            domain: self.domain.map(|domain| Cow::Owned(domain.into_owned())),
+            email_hash: self
+                .email_hash
+                .map(|email_hash| Cow::Owned(email_hash.into_owned())),
+            message_key: self
+                .message_key
+                .map(|message_key| Cow::Owned(message_key.into_owned())),
+            minted_nftokens: self.minted_nftokens,
+            nftoken_minter: self
+                .nftoken_minter
+                .map(|nftoken_minter| Cow::Owned(nftoken_minter.into_owned())),
+            regular_key: self
+                .regular_key
+                .map(|regular_key| Cow::Owned(regular_key.into_owned())),
+            ticket_count: self.ticket_count,
+            tick_size: self.tick_size,
+            transfer_rate: self.transfer_rate,
+            wallet_locator: self
+                .wallet_locator
+                .map(|wallet_locator| Cow::Owned(wallet_locator.into_owned())),
+            wallet_size: self.wallet_size,
+        }
+    }
+
+    /// Returns whether incoming payments to this account are
+    /// required to specify a destination tag.
+    pub fn requires_destination_tag(&self) -> bool {
+        self.flags.contains(&AccountRootFlag::LsfRequireDestTag)
+    }
+
+    /// Returns the XRP the account can spend, its balance minus
+    /// the base reserve and the owner reserve for each object it
+    /// owns. The reserves are given in drops, like the
+    /// `reserve_base` and `reserve_inc` of the validated ledger
+    /// reported by `server_state`. A missing balance counts as zero.
+    ///
+    /// Errors if the balance is not in drops or below the reserve.
+    pub fn spendable_balance(
+        &self,
+        reserve_base: u32,
+        reserve_inc: u32,
+    ) -> Result<XRPAmount<'static>, XRPLAmountException> {
+        let balance = self.balance.as_ref().map_or("0", |balance| &balance.0);
+        let balance_drops: u64 =
+            balance
+                .parse()
+                .map_err(|_| XRPLAmountException::InvalidDropsFormat {
+                    found: balance.to_string(),
+                })?;
+        let reserve_drops = _owner_reserve_drops(self.owner_count, reserve_base, reserve_inc);
+
+        match balance_drops.checked_sub(reserve_drops) {
+            Some(spendable) => Ok(spendable.to_string().into()),
+            None => Err(XRPLAmountException::BalanceBelowReserve {
+                balance: balance.to_string(),
+                reserve: reserve_drops.to_string(),
+            }),
+        }
+    }
+
     pub fn new(
         flags: Vec<AccountRootFlag>,
         index: Cow<'a, str>,
@@ -199,6 +277,97 @@ impl<'a> AccountRoot<'a> {
     }
 }
 
+/// Returns the reserve an account owning `owner_count` objects
+/// must hold: the base reserve plus the owner reserve for each
+/// object. All amounts are in drops.
+///
+/// See Reserves:
+/// `<https://xrpl.org/reserves.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::amount::XRPAmount;
+/// use xrpl::models::ledger::owner_reserve;
+///
+/// assert_eq!(
+///     owner_reserve(3, 10_000_000, 2_000_000),
+///     XRPAmount::from("16000000")
+/// );
+/// ```
+pub fn owner_reserve(owner_count: u32, reserve_base: u32, reserve_inc: u32) -> XRPAmount<'static> {
+    _owner_reserve_drops(owner_count, reserve_base, reserve_inc)
+        .to_string()
+        .into()
+}
+
+fn _owner_reserve_drops(owner_count: u32, reserve_base: u32, reserve_inc: u32) -> u64 {
+    reserve_base as u64 + owner_count as u64 * reserve_inc as u64
+}
+
+#[cfg(test)]
+mod test_reserve {
+    use super::*;
+
+    #[test]
+    fn test_owner_reserve() {
+        assert_eq!(
+            owner_reserve(3, 10_000_000, 2_000_000),
+            XRPAmount::from("16000000")
+        );
+        assert_eq!(
+            owner_reserve(0, 10_000_000, 2_000_000),
+            XRPAmount::from("10000000")
+        );
+        assert_eq!(
+            owner_reserve(u32::MAX, u32::MAX, u32::MAX),
+            XRPAmount::from("18446744069414584320")
+        );
+    }
+
+    #[test]
+    fn test_spendable_balance() {
+        let mut account_root = AccountRoot {
+            owner_count: 5,
+            balance: Some("25000000".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            account_root.spendable_balance(10_000_000, 2_000_000),
+            Ok(XRPAmount::from("5000000"))
+        );
+
+        account_root.balance = Some("20000000".into());
+
+        assert_eq!(
+            account_root.spendable_balance(10_000_000, 2_000_000),
+            Ok(XRPAmount::from("0"))
+        );
+
+        account_root.balance = Some("19999999".into());
+
+        assert_eq!(
+            account_root.spendable_balance(10_000_000, 2_000_000),
+            Err(XRPLAmountException::BalanceBelowReserve {
+                balance: "19999999".into(),
+                reserve: "20000000".into(),
+            })
+        );
+
+        account_root.balance = Some("1.5".into());
+
+        assert_eq!(
+            account_root.spendable_balance(10_000_000, 2_000_000),
+            Err(XRPLAmountException::InvalidDropsFormat {
+                found: "1.5".into()
+            })
+        );
+    }
+}
+
 #[cfg(test)]
 mod test_serde {
     use super::*;
@@ -239,5 +408,19 @@ mod test_serde {
         assert_eq!(expected, actual);
     }
 
-    // TODO: test_deserialize
+    #[test]
+    fn test_deserialize_flags() {
+        let json = r#"{"LedgerEntryType":"AccountRoot","Flags":9568256,"index":"13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","OwnerCount":3,"PreviousTxnID":"0D5FB50FA65C9FE1538FD7E398FFFE9D1908DFA4576D8D7A020040686F93C77D","PreviousTxnLgrSeq":14091160,"Sequence":336,"Balance":"148446663"}"#;
+        let account_root: AccountRoot = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            account_root.flags,
+            vec![
+                AccountRootFlag::LsfDefaultRipple,
+                AccountRootFlag::LsfDisableMaster,
+                AccountRootFlag::LsfRequireDestTag,
+            ]
+        );
+        assert!(account_root.requires_destination_tag());
+    }
 }
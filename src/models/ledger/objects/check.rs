@@ -38,6 +38,7 @@ pub struct Check<'a> {
     pub previous_txn_lgr_seq: u32,
     /// The maximum amount of currency this Check can debit the sender. If the Check is successfully
     /// cashed, the destination is credited in the same currency for up to this amount.
+    #[serde(borrow)]
     pub send_max: Amount<'a>,
     /// The sequence number of the `CheckCreate` transaction that created this check.
     pub sequence: u32,
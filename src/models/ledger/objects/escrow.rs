@@ -1,5 +1,6 @@
 use crate::models::ledger::LedgerEntryType;
 use crate::models::{amount::Amount, Model};
+use crate::utils::ripple_time_to_posix;
 use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
 
@@ -38,6 +39,7 @@ pub struct Escrow<'a> {
     /// the XRP, and gets it back if the held payment is canceled.
     pub account: Cow<'a, str>,
     /// The amount of XRP, in drops, to be delivered by the held payment.
+    #[serde(borrow)]
     pub amount: Amount<'a>,
     /// The destination address where the XRP is paid if the held payment is successful.
     pub destination: Cow<'a, str>,
@@ -130,6 +132,39 @@ impl<'a> Escrow<'a> {
             source_tag,
         }
     }
+
+    /// The `finish_after` time as a Unix timestamp.
+    pub fn finish_after_unix(&self) -> Option<i64> {
+        self.finish_after
+            .and_then(|finish_after| ripple_time_to_posix(finish_after.into()).ok())
+    }
+
+    /// The `cancel_after` time as a Unix timestamp.
+    pub fn cancel_after_unix(&self) -> Option<i64> {
+        self.cancel_after
+            .and_then(|cancel_after| ripple_time_to_posix(cancel_after.into()).ok())
+    }
+
+    /// Returns whether an `EscrowFinish` can execute the held
+    /// payment at the Unix time `now_unix`: the `finish_after`
+    /// time, if any, has passed and the escrow is not yet
+    /// cancelable. A `condition` still requires the fulfillment.
+    pub fn is_finishable(&self, now_unix: i64) -> bool {
+        let finish_after_passed = match self.finish_after_unix() {
+            Some(finish_after) => now_unix > finish_after,
+            None => true,
+        };
+
+        finish_after_passed && !self.is_cancelable(now_unix)
+    }
+
+    /// Returns whether an `EscrowCancel` can return the held
+    /// payment to its owner at the Unix time `now_unix`, that is
+    /// whether the `cancel_after` time is defined and has passed.
+    pub fn is_cancelable(&self, now_unix: i64) -> bool {
+        self.cancel_after_unix()
+            .is_some_and(|cancel_after| now_unix > cancel_after)
+    }
 }
 
 #[cfg(test)]
@@ -165,3 +200,43 @@ mod test_serde {
 
     // TODO: test_deserialize
 }
+
+#[cfg(test)]
+mod test_times {
+    use super::*;
+
+    /// 2017-04-12T23:15:32Z and 2017-04-13T23:10:32Z.
+    const FINISH_AFTER_UNIX: i64 = 1492038932;
+    const CANCEL_AFTER_UNIX: i64 = 1492125032;
+
+    fn escrow() -> Escrow<'static> {
+        Escrow {
+            cancel_after: Some(545440232),
+            finish_after: Some(545354132),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_unix_times() {
+        assert_eq!(escrow().finish_after_unix(), Some(FINISH_AFTER_UNIX));
+        assert_eq!(escrow().cancel_after_unix(), Some(CANCEL_AFTER_UNIX));
+        assert_eq!(Escrow::default().finish_after_unix(), None);
+    }
+
+    #[test]
+    fn test_finishable_not_cancelable() {
+        let now_unix = FINISH_AFTER_UNIX + 3600;
+
+        assert!(escrow().is_finishable(now_unix));
+        assert!(!escrow().is_cancelable(now_unix));
+    }
+
+    #[test]
+    fn test_times_passed() {
+        assert!(!escrow().is_finishable(FINISH_AFTER_UNIX));
+        assert!(!escrow().is_cancelable(CANCEL_AFTER_UNIX));
+        assert!(!escrow().is_finishable(CANCEL_AFTER_UNIX + 1));
+        assert!(escrow().is_cancelable(CANCEL_AFTER_UNIX + 1));
+    }
+}
@@ -42,6 +42,7 @@ pub struct NFTokenOffer<'a> {
     /// the amount must be specified in XRP. Sell offers that specify assets other than XRP
     /// must specify a non-zero amount. Sell offers that specify XRP can be 'free'
     /// (that is, the Amount field can be equal to "0").
+    #[serde(borrow)]
     pub amount: Amount<'a>,
     /// The `NFTokenID` of the `NFToken` object referenced by this offer.
     #[serde(rename = "NFTokenID")]
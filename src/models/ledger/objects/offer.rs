@@ -62,8 +62,10 @@ pub struct Offer<'a> {
     /// Used in combination with the `Account` to identify this `Offer`.
     pub sequence: u32,
     /// The remaining amount and type of currency being provided by the `Offer` creator.
+    #[serde(borrow)]
     pub taker_gets: Amount<'a>,
     /// The remaining amount and type of currency requested by the `Offer` creator.
+    #[serde(borrow)]
     pub taker_pays: Amount<'a>,
     /// Indicates the time after which this Offer is considered unfunded.
     pub expiration: Option<u32>,
@@ -28,10 +28,12 @@ pub struct PayChannel<'a> {
     pub account: Cow<'a, str>,
     /// Total XRP, in drops, that has been allocated to this channel. This includes XRP
     /// that has been paid to the destination address.
+    #[serde(borrow)]
     pub amount: Amount<'a>,
     /// Total XRP, in drops, already paid out by the channel. The difference between
     /// this value and the `Amount` field is how much XRP can still be paid to the destination
     /// address with `PaymentChannelClaim` transactions.
+    #[serde(borrow)]
     pub balance: Amount<'a>,
     /// The destination address for this payment channel. While the payment channel is open,
     /// this address is the only one that can receive XRP from the channel.
@@ -54,15 +54,18 @@ pub struct RippleState<'a> {
     pub index: Cow<'a, str>,
     /// The balance of the trust line, from the perspective of the low account. A negative
     /// balance indicates that the high account holds tokens issued by the low account.
+    #[serde(borrow)]
     pub balance: Amount<'a>,
     /// The limit that the high account has set on the trust line. The issuer is the address
     /// of the high account that set this limit.
+    #[serde(borrow)]
     pub high_limit: Amount<'a>,
     /// (Omitted in some historical ledgers) A hint indicating which page of the high account's
     /// owner directory links to this object, in case the directory consists of multiple pages.
     pub high_node: Cow<'a, str>,
     /// The limit that the low account has set on the trust line. The issuer is the address of
     /// the low account that set this limit.
+    #[serde(borrow)]
     pub low_limit: Amount<'a>,
     /// Omitted in some historical ledgers) A hint indicating which page of the low account's
     /// owner directory links to this object, in case the directory consists of multiple pages.
@@ -187,5 +190,23 @@ mod test_serde {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn test_negative_balance() {
+        let ripple_state = RippleState {
+            balance: Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rrrrrrrrrrrrrrrrrrrrBZbvji".into(),
+                "-10".into(),
+            )),
+            ..Default::default()
+        };
+
+        match &ripple_state.balance {
+            Amount::IssuedCurrencyAmount(balance) => assert!(balance.is_negative()),
+            _ => panic!("expected an issued currency balance"),
+        }
+        assert!(ripple_state.validate().is_ok());
+    }
+
     // TODO: test_deserialize
 }
@@ -14,6 +14,8 @@ pub mod model;
 #[cfg(feature = "requests")]
 #[allow(clippy::too_many_arguments)]
 pub mod requests;
+#[cfg(feature = "results")]
+pub mod results;
 #[cfg(feature = "transactions")]
 #[allow(clippy::too_many_arguments)]
 pub mod transactions;
@@ -24,6 +26,7 @@ pub mod amount;
 pub mod currency;
 pub mod utils;
 
+use alloc::borrow::Cow;
 use derive_new::new;
 pub use model::Model;
 
@@ -48,15 +51,56 @@ pub enum AccountObjectType {
     Ticket,
 }
 
+/// Represents the object types that a LedgerData
+/// Request can filter by.
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerObjectType {
+    Account,
+    Amendments,
+    Amm,
+    Check,
+    DepositPreauth,
+    Directory,
+    Escrow,
+    Fee,
+    Hashes,
+    NftOffer,
+    NftPage,
+    Offer,
+    PaymentChannel,
+    SignerList,
+    State,
+    Ticket,
+}
+
 /// A PathStep represents an individual step along a Path.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, new)]
 #[serde(rename_all = "PascalCase")]
 pub struct PathStep<'a> {
-    account: Option<&'a str>,
-    currency: Option<&'a str>,
-    issuer: Option<&'a str>,
+    account: Option<Cow<'a, str>>,
+    currency: Option<Cow<'a, str>>,
+    issuer: Option<Cow<'a, str>>,
     r#type: Option<u8>,
-    type_hex: Option<&'a str>,
+    type_hex: Option<Cow<'a, str>>,
+}
+
+impl<'a> PathStep<'a> {
+    /// Returns the path step without borrowed data.
+    pub fn into_owned(self) -> PathStep<'static> {
+        PathStep {
+            account: self.account.map(|account| Cow::Owned(account.into_owned())),
+            currency: self
+                .currency
+                .map(|currency| Cow::Owned(currency.into_owned())),
+            issuer: self.issuer.map(|issuer| Cow::Owned(issuer.into_owned())),
+            r#type: self.r#type,
+            type_hex: self
+                .type_hex
+                .map(|type_hex| Cow::Owned(type_hex.into_owned())),
+        }
+    }
 }
 
 /// Returns a Currency as XRP for the currency, without a value.
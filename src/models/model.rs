@@ -1,5 +1,9 @@
 //! Base model
 
+use crate::Err;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
 use anyhow::Result;
 
 /// A trait that implements basic functions to every model.
@@ -9,6 +13,17 @@ pub trait Model {
         Ok(())
     }
 
+    /// Collects all errors of a model instead of stopping at the
+    /// first one, e.g. to report every invalid field of a form at
+    /// once. Models without independent validations return the
+    /// error of `get_errors`.
+    fn get_all_errors(&self) -> Vec<anyhow::Error> {
+        match self.get_errors() {
+            Ok(_no_error) => vec![],
+            Err(error) => vec![error],
+        }
+    }
+
     /// Simply forwards the error from `get_errors` if there was one.
     fn validate(&self) -> Result<()> {
         match self.get_errors() {
@@ -25,3 +40,20 @@ pub trait Model {
         }
     }
 }
+
+/// Turns the results of independent validations of a model
+/// into the errors of `get_all_errors`.
+pub(crate) fn collect_errors<E: ToString>(
+    results: impl IntoIterator<Item = core::result::Result<(), E>>,
+) -> Vec<anyhow::Error> {
+    results
+        .into_iter()
+        .filter_map(|result| {
+            let result: Result<()> = match result {
+                Err(error) => Err!(error),
+                Ok(_no_error) => Ok(()),
+            };
+            result.err()
+        })
+        .collect()
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use crate::models::{requests::RequestMethod, Model};
@@ -20,7 +21,7 @@ pub struct AccountNfts<'a> {
     pub limit: Option<u32>,
     /// Value from a previous paginated response. Resume
     /// retrieving data where that response left off.
-    pub marker: Option<u32>,
+    pub marker: Option<Value>,
     /// The request method.
     #[serde(default = "RequestMethod::account_nfts")]
     pub command: RequestMethod,
@@ -41,7 +42,12 @@ impl<'a> Default for AccountNfts<'a> {
 impl<'a> Model for AccountNfts<'a> {}
 
 impl<'a> AccountNfts<'a> {
-    fn new(account: &'a str, id: Option<&'a str>, limit: Option<u32>, marker: Option<u32>) -> Self {
+    fn new(
+        account: &'a str,
+        id: Option<&'a str>,
+        limit: Option<u32>,
+        marker: Option<Value>,
+    ) -> Self {
         Self {
             account,
             id,
@@ -51,3 +57,24 @@ impl<'a> AccountNfts<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let req = AccountNfts {
+            account: "rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm",
+            marker: Some(Value::from(
+                "A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004",
+            )),
+            ..Default::default()
+        };
+        let req_as_string = serde_json::to_string(&req).unwrap();
+        let req_json = r#"{"account":"rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm","marker":"A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004","command":"account_nfts"}"#;
+
+        assert_eq!(req_as_string, req_json);
+        assert_eq!(serde_json::from_str::<AccountNfts>(req_json).unwrap(), req);
+    }
+}
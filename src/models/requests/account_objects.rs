@@ -9,11 +9,12 @@ use crate::models::{requests::RequestMethod, Model};
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Display)]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
-#[serde(tag = "type")]
 pub enum AccountObjectType {
     Check,
     DepositPreauth,
     Escrow,
+    NftOffer,
+    NftPage,
     Offer,
     PaymentChannel,
     SignerList,
@@ -42,8 +43,8 @@ pub struct AccountObjects<'a> {
     pub ledger_index: Option<&'a str>,
     /// If included, filter results to include only this type
     /// of ledger object. The valid types are: check, deposit_preauth,
-    /// escrow, offer, payment_channel, signer_list, ticket,
-    /// and state (trust line).
+    /// escrow, nft_offer, nft_page, offer, payment_channel,
+    /// signer_list, ticket, and state (trust line).
     pub r#type: Option<AccountObjectType>,
     /// If true, the response only includes objects that would block
     /// this account from being deleted. The default is false.
@@ -102,3 +103,27 @@ impl<'a> AccountObjects<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let req = AccountObjects {
+            account: "rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx",
+            ledger_index: Some("validated"),
+            r#type: Some(AccountObjectType::Escrow),
+            deletion_blockers_only: Some(true),
+            ..Default::default()
+        };
+        let req_as_string = serde_json::to_string(&req).unwrap();
+        let req_json = r#"{"account":"rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx","ledger_index":"validated","type":"escrow","deletion_blockers_only":true,"command":"account_objects"}"#;
+
+        assert_eq!(req_as_string, req_json);
+        assert_eq!(
+            serde_json::from_str::<AccountObjects>(req_json).unwrap(),
+            req
+        );
+    }
+}
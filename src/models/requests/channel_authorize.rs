@@ -12,6 +12,10 @@ use crate::{
     Err,
 };
 
+/// The documentation of the `channel_authorize` method,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/channel_authorize.html";
+
 /// The channel_authorize method creates a signature that can  be
 /// used to redeem a specific amount of XRP from a payment channel.
 ///
@@ -111,7 +115,7 @@ impl<'a> ChannelAuthorizeError for ChannelAuthorize<'a> {
                 field2: "seed",
                 field3: "seed_hex",
                 field4: "passphrase",
-                resource: "",
+                resource: RESOURCE,
             })
         } else {
             Ok(())
@@ -172,7 +176,7 @@ mod test_channel_authorize_errors {
 
         assert_eq!(
             channel_authorize.validate().unwrap_err().to_string().as_str(),
-            "The field `secret` can not be defined with `seed`, `seed_hex`, `passphrase`. Define exactly one of them. For more information see: "
+            "The field `secret` can not be defined with `seed`, `seed_hex`, `passphrase`. Define exactly one of them. For more information see: https://xrpl.org/channel_authorize.html"
         );
     }
 }
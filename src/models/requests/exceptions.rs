@@ -1,22 +1,37 @@
-use strum_macros::Display;
 use thiserror_no_std::Error;
 
-#[derive(Debug, Clone, PartialEq, Eq, Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLRequestException<'a> {
+    #[error("{0}")]
     XRPLChannelAuthorizeError(XRPLChannelAuthorizeException<'a>),
+    #[error("{0}")]
     XRPLLedgerEntryError(XRPLLedgerEntryException<'a>),
     /*SignAndSubmitError(SignAndSubmitException),
     SignForError(SignForException),
     SignError(SignException),*/
 }
 
+impl<'a> From<XRPLChannelAuthorizeException<'a>> for XRPLRequestException<'a> {
+    fn from(err: XRPLChannelAuthorizeException<'a>) -> Self {
+        XRPLRequestException::XRPLChannelAuthorizeError(err)
+    }
+}
+
+impl<'a> From<XRPLLedgerEntryException<'a>> for XRPLRequestException<'a> {
+    fn from(err: XRPLLedgerEntryException<'a>) -> Self {
+        XRPLRequestException::XRPLLedgerEntryError(err)
+    }
+}
+
 #[cfg(feature = "std")]
 impl<'a> alloc::error::Error for XRPLRequestException<'a> {}
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLChannelAuthorizeException<'a> {
     /// A field cannot be defined with other fields.
-    #[error("The field `{field1:?}` can not be defined with `{field2:?}`, `{field3:?}`, `{field4:?}`. Define exactly one of them. For more information see: {resource:?}")]
+    #[error("The field `{field1:?}` can not be defined with `{field2:?}`, `{field3:?}`, `{field4:?}`. Define exactly one of them. For more information see: {resource}")]
     DefineExactlyOneOf {
         field1: &'a str,
         field2: &'a str,
@@ -36,9 +51,10 @@ pub enum XRPLChannelAuthorizeException<'a> {
 impl<'a> alloc::error::Error for XRPLChannelAuthorizeException<'a> {}
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLLedgerEntryException<'a> {
     /// A field cannot be defined with other fields.
-    #[error("Define one of: `{field1:?}`, `{field2:?}`, `{field3:?}`, `{field4:?}`, `{field5:?}`, `{field6:?}`, `{field7:?}`, `{field8:?}`, `{field9:?}`, `{field10:?}`. Define exactly one of them. For more information see: {resource:?}")]
+    #[error("Define one of: `{field1:?}`, `{field2:?}`, `{field3:?}`, `{field4:?}`, `{field5:?}`, `{field6:?}`, `{field7:?}`, `{field8:?}`, `{field9:?}`, `{field10:?}`. Define exactly one of them. For more information see: {resource}")]
     DefineExactlyOneOf {
         field1: &'a str,
         field2: &'a str,
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{requests::RequestMethod, Model};
+
+/// The ledger_accept method forces the server to close the
+/// current-working ledger and move to the next ledger number.
+/// This method is intended for testing purposes only, and is
+/// only available when the rippled server is running in
+/// stand-alone mode. It is an admin method that requires the
+/// `integration` feature.
+///
+/// See Ledger Accept:
+/// `<https://xrpl.org/ledger_accept.html#ledger_accept>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct LedgerAccept<'a> {
+    /// The unique request id.
+    pub id: Option<&'a str>,
+    /// The request method.
+    #[serde(default = "RequestMethod::ledger_accept")]
+    pub command: RequestMethod,
+}
+
+impl<'a> Default for LedgerAccept<'a> {
+    fn default() -> Self {
+        LedgerAccept {
+            id: None,
+            command: RequestMethod::LedgerAccept,
+        }
+    }
+}
+
+impl<'a> Model for LedgerAccept<'a> {}
+
+impl<'a> LedgerAccept<'a> {
+    fn new(id: Option<&'a str>) -> Self {
+        Self {
+            id,
+            command: RequestMethod::LedgerAccept,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let req = LedgerAccept::new(None);
+
+        assert_eq!(
+            serde_json::to_string(&req).unwrap(),
+            r#"{"command":"ledger_accept"}"#
+        );
+    }
+}
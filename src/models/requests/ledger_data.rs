@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{requests::RequestMethod, Model};
+use crate::models::{requests::RequestMethod, LedgerObjectType, Model};
 
 /// The ledger_data method retrieves contents of the specified
 /// ledger. You can iterate through several calls to retrieve
@@ -28,6 +28,8 @@ pub struct LedgerData<'a> {
     /// Value from a previous paginated response.
     /// Resume retrieving data where that response left off.
     pub marker: Option<u32>,
+    /// Filter results to a specific type of ledger objects.
+    pub r#type: Option<LedgerObjectType>,
     /// The request method.
     #[serde(default = "RequestMethod::ledger_data")]
     pub command: RequestMethod,
@@ -42,6 +44,7 @@ impl<'a> Default for LedgerData<'a> {
             binary: None,
             limit: None,
             marker: None,
+            r#type: None,
             command: RequestMethod::LedgerData,
         }
     }
@@ -57,6 +60,7 @@ impl<'a> LedgerData<'a> {
         binary: Option<bool>,
         limit: Option<u16>,
         marker: Option<u32>,
+        r#type: Option<LedgerObjectType>,
     ) -> Self {
         Self {
             id,
@@ -65,7 +69,29 @@ impl<'a> LedgerData<'a> {
             binary,
             limit,
             marker,
+            r#type,
             command: RequestMethod::LedgerData,
         }
     }
 }
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let req = LedgerData {
+            ledger_index: Some("validated"),
+            binary: Some(true),
+            limit: Some(5),
+            r#type: Some(LedgerObjectType::Offer),
+            ..Default::default()
+        };
+        let req_as_string = serde_json::to_string(&req).unwrap();
+        let req_json = r#"{"ledger_index":"validated","binary":true,"limit":5,"type":"offer","command":"ledger_data"}"#;
+
+        assert_eq!(req_as_string, req_json);
+        assert_eq!(serde_json::from_str::<LedgerData>(req_json).unwrap(), req);
+    }
+}
@@ -8,6 +8,10 @@ use alloc::string::ToString;
 use crate::models::requests::XRPLLedgerEntryException;
 use crate::models::{requests::RequestMethod, Model};
 
+/// The documentation of the `ledger_entry` method,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/ledger_entry.html";
+
 /// Required fields for requesting a DepositPreauth if not
 /// querying by object ID.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
@@ -169,7 +173,7 @@ impl<'a> LedgerEntryError for LedgerEntry<'a> {
                 field8: "payment_channel",
                 field9: "deposit_preauth",
                 field10: "ticket",
-                resource: "",
+                resource: RESOURCE,
             })
         } else {
             Ok(())
@@ -260,11 +264,11 @@ mod test_ledger_entry_errors {
             field8: "payment_channel",
             field9: "deposit_preauth",
             field10: "ticket",
-            resource: "",
+            resource: RESOURCE,
         };
         assert_eq!(
             ledger_entry.validate().unwrap_err().to_string().as_str(),
-            "Define one of: `index`, `account_root`, `check`, `directory`, `offer`, `ripple_state`, `escrow`, `payment_channel`, `deposit_preauth`, `ticket`. Define exactly one of them. For more information see: "
+            "Define one of: `index`, `account_root`, `check`, `directory`, `offer`, `ripple_state`, `escrow`, `payment_channel`, `deposit_preauth`, `ticket`. Define exactly one of them. For more information see: https://xrpl.org/ledger_entry.html"
         );
     }
 }
@@ -14,6 +14,8 @@ pub mod exceptions;
 pub mod fee;
 pub mod gateway_balances;
 pub mod ledger;
+#[cfg(feature = "integration")]
+pub mod ledger_accept;
 pub mod ledger_closed;
 pub mod ledger_current;
 pub mod ledger_data;
@@ -51,6 +53,8 @@ pub use exceptions::*;
 pub use fee::*;
 pub use gateway_balances::*;
 pub use ledger::*;
+#[cfg(feature = "integration")]
+pub use ledger_accept::*;
 pub use ledger_closed::*;
 pub use ledger_current::*;
 pub use ledger_data::*;
@@ -133,6 +137,10 @@ pub enum RequestMethod {
     // Utility methods
     Ping,
     Random,
+
+    // Admin methods
+    #[cfg(feature = "integration")]
+    LedgerAccept,
 }
 
 /// For use with serde defaults.
@@ -183,6 +191,10 @@ impl RequestMethod {
     fn ledger_current() -> Self {
         RequestMethod::LedgerCurrent
     }
+    #[cfg(feature = "integration")]
+    fn ledger_accept() -> Self {
+        RequestMethod::LedgerAccept
+    }
     fn ledger_data() -> Self {
         RequestMethod::LedgerData
     }
@@ -9,18 +9,45 @@ use crate::models::{currency::Currency, default_false, requests::RequestMethod,
 ///
 /// See Subscribe:
 /// `<https://xrpl.org/subscribe.html#subscribe>`
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
-#[serde(rename_all(serialize = "PascalCase", deserialize = "snake_case"))]
 pub struct SubscribeBook<'a> {
+    /// Specification of which currency the account taking
+    /// the Offer would receive.
     pub taker_gets: Currency<'a>,
+    /// Specification of which currency the account taking
+    /// the Offer would pay.
     pub taker_pays: Currency<'a>,
+    /// Unique account address to use as a perspective for
+    /// viewing offers.
     pub taker: &'a str,
+    /// If true, return the current state of the order book once
+    /// when you subscribe before sending updates.
     #[serde(default = "default_false")]
     pub snapshot: Option<bool>,
+    /// If true, return both sides of the order book.
     #[serde(default = "default_false")]
     pub both: Option<bool>,
 }
 
+impl<'a> SubscribeBook<'a> {
+    pub fn new(
+        taker_gets: Currency<'a>,
+        taker_pays: Currency<'a>,
+        taker: &'a str,
+        snapshot: Option<bool>,
+        both: Option<bool>,
+    ) -> Self {
+        Self {
+            taker_gets,
+            taker_pays,
+            taker,
+            snapshot,
+            both,
+        }
+    }
+}
+
 /// Represents possible values of the streams query param
 /// for subscribe.
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Display)]
@@ -93,6 +120,18 @@ impl<'a> Default for Subscribe<'a> {
 impl<'a> Model for Subscribe<'a> {}
 
 impl<'a> Subscribe<'a> {
+    /// Removes duplicate `streams`, `books`, `accounts` and
+    /// `accounts_proposed`, keeping the first occurrence of each,
+    /// so that the server does not deliver the same events twice.
+    pub fn deduplicated(mut self) -> Self {
+        _dedup_in_order(&mut self.books);
+        _dedup_in_order(&mut self.streams);
+        _dedup_in_order(&mut self.accounts);
+        _dedup_in_order(&mut self.accounts_proposed);
+
+        self
+    }
+
     fn new(
         id: Option<&'a str>,
         books: Option<Vec<SubscribeBook<'a>>>,
@@ -116,3 +155,88 @@ impl<'a> Subscribe<'a> {
         }
     }
 }
+
+/// Removes later duplicates of a list's elements, keeping the
+/// order of their first occurrences.
+fn _dedup_in_order<T: PartialEq>(list: &mut Option<Vec<T>>) {
+    if let Some(list) = list {
+        let mut index = 0;
+        while index < list.len() {
+            if list[..index].contains(&list[index]) {
+                list.remove(index);
+            } else {
+                index += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use alloc::vec;
+
+    use super::*;
+    use crate::models::currency::{IssuedCurrency, XRP};
+
+    #[test]
+    fn test_serialize_books() {
+        let subscribe = Subscribe {
+            books: Some(vec![SubscribeBook::new(
+                Currency::XRP(XRP::new()),
+                Currency::IssuedCurrency(IssuedCurrency::new(
+                    "USD".into(),
+                    "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                )),
+                "rUQTpMqAF5jhykj4FExVeXakrZpiKF6cQV",
+                Some(true),
+                None,
+            )]),
+            ..Default::default()
+        };
+        let subscribe_json = serde_json::to_string(&subscribe).unwrap();
+        let actual = subscribe_json.as_str();
+        let expected = r#"{"books":[{"taker_gets":{"currency":"XRP"},"taker_pays":{"currency":"USD","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"},"taker":"rUQTpMqAF5jhykj4FExVeXakrZpiKF6cQV","snapshot":true}],"command":"subscribe"}"#;
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_serialize_proposed() {
+        let subscribe = Subscribe {
+            streams: Some(vec![StreamParameter::TransactionsProposed]),
+            accounts_proposed: Some(vec!["rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"]),
+            ..Default::default()
+        };
+        let subscribe_json = serde_json::to_string(&subscribe).unwrap();
+        let actual = subscribe_json.as_str();
+        let expected = r#"{"streams":["transactions_proposed"],"accounts_proposed":["rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"],"command":"subscribe"}"#;
+
+        assert_eq!(expected, actual);
+        assert_eq!(
+            serde_json::from_str::<Subscribe>(actual).unwrap(),
+            subscribe
+        );
+    }
+
+    #[test]
+    fn test_serialize_deduplicated() {
+        let subscribe = Subscribe {
+            streams: Some(vec![
+                StreamParameter::Transactions,
+                StreamParameter::Ledger,
+                StreamParameter::Transactions,
+            ]),
+            accounts: Some(vec![
+                "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            ]),
+            ..Default::default()
+        }
+        .deduplicated();
+        let subscribe_json = serde_json::to_string(&subscribe).unwrap();
+        let actual = subscribe_json.as_str();
+        let expected = r#"{"streams":["transactions","ledger"],"accounts":["rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"],"command":"subscribe"}"#;
+
+        assert_eq!(expected, actual);
+    }
+}
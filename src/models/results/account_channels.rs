@@ -0,0 +1,155 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::amount::XRPAmount;
+
+/// A payment channel of an `account_channels` response. Unlike
+/// the PascalCase `PayChannel` ledger object, its fields are
+/// snake_case, e.g. `channel_id` and `destination_account`.
+///
+/// See Account Channels:
+/// `<https://xrpl.org/account_channels.html#channel-objects>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct AccountChannel<'a> {
+    /// The owner of the channel, as an Address.
+    pub account: Cow<'a, str>,
+    /// The total amount of XRP, in drops, allocated to this channel.
+    pub amount: XRPAmount<'a>,
+    /// The total amount of XRP, in drops, paid out from this channel.
+    pub balance: XRPAmount<'a>,
+    /// A unique ID for this channel, as a 64-character hex string.
+    pub channel_id: Cow<'a, str>,
+    /// The destination account of the channel, as an Address.
+    pub destination_account: Cow<'a, str>,
+    /// The number of seconds the payment channel must stay open
+    /// after the owner requests to close it.
+    pub settle_delay: u32,
+    /// The public key for the payment channel in the XRP Ledger's
+    /// base58 format.
+    pub public_key: Option<Cow<'a, str>>,
+    /// The public key for the payment channel in hexadecimal format.
+    pub public_key_hex: Option<Cow<'a, str>>,
+    /// Time, in seconds since the Ripple Epoch, when this channel
+    /// is set to expire.
+    pub expiration: Option<u32>,
+    /// Time, in seconds since the Ripple Epoch, of this channel's
+    /// immutable expiration.
+    pub cancel_after: Option<u32>,
+    /// A 32-bit unsigned integer to use as a source tag for
+    /// payments through this payment channel.
+    pub source_tag: Option<u32>,
+    /// A 32-bit unsigned integer to use as a destination tag for
+    /// payments through this channel.
+    pub destination_tag: Option<u32>,
+}
+
+impl<'a> AccountChannel<'a> {
+    /// Returns the channel without borrowed data.
+    pub fn into_owned(self) -> AccountChannel<'static> {
+        AccountChannel {
+            account: Cow::Owned(self.account.into_owned()),
+            amount: self.amount.into_owned(),
+            balance: self.balance.into_owned(),
+            channel_id: Cow::Owned(self.channel_id.into_owned()),
+            destination_account: Cow::Owned(self.destination_account.into_owned()),
+            settle_delay: self.settle_delay,
+            public_key: self
+                .public_key
+                .map(|public_key| Cow::Owned(public_key.into_owned())),
+            public_key_hex: self
+                .public_key_hex
+                .map(|public_key_hex| Cow::Owned(public_key_hex.into_owned())),
+            expiration: self.expiration,
+            cancel_after: self.cancel_after,
+            source_tag: self.source_tag,
+            destination_tag: self.destination_tag,
+        }
+    }
+}
+
+/// The result of an `account_channels` request containing the
+/// payment channels where the account is the source.
+///
+/// See Account Channels:
+/// `<https://xrpl.org/account_channels.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct AccountChannels<'a> {
+    /// The address of the source account of the channels.
+    pub account: Cow<'a, str>,
+    /// The payment channels owned by this account.
+    pub channels: Vec<AccountChannel<'a>>,
+    /// The identifying hash of the ledger version used to
+    /// generate this response.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    /// The ledger index of the ledger version used to generate
+    /// this response.
+    pub ledger_index: Option<u32>,
+    /// The ledger index of the current in-progress ledger version,
+    /// which was used to generate this response.
+    pub ledger_current_index: Option<u32>,
+    /// The limit that was used in this request, if any.
+    pub limit: Option<u32>,
+    /// Server-defined value indicating the response is paginated.
+    /// Pass this to the next call to resume where this call left off.
+    pub marker: Option<Value>,
+    /// If true, this data comes from a validated ledger.
+    pub validated: Option<bool>,
+}
+
+impl<'a> AccountChannels<'a> {
+    /// Returns the result without borrowed data.
+    pub fn into_owned(self) -> AccountChannels<'static> {
+        AccountChannels {
+            account: Cow::Owned(self.account.into_owned()),
+            channels: self
+                .channels
+                .into_iter()
+                .map(AccountChannel::into_owned)
+                .collect(),
+            ledger_hash: self
+                .ledger_hash
+                .map(|ledger_hash| Cow::Owned(ledger_hash.into_owned())),
+            ledger_index: self.ledger_index,
+            ledger_current_index: self.ledger_current_index,
+            limit: self.limit,
+            marker: self.marker,
+            validated: self.validated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+    use crate::models::results::Strict;
+
+    const ACCOUNT_CHANNELS: &str = r#"{"account":"rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH","channels":[{"account":"rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH","amount":"1000","balance":"0","channel_id":"C7F634794B79DB40E87179A9D1BF05D05797AE7E92DF8E93FD6656E8C4BE3AE7","destination_account":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX","public_key":"aBR7mdD75Ycs8DRhMgQ4EMUEmBArF8SEh1hfjrT2V9DQTLNbJVqw","public_key_hex":"03CFD18E689434F032A4E84C63E2A3A6472D684EAF4FD52CA67742F3E24BAE81B2","settle_delay":60}],"ledger_hash":"27F530E5C93ED5C13994812787C1ED073C822BAEC7597964608F2C049C2ACD2D","ledger_index":71766343,"validated":true}"#;
+
+    #[test]
+    fn test_deserialize() {
+        let Strict(account_channels) =
+            serde_json::from_str::<Strict<AccountChannels>>(ACCOUNT_CHANNELS).unwrap();
+        let channel = &account_channels.channels[0];
+
+        assert_eq!(
+            channel.channel_id,
+            "C7F634794B79DB40E87179A9D1BF05D05797AE7E92DF8E93FD6656E8C4BE3AE7"
+        );
+        assert_eq!(channel.amount, XRPAmount::from("1000"));
+        assert_eq!(channel.settle_delay, 60);
+        assert_eq!(account_channels.ledger_index, Some(71766343));
+        assert_eq!(account_channels.validated, Some(true));
+    }
+
+    #[test]
+    fn test_deserialize_ledger_object_casing() {
+        let json = ACCOUNT_CHANNELS.replace("channel_id", "ChannelID");
+
+        assert!(serde_json::from_str::<AccountChannels>(&json).is_err());
+    }
+}
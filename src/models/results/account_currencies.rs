@@ -0,0 +1,89 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// The result of an `account_currencies` request containing the
+/// currencies an account can send or receive based on its
+/// trust lines.
+///
+/// See Account Currencies:
+/// `<https://xrpl.org/account_currencies.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct AccountCurrencies<'a> {
+    /// The identifying hash of the ledger version used to
+    /// retrieve this data.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    /// The ledger index of the ledger version used to
+    /// retrieve this data.
+    pub ledger_index: Option<u32>,
+    /// Array of currency codes for currencies that this
+    /// account can receive.
+    pub receive_currencies: Vec<Cow<'a, str>>,
+    /// Array of currency codes for currencies that this
+    /// account can send.
+    pub send_currencies: Vec<Cow<'a, str>>,
+    /// If true, this data comes from a validated ledger.
+    pub validated: Option<bool>,
+}
+
+impl<'a> AccountCurrencies<'a> {
+    /// Returns the result without borrowed data.
+    pub fn into_owned(self) -> AccountCurrencies<'static> {
+        AccountCurrencies {
+            ledger_hash: self
+                .ledger_hash
+                .map(|ledger_hash| Cow::Owned(ledger_hash.into_owned())),
+            ledger_index: self.ledger_index,
+            receive_currencies: self
+                .receive_currencies
+                .into_iter()
+                .map(|currency| Cow::Owned(currency.into_owned()))
+                .collect(),
+            send_currencies: self
+                .send_currencies
+                .into_iter()
+                .map(|currency| Cow::Owned(currency.into_owned()))
+                .collect(),
+            validated: self.validated,
+        }
+    }
+
+    pub fn receive_currencies(&self) -> &[Cow<'a, str>] {
+        &self.receive_currencies
+    }
+
+    pub fn send_currencies(&self) -> &[Cow<'a, str>] {
+        &self.send_currencies
+    }
+
+    /// Returns whether the account can receive the currency.
+    pub fn can_receive(&self, currency: &str) -> bool {
+        self.receive_currencies.iter().any(|code| code == currency)
+    }
+
+    /// Returns whether the account can send the currency.
+    pub fn can_send(&self, currency: &str) -> bool {
+        self.send_currencies.iter().any(|code| code == currency)
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let json = r#"{"ledger_index":11775844,"receive_currencies":["BTC","CNY","DYM","EUR","JOE","MXN","USD","015841551A748AD2C1F76FF6ECB0CCCD00000000"],"send_currencies":["ASP","BTC","CHF","CNY","DYM","EUR","JOE","JPY","MXN","USD"],"validated":true}"#;
+        let account_currencies: AccountCurrencies = serde_json::from_str(json).unwrap();
+
+        assert_eq!(account_currencies.receive_currencies().len(), 8);
+        assert_eq!(account_currencies.send_currencies().len(), 10);
+        assert!(account_currencies.can_receive("USD"));
+        assert!(account_currencies.can_receive("015841551A748AD2C1F76FF6ECB0CCCD00000000"));
+        assert!(!account_currencies.can_receive("JPY"));
+        assert!(account_currencies.can_send("JPY"));
+        assert!(!account_currencies.can_send("GBP"));
+    }
+}
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::ledger::AccountRoot;
+
+/// The result of an `account_info` request containing the
+/// `AccountRoot` ledger object of the account.
+///
+/// See Account Info:
+/// `<https://xrpl.org/account_info.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct AccountInfo<'a> {
+    /// The `AccountRoot` ledger object with this account's
+    /// information, as stored in the ledger.
+    pub account_data: AccountRoot<'a>,
+    /// The `SignerList` ledger objects associated with this
+    /// account, if requested with `signer_lists`.
+    pub signer_lists: Option<Value>,
+    /// The ledger index of the current in-progress ledger,
+    /// which was used when retrieving this information.
+    pub ledger_current_index: Option<u32>,
+    /// The ledger index of the ledger version used when
+    /// retrieving this information.
+    pub ledger_index: Option<u32>,
+    /// Information about queued transactions sent by this
+    /// account, if requested with `queue`.
+    pub queue_data: Option<Value>,
+    /// If true, this data comes from a validated ledger.
+    pub validated: Option<bool>,
+}
+
+impl<'a> AccountInfo<'a> {
+    /// Returns the result without borrowed data.
+    pub fn into_owned(self) -> AccountInfo<'static> {
+        AccountInfo {
+            account_data: self.account_data.into_owned(),
+            signer_lists: self.signer_lists,
+            ledger_current_index: self.ledger_current_index,
+            ledger_index: self.ledger_index,
+            queue_data: self.queue_data,
+            validated: self.validated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+    use crate::models::amount::XRPAmount;
+    use crate::models::ledger::AccountRootFlag;
+    use crate::models::results::Strict;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    const ACCOUNT_INFO: &str = r#"{"account_data":{"Account":"rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn","Balance":"999999999960","Flags":8388608,"LedgerEntryType":"AccountRoot","OwnerCount":0,"PreviousTxnID":"4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42","PreviousTxnLgrSeq":3,"Sequence":6,"index":"92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"},"ledger_current_index":4,"validated":false}"#;
+    const ACCOUNT_INFO_UNKNOWN_FIELD: &str = r#"{"account_data":{"Account":"rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn","Balance":"999999999960","Flags":8388608,"LedgerEntryType":"AccountRoot","OwnerCount":0,"PreviousTxnID":"4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42","PreviousTxnLgrSeq":3,"Sequence":6,"UnknownField":"ABC","index":"92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"},"ledger_current_index":4,"validated":false}"#;
+
+    #[test]
+    fn test_deserialize() {
+        let account_info: AccountInfo = serde_json::from_str(ACCOUNT_INFO).unwrap();
+
+        assert_eq!(
+            account_info.account_data.account,
+            "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn"
+        );
+        assert_eq!(account_info.account_data.sequence, 6);
+        assert_eq!(account_info.ledger_current_index, Some(4));
+    }
+
+    #[test]
+    fn test_deserialize_account_root() {
+        let account_info: AccountInfo = serde_json::from_str(ACCOUNT_INFO).unwrap();
+        let account_root = &account_info.account_data;
+
+        assert_eq!(account_root.balance, Some(XRPAmount::from("999999999960")));
+        assert_eq!(account_root.sequence, 6);
+        assert_eq!(account_root.flags, vec![AccountRootFlag::LsfDefaultRipple]);
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let account_info = {
+            let json = ACCOUNT_INFO.to_string();
+            serde_json::from_str::<AccountInfo>(&json)
+                .unwrap()
+                .into_owned()
+        };
+
+        assert_eq!(
+            account_info,
+            serde_json::from_str::<AccountInfo>(ACCOUNT_INFO).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_deserialize_strict() {
+        let Strict(account_info) =
+            serde_json::from_str::<Strict<AccountInfo>>(ACCOUNT_INFO).unwrap();
+
+        assert_eq!(account_info.account_data.sequence, 6);
+    }
+
+    #[test]
+    fn test_deserialize_strict_unknown_field() {
+        assert!(serde_json::from_str::<AccountInfo>(ACCOUNT_INFO_UNKNOWN_FIELD).is_ok());
+        assert_eq!(
+            serde_json::from_str::<Strict<AccountInfo>>(ACCOUNT_INFO_UNKNOWN_FIELD)
+                .unwrap_err()
+                .to_string(),
+            "unknown field `account_data.UnknownField`"
+        );
+    }
+}
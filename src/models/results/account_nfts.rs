@@ -0,0 +1,262 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+use strum::IntoEnumIterator;
+
+use crate::core::addresscodec::encode_classic_address;
+use crate::models::transactions::NFTokenMintFlag;
+use crate::utils::hex::from_hex;
+
+/// The fields encoded into an `NFTokenID`.
+///
+/// See NFToken:
+/// `<https://xrpl.org/nftoken.html#nftokenid>`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NFTokenIdFields {
+    /// The flags the token was minted with.
+    pub flags: u16,
+    /// The transfer fee in 1/100,000ths.
+    pub transfer_fee: u16,
+    /// The classic address of the issuer.
+    pub issuer: String,
+    /// The unscrambled taxon of the token.
+    pub taxon: u32,
+    /// The sequence number of the token, unique per issuer.
+    pub sequence: u32,
+}
+
+/// Parses the fields encoded into an `NFTokenID`. Returns `None`
+/// if the ID is not a 32 byte hex string.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::results::parse_nftoken_id;
+///
+/// let fields = parse_nftoken_id(
+///     "00010000A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(fields.issuer, "rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm");
+/// assert_eq!(fields.taxon, 0);
+/// assert_eq!(fields.sequence, 4);
+/// ```
+pub fn parse_nftoken_id(nftoken_id: &str) -> Option<NFTokenIdFields> {
+    let bytes: [u8; 32] = from_hex(nftoken_id).ok()?.try_into().ok()?;
+    let sequence = u32::from_be_bytes(bytes[28..32].try_into().ok()?);
+    let scrambled_taxon = u32::from_be_bytes(bytes[24..28].try_into().ok()?);
+    // The taxon is scrambled with a linear congruential generator
+    // seeded by the sequence to spread the tokens of a taxon.
+    let cipher = 384160001u32.wrapping_mul(sequence).wrapping_add(2459);
+
+    Some(NFTokenIdFields {
+        flags: u16::from_be_bytes([bytes[0], bytes[1]]),
+        transfer_fee: u16::from_be_bytes([bytes[2], bytes[3]]),
+        issuer: encode_classic_address(&bytes[4..24]).ok()?,
+        taxon: scrambled_taxon ^ cipher,
+        sequence,
+    })
+}
+
+/// An NFT of an `account_nfts` response.
+///
+/// See Account NFTs:
+/// `<https://xrpl.org/account_nfts.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccountNft<'a> {
+    /// A bit-map of boolean flags enabled for this NFToken.
+    pub flags: u32,
+    /// The account that issued this NFToken.
+    pub issuer: Cow<'a, str>,
+    /// The unique identifier of this NFToken, in hexadecimal.
+    #[serde(rename = "NFTokenID")]
+    pub nftoken_id: Cow<'a, str>,
+    /// The unscrambled version of this token's taxon.
+    #[serde(rename = "NFTokenTaxon")]
+    pub nftoken_taxon: u32,
+    /// The URI data associated with this NFToken, in hexadecimal.
+    #[serde(rename = "URI")]
+    pub uri: Option<Cow<'a, str>>,
+    /// The token sequence number of this NFToken, which is
+    /// unique for its issuer.
+    #[serde(rename = "nft_serial")]
+    pub nft_serial: u32,
+    /// The transfer fee of this NFToken in 1/100,000ths.
+    pub transfer_fee: Option<u16>,
+}
+
+impl<'a> AccountNft<'a> {
+    /// Returns the flags the NFToken was minted with.
+    pub fn flags(&self) -> Vec<NFTokenMintFlag> {
+        NFTokenMintFlag::iter()
+            .filter(|flag| self.flags & flag.clone() as u32 != 0)
+            .collect()
+    }
+
+    /// Returns the fields encoded into the `NFTokenID`.
+    pub fn parse_id(&self) -> Option<NFTokenIdFields> {
+        parse_nftoken_id(&self.nftoken_id)
+    }
+
+    /// Returns the NFT without borrowed data.
+    pub fn into_owned(self) -> AccountNft<'static> {
+        AccountNft {
+            flags: self.flags,
+            issuer: Cow::Owned(self.issuer.into_owned()),
+            nftoken_id: Cow::Owned(self.nftoken_id.into_owned()),
+            nftoken_taxon: self.nftoken_taxon,
+            uri: self.uri.map(|uri| Cow::Owned(uri.into_owned())),
+            nft_serial: self.nft_serial,
+            transfer_fee: self.transfer_fee,
+        }
+    }
+}
+
+/// The result of an `account_nfts` request containing a page of
+/// the NFTs owned by an account.
+///
+/// See Account NFTs:
+/// `<https://xrpl.org/account_nfts.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct AccountNfts<'a> {
+    /// The account that owns the NFTs.
+    pub account: Cow<'a, str>,
+    /// The NFTs owned by the account.
+    #[serde(borrow)]
+    pub account_nfts: Vec<AccountNft<'a>>,
+    /// The identifying hash of the ledger that was used to
+    /// generate this response.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    /// The ledger index of the ledger that was used to generate
+    /// this response.
+    pub ledger_index: Option<u32>,
+    /// The ledger index of the current in-progress ledger version,
+    /// which was used to generate this response.
+    pub ledger_current_index: Option<u32>,
+    /// The limit that was used in this request, if any.
+    pub limit: Option<u32>,
+    /// Server-defined value indicating the response is paginated.
+    /// Pass this to the next call to resume where this call left off.
+    pub marker: Option<Value>,
+    /// If true, this data comes from a validated ledger.
+    pub validated: Option<bool>,
+}
+
+impl<'a> AccountNfts<'a> {
+    /// Returns the result without borrowed data.
+    pub fn into_owned(self) -> AccountNfts<'static> {
+        AccountNfts {
+            account: Cow::Owned(self.account.into_owned()),
+            account_nfts: self
+                .account_nfts
+                .into_iter()
+                .map(AccountNft::into_owned)
+                .collect(),
+            ledger_hash: self
+                .ledger_hash
+                .map(|ledger_hash| Cow::Owned(ledger_hash.into_owned())),
+            ledger_index: self.ledger_index,
+            ledger_current_index: self.ledger_current_index,
+            limit: self.limit,
+            marker: self.marker,
+            validated: self.validated,
+        }
+    }
+}
+
+/// Collects the NFTs of all pages of `account_nfts` responses.
+///
+/// `fetch` is called with the `marker` of the previous page,
+/// `None` for the first one. It sends the `account_nfts` request
+/// with this marker and returns its result. Stops at the first
+/// page without a marker or at the first error.
+pub fn collect_account_nfts<F, E>(mut fetch: F) -> Result<Vec<AccountNft<'static>>, E>
+where
+    F: FnMut(Option<Value>) -> Result<AccountNfts<'static>, E>,
+{
+    let mut account_nfts = Vec::new();
+    let mut marker = None;
+    loop {
+        let page = fetch(marker.take())?;
+        account_nfts.extend(page.account_nfts);
+        match page.marker {
+            Some(next_marker) => marker = Some(next_marker),
+            None => return Ok(account_nfts),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+    use alloc::vec;
+
+    const PAGE_1: &str = r#"{"account":"rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm","account_nfts":[{"Flags":1,"Issuer":"rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm","NFTokenID":"00010000A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004","NFTokenTaxon":0,"URI":"697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469","nft_serial":4}],"ledger_current_index":17,"limit":1,"marker":"A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004","validated":false}"#;
+    const PAGE_2: &str = r#"{"account":"rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm","account_nfts":[{"Flags":9,"Issuer":"rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm","NFTokenID":"00090000A7CAD27B688D14BA1A9FA5366554D6ADCF9CE087727D1EA000000005","NFTokenTaxon":0,"nft_serial":5}],"ledger_current_index":17,"limit":1,"validated":false}"#;
+
+    #[test]
+    fn test_deserialize() {
+        let account_nfts: AccountNfts = serde_json::from_str(PAGE_1).unwrap();
+        let nft = &account_nfts.account_nfts[0];
+
+        assert_eq!(nft.flags(), vec![NFTokenMintFlag::TfBurnable]);
+        assert_eq!(
+            nft.parse_id(),
+            Some(NFTokenIdFields {
+                flags: 1,
+                transfer_fee: 0,
+                issuer: "rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm".into(),
+                taxon: 0,
+                sequence: 4,
+            })
+        );
+        assert!(account_nfts.marker.is_some());
+    }
+
+    #[test]
+    fn test_collect_pages() {
+        let mut markers = Vec::new();
+        let nfts = collect_account_nfts(|marker| {
+            let page = match marker {
+                None => PAGE_1,
+                Some(_) => PAGE_2,
+            };
+            markers.push(marker);
+            serde_json::from_str::<AccountNfts<'static>>(page)
+        })
+        .unwrap();
+
+        assert_eq!(
+            markers,
+            vec![
+                None,
+                Some(Value::from(
+                    "A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004"
+                ))
+            ]
+        );
+        assert_eq!(nfts.len(), 2);
+        assert_eq!(
+            nfts[1].flags(),
+            vec![NFTokenMintFlag::TfBurnable, NFTokenMintFlag::TfTransferable]
+        );
+        assert_eq!(nfts[1].parse_id().unwrap().sequence, 5);
+        assert_eq!(nfts[1].parse_id().unwrap().taxon, 0);
+    }
+
+    #[test]
+    fn test_parse_invalid_id() {
+        assert_eq!(parse_nftoken_id("0001"), None);
+        assert_eq!(parse_nftoken_id("invalid"), None);
+    }
+}
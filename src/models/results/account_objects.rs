@@ -0,0 +1,136 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+/// The ledger entry types of objects preventing the deletion
+/// of the account owning them.
+///
+/// See Deletion of Accounts:
+/// `<https://xrpl.org/accounts.html#deletion-of-accounts>`
+const DELETION_BLOCKERS: [&str; 10] = [
+    "Bridge",
+    "Check",
+    "Escrow",
+    "MPToken",
+    "MPTokenIssuance",
+    "NFTokenPage",
+    "PayChannel",
+    "RippleState",
+    "XChainOwnedClaimID",
+    "XChainOwnedCreateAccountClaimID",
+];
+
+/// The result of an `account_objects` request containing the
+/// raw ledger objects owned by an account.
+///
+/// See Account Objects:
+/// `<https://xrpl.org/account_objects.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct AccountObjects<'a> {
+    /// Unique address of the account this request corresponds to.
+    pub account: Cow<'a, str>,
+    /// Array of objects owned by this account. Each object is in
+    /// its raw ledger format.
+    pub account_objects: Vec<Value>,
+    /// The identifying hash of the ledger that was used to
+    /// generate this response.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    /// The ledger index of the ledger that was used to generate
+    /// this response.
+    pub ledger_index: Option<u32>,
+    /// The ledger index of the current in-progress ledger version,
+    /// which was used to generate this response.
+    pub ledger_current_index: Option<u32>,
+    /// The limit that was used in this request, if any.
+    pub limit: Option<u32>,
+    /// Server-defined value indicating the response is paginated.
+    /// Pass this to the next call to resume where this call left off.
+    pub marker: Option<Value>,
+    /// If true, this data comes from a validated ledger.
+    pub validated: Option<bool>,
+}
+
+impl<'a> AccountObjects<'a> {
+    /// Returns the objects preventing the deletion of the account,
+    /// filtering a response to a request without
+    /// `deletion_blockers_only` by their `LedgerEntryType`.
+    pub fn deletion_blockers(&self) -> impl Iterator<Item = &Value> {
+        self.account_objects.iter().filter(|object| {
+            object["LedgerEntryType"]
+                .as_str()
+                .is_some_and(|entry_type| DELETION_BLOCKERS.contains(&entry_type))
+        })
+    }
+
+    /// Returns whether no object prevents the deletion of the
+    /// account. The response has to be of a request with
+    /// `deletion_blockers_only`, so every returned object is a
+    /// blocker, including those of ledger entry types added after
+    /// this library. Returns `false` while a `marker` indicates
+    /// that more objects are left to request.
+    pub fn can_delete_account(&self) -> bool {
+        self.account_objects.is_empty() && self.marker.is_none()
+    }
+
+    /// Returns the result without borrowed data.
+    pub fn into_owned(self) -> AccountObjects<'static> {
+        AccountObjects {
+            account: Cow::Owned(self.account.into_owned()),
+            account_objects: self.account_objects,
+            ledger_hash: self
+                .ledger_hash
+                .map(|ledger_hash| Cow::Owned(ledger_hash.into_owned())),
+            ledger_index: self.ledger_index,
+            ledger_current_index: self.ledger_current_index,
+            limit: self.limit,
+            marker: self.marker,
+            validated: self.validated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_blocking_escrow() {
+        let json = r#"{"account":"rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx","account_objects":[{"Account":"rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx","Amount":"10000","Destination":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","DestinationNode":"0000000000000000","FinishAfter":533257958,"Flags":0,"LedgerEntryType":"Escrow","OwnerNode":"0000000000000000","PreviousTxnID":"C44F2EB84196B9AD820313DBEBA6316A15C9A2D35787579ED172B87A30131DA7","PreviousTxnLgrSeq":28991004,"index":"DC5F3851D8A1AB622F957761E5963BC5BD439D5C24AC6AD7AC4523F0640244AC"}],"ledger_hash":"053DF17D2289D1C4971C22F235BC1FCA7D4B3AE966F842E5819D0749E0B8ECD3","ledger_index":14380380,"validated":true}"#;
+        let account_objects: AccountObjects = serde_json::from_str(json).unwrap();
+
+        assert_eq!(account_objects.deletion_blockers().count(), 1);
+        assert!(!account_objects.can_delete_account());
+    }
+
+    #[test]
+    fn test_deserialize_no_blockers() {
+        let json = r#"{"account":"rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx","account_objects":[{"Account":"rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx","BookDirectory":"50AD0A9E54D2B381288D535EB724E4275FFBF41580D28A925D038D7EA4C68000","BookNode":"0000000000000000","Flags":0,"LedgerEntryType":"Offer","OwnerNode":"0000000000000000","PreviousTxnID":"F0AB71E777B2DA54B86231E19B82554EF1F8211F92ECA473121C655BFC5329BF","PreviousTxnLgrSeq":14524914,"Sequence":5,"TakerGets":"1000000","TakerPays":{"currency":"USD","issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","value":"1"},"index":"96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797"}],"ledger_current_index":14380381}"#;
+        let account_objects: AccountObjects = serde_json::from_str(json).unwrap();
+
+        assert_eq!(account_objects.deletion_blockers().count(), 0);
+
+        let json = r#"{"account":"rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx","account_objects":[],"ledger_current_index":14380381}"#;
+        let account_objects: AccountObjects = serde_json::from_str(json).unwrap();
+
+        assert!(account_objects.can_delete_account());
+    }
+
+    #[test]
+    fn test_deserialize_blockers_only() {
+        // The `MPToken` blocks the deletion of the account like
+        // every object of a `deletion_blockers_only` response.
+        let json = r#"{"account":"rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx","account_objects":[{"Account":"rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx","Flags":0,"LedgerEntryType":"MPToken","MPTAmount":"100","MPTokenIssuanceID":"000004C463C52827307480341125DA0577DEFC38405B0E3E","OwnerNode":"0000000000000000","PreviousTxnID":"F0AB71E777B2DA54B86231E19B82554EF1F8211F92ECA473121C655BFC5329BF","PreviousTxnLgrSeq":14524914,"index":"A738A1E6E8505E1FC77BBB9FEF84FF9A9C609F2739E0F9573CDD6367100A0AA9"}],"ledger_current_index":14380381}"#;
+        let account_objects: AccountObjects = serde_json::from_str(json).unwrap();
+
+        assert_eq!(account_objects.deletion_blockers().count(), 1);
+        assert!(!account_objects.can_delete_account());
+
+        let json = r#"{"account":"rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx","account_objects":[],"ledger_current_index":14380381,"limit":10,"marker":"F60ADF645E78B69857D2E4AEC8B7742FEABC8431BD8611D099B428C3E816DF93,0"}"#;
+        let account_objects: AccountObjects = serde_json::from_str(json).unwrap();
+
+        assert!(!account_objects.can_delete_account());
+    }
+}
@@ -0,0 +1,184 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::amount::{normalized_value, Amount};
+use crate::models::currency::Currency;
+
+/// An Offer of an `account_offers` response.
+///
+/// See Account Offers:
+/// `<https://xrpl.org/account_offers.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct AccountOffer<'a> {
+    /// Options set for this offer entry as bit-flags.
+    pub flags: u32,
+    /// Sequence number of the transaction that created this entry.
+    pub seq: u32,
+    /// The amount the account placing this Offer receives.
+    #[serde(borrow)]
+    pub taker_gets: Amount<'a>,
+    /// The amount the account placing this Offer pays.
+    pub taker_pays: Amount<'a>,
+    /// The exchange rate of the Offer, as the ratio of the
+    /// original `taker_pays` divided by the original `taker_gets`.
+    pub quality: Cow<'a, str>,
+    /// A time after which this Offer is considered unfunded, as
+    /// the number of seconds since the Ripple Epoch.
+    pub expiration: Option<u32>,
+}
+
+impl<'a> AccountOffer<'a> {
+    /// Returns the `quality` as decimal. Returns `None` if it is
+    /// not a valid number.
+    pub fn quality(&self) -> Option<Decimal> {
+        normalized_value(&self.quality)
+    }
+
+    /// Returns whether the Offer trades `gets` for `pays` from
+    /// the view of the account placing it.
+    pub fn is_pair(&self, gets: &Currency, pays: &Currency) -> bool {
+        let currency = |amount: &Amount<'a>| Currency::try_from(amount.clone()).ok();
+
+        currency(&self.taker_gets).as_ref() == Some(gets)
+            && currency(&self.taker_pays).as_ref() == Some(pays)
+    }
+
+    /// Returns the offer without borrowed data.
+    pub fn into_owned(self) -> AccountOffer<'static> {
+        AccountOffer {
+            flags: self.flags,
+            seq: self.seq,
+            taker_gets: self.taker_gets.into_owned(),
+            taker_pays: self.taker_pays.into_owned(),
+            quality: Cow::Owned(self.quality.into_owned()),
+            expiration: self.expiration,
+        }
+    }
+}
+
+/// The result of an `account_offers` request containing the
+/// Offers made by an account.
+///
+/// See Account Offers:
+/// `<https://xrpl.org/account_offers.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct AccountOffers<'a> {
+    /// Unique address identifying the account that made the Offers.
+    pub account: Cow<'a, str>,
+    /// The Offers made by this account that are outstanding.
+    #[serde(borrow)]
+    pub offers: Vec<AccountOffer<'a>>,
+    /// The identifying hash of the ledger that was used to
+    /// generate this response.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    /// The ledger index of the ledger that was used to generate
+    /// this response.
+    pub ledger_index: Option<u32>,
+    /// The ledger index of the current in-progress ledger version,
+    /// which was used to generate this response.
+    pub ledger_current_index: Option<u32>,
+    /// The limit that was used in this request, if any.
+    pub limit: Option<u32>,
+    /// Server-defined value indicating the response is paginated.
+    /// Pass this to the next call to resume where this call left off.
+    pub marker: Option<Value>,
+    /// If true, this data comes from a validated ledger.
+    pub validated: Option<bool>,
+}
+
+impl<'a> AccountOffers<'a> {
+    /// Returns the Offers of the order book trading `gets` for
+    /// `pays`, ordered by ascending `quality`. This is the order in
+    /// which the order book consumes them.
+    pub fn by_pair(&self, gets: &Currency, pays: &Currency) -> Vec<&AccountOffer<'a>> {
+        let mut offers: Vec<&AccountOffer<'a>> = self
+            .offers
+            .iter()
+            .filter(|offer| offer.is_pair(gets, pays))
+            .collect();
+        offers.sort_by_key(|offer| offer.quality());
+
+        offers
+    }
+
+    /// Returns the result without borrowed data.
+    pub fn into_owned(self) -> AccountOffers<'static> {
+        AccountOffers {
+            account: Cow::Owned(self.account.into_owned()),
+            offers: self
+                .offers
+                .into_iter()
+                .map(AccountOffer::into_owned)
+                .collect(),
+            ledger_hash: self
+                .ledger_hash
+                .map(|ledger_hash| Cow::Owned(ledger_hash.into_owned())),
+            ledger_index: self.ledger_index,
+            ledger_current_index: self.ledger_current_index,
+            limit: self.limit,
+            marker: self.marker,
+            validated: self.validated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+    use crate::models::currency::{IssuedCurrency, XRP};
+    use alloc::vec;
+
+    const ACCOUNT_OFFERS: &str = r#"{"account":"rpP2JgiMyTF5jR5hLG3xHCPi1knBb1v9cM","ledger_current_index":18539596,"offers":[{"flags":0,"quality":"0.000000007599140009999998","seq":6578020,"taker_gets":"29740867287","taker_pays":{"currency":"USD","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","value":"226.0050145327418"}},{"flags":0,"quality":"7989247009094172e-24","seq":6572128,"taker_gets":"2361918758","taker_pays":{"currency":"USD","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","value":"18.87001561455497"}},{"flags":0,"quality":"0.00000004307706997609256","seq":6576399,"taker_gets":"12280377","taker_pays":{"currency":"EUR","issuer":"rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq","value":"0.529"}},{"flags":0,"quality":"132899.7","seq":6577664,"taker_gets":{"currency":"USD","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","value":"10"},"taker_pays":"1328997"}],"validated":false}"#;
+
+    fn usd<'a>() -> Currency<'a> {
+        Currency::IssuedCurrency(IssuedCurrency::new(
+            "USD".into(),
+            "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+        ))
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let account_offers: AccountOffers = serde_json::from_str(ACCOUNT_OFFERS).unwrap();
+
+        assert_eq!(account_offers.offers.len(), 4);
+        assert_eq!(account_offers.offers[0].seq, 6578020);
+        assert_eq!(
+            account_offers.offers[1].quality(),
+            Some(Decimal::new(7989247009094172, 24))
+        );
+        assert_eq!(
+            serde_json::to_value(&account_offers).unwrap(),
+            serde_json::from_str::<Value>(ACCOUNT_OFFERS).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_by_pair() {
+        let account_offers: AccountOffers = serde_json::from_str(ACCOUNT_OFFERS).unwrap();
+        let xrp = Currency::XRP(XRP::new());
+
+        let xrp_for_usd: Vec<u32> = account_offers
+            .by_pair(&xrp, &usd())
+            .iter()
+            .map(|offer| offer.seq)
+            .collect();
+        assert_eq!(xrp_for_usd, vec![6578020, 6572128]);
+
+        let usd_for_xrp: Vec<u32> = account_offers
+            .by_pair(&usd(), &xrp)
+            .iter()
+            .map(|offer| offer.seq)
+            .collect();
+        assert_eq!(usd_for_xrp, vec![6577664]);
+
+        assert!(account_offers.by_pair(&usd(), &usd()).is_empty());
+    }
+}
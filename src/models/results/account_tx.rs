@@ -0,0 +1,182 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::results::TransactionMetadata;
+use crate::models::transactions::AnyTransaction;
+
+/// A transaction of an `account_tx` response together with
+/// its metadata.
+///
+/// API version 1 returns the transaction as `tx`, API version 2
+/// as `tx_json` with the `hash` moved next to it. Both shapes are
+/// accepted; use `transaction` to get the transaction regardless
+/// of the API version.
+///
+/// See Account Tx:
+/// `<https://xrpl.org/account_tx.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct AccountTxTransaction<'a> {
+    /// The transaction, as returned by API version 1.
+    #[serde(borrow)]
+    pub tx: Option<AnyTransaction<'a>>,
+    /// The transaction, as returned by API version 2.
+    #[serde(borrow)]
+    pub tx_json: Option<AnyTransaction<'a>>,
+    /// The transaction metadata describing its outcome.
+    #[serde(borrow)]
+    pub meta: Option<TransactionMetadata<'a>>,
+    /// The identifying hash of the transaction. Only returned
+    /// next to the transaction by API version 2.
+    pub hash: Option<Cow<'a, str>>,
+    /// The ledger index of the ledger that includes the
+    /// transaction. Only returned by API version 2.
+    pub ledger_index: Option<u32>,
+    /// Whether the transaction is included in a validated ledger.
+    pub validated: bool,
+}
+
+impl<'a> AccountTxTransaction<'a> {
+    /// Returns the transaction, whether it was returned as `tx`
+    /// or as `tx_json`.
+    pub fn transaction(&self) -> Option<&AnyTransaction<'a>> {
+        self.tx.as_ref().or(self.tx_json.as_ref())
+    }
+
+    /// Returns the metadata of the transaction.
+    pub fn metadata(&self) -> Option<&TransactionMetadata<'a>> {
+        self.meta.as_ref()
+    }
+
+    /// Returns the transaction without borrowed data.
+    pub fn into_owned(self) -> AccountTxTransaction<'static> {
+        AccountTxTransaction {
+            tx: self.tx.map(AnyTransaction::into_owned),
+            tx_json: self.tx_json.map(AnyTransaction::into_owned),
+            meta: self.meta.map(TransactionMetadata::into_owned),
+            hash: self.hash.map(|hash| Cow::Owned(hash.into_owned())),
+            ledger_index: self.ledger_index,
+            validated: self.validated,
+        }
+    }
+}
+
+/// The result of an `account_tx` request containing the
+/// transactions that affected the account.
+///
+/// See Account Tx:
+/// `<https://xrpl.org/account_tx.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct AccountTx<'a> {
+    /// The unique address of the account.
+    pub account: Cow<'a, str>,
+    /// The ledger index of the earliest ledger actually searched.
+    pub ledger_index_min: u32,
+    /// The ledger index of the most recent ledger actually searched.
+    pub ledger_index_max: u32,
+    /// The limit value used in the request.
+    pub limit: Option<u32>,
+    /// Server-defined value indicating the response is paginated.
+    /// Pass this to the next call to resume where this call left off.
+    pub marker: Option<Value>,
+    /// The transactions that affected the account, with their metadata.
+    #[serde(borrow)]
+    pub transactions: Vec<AccountTxTransaction<'a>>,
+    /// If true, this data comes from a validated ledger.
+    pub validated: Option<bool>,
+}
+
+impl<'a> AccountTx<'a> {
+    /// Returns the result without borrowed data.
+    pub fn into_owned(self) -> AccountTx<'static> {
+        AccountTx {
+            account: Cow::Owned(self.account.into_owned()),
+            ledger_index_min: self.ledger_index_min,
+            ledger_index_max: self.ledger_index_max,
+            limit: self.limit,
+            marker: self.marker,
+            transactions: self
+                .transactions
+                .into_iter()
+                .map(AccountTxTransaction::into_owned)
+                .collect(),
+            validated: self.validated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+    use crate::models::transactions::Transaction;
+    use alloc::string::ToString;
+
+    const ACCOUNT_TX_V1: &str = r#"{"account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","ledger_index_max":84803072,"ledger_index_min":32570,"limit":1,"marker":{"ledger":84802994,"seq":12},"transactions":[{"meta":{"AffectedNodes":[{"ModifiedNode":{"FinalFields":{"Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","Balance":"98999988","Flags":0,"OwnerCount":0,"Sequence":3},"LedgerEntryType":"AccountRoot","LedgerIndex":"13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8","PreviousFields":{"Balance":"100000000","Sequence":2}}}],"TransactionIndex":12,"TransactionResult":"tesSUCCESS","delivered_amount":"1000000"},"tx":{"Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","Amount":"1000000","Destination":"rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK","Fee":"12","Flags":0,"Sequence":2,"TransactionType":"Payment","date":782423350,"hash":"E08D6E9754025BA2534A78707605E0601F03ACE063687A0CA1BDDACFCD1698C7","inLedger":84802994,"ledger_index":84802994},"validated":true}],"validated":true}"#;
+    const ACCOUNT_TX_V2: &str = r#"{"account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","ledger_index_max":84803072,"ledger_index_min":32570,"transactions":[{"hash":"9C7B5B1C5BF1F5DE2F2C9E6AA3E0E9A1E5C2A1F2A7E0C2C1B7C5E6F0B0F2D1A8","ledger_index":84802994,"meta":{"AffectedNodes":[{"ModifiedNode":{"FinalFields":{"Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Balance":"99999988","Domain":"6578616D706C652E636F6D","Flags":0,"OwnerCount":0,"Sequence":6},"LedgerEntryType":"AccountRoot","LedgerIndex":"9B242A0D59328CE964FFFBFF7D3BBF8B024F9CB1A212923727B42F24ADC93930","PreviousFields":{"Balance":"100000000","Sequence":5}}}],"TransactionIndex":3,"TransactionResult":"tesSUCCESS"},"tx_json":{"Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Domain":"6578616D706C652E636F6D","Fee":"12","Flags":0,"Sequence":5,"TransactionType":"AccountSet","date":782423350,"ledger_index":84802994},"validated":true}],"validated":true}"#;
+
+    #[test]
+    fn test_deserialize_api_v1() {
+        let account_tx: AccountTx = serde_json::from_str(ACCOUNT_TX_V1).unwrap();
+        let transaction = &account_tx.transactions[0];
+
+        assert_eq!(account_tx.transactions.len(), 1);
+        assert!(transaction.validated);
+        assert!(transaction.tx_json.is_none());
+        match transaction.transaction() {
+            Some(AnyTransaction::Payment(payment)) => {
+                assert_eq!(payment.destination, "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK");
+                assert_eq!(payment.common_fields.sequence, Some(2));
+            }
+            other => panic!("expected `Payment`, found {:?}", other),
+        }
+        let metadata = transaction.metadata().unwrap();
+        assert_eq!(metadata.transaction_result, "tesSUCCESS");
+        assert_eq!(
+            metadata.sequence_after("rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb"),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_api_v2() {
+        let account_tx: AccountTx = serde_json::from_str(ACCOUNT_TX_V2).unwrap();
+        let transaction = &account_tx.transactions[0];
+
+        assert!(transaction.tx.is_none());
+        assert_eq!(transaction.ledger_index, Some(84802994));
+        assert_eq!(
+            transaction.hash.as_deref(),
+            Some("9C7B5B1C5BF1F5DE2F2C9E6AA3E0E9A1E5C2A1F2A7E0C2C1B7C5E6F0B0F2D1A8")
+        );
+        match transaction.transaction() {
+            Some(AnyTransaction::AccountSet(account_set)) => {
+                assert_eq!(
+                    account_set.domain.as_deref(),
+                    Some("6578616D706C652E636F6D")
+                );
+                assert!(!account_set.is_pseudo_transaction());
+            }
+            other => panic!("expected `AccountSet`, found {:?}", other),
+        }
+        assert_eq!(transaction.metadata().unwrap().transaction_index, 3);
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let account_tx = {
+            let json = ACCOUNT_TX_V2.to_string();
+            serde_json::from_str::<AccountTx>(&json)
+                .unwrap()
+                .into_owned()
+        };
+
+        assert_eq!(
+            account_tx,
+            serde_json::from_str::<AccountTx>(ACCOUNT_TX_V2).unwrap()
+        );
+    }
+}
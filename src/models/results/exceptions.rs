@@ -0,0 +1,19 @@
+use alloc::string::String;
+use thiserror_no_std::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLResponseException {
+    /// The request failed and rippled responded with an error.
+    #[error("The request failed with error `{error:?}` ({error_message:?})")]
+    ErrorResponse {
+        error: String,
+        error_message: String,
+    },
+    /// A successful response did not contain a result.
+    #[error("The response does not contain a result")]
+    MissingResult,
+}
+
+#[cfg(feature = "std")]
+impl alloc::error::Error for XRPLResponseException {}
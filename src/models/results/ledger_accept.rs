@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// The result of a `ledger_accept` request containing the
+/// index of the new current in-progress ledger.
+///
+/// See Ledger Accept:
+/// `<https://xrpl.org/ledger_accept.html#response-format>`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct LedgerAccept {
+    /// The ledger index of the ledger opened after closing
+    /// the previous one.
+    pub ledger_current_index: u32,
+}
+
+impl LedgerAccept {
+    pub fn ledger_index(&self) -> u32 {
+        self.ledger_current_index
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let json = r#"{"ledger_current_index":6643241}"#;
+        let ledger_accept: LedgerAccept = serde_json::from_str(json).unwrap();
+
+        assert_eq!(ledger_accept.ledger_index(), 6643241);
+    }
+}
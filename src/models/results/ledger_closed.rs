@@ -0,0 +1,50 @@
+use alloc::borrow::Cow;
+use serde::{Deserialize, Serialize};
+
+/// The result of a `ledger_closed` request containing the
+/// unique identifiers of the most recently closed ledger.
+///
+/// See Ledger Closed:
+/// `<https://xrpl.org/ledger_closed.html#response-format>`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct LedgerClosed<'a> {
+    /// The unique Hash of this ledger version, in hexadecimal.
+    pub ledger_hash: Cow<'a, str>,
+    /// The ledger index of this ledger version.
+    pub ledger_index: u32,
+}
+
+impl<'a> LedgerClosed<'a> {
+    pub fn ledger_hash(&self) -> &str {
+        &self.ledger_hash
+    }
+
+    pub fn ledger_index(&self) -> u32 {
+        self.ledger_index
+    }
+
+    /// Returns the result without borrowed data.
+    pub fn into_owned(self) -> LedgerClosed<'static> {
+        LedgerClosed {
+            ledger_hash: Cow::Owned(self.ledger_hash.into_owned()),
+            ledger_index: self.ledger_index,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let json = r#"{"ledger_hash":"17ACB57A0F73B5160713F81FE2F6B1C8A5E3A3F8D6F1E27E3E4F4A8C4B0E5D2A","ledger_index":6643099}"#;
+        let ledger_closed: LedgerClosed = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            ledger_closed.ledger_hash(),
+            "17ACB57A0F73B5160713F81FE2F6B1C8A5E3A3F8D6F1E27E3E4F4A8C4B0E5D2A"
+        );
+        assert_eq!(ledger_closed.ledger_index(), 6643099);
+    }
+}
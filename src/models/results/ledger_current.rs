@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// The result of a `ledger_current` request containing the
+/// index of the current in-progress ledger.
+///
+/// See Ledger Current:
+/// `<https://xrpl.org/ledger_current.html#response-format>`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct LedgerCurrent {
+    /// The ledger index of this ledger version.
+    pub ledger_current_index: u32,
+}
+
+impl LedgerCurrent {
+    pub fn ledger_index(&self) -> u32 {
+        self.ledger_current_index
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let json = r#"{"ledger_current_index":6643240}"#;
+        let ledger_current: LedgerCurrent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(ledger_current.ledger_index(), 6643240);
+    }
+}
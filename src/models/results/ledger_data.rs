@@ -0,0 +1,157 @@
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use serde_with::skip_serializing_none;
+
+use crate::core::binarycodec::decode;
+use crate::Err;
+
+/// A ledger object of a `ledger_data` response.
+///
+/// If the request set `binary` to `true`, the object is returned
+/// as hex encoded binary `data`, otherwise its fields are returned
+/// as JSON next to the `index`. Use `ledger_object` to get the
+/// typed ledger object in both cases.
+///
+/// See Ledger Data:
+/// `<https://xrpl.org/ledger_data.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct LedgerDataObject<'a> {
+    /// The hex encoded binary ledger object, if requested as binary.
+    pub data: Option<Cow<'a, str>>,
+    /// The object ID of the ledger object.
+    pub index: Cow<'a, str>,
+    /// The fields of the ledger object, if requested as JSON.
+    #[serde(flatten)]
+    pub fields: Map<String, Value>,
+}
+
+impl<'a> LedgerDataObject<'a> {
+    /// Returns the ledger object as the given ledger object type,
+    /// decoding it from its binary format if necessary. The
+    /// ledger object owns its data.
+    pub fn ledger_object<T: Deserialize<'static>>(&self) -> Result<T> {
+        let mut object = match &self.data {
+            Some(data) => match decode(data) {
+                Ok(Value::Object(object)) => object,
+                Ok(_) => self.fields.clone(),
+                Err(error) => return Err!(error),
+            },
+            None => self.fields.clone(),
+        };
+        object.insert("index".into(), Value::from(self.index.as_ref()));
+
+        match T::deserialize(Value::Object(object)) {
+            Ok(ledger_object) => Ok(ledger_object),
+            Err(error) => Err!(error),
+        }
+    }
+
+    /// Returns the ledger object without borrowed data.
+    pub fn into_owned(self) -> LedgerDataObject<'static> {
+        LedgerDataObject {
+            data: self.data.map(|data| Cow::Owned(data.into_owned())),
+            index: Cow::Owned(self.index.into_owned()),
+            fields: self.fields,
+        }
+    }
+}
+
+/// The result of a `ledger_data` request containing the
+/// contents of a ledger.
+///
+/// See Ledger Data:
+/// `<https://xrpl.org/ledger_data.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct LedgerData<'a> {
+    /// The identifying hash of this ledger version.
+    pub ledger_hash: Cow<'a, str>,
+    /// The ledger index of this ledger version.
+    pub ledger_index: Value,
+    /// Server-defined value indicating the response is paginated.
+    /// Pass this to the next call to resume where this call left off.
+    pub marker: Option<Value>,
+    /// The ledger objects in this ledger version.
+    #[serde(borrow)]
+    pub state: Vec<LedgerDataObject<'a>>,
+    /// Whether this ledger version is validated.
+    pub validated: Option<bool>,
+}
+
+impl<'a> LedgerData<'a> {
+    /// Returns the result without borrowed data.
+    pub fn into_owned(self) -> LedgerData<'static> {
+        LedgerData {
+            ledger_hash: Cow::Owned(self.ledger_hash.into_owned()),
+            ledger_index: self.ledger_index,
+            marker: self.marker,
+            state: self
+                .state
+                .into_iter()
+                .map(LedgerDataObject::into_owned)
+                .collect(),
+            validated: self.validated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+    use crate::models::amount::{Amount, IssuedCurrencyAmount, XRPAmount};
+    use crate::models::ledger::Offer;
+
+    const LEDGER_DATA_BINARY: &str = r#"{"ledger_hash":"842B57C1CC0613299A686D3E9F310EC0422C84D3911E5056389AA7E5808A93C8","ledger_index":"6885842","marker":"0002A4F4E0D2D0F9ED19BB5E85E4FDEE0A0F0DE87A3C8C24C0A8F6A7A2E8D1B0","state":[{"data":"11006F2200000000240000000825000045A23300000000000000003400000000000000005515955F0DCBF3237CE8F5ACAB92C81B4368857AF2E9BD2BC3D0C1D9CEA26F45BA50102FB4904ACFB96228FC002335B1B5A4C5584D9D727BBE82145003BAF82D03A00064D4CB30E8870AE00000000000000000000000000055534400000000002B6C42A95B3F7EE1971E4A10098E8F1B5F66AA086540000000002DC6C0811462FE474693228F7F9ED1C5EFADB3B6555FBEAFBE","index":"0000041EFD027808D3F78C8352F97E324CB816318E00B977C74ECDDC7CD975B2"}]}"#;
+
+    #[test]
+    fn test_deserialize_binary() {
+        let ledger_data: LedgerData = serde_json::from_str(LEDGER_DATA_BINARY).unwrap();
+        let offer: Offer = ledger_data.state[0].ledger_object().unwrap();
+
+        assert_eq!(ledger_data.state.len(), 1);
+        assert_eq!(offer.index, ledger_data.state[0].index);
+        assert_eq!(offer.account, "rwpRq4gQrb58N7PRJwYEQaoSui6Xd3FC7j");
+        assert_eq!(offer.sequence, 8);
+        assert_eq!(offer.previous_txn_lgr_seq, 17826);
+        assert_eq!(
+            offer.taker_gets,
+            Amount::XRPAmount(XRPAmount::from("3000000"))
+        );
+        assert_eq!(
+            offer.taker_pays,
+            Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rhxbkK9jGqPVLZSWPvCEmmf15xHBfJfCEy".into(),
+                "31.5".into(),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_deserialize_json() {
+        let json = r#"{"ledger_hash":"842B57C1CC0613299A686D3E9F310EC0422C84D3911E5056389AA7E5808A93C8","ledger_index":6885842,"state":[{"Account":"rwpRq4gQrb58N7PRJwYEQaoSui6Xd3FC7j","BookDirectory":"2FB4904ACFB96228FC002335B1B5A4C5584D9D727BBE82145003BAF82D03A000","BookNode":"0000000000000000","Flags":0,"LedgerEntryType":"Offer","OwnerNode":"0000000000000000","PreviousTxnID":"15955F0DCBF3237CE8F5ACAB92C81B4368857AF2E9BD2BC3D0C1D9CEA26F45BA","PreviousTxnLgrSeq":17826,"Sequence":8,"TakerGets":"3000000","TakerPays":{"currency":"USD","issuer":"rhxbkK9jGqPVLZSWPvCEmmf15xHBfJfCEy","value":"31.5"},"index":"0000041EFD027808D3F78C8352F97E324CB816318E00B977C74ECDDC7CD975B2"}],"validated":true}"#;
+        let ledger_data: LedgerData = serde_json::from_str(json).unwrap();
+        let binary: LedgerData = serde_json::from_str(LEDGER_DATA_BINARY).unwrap();
+
+        assert_eq!(
+            ledger_data.state[0].ledger_object::<Offer>().unwrap(),
+            binary.state[0].ledger_object::<Offer>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ledger_object_error() {
+        let object = LedgerDataObject {
+            data: Some("11006F".into()),
+            index: "0000041EFD027808D3F78C8352F97E324CB816318E00B977C74ECDDC7CD975B2".into(),
+            fields: Map::new(),
+        };
+
+        assert!(object.ledger_object::<Offer>().is_err());
+    }
+}
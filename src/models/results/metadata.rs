@@ -0,0 +1,438 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::amount::{Amount, XRPAmount};
+
+/// A ledger object that was created by a transaction.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreatedNode<'a> {
+    /// The type of the ledger object.
+    pub ledger_entry_type: Cow<'a, str>,
+    /// The ID of the ledger object.
+    pub ledger_index: Cow<'a, str>,
+    /// The content fields of the newly created ledger object.
+    pub new_fields: Value,
+}
+
+impl<'a> CreatedNode<'a> {
+    /// Returns the node without borrowed data.
+    pub fn into_owned(self) -> CreatedNode<'static> {
+        CreatedNode {
+            ledger_entry_type: Cow::Owned(self.ledger_entry_type.into_owned()),
+            ledger_index: Cow::Owned(self.ledger_index.into_owned()),
+            new_fields: self.new_fields,
+        }
+    }
+}
+
+/// A ledger object that was modified by a transaction.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModifiedNode<'a> {
+    /// The type of the ledger object.
+    pub ledger_entry_type: Cow<'a, str>,
+    /// The ID of the ledger object.
+    pub ledger_index: Cow<'a, str>,
+    /// The content fields of the ledger object after applying
+    /// the transaction.
+    pub final_fields: Option<Value>,
+    /// The previous values of the content fields that were
+    /// changed by the transaction.
+    pub previous_fields: Option<Value>,
+    /// The identifying hash of the previous transaction to
+    /// modify this ledger object.
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Option<Cow<'a, str>>,
+    /// The ledger index of the ledger that contains the
+    /// previous transaction to modify this ledger object.
+    pub previous_txn_lgr_seq: Option<u32>,
+}
+
+impl<'a> ModifiedNode<'a> {
+    /// Returns the node without borrowed data.
+    pub fn into_owned(self) -> ModifiedNode<'static> {
+        ModifiedNode {
+            ledger_entry_type: Cow::Owned(self.ledger_entry_type.into_owned()),
+            ledger_index: Cow::Owned(self.ledger_index.into_owned()),
+            final_fields: self.final_fields,
+            previous_fields: self.previous_fields,
+            previous_txn_id: self
+                .previous_txn_id
+                .map(|previous_txn_id| Cow::Owned(previous_txn_id.into_owned())),
+            previous_txn_lgr_seq: self.previous_txn_lgr_seq,
+        }
+    }
+}
+
+/// A ledger object that was deleted by a transaction.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeletedNode<'a> {
+    /// The type of the ledger object.
+    pub ledger_entry_type: Cow<'a, str>,
+    /// The ID of the ledger object.
+    pub ledger_index: Cow<'a, str>,
+    /// The content fields of the ledger object immediately
+    /// before it was deleted.
+    pub final_fields: Value,
+    /// The previous values of the content fields that were
+    /// changed by the transaction before deleting the object.
+    pub previous_fields: Option<Value>,
+}
+
+impl<'a> DeletedNode<'a> {
+    /// Returns the node without borrowed data.
+    pub fn into_owned(self) -> DeletedNode<'static> {
+        DeletedNode {
+            ledger_entry_type: Cow::Owned(self.ledger_entry_type.into_owned()),
+            ledger_index: Cow::Owned(self.ledger_index.into_owned()),
+            final_fields: self.final_fields,
+            previous_fields: self.previous_fields,
+        }
+    }
+}
+
+/// A ledger object affected by a transaction.
+///
+/// See Affected Nodes:
+/// `<https://xrpl.org/transaction-metadata.html#affectednodes>`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub enum AffectedNode<'a> {
+    CreatedNode(CreatedNode<'a>),
+    ModifiedNode(ModifiedNode<'a>),
+    DeletedNode(DeletedNode<'a>),
+}
+
+impl<'a> AffectedNode<'a> {
+    /// Returns the node without borrowed data.
+    pub fn into_owned(self) -> AffectedNode<'static> {
+        match self {
+            AffectedNode::CreatedNode(node) => AffectedNode::CreatedNode(node.into_owned()),
+            AffectedNode::ModifiedNode(node) => AffectedNode::ModifiedNode(node.into_owned()),
+            AffectedNode::DeletedNode(node) => AffectedNode::DeletedNode(node.into_owned()),
+        }
+    }
+}
+
+/// The metadata of a validated transaction, describing its
+/// outcome and the ledger objects it affected.
+///
+/// See Transaction Metadata:
+/// `<https://xrpl.org/transaction-metadata.html>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct TransactionMetadata<'a> {
+    /// The ledger objects created, modified or deleted by
+    /// the transaction.
+    pub affected_nodes: Vec<AffectedNode<'a>>,
+    /// The transaction's position within the ledger that
+    /// included it.
+    pub transaction_index: u32,
+    /// The result code of the transaction.
+    pub transaction_result: Cow<'a, str>,
+    /// The amount actually delivered by a Payment.
+    #[serde(rename = "delivered_amount")]
+    #[serde(borrow)]
+    pub delivered_amount: Option<Amount<'a>>,
+}
+
+impl<'a> TransactionMetadata<'a> {
+    /// Returns the XRP balance of `account` after applying the
+    /// transaction, or `None` if its `AccountRoot` was not created
+    /// or modified by the transaction.
+    pub fn balance_after(&self, account: &str) -> Option<XRPAmount<'static>> {
+        self._get_account_root_fields(account)?["Balance"]
+            .as_str()
+            .map(|balance| XRPAmount::from(String::from(balance)))
+    }
+
+    /// Returns the sequence number of `account` after applying the
+    /// transaction, or `None` if its `AccountRoot` was not created
+    /// or modified by the transaction.
+    pub fn sequence_after(&self, account: &str) -> Option<u32> {
+        self._get_account_root_fields(account)?["Sequence"]
+            .as_u64()
+            .and_then(|sequence| sequence.try_into().ok())
+    }
+
+    /// Returns the resulting fields of the `AccountRoot` of `account`.
+    fn _get_account_root_fields(&self, account: &str) -> Option<&Value> {
+        self.affected_nodes
+            .iter()
+            .filter_map(|affected_node| match affected_node {
+                AffectedNode::CreatedNode(node) if node.ledger_entry_type == "AccountRoot" => {
+                    Some(&node.new_fields)
+                }
+                AffectedNode::ModifiedNode(node) if node.ledger_entry_type == "AccountRoot" => {
+                    node.final_fields.as_ref()
+                }
+                _ => None,
+            })
+            .find(|fields| fields["Account"].as_str() == Some(account))
+    }
+
+    /// Returns the metadata without borrowed data.
+    pub fn into_owned(self) -> TransactionMetadata<'static> {
+        TransactionMetadata {
+            affected_nodes: self
+                .affected_nodes
+                .into_iter()
+                .map(AffectedNode::into_owned)
+                .collect(),
+            transaction_index: self.transaction_index,
+            transaction_result: Cow::Owned(self.transaction_result.into_owned()),
+            delivered_amount: self.delivered_amount.map(Amount::into_owned),
+        }
+    }
+}
+
+/// Returns the ID of the NFToken minted by an `NFTokenMint`
+/// transaction, given the transaction's metadata.
+///
+/// The ID is only available from the metadata, by comparing the
+/// `NFTokens` of the affected `NFTokenPage` objects before and
+/// after the transaction.
+pub fn get_nftoken_id(meta: &TransactionMetadata) -> Option<String> {
+    let mut previous_nftoken_ids = Vec::new();
+    let mut final_nftoken_ids = Vec::new();
+
+    for affected_node in meta.affected_nodes.iter() {
+        match affected_node {
+            AffectedNode::CreatedNode(node) if node.ledger_entry_type == "NFTokenPage" => {
+                final_nftoken_ids.extend(_get_nftoken_ids(&node.new_fields));
+            }
+            AffectedNode::ModifiedNode(node) if node.ledger_entry_type == "NFTokenPage" => {
+                // The `PreviousFields` only list the `NFTokens` if they changed.
+                if let (Some(previous_fields), Some(final_fields)) =
+                    (&node.previous_fields, &node.final_fields)
+                {
+                    previous_nftoken_ids.extend(_get_nftoken_ids(previous_fields));
+                    final_nftoken_ids.extend(_get_nftoken_ids(final_fields));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    final_nftoken_ids
+        .into_iter()
+        .find(|nftoken_id| !previous_nftoken_ids.contains(nftoken_id))
+        .map(String::from)
+}
+
+fn _get_nftoken_ids(fields: &Value) -> Vec<&str> {
+    match fields["NFTokens"].as_array() {
+        Some(nftokens) => nftokens
+            .iter()
+            .filter_map(|nftoken| nftoken["NFToken"]["NFTokenID"].as_str())
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    const NFTOKEN_MINT_META: &str = r#"{
+        "AffectedNodes": [
+            {
+                "ModifiedNode": {
+                    "FinalFields": {
+                        "Account": "rJp6SEa8tHCpW7a5V8C5PGrz2BjFp7kuEr",
+                        "Balance": "99999988",
+                        "Flags": 0,
+                        "MintedNFTokens": 2,
+                        "OwnerCount": 1,
+                        "Sequence": 3
+                    },
+                    "LedgerEntryType": "AccountRoot",
+                    "LedgerIndex": "0BAF0E12F0D2A3A7AB93FA1C14DB2D44CAD3CD8B4DDF14F7EC3AFDB3F9E88A41",
+                    "PreviousFields": {
+                        "Balance": "99999994",
+                        "MintedNFTokens": 1,
+                        "Sequence": 2
+                    },
+                    "PreviousTxnID": "5F1E2D8B7FCAE7E7A6A9C2E5A8B1C7F3D0E6B2D9A4C7E1F8B3A6D5C2E9F0A1B4",
+                    "PreviousTxnLgrSeq": 5
+                }
+            },
+            {
+                "ModifiedNode": {
+                    "FinalFields": {
+                        "Flags": 0,
+                        "NFTokens": [
+                            {
+                                "NFToken": {
+                                    "NFTokenID": "00080000BD3B9D0A4E3C1F4D9FE0AB9BE3B10B0E9F6A5BD70000099B00000000",
+                                    "URI": "697066733A2F2F62616679"
+                                }
+                            },
+                            {
+                                "NFToken": {
+                                    "NFTokenID": "00080000BD3B9D0A4E3C1F4D9FE0AB9BE3B10B0E9F6A5BD716E5DA9C00000001",
+                                    "URI": "697066733A2F2F62616679"
+                                }
+                            }
+                        ]
+                    },
+                    "LedgerEntryType": "NFTokenPage",
+                    "LedgerIndex": "BD3B9D0A4E3C1F4D9FE0AB9BE3B10B0E9F6A5BD7FFFFFFFFFFFFFFFFFFFFFFFF",
+                    "PreviousFields": {
+                        "NFTokens": [
+                            {
+                                "NFToken": {
+                                    "NFTokenID": "00080000BD3B9D0A4E3C1F4D9FE0AB9BE3B10B0E9F6A5BD70000099B00000000",
+                                    "URI": "697066733A2F2F62616679"
+                                }
+                            }
+                        ]
+                    },
+                    "PreviousTxnID": "5F1E2D8B7FCAE7E7A6A9C2E5A8B1C7F3D0E6B2D9A4C7E1F8B3A6D5C2E9F0A1B4",
+                    "PreviousTxnLgrSeq": 5
+                }
+            }
+        ],
+        "TransactionIndex": 0,
+        "TransactionResult": "tesSUCCESS"
+    }"#;
+
+    #[test]
+    fn test_deserialize() {
+        let meta: TransactionMetadata = serde_json::from_str(NFTOKEN_MINT_META).unwrap();
+
+        assert_eq!(meta.transaction_index, 0);
+        assert_eq!(meta.transaction_result, "tesSUCCESS");
+        assert_eq!(meta.affected_nodes.len(), 2);
+        assert!(matches!(
+            &meta.affected_nodes[0],
+            AffectedNode::ModifiedNode(node) if node.ledger_entry_type == "AccountRoot"
+        ));
+        assert!(meta.delivered_amount.is_none());
+    }
+
+    #[test]
+    fn test_get_nftoken_id() {
+        let meta: TransactionMetadata = serde_json::from_str(NFTOKEN_MINT_META).unwrap();
+
+        assert_eq!(
+            get_nftoken_id(&meta).as_deref(),
+            Some("00080000BD3B9D0A4E3C1F4D9FE0AB9BE3B10B0E9F6A5BD716E5DA9C00000001")
+        );
+    }
+
+    #[test]
+    fn test_get_nftoken_id_from_created_page() {
+        let meta: TransactionMetadata = serde_json::from_str(
+            r#"{
+                "AffectedNodes": [
+                    {
+                        "CreatedNode": {
+                            "LedgerEntryType": "NFTokenPage",
+                            "LedgerIndex": "BD3B9D0A4E3C1F4D9FE0AB9BE3B10B0E9F6A5BD7FFFFFFFFFFFFFFFFFFFFFFFF",
+                            "NewFields": {
+                                "NFTokens": [
+                                    {
+                                        "NFToken": {
+                                            "NFTokenID": "00080000BD3B9D0A4E3C1F4D9FE0AB9BE3B10B0E9F6A5BD70000099B00000000",
+                                            "URI": "697066733A2F2F62616679"
+                                        }
+                                    }
+                                ]
+                            }
+                        }
+                    }
+                ],
+                "TransactionIndex": 1,
+                "TransactionResult": "tesSUCCESS"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_nftoken_id(&meta).as_deref(),
+            Some("00080000BD3B9D0A4E3C1F4D9FE0AB9BE3B10B0E9F6A5BD70000099B00000000")
+        );
+    }
+
+    const PAYMENT_META: &str = r#"{
+        "AffectedNodes": [
+            {
+                "ModifiedNode": {
+                    "FinalFields": {
+                        "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                        "Balance": "99998999988",
+                        "Flags": 0,
+                        "OwnerCount": 0,
+                        "Sequence": 3
+                    },
+                    "LedgerEntryType": "AccountRoot",
+                    "LedgerIndex": "2B6AC232AA4C4BE41BF49D2459FA4A0347E1B543A4C92FCEE0821C0201E2E9A8",
+                    "PreviousFields": {
+                        "Balance": "100000000000",
+                        "Sequence": 2
+                    },
+                    "PreviousTxnID": "8D1C0E3C7F4C2B6A5D9E8F7A6B5C4D3E2F1A0B9C8D7E6F5A4B3C2D1E0F9A8B7C",
+                    "PreviousTxnLgrSeq": 4
+                }
+            },
+            {
+                "CreatedNode": {
+                    "LedgerEntryType": "AccountRoot",
+                    "LedgerIndex": "4E3F2A1B0C9D8E7F6A5B4C3D2E1F0A9B8C7D6E5F4A3B2C1D0E9F8A7B6C5D4E3F",
+                    "NewFields": {
+                        "Account": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+                        "Balance": "1000000000",
+                        "Sequence": 5
+                    }
+                }
+            }
+        ],
+        "TransactionIndex": 0,
+        "TransactionResult": "tesSUCCESS",
+        "delivered_amount": "1000000000"
+    }"#;
+
+    #[test]
+    fn test_account_root_after() {
+        let meta: TransactionMetadata = serde_json::from_str(PAYMENT_META).unwrap();
+
+        assert_eq!(
+            meta.balance_after("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"),
+            Some(XRPAmount::from("99998999988"))
+        );
+        assert_eq!(
+            meta.sequence_after("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"),
+            Some(3)
+        );
+        assert_eq!(
+            meta.balance_after("ra5nK24KXen9AHvsdFTKHSANinZseWnPcX"),
+            Some(XRPAmount::from("1000000000"))
+        );
+        assert_eq!(
+            meta.sequence_after("ra5nK24KXen9AHvsdFTKHSANinZseWnPcX"),
+            Some(5)
+        );
+        assert_eq!(
+            meta.balance_after("rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb"),
+            None
+        );
+        assert_eq!(
+            meta.sequence_after("rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb"),
+            None
+        );
+        assert_eq!(
+            meta.delivered_amount,
+            Some(Amount::XRPAmount(XRPAmount::from("1000000000")))
+        );
+    }
+}
@@ -0,0 +1,37 @@
+pub mod account_channels;
+pub mod account_currencies;
+pub mod account_info;
+pub mod account_nfts;
+pub mod account_objects;
+pub mod account_offers;
+pub mod account_tx;
+pub mod exceptions;
+#[cfg(feature = "integration")]
+pub mod ledger_accept;
+pub mod ledger_closed;
+pub mod ledger_current;
+pub mod ledger_data;
+pub mod metadata;
+pub mod response;
+pub mod server_state;
+pub mod strict;
+pub mod subscribe;
+
+pub use account_channels::*;
+pub use account_currencies::*;
+pub use account_info::*;
+pub use account_nfts::*;
+pub use account_objects::*;
+pub use account_offers::*;
+pub use account_tx::*;
+pub use exceptions::*;
+#[cfg(feature = "integration")]
+pub use ledger_accept::*;
+pub use ledger_closed::*;
+pub use ledger_current::*;
+pub use ledger_data::*;
+pub use metadata::*;
+pub use response::*;
+pub use server_state::*;
+pub use strict::*;
+pub use subscribe::*;
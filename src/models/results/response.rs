@@ -0,0 +1,230 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::results::XRPLResponseException;
+
+/// The status of a response.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseStatus {
+    Success,
+    Error,
+}
+
+/// A warning rippled attached to a response.
+///
+/// See Response Formatting:
+/// `<https://xrpl.org/response-formatting.html#api-warnings>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Warning {
+    /// The unique ID of the warning, like 1001 for unsupported
+    /// amendments that reached majority or 1002 if the server is
+    /// amendment blocked.
+    pub id: u32,
+    /// A human-readable description of the warning.
+    pub message: String,
+    /// Additional information about the warning.
+    pub details: Option<Value>,
+}
+
+/// The envelope of a response from rippled, holding either the
+/// `result` of a successful request or the error it failed with.
+///
+/// JSON-RPC responses, which put the error fields into `result`,
+/// are accepted as well as WebSocket responses.
+///
+/// See Response Formatting:
+/// `<https://xrpl.org/response-formatting.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::results::{LedgerCurrent, XRPLResponse};
+///
+/// let json = r#"{"id":1,"status":"error","type":"response","error":"actNotFound","error_message":"Account not found."}"#;
+/// let response: XRPLResponse<LedgerCurrent> = serde_json::from_str(json).unwrap();
+///
+/// assert!(!response.is_success());
+/// assert_eq!(response.error_code(), Some("actNotFound"));
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Serialize, PartialEq, Clone)]
+pub struct XRPLResponse<T> {
+    /// The ID of the request this response belongs to.
+    pub id: Option<Value>,
+    /// The result of the request, if it succeeded.
+    pub result: Option<T>,
+    /// Whether the request succeeded.
+    pub status: Option<ResponseStatus>,
+    /// The type of the message, `response` for direct responses.
+    pub r#type: Option<String>,
+    /// A unique code of the error, like `actNotFound`.
+    pub error: Option<String>,
+    /// A human-readable description of the error.
+    pub error_message: Option<String>,
+    /// A copy of the request that caused the error.
+    pub request: Option<Value>,
+    /// Warnings about the request, like the use of an amendment
+    /// blocked server.
+    pub warnings: Option<Vec<Warning>>,
+}
+
+impl<T> XRPLResponse<T> {
+    /// Returns whether the request succeeded.
+    pub fn is_success(&self) -> bool {
+        self.error.is_none() && self.status != Some(ResponseStatus::Error)
+    }
+
+    /// Returns the code of the error, like `actNotFound`, if the
+    /// request failed.
+    pub fn error_code(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns whether the response contains the warning with
+    /// the ID.
+    pub fn has_warning(&self, id: u32) -> bool {
+        self.warnings
+            .iter()
+            .flatten()
+            .any(|warning| warning.id == id)
+    }
+
+    /// Turns the response into its result or the error the
+    /// request failed with.
+    pub fn into_result(self) -> Result<T, XRPLResponseException> {
+        if let Some(error) = self.error {
+            return Err(XRPLResponseException::ErrorResponse {
+                error,
+                error_message: self.error_message.unwrap_or_default(),
+            });
+        }
+
+        self.result.ok_or(XRPLResponseException::MissingResult)
+    }
+}
+
+#[derive(Deserialize)]
+struct XRPLResponseHelper {
+    id: Option<Value>,
+    result: Option<Value>,
+    status: Option<ResponseStatus>,
+    r#type: Option<String>,
+    error: Option<String>,
+    error_message: Option<String>,
+    request: Option<Value>,
+    warnings: Option<Vec<Warning>>,
+}
+
+impl<'de, T> Deserialize<'de> for XRPLResponse<T>
+where
+    T: Deserialize<'static>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut helper = XRPLResponseHelper::deserialize(deserializer)?;
+
+        // JSON-RPC responses have their error fields in `result`.
+        if helper.error.is_none() {
+            if let Some(Value::Object(result)) = &helper.result {
+                if result.contains_key("error") {
+                    let error_result: XRPLResponseHelper =
+                        serde_json::from_value(Value::Object(result.clone()))
+                            .map_err(D::Error::custom)?;
+                    helper.status = error_result.status;
+                    helper.error = error_result.error;
+                    helper.error_message = error_result.error_message;
+                    helper.request = error_result.request;
+                    helper.result = None;
+                }
+            }
+        }
+
+        let result = match helper.result {
+            Some(result) if helper.error.is_none() => {
+                Some(T::deserialize(result).map_err(D::Error::custom)?)
+            }
+            _ => None,
+        };
+
+        Ok(XRPLResponse {
+            id: helper.id,
+            result,
+            status: helper.status,
+            r#type: helper.r#type,
+            error: helper.error,
+            error_message: helper.error_message,
+            request: helper.request,
+            warnings: helper.warnings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::models::results::LedgerCurrent;
+
+    #[test]
+    fn test_deserialize_success() {
+        let json = r#"{"id":1,"result":{"ledger_current_index":6643240},"status":"success","type":"response"}"#;
+        let response: XRPLResponse<LedgerCurrent> = serde_json::from_str(json).unwrap();
+
+        assert!(response.is_success());
+        assert_eq!(response.error_code(), None);
+        assert_eq!(
+            response.into_result(),
+            Ok(LedgerCurrent {
+                ledger_current_index: 6643240
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_error() {
+        let json = r#"{"id":2,"status":"error","type":"response","error":"actNotFound","error_code":19,"error_message":"Account not found.","request":{"account":"rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn","command":"account_info","id":2}}"#;
+        let response: XRPLResponse<LedgerCurrent> = serde_json::from_str(json).unwrap();
+
+        assert!(!response.is_success());
+        assert_eq!(response.error_code(), Some("actNotFound"));
+        assert_eq!(
+            response.into_result(),
+            Err(XRPLResponseException::ErrorResponse {
+                error: "actNotFound".to_string(),
+                error_message: "Account not found.".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_json_rpc_error() {
+        let json = r#"{"result":{"error":"actNotFound","error_code":19,"error_message":"Account not found.","request":{"account":"rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn","command":"account_info"},"status":"error"}}"#;
+        let response: XRPLResponse<LedgerCurrent> = serde_json::from_str(json).unwrap();
+
+        assert!(!response.is_success());
+        assert_eq!(response.error_code(), Some("actNotFound"));
+        assert_eq!(response.result, None);
+    }
+
+    #[test]
+    fn test_deserialize_warnings() {
+        let json = r#"{"id":3,"result":{"ledger_current_index":6643240},"status":"success","type":"response","warnings":[{"id":1001,"message":"One or more unsupported amendments have reached majority. Upgrade to the latest version before they are activated to avoid being amendment blocked.","details":{"expected_date":"2021-Jun-30 16:26:30 UTC","expected_date_UTC":"2021-Jun-30 16:26:30 UTC"}}]}"#;
+        let response: XRPLResponse<LedgerCurrent> = serde_json::from_str(json).unwrap();
+
+        assert!(response.is_success());
+        assert!(response.has_warning(1001));
+        assert!(!response.has_warning(1002));
+        assert_eq!(response.warnings.unwrap()[0].id, 1001);
+    }
+}
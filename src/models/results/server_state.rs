@@ -0,0 +1,203 @@
+use alloc::borrow::Cow;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// The most recent fully-validated ledger as reported by
+/// `server_state`. Fees and reserves are given in drops.
+///
+/// See Server State:
+/// `<https://xrpl.org/server_state.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct ServerStateValidatedLedger<'a> {
+    /// Base fee, in drops of XRP, for propagating a transaction
+    /// to the network.
+    pub base_fee: u32,
+    /// Time this ledger was closed, in seconds since the Ripple Epoch.
+    pub close_time: u32,
+    /// Unique hash of this ledger version.
+    pub hash: Cow<'a, str>,
+    /// The minimum account reserve, in drops of XRP.
+    pub reserve_base: u32,
+    /// The owner reserve for each object an account owns, in
+    /// drops of XRP.
+    pub reserve_inc: u32,
+    /// The ledger index of this ledger.
+    pub seq: u32,
+}
+
+impl<'a> ServerStateValidatedLedger<'a> {
+    /// Returns the ledger without borrowed data.
+    pub fn into_owned(self) -> ServerStateValidatedLedger<'static> {
+        ServerStateValidatedLedger {
+            base_fee: self.base_fee,
+            close_time: self.close_time,
+            hash: Cow::Owned(self.hash.into_owned()),
+            reserve_base: self.reserve_base,
+            reserve_inc: self.reserve_inc,
+            seq: self.seq,
+        }
+    }
+}
+
+/// The status of the server as reported by `server_state`.
+///
+/// See Server State:
+/// `<https://xrpl.org/server_state.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct ServerStateInfo<'a> {
+    /// The version number of the running rippled version.
+    pub build_version: Cow<'a, str>,
+    /// Range expression indicating the sequence numbers of local
+    /// ledger versions the server has in its database.
+    pub complete_ledgers: Cow<'a, str>,
+    /// Amount of time spent waiting for I/O operations, in milliseconds.
+    pub io_latency_ms: Option<u32>,
+    /// The baseline amount of server load used in transaction
+    /// cost calculations.
+    pub load_base: u32,
+    /// The load-scaled open ledger transaction cost the server is
+    /// currently enforcing, as a multiple of `load_base`.
+    pub load_factor: u32,
+    /// The current multiplier to the transaction cost to get into
+    /// the open ledger, in fee levels.
+    pub load_factor_fee_escalation: Option<u32>,
+    /// The current multiplier to the transaction cost to get into
+    /// the queue, if the queue is full, in fee levels.
+    pub load_factor_fee_queue: Option<u32>,
+    /// The transaction cost with no load scaling, in fee levels.
+    pub load_factor_fee_reference: Option<u32>,
+    /// The load factor the server is enforcing, not including the
+    /// open ledger cost.
+    pub load_factor_server: Option<u32>,
+    /// The network ID of the network the server is connected to.
+    pub network_id: Option<u32>,
+    /// How many other rippled servers this one is currently
+    /// connected to.
+    pub peers: Option<u32>,
+    /// Public key used to verify this server for peer-to-peer
+    /// communications.
+    pub pubkey_node: Option<Cow<'a, str>>,
+    /// A string indicating to what extent the server is
+    /// participating in the network, like `full` or `proposing`.
+    pub server_state: Cow<'a, str>,
+    /// Number of consecutive seconds that the server has been
+    /// operational.
+    pub uptime: Option<u64>,
+    /// Information about the most recent fully-validated ledger.
+    #[serde(borrow)]
+    pub validated_ledger: Option<ServerStateValidatedLedger<'a>>,
+    /// Minimum number of trusted validations required to
+    /// validate a ledger version.
+    pub validation_quorum: Option<u32>,
+}
+
+impl<'a> ServerStateInfo<'a> {
+    /// Returns the server state without borrowed data.
+    pub fn into_owned(self) -> ServerStateInfo<'static> {
+        ServerStateInfo {
+            build_version: Cow::Owned(self.build_version.into_owned()),
+            complete_ledgers: Cow::Owned(self.complete_ledgers.into_owned()),
+            io_latency_ms: self.io_latency_ms,
+            load_base: self.load_base,
+            load_factor: self.load_factor,
+            load_factor_fee_escalation: self.load_factor_fee_escalation,
+            load_factor_fee_queue: self.load_factor_fee_queue,
+            load_factor_fee_reference: self.load_factor_fee_reference,
+            load_factor_server: self.load_factor_server,
+            network_id: self.network_id,
+            peers: self.peers,
+            pubkey_node: self
+                .pubkey_node
+                .map(|pubkey_node| Cow::Owned(pubkey_node.into_owned())),
+            server_state: Cow::Owned(self.server_state.into_owned()),
+            uptime: self.uptime,
+            validated_ledger: self
+                .validated_ledger
+                .map(ServerStateValidatedLedger::into_owned),
+            validation_quorum: self.validation_quorum,
+        }
+    }
+}
+
+/// The result of a `server_state` request. Unlike `server_info`,
+/// load factors are unscaled integers relative to `load_base` and
+/// fees are given in drops, which is convenient for calculating
+/// transaction costs.
+///
+/// See Server State:
+/// `<https://xrpl.org/server_state.html#response-format>`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct ServerState<'a> {
+    /// The status of the server.
+    #[serde(borrow)]
+    pub state: ServerStateInfo<'a>,
+}
+
+impl<'a> ServerState<'a> {
+    /// Returns the load factor of the server relative to its
+    /// `load_base`, like `1` without any load. Pass it to
+    /// `calculate_fee` to scale the base fee.
+    pub fn load_factor(&self) -> Decimal {
+        Decimal::from(self.state.load_factor)
+            .checked_div(Decimal::from(self.state.load_base))
+            .unwrap_or(Decimal::ONE)
+            .normalize()
+    }
+
+    /// Returns the ledger index of the most recent
+    /// fully-validated ledger, if the server knows one.
+    pub fn validated_ledger_index(&self) -> Option<u32> {
+        self.state
+            .validated_ledger
+            .as_ref()
+            .map(|validated_ledger| validated_ledger.seq)
+    }
+
+    /// Returns to what extent the server is participating in
+    /// the network, like `full` or `proposing`.
+    pub fn state_string(&self) -> &str {
+        &self.state.server_state
+    }
+
+    /// Returns the result without borrowed data.
+    pub fn into_owned(self) -> ServerState<'static> {
+        ServerState {
+            state: self.state.into_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+    use crate::utils::calculate_fee;
+    use alloc::string::ToString;
+
+    const SERVER_STATE: &str = r#"{"state":{"build_version":"1.12.0","complete_ledgers":"32570-83000000","io_latency_ms":1,"jq_trans_overflow":"0","last_close":{"converge_time":2000,"proposers":35},"load_base":256,"load_factor":384,"load_factor_fee_escalation":256,"load_factor_fee_queue":256,"load_factor_fee_reference":256,"load_factor_server":384,"network_id":0,"peer_disconnects":"0","peers":21,"pubkey_node":"n9KUjqxCr5FKThSNXdzb7oqN8rYwScB2dUnNqxQxbEA17JkaWy5x","server_state":"full","server_state_duration_us":"79311240","time":"2023-Jul-10 12:00:00.000000 UTC","uptime":1094,"validated_ledger":{"base_fee":10,"close_time":741787442,"hash":"A2F3EF6B8CC9B3F95C8E4E5FAE7AEFDAB5F0C48B24FCB7FD8A6E11CFCCB1D8C8","reserve_base":10000000,"reserve_inc":2000000,"seq":83000000},"validation_quorum":28}}"#;
+
+    #[test]
+    fn test_deserialize() {
+        let server_state: ServerState = serde_json::from_str(SERVER_STATE).unwrap();
+
+        assert_eq!(server_state.state_string(), "full");
+        assert_eq!(server_state.validated_ledger_index(), Some(83000000));
+        assert_eq!(server_state.load_factor(), Decimal::new(15, 1));
+        assert_eq!(
+            calculate_fee("10", &server_state.load_factor().to_string(), None),
+            Ok("15".to_string())
+        );
+    }
+
+    #[test]
+    fn test_deserialize_without_validated_ledger() {
+        let json = r#"{"state":{"build_version":"1.12.0","complete_ledgers":"empty","load_base":256,"load_factor":256,"server_state":"connected"}}"#;
+        let server_state: ServerState = serde_json::from_str(json).unwrap();
+
+        assert_eq!(server_state.state_string(), "connected");
+        assert_eq!(server_state.validated_ledger_index(), None);
+        assert_eq!(server_state.load_factor(), Decimal::ONE);
+    }
+}
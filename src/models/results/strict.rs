@@ -0,0 +1,77 @@
+use alloc::format;
+use alloc::string::String;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// Deserializes a result like `T`, but fails on fields `T` does
+/// not model instead of silently dropping them. This helps to
+/// find fields missing in a model when debugging integrations.
+///
+/// A field is considered unknown if it is set in the response
+/// but missing after serializing the deserialized `T` again.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::results::{LedgerCurrent, Strict};
+///
+/// let json = r#"{"ledger_current_index":6643240,"status":"success"}"#;
+///
+/// assert!(serde_json::from_str::<LedgerCurrent>(json).is_ok());
+/// assert!(serde_json::from_str::<Strict<LedgerCurrent>>(json).is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Strict<T>(pub T);
+
+impl<'de, T> Deserialize<'de> for Strict<T>
+where
+    T: Serialize + Deserialize<'static>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let result = T::deserialize(value.clone()).map_err(D::Error::custom)?;
+        let modeled_value = serde_json::to_value(&result).map_err(D::Error::custom)?;
+
+        match _find_unknown_field(&value, &modeled_value, String::new()) {
+            Some(field) => Err(D::Error::custom(format!("unknown field `{}`", field))),
+            None => Ok(Strict(result)),
+        }
+    }
+}
+
+/// Returns the path of the first non-null field of `value`
+/// which is missing in `modeled_value`.
+fn _find_unknown_field(value: &Value, modeled_value: &Value, path: String) -> Option<String> {
+    match (value, modeled_value) {
+        (Value::Object(object), Value::Object(modeled_object)) => {
+            object.iter().find_map(|(key, field_value)| {
+                let field_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match modeled_object.get(key) {
+                    Some(modeled_field_value) => {
+                        _find_unknown_field(field_value, modeled_field_value, field_path)
+                    }
+                    None if field_value.is_null() => None,
+                    None => Some(field_path),
+                }
+            })
+        }
+        (Value::Array(array), Value::Array(modeled_array)) => {
+            array.iter().zip(modeled_array.iter()).enumerate().find_map(
+                |(index, (element, modeled_element))| {
+                    _find_unknown_field(element, modeled_element, format!("{}[{}]", path, index))
+                },
+            )
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,400 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::amount::Amount;
+
+/// The kind of change an Offer ledger object went through.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub enum OfferChangeType {
+    Created,
+    Modified,
+    Deleted,
+}
+
+/// An Offer that was created, modified or deleted by a transaction
+/// of a subscribed order book.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct OfferChange<'a> {
+    /// Whether the Offer was created, modified or deleted.
+    pub change_type: OfferChangeType,
+    /// The ID of the Offer ledger object.
+    pub ledger_index: Cow<'a, str>,
+    /// The address of the account that owns the Offer.
+    pub account: Cow<'a, str>,
+    /// The sequence number of the transaction that created the Offer.
+    pub sequence: u32,
+    /// The remaining amount the Offer provides.
+    #[serde(borrow)]
+    pub taker_gets: Amount<'a>,
+    /// The remaining amount the Offer requests in return.
+    #[serde(borrow)]
+    pub taker_pays: Amount<'a>,
+}
+
+impl<'a> OfferChange<'a> {
+    /// Returns the offer change without borrowed data.
+    pub fn into_owned(self) -> OfferChange<'static> {
+        OfferChange {
+            change_type: self.change_type,
+            ledger_index: Cow::Owned(self.ledger_index.into_owned()),
+            account: Cow::Owned(self.account.into_owned()),
+            sequence: self.sequence,
+            taker_gets: self.taker_gets.into_owned(),
+            taker_pays: self.taker_pays.into_owned(),
+        }
+    }
+}
+
+/// A closed ledger as reported by the `ledger` stream. Fees
+/// and reserves are given in drops.
+///
+/// See Ledger Stream:
+/// `<https://xrpl.org/subscribe.html#ledger-stream>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct LedgerClosedMessage<'a> {
+    /// The reference transaction cost in drops of XRP.
+    pub fee_base: u32,
+    /// The reference transaction cost in fee levels. Deprecated.
+    pub fee_ref: Option<u32>,
+    /// The identifying hash of the ledger version that was closed.
+    pub ledger_hash: Cow<'a, str>,
+    /// The ledger index of the ledger that was closed.
+    pub ledger_index: u32,
+    /// The time this ledger was closed, in seconds since the
+    /// Ripple Epoch.
+    pub ledger_time: u32,
+    /// The network ID of the network the server is connected to.
+    pub network_id: Option<u32>,
+    /// The minimum reserve, in drops of XRP, that is required
+    /// for an account.
+    pub reserve_base: u32,
+    /// The increase in account reserve that is added for each
+    /// item the account owns.
+    pub reserve_inc: u32,
+    /// Number of new transactions included in this ledger. Only
+    /// sent in `ledgerClosed` messages of the stream.
+    pub txn_count: Option<u32>,
+    /// Range of ledgers that the server has available, like
+    /// `32570-7125358`. Omitted if the server is not connected to
+    /// the network.
+    pub validated_ledgers: Option<Cow<'a, str>>,
+}
+
+impl<'a> LedgerClosedMessage<'a> {
+    /// Returns the message without borrowed data.
+    pub fn into_owned(self) -> LedgerClosedMessage<'static> {
+        LedgerClosedMessage {
+            fee_base: self.fee_base,
+            fee_ref: self.fee_ref,
+            ledger_hash: Cow::Owned(self.ledger_hash.into_owned()),
+            ledger_index: self.ledger_index,
+            ledger_time: self.ledger_time,
+            network_id: self.network_id,
+            reserve_base: self.reserve_base,
+            reserve_inc: self.reserve_inc,
+            txn_count: self.txn_count,
+            validated_ledgers: self
+                .validated_ledgers
+                .map(|validated_ledgers| Cow::Owned(validated_ledgers.into_owned())),
+        }
+    }
+}
+
+/// A `ledgerClosed` message of the `ledger` stream, sent
+/// whenever the consensus process declares a new validated ledger.
+///
+/// See Ledger Stream:
+/// `<https://xrpl.org/subscribe.html#ledger-stream>`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(tag = "type", rename = "ledgerClosed")]
+pub struct LedgerStream<'a> {
+    /// The ledger that was closed.
+    #[serde(flatten, borrow)]
+    pub ledger: LedgerClosedMessage<'a>,
+}
+
+impl<'a> LedgerStream<'a> {
+    /// Returns the message without borrowed data.
+    pub fn into_owned(self) -> LedgerStream<'static> {
+        LedgerStream {
+            ledger: self.ledger.into_owned(),
+        }
+    }
+}
+
+/// The result of a `subscribe` request. Subscribing to the
+/// `ledger` stream returns the most recently closed ledger in
+/// the response, the result of other subscriptions is empty.
+///
+/// See Subscribe:
+/// `<https://xrpl.org/subscribe.html#response-format>`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct Subscribe<'a> {
+    /// The most recently closed ledger, if subscribed to the
+    /// `ledger` stream.
+    #[serde(flatten, borrow)]
+    pub ledger: Option<LedgerClosedMessage<'a>>,
+}
+
+impl<'a> Subscribe<'a> {
+    /// Returns the result without borrowed data.
+    pub fn into_owned(self) -> Subscribe<'static> {
+        Subscribe {
+            ledger: self.ledger.map(LedgerClosedMessage::into_owned),
+        }
+    }
+}
+
+/// A message of the `transactions` stream or of a `books`
+/// subscription, sent whenever a transaction affects a
+/// subscribed order book.
+///
+/// Messages of the `transactions_proposed` stream and of
+/// `accounts_proposed` subscriptions are sent before the
+/// transaction is validated. They carry no `meta` and no
+/// `ledger_index`, but the `ledger_current_index`.
+///
+/// See Transaction Streams:
+/// `<https://xrpl.org/subscribe.html#transaction-streams>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct TransactionStream<'a> {
+    /// The result code of the transaction.
+    pub engine_result: Cow<'a, str>,
+    /// The ledger index of the ledger that includes the transaction.
+    pub ledger_index: Option<u32>,
+    /// The ledger index of the current in-progress ledger. Only
+    /// sent for transactions that are not yet validated.
+    pub ledger_current_index: Option<u32>,
+    /// The transaction metadata describing the affected ledger objects.
+    pub meta: Option<Value>,
+    /// The transaction in JSON format.
+    pub transaction: Value,
+    /// Whether the transaction is included in a validated ledger.
+    pub validated: bool,
+}
+
+impl<'a> TransactionStream<'a> {
+    /// Collects the Offers created, modified or deleted by the
+    /// transaction from the `AffectedNodes` of its metadata.
+    pub fn offer_changes(&self) -> Vec<OfferChange<'static>> {
+        let affected_nodes = match self
+            .meta
+            .as_ref()
+            .and_then(|meta| meta["AffectedNodes"].as_array())
+        {
+            Some(affected_nodes) => affected_nodes,
+            None => return Vec::new(),
+        };
+
+        affected_nodes
+            .iter()
+            .filter_map(|affected_node| {
+                let (change_type, node, fields) =
+                    if let Some(node) = affected_node.get("CreatedNode") {
+                        (OfferChangeType::Created, node, "NewFields")
+                    } else if let Some(node) = affected_node.get("ModifiedNode") {
+                        (OfferChangeType::Modified, node, "FinalFields")
+                    } else {
+                        let node = affected_node.get("DeletedNode")?;
+                        (OfferChangeType::Deleted, node, "FinalFields")
+                    };
+                if node["LedgerEntryType"] != "Offer" {
+                    return None;
+                }
+                let fields = &node[fields];
+
+                Some(OfferChange {
+                    change_type,
+                    ledger_index: Cow::Owned(node["LedgerIndex"].as_str()?.into()),
+                    account: Cow::Owned(fields["Account"].as_str()?.into()),
+                    sequence: fields["Sequence"].as_u64()? as u32,
+                    taker_gets: Amount::deserialize(fields["TakerGets"].clone()).ok()?,
+                    taker_pays: Amount::deserialize(fields["TakerPays"].clone()).ok()?,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the message without borrowed data.
+    pub fn into_owned(self) -> TransactionStream<'static> {
+        TransactionStream {
+            engine_result: Cow::Owned(self.engine_result.into_owned()),
+            ledger_index: self.ledger_index,
+            ledger_current_index: self.ledger_current_index,
+            meta: self.meta,
+            transaction: self.transaction,
+            validated: self.validated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_offer_changes {
+    use alloc::vec;
+
+    use super::*;
+    use crate::models::amount::{IssuedCurrencyAmount, XRPAmount};
+
+    #[test]
+    fn test_offer_changes() {
+        let json = r#"{
+            "engine_result": "tesSUCCESS",
+            "ledger_index": 7125442,
+            "meta": {
+                "AffectedNodes": [
+                    {
+                        "ModifiedNode": {
+                            "FinalFields": {
+                                "Account": "rEhxGqkqPPSxQ3P25J66ft5TwpzV14k2de",
+                                "Balance": "99999988",
+                                "Sequence": 4
+                            },
+                            "LedgerEntryType": "AccountRoot",
+                            "LedgerIndex": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+                        }
+                    },
+                    {
+                        "CreatedNode": {
+                            "LedgerEntryType": "Offer",
+                            "LedgerIndex": "96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797",
+                            "NewFields": {
+                                "Account": "rEhxGqkqPPSxQ3P25J66ft5TwpzV14k2de",
+                                "Sequence": 3,
+                                "TakerGets": "1000000",
+                                "TakerPays": {
+                                    "currency": "USD",
+                                    "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                                    "value": "10"
+                                }
+                            }
+                        }
+                    },
+                    {
+                        "DeletedNode": {
+                            "FinalFields": {
+                                "Account": "rUQTpMqAF5jhykj4FExVeXakrZpiKF6cQV",
+                                "Sequence": 8,
+                                "TakerGets": {
+                                    "currency": "USD",
+                                    "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                                    "value": "0"
+                                },
+                                "TakerPays": "0"
+                            },
+                            "LedgerEntryType": "Offer",
+                            "LedgerIndex": "2B7E3F3A1A4F1C1CFF6A3A9C2E8A3D4F5B6C7D8E9F0A1B2C3D4E5F60718293A4"
+                        }
+                    }
+                ],
+                "TransactionIndex": 0,
+                "TransactionResult": "tesSUCCESS"
+            },
+            "transaction": {"TransactionType": "OfferCreate"},
+            "type": "transaction",
+            "validated": true
+        }"#;
+        let transaction_stream: TransactionStream = serde_json::from_str(json).unwrap();
+        let usd = |value: &'static str| {
+            Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                value.into(),
+            ))
+        };
+
+        assert_eq!(
+            transaction_stream.offer_changes(),
+            vec![
+                OfferChange {
+                    change_type: OfferChangeType::Created,
+                    ledger_index:
+                        "96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797".into(),
+                    account: "rEhxGqkqPPSxQ3P25J66ft5TwpzV14k2de".into(),
+                    sequence: 3,
+                    taker_gets: Amount::XRPAmount(XRPAmount::from("1000000")),
+                    taker_pays: usd("10"),
+                },
+                OfferChange {
+                    change_type: OfferChangeType::Deleted,
+                    ledger_index:
+                        "2B7E3F3A1A4F1C1CFF6A3A9C2E8A3D4F5B6C7D8E9F0A1B2C3D4E5F60718293A4".into(),
+                    account: "rUQTpMqAF5jhykj4FExVeXakrZpiKF6cQV".into(),
+                    sequence: 8,
+                    taker_gets: usd("0"),
+                    taker_pays: Amount::XRPAmount(XRPAmount::from("0")),
+                },
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_ledger_subscription() {
+        let json = r#"{"fee_base":10,"fee_ref":10,"ledger_hash":"7CD50477F23FF158B430772D8E82A961376A7B40E13C695AA849811EDF66C5C0","ledger_index":18955938,"ledger_time":591914220,"reserve_base":20000000,"reserve_inc":5000000,"validated_ledgers":"18955540-18955938"}"#;
+        let subscribe: Subscribe = serde_json::from_str(json).unwrap();
+        let ledger = subscribe.ledger.unwrap();
+
+        assert_eq!(ledger.ledger_index, 18955938);
+        assert_eq!(ledger.fee_base, 10);
+        assert_eq!(ledger.reserve_base, 20000000);
+        assert_eq!(ledger.txn_count, None);
+        assert_eq!(
+            ledger.validated_ledgers.as_deref(),
+            Some("18955540-18955938")
+        );
+
+        let subscribe: Subscribe = serde_json::from_str("{}").unwrap();
+
+        assert_eq!(subscribe.ledger, None);
+    }
+
+    #[test]
+    fn test_deserialize_ledger_stream() {
+        let json = r#"{"type":"ledgerClosed","fee_base":10,"fee_ref":10,"ledger_hash":"687F604EF6B2F67319E8DCC8C66EF49D84D18A1E18F948421FC24D2C7C3DB464","ledger_index":7125358,"ledger_time":455751310,"reserve_base":20000000,"reserve_inc":5000000,"txn_count":7,"validated_ledgers":"32570-7125358"}"#;
+        let ledger_stream: LedgerStream = serde_json::from_str(json).unwrap();
+
+        assert_eq!(ledger_stream.ledger.ledger_index, 7125358);
+        assert_eq!(ledger_stream.ledger.txn_count, Some(7));
+        assert_eq!(serde_json::to_string(&ledger_stream).unwrap(), json);
+    }
+
+    #[test]
+    fn test_deserialize_proposed() {
+        let json = r#"{
+            "type": "transaction",
+            "engine_result": "tesSUCCESS",
+            "engine_result_code": 0,
+            "engine_result_message": "The transaction was applied. Only final in a validated ledger.",
+            "ledger_current_index": 7125443,
+            "status": "proposed",
+            "transaction": {
+                "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "Amount": "1000000",
+                "Destination": "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK",
+                "Fee": "12",
+                "Flags": 0,
+                "Sequence": 5,
+                "TransactionType": "Payment",
+                "hash": "E08D6E9754025BA2534A78707605E0601F03ACE063687A0CA1BDDACFCD1698C7"
+            },
+            "validated": false
+        }"#;
+        let proposed: TransactionStream = serde_json::from_str(json).unwrap();
+
+        assert!(!proposed.validated);
+        assert_eq!(proposed.meta, None);
+        assert_eq!(proposed.ledger_index, None);
+        assert_eq!(proposed.ledger_current_index, Some(7125443));
+        assert_eq!(proposed.transaction["TransactionType"], "Payment");
+        assert!(proposed.offer_changes().is_empty());
+    }
+}
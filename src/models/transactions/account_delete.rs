@@ -1,13 +1,24 @@
+use crate::Err;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
 use alloc::vec::Vec;
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
 use crate::models::{
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_common_fields_error, normalize_x_address, CommonFields, Memo, Signer, Transaction,
+        TransactionType, TxnCommonFields, XRPLTransactionFieldException,
+    },
 };
 
+/// The documentation of the `AccountDelete` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/accountdelete.html";
+
 /// An AccountDelete transaction deletes an account and any objects it
 /// owns in the XRP Ledger, if possible, sending the account's remaining
 /// XRP to a specified destination account. See Deletion of Accounts for
@@ -19,63 +30,16 @@ use crate::models::{
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct AccountDelete<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
-    #[serde(default = "TransactionType::account_set")]
+    #[serde(default = "TransactionType::account_delete")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     // The custom fields for the AccountDelete model.
     //
     // See AccountDelete fields:
@@ -83,10 +47,13 @@ pub struct AccountDelete<'a> {
     /// The address of an account to receive any leftover XRP after
     /// deleting the sending account. Must be a funded account in
     /// the ledger, and must not be the sending account.
-    pub destination: &'a str,
+    #[serde(borrow)]
+    pub destination: Cow<'a, str>,
     /// Arbitrary destination tag that identifies a hosted
     /// recipient or other information for the recipient
-    /// of the deleted account's leftover XRP.
+    /// of the deleted account's leftover XRP. Any value from 0 to
+    /// 4294967295 is valid; `Some(0)` is serialized and differs from
+    /// omitting the tag with `None`.
     pub destination_tag: Option<u32>,
 }
 
@@ -94,30 +61,34 @@ impl<'a> Default for AccountDelete<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::AccountDelete,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             destination: Default::default(),
             destination_tag: Default::default(),
         }
     }
 }
 
-impl<'a> Model for AccountDelete<'a> {}
+impl<'a> Model for AccountDelete<'a> {
+    fn get_errors(&self) -> Result<()> {
+        match get_common_fields_error(&self.common_fields) {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
 
 impl<'a> Transaction for AccountDelete<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> AccountDelete<'a> {
@@ -138,28 +109,93 @@ impl<'a> AccountDelete<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::AccountDelete,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
-            destination,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
+            destination: destination.into(),
             destination_tag,
         }
     }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> AccountDelete<'static> {
+        AccountDelete {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            destination: Cow::Owned(self.destination.into_owned()),
+            destination_tag: self.destination_tag,
+        }
+    }
+}
+
+impl<'a> AccountDelete<'a> {
+    /// Replaces X-Addresses in `account` and `destination` with their
+    /// classic addresses and moves the embedded tags into `source_tag`
+    /// and `destination_tag`. Errors if a tag is already defined with a
+    /// different value. Classic addresses are left unchanged.
+    pub fn normalize_x_address(self) -> Result<Self> {
+        match self._normalize_x_address() {
+            Err(error) => Err!(error),
+            Ok(account_delete) => Ok(account_delete),
+        }
+    }
+
+    fn _normalize_x_address(mut self) -> Result<Self, XRPLTransactionFieldException<'static>> {
+        self.common_fields = self.common_fields.normalize_x_address()?;
+        let (destination, destination_tag) = normalize_x_address(
+            &self.destination,
+            self.destination_tag,
+            "destination",
+            "destination_tag",
+            RESOURCE,
+        )?;
+        self.destination = destination.into();
+        self.destination_tag = destination_tag;
+
+        Ok(self)
+    }
 }
 
 #[cfg(test)]
 mod test_serde {
     use super::*;
 
+    #[test]
+    fn test_destination_tag_zero() {
+        let mut txn = AccountDelete {
+            common_fields: CommonFields {
+                account: "rWYkbWkCeg8dP6rXALnjgZSjjLyih5NXm".into(),
+                ..Default::default()
+            },
+            destination: "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into(),
+            destination_tag: Some(0),
+            ..Default::default()
+        };
+        let json = r#"{"TransactionType":"AccountDelete","Account":"rWYkbWkCeg8dP6rXALnjgZSjjLyih5NXm","Destination":"rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe","DestinationTag":0}"#;
+
+        assert_eq!(serde_json::to_string(&txn).unwrap(), json);
+        assert_eq!(serde_json::from_str::<AccountDelete>(json).unwrap(), txn);
+
+        txn.destination_tag = None;
+
+        assert_eq!(
+            serde_json::to_string(&txn).unwrap(),
+            r#"{"TransactionType":"AccountDelete","Account":"rWYkbWkCeg8dP6rXALnjgZSjjLyih5NXm","Destination":"rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe"}"#
+        );
+    }
+
     #[test]
     fn test_serialize() {
         let default_txn = AccountDelete::new(
@@ -208,4 +244,12 @@ mod test_serde {
 
         assert_eq!(txn_as_obj, default_txn);
     }
+
+    #[test]
+    fn test_deserialize_without_transaction_type() {
+        let json = r#"{"Account":"rWYkbWkCeg8dP6rXALnjgZSjjLyih5NXm","Destination":"rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe"}"#;
+        let txn_as_obj: AccountDelete = serde_json::from_str(json).unwrap();
+
+        assert_eq!(txn_as_obj.transaction_type, TransactionType::AccountDelete);
+    }
 }
@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -8,20 +9,28 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 use alloc::string::ToString;
 
 use crate::models::amount::XRPAmount;
-use crate::models::transactions::XRPLAccountSetException;
+use crate::models::transactions::{
+    clear_txn_flag, set_txn_flag, XRPLAccountSetException, XRPLTransactionException,
+};
 use crate::{
-    _serde::txn_flags,
     constants::{
         DISABLE_TICK_SIZE, MAX_DOMAIN_LENGTH, MAX_TICK_SIZE, MAX_TRANSFER_RATE, MIN_TICK_SIZE,
         MIN_TRANSFER_RATE, SPECIAL_CASE_TRANFER_RATE,
     },
     models::{
-        model::Model,
-        transactions::{Flag, Memo, Signer, Transaction, TransactionType},
+        model::{collect_errors, Model},
+        transactions::{
+            get_common_fields_error, CommonFields, Flag, Memo, Signer, Transaction,
+            TransactionType, TxnCommonFields,
+        },
     },
     Err,
 };
 
+/// The documentation of the `AccountSet` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/accountset.html";
+
 /// Transactions of the AccountSet type support additional values
 /// in the Flags field. This enum represents those options.
 ///
@@ -75,65 +84,16 @@ pub enum AccountSetFlag {
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct AccountSet<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::account_set")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    #[serde(default)]
-    #[serde(with = "txn_flags")]
-    pub flags: Option<Vec<AccountSetFlag>>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, Vec<AccountSetFlag>>,
     // The custom fields for the AccountSet model.
     //
     // See AccountSet fields:
@@ -143,22 +103,27 @@ pub struct AccountSet<'a> {
     /// The domain that owns this account, as a string of hex
     /// representing the ASCII for the domain in lowercase.
     /// Cannot be more than 256 bytes in length.
-    pub domain: Option<&'a str>,
+    #[serde(borrow)]
+    pub domain: Option<Cow<'a, str>>,
     /// Hash of an email address to be used for generating an
     /// avatar image. Conventionally, clients use Gravatar
     /// to display this image.
-    pub email_hash: Option<&'a str>,
+    #[serde(borrow)]
+    pub email_hash: Option<Cow<'a, str>>,
     /// Public key for sending encrypted messages to this account.
     /// To set the key, it must be exactly 33 bytes, with the
     /// first byte indicating the key type: 0x02 or 0x03 for
     /// secp256k1 keys, 0xED for Ed25519 keys. To remove the
     /// key, use an empty value.
-    pub message_key: Option<&'a str>,
+    #[serde(borrow)]
+    pub message_key: Option<Cow<'a, str>>,
     /// Sets an alternate account that is allowed to mint NFTokens
     /// on this account's behalf using NFTokenMint's Issuer field.
     /// This field is part of the experimental XLS-20 standard
     /// for non-fungible tokens.
-    pub nftoken_minter: Option<&'a str>,
+    #[serde(rename = "NFTokenMinter")]
+    #[serde(borrow)]
+    pub nftoken_minter: Option<Cow<'a, str>>,
     /// Flag to enable for this account.
     pub set_flag: Option<AccountSetFlag>,
     /// The fee to charge when users transfer this account's tokens,
@@ -177,18 +142,7 @@ impl<'a> Default for AccountSet<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::AccountSet,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             clear_flag: Default::default(),
             domain: Default::default(),
             email_hash: Default::default(),
@@ -203,30 +157,45 @@ impl<'a> Default for AccountSet<'a> {
 
 impl<'a: 'static> Model for AccountSet<'a> {
     fn get_errors(&self) -> Result<()> {
-        match self._get_tick_size_error() {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self._get_tick_size_error()?;
+            self._get_transfer_rate_error()?;
+            self._get_domain_error()?;
+            self._get_clear_flag_error()?;
+            self._get_nftoken_minter_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
             Err(error) => Err!(error),
-            Ok(_no_error) => match self._get_transfer_rate_error() {
-                Err(error) => Err!(error),
-                Ok(_no_error) => match self._get_domain_error() {
-                    Err(error) => Err!(error),
-                    Ok(_no_error) => match self._get_clear_flag_error() {
-                        Err(error) => Err!(error),
-                        Ok(_no_error) => match self._get_nftoken_minter_error() {
-                            Err(error) => Err!(error),
-                            Ok(_no_error) => Ok(()),
-                        },
-                    },
-                },
-            },
+            Ok(_no_error) => Ok(()),
         }
     }
+
+    fn get_all_errors(&self) -> Vec<anyhow::Error> {
+        collect_errors([
+            get_common_fields_error(&self.common_fields).map_err(XRPLTransactionException::from),
+            self._get_tick_size_error()
+                .map_err(XRPLTransactionException::from),
+            self._get_transfer_rate_error()
+                .map_err(XRPLTransactionException::from),
+            self._get_domain_error()
+                .map_err(XRPLTransactionException::from),
+            self._get_clear_flag_error()
+                .map_err(XRPLTransactionException::from),
+            self._get_nftoken_minter_error()
+                .map_err(XRPLTransactionException::from),
+        ])
+    }
 }
 
 impl<'a> Transaction for AccountSet<'a> {
     fn has_flag(&self, flag: &Flag) -> bool {
         let mut flags = &Vec::new();
 
-        if let Some(flag_set) = self.flags.as_ref() {
+        if let Some(flag_set) = self.common_fields.flags.as_ref() {
             flags = flag_set;
         }
 
@@ -253,9 +222,29 @@ impl<'a> Transaction for AccountSet<'a> {
         }
     }
 
+    fn set_flag(&mut self, flag: &Flag) {
+        if let Flag::AccountSet(account_set_flag) = flag {
+            set_txn_flag(&mut self.common_fields.flags, account_set_flag.clone());
+        }
+    }
+
+    fn clear_flag(&mut self, flag: &Flag) {
+        if let Flag::AccountSet(account_set_flag) = flag {
+            clear_txn_flag(&mut self.common_fields.flags, account_set_flag);
+        }
+    }
+
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> AccountSetError for AccountSet<'a> {
@@ -266,14 +255,14 @@ impl<'a> AccountSetError for AccountSet<'a> {
                     field: "tick_size",
                     max: MAX_TICK_SIZE,
                     found: tick_size,
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else if tick_size < MIN_TICK_SIZE && tick_size != DISABLE_TICK_SIZE {
                 Err(XRPLAccountSetException::ValueTooLow {
                     field: "tick_size",
                     min: MIN_TICK_SIZE,
                     found: tick_size,
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else {
                 Ok(())
@@ -290,7 +279,7 @@ impl<'a> AccountSetError for AccountSet<'a> {
                     field: "transfer_rate",
                     max: MAX_TRANSFER_RATE,
                     found: transfer_rate,
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else if transfer_rate < MIN_TRANSFER_RATE
                 && transfer_rate != SPECIAL_CASE_TRANFER_RATE
@@ -299,7 +288,7 @@ impl<'a> AccountSetError for AccountSet<'a> {
                     field: "transfer_rate",
                     min: MIN_TRANSFER_RATE,
                     found: transfer_rate,
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else {
                 Ok(())
@@ -310,20 +299,20 @@ impl<'a> AccountSetError for AccountSet<'a> {
     }
 
     fn _get_domain_error(&self) -> Result<(), XRPLAccountSetException> {
-        if let Some(domain) = self.domain {
-            if domain.to_lowercase().as_str() != domain {
+        if let Some(domain) = &self.domain {
+            if domain.to_lowercase() != *domain {
                 Err(XRPLAccountSetException::InvalidValueFormat {
                     field: "domain",
                     found: domain,
                     format: "lowercase",
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else if domain.len() > MAX_DOMAIN_LENGTH {
                 Err(XRPLAccountSetException::ValueTooLong {
                     field: "domain",
                     max: MAX_DOMAIN_LENGTH,
                     found: domain.len(),
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else {
                 Ok(())
@@ -338,7 +327,7 @@ impl<'a> AccountSetError for AccountSet<'a> {
         {
             Err(XRPLAccountSetException::SetAndUnsetSameFlag {
                 found: self.clear_flag.clone().unwrap(),
-                resource: "",
+                resource: RESOURCE,
             })
         } else {
             Ok(())
@@ -346,7 +335,7 @@ impl<'a> AccountSetError for AccountSet<'a> {
     }
 
     fn _get_nftoken_minter_error(&self) -> Result<(), XRPLAccountSetException> {
-        if let Some(_nftoken_minter) = self.nftoken_minter {
+        if self.nftoken_minter.is_some() {
             if self.set_flag.is_none() {
                 if let Some(clear_flag) = &self.clear_flag {
                     match clear_flag {
@@ -354,7 +343,7 @@ impl<'a> AccountSetError for AccountSet<'a> {
                             Err(XRPLAccountSetException::SetFieldWhenUnsetRequiredFlag {
                                 field: "nftoken_minter",
                                 flag: AccountSetFlag::AsfAuthorizedNFTokenMinter,
-                                resource: "",
+                                resource: RESOURCE,
                             })
                         }
                         _ => Ok(()),
@@ -363,7 +352,7 @@ impl<'a> AccountSetError for AccountSet<'a> {
                     Err(XRPLAccountSetException::FieldRequiresFlag {
                         field: "set_flag",
                         flag: AccountSetFlag::AsfAuthorizedNFTokenMinter,
-                        resource: "",
+                        resource: RESOURCE,
                     })
                 }
             } else {
@@ -375,7 +364,7 @@ impl<'a> AccountSetError for AccountSet<'a> {
                     Err(XRPLAccountSetException::FlagRequiresField {
                         flag: AccountSetFlag::AsfAuthorizedNFTokenMinter,
                         field: "nftoken_minter",
-                        resource: "",
+                        resource: RESOURCE,
                     })
                 }
                 _ => Ok(()),
@@ -401,28 +390,30 @@ impl<'a> AccountSet<'a> {
         memos: Option<Vec<Memo<'a>>>,
         signers: Option<Vec<Signer<'a>>>,
         clear_flag: Option<AccountSetFlag>,
-        domain: Option<&'a str>,
-        email_hash: Option<&'a str>,
-        message_key: Option<&'a str>,
+        domain: Option<Cow<'a, str>>,
+        email_hash: Option<Cow<'a, str>>,
+        message_key: Option<Cow<'a, str>>,
         set_flag: Option<AccountSetFlag>,
         transfer_rate: Option<u32>,
         tick_size: Option<u32>,
-        nftoken_minter: Option<&'a str>,
+        nftoken_minter: Option<Cow<'a, str>>,
     ) -> Self {
         Self {
             transaction_type: TransactionType::AccountSet,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags,
-            memos,
-            signers,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags,
+                memos,
+                signers,
+            },
             clear_flag,
             domain,
             email_hash,
@@ -433,6 +424,29 @@ impl<'a> AccountSet<'a> {
             tick_size,
         }
     }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> AccountSet<'static> {
+        AccountSet {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            clear_flag: self.clear_flag,
+            domain: self.domain.map(|domain| Cow::Owned(domain.into_owned())),
+            email_hash: self
+                .email_hash
+                .map(|email_hash| Cow::Owned(email_hash.into_owned())),
+            message_key: self
+                .message_key
+                .map(|message_key| Cow::Owned(message_key.into_owned())),
+            nftoken_minter: self
+                .nftoken_minter
+                .map(|nftoken_minter| Cow::Owned(nftoken_minter.into_owned())),
+            set_flag: self.set_flag,
+            transfer_rate: self.transfer_rate,
+            tick_size: self.tick_size,
+        }
+    }
 }
 
 pub trait AccountSetError {
@@ -455,18 +469,20 @@ mod test_account_set_errors {
     fn test_tick_size_error() {
         let mut account_set = AccountSet {
             transaction_type: TransactionType::AccountSet,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             clear_flag: None,
             domain: None,
             email_hash: None,
@@ -481,7 +497,7 @@ mod test_account_set_errors {
 
         assert_eq!(
             account_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `tick_size` is defined below its minimum (min 3, found 2). For more information see: "
+            "The value of the field `tick_size` is defined below its minimum (min 3, found 2). For more information see: https://xrpl.org/accountset.html"
         );
 
         let tick_size_too_high = Some(16);
@@ -489,7 +505,7 @@ mod test_account_set_errors {
 
         assert_eq!(
             account_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `tick_size` is defined above its maximum (max 15, found 16). For more information see: "
+            "The value of the field `tick_size` is defined above its maximum (max 15, found 16). For more information see: https://xrpl.org/accountset.html"
         );
     }
 
@@ -497,18 +513,20 @@ mod test_account_set_errors {
     fn test_transfer_rate_error() {
         let mut account_set = AccountSet {
             transaction_type: TransactionType::AccountSet,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             clear_flag: None,
             domain: None,
             email_hash: None,
@@ -523,7 +541,7 @@ mod test_account_set_errors {
 
         assert_eq!(
             account_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `transfer_rate` is defined below its minimum (min 1000000000, found 999999999). For more information see: "
+            "The value of the field `transfer_rate` is defined below its minimum (min 1000000000, found 999999999). For more information see: https://xrpl.org/accountset.html"
         );
 
         let tick_size_too_high = Some(2000000001);
@@ -531,26 +549,61 @@ mod test_account_set_errors {
 
         assert_eq!(
             account_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `transfer_rate` is defined above its maximum (max 2000000000, found 2000000001). For more information see: "
+            "The value of the field `transfer_rate` is defined above its maximum (max 2000000000, found 2000000001). For more information see: https://xrpl.org/accountset.html"
         );
     }
 
+    #[test]
+    fn test_all_errors() {
+        let mut account_set = AccountSet {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            tick_size: Some(16),
+            transfer_rate: Some(2000000001),
+            ..Default::default()
+        };
+        let errors = account_set.get_all_errors();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[0].to_string().as_str(),
+            "The value of the field `tick_size` is defined above its maximum (max 15, found 16). For more information see: https://xrpl.org/accountset.html"
+        );
+        assert_eq!(
+            errors[1].to_string().as_str(),
+            "The value of the field `transfer_rate` is defined above its maximum (max 2000000000, found 2000000001). For more information see: https://xrpl.org/accountset.html"
+        );
+        assert_eq!(
+            account_set.validate().unwrap_err().to_string(),
+            errors[0].to_string()
+        );
+
+        account_set.tick_size = None;
+        account_set.transfer_rate = None;
+
+        assert!(account_set.get_all_errors().is_empty());
+    }
+
     #[test]
     fn test_domain_error() {
         let mut account_set = AccountSet {
             transaction_type: TransactionType::AccountSet,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             clear_flag: None,
             domain: None,
             email_hash: None,
@@ -560,20 +613,20 @@ mod test_account_set_errors {
             tick_size: None,
             nftoken_minter: None,
         };
-        let domain_not_lowercase = Some("https://Example.com/");
+        let domain_not_lowercase = Some("https://Example.com/".into());
         account_set.domain = domain_not_lowercase;
 
         assert_eq!(
             account_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `domain` does not have the correct format (expected lowercase, found https://Example.com/). For more information see: "
+            "The value of the field `domain` does not have the correct format (expected lowercase, found https://Example.com/). For more information see: https://xrpl.org/accountset.html"
         );
 
-        let domain_too_long = Some("https://example.com/aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa");
+        let domain_too_long = Some("https://example.com/aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".into());
         account_set.domain = domain_too_long;
 
         assert_eq!(
             account_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `domain` exceeds its maximum length of characters (max 256, found 270). For more information see: "
+            "The value of the field `domain` exceeds its maximum length of characters (max 256, found 270). For more information see: https://xrpl.org/accountset.html"
         );
     }
 
@@ -581,18 +634,20 @@ mod test_account_set_errors {
     fn test_flag_error() {
         let account_set = AccountSet {
             transaction_type: TransactionType::AccountSet,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             clear_flag: Some(AccountSetFlag::AsfDisallowXRP),
             domain: None,
             email_hash: None,
@@ -605,26 +660,45 @@ mod test_account_set_errors {
 
         assert_eq!(
             account_set.validate().unwrap_err().to_string().as_str(),
-            "A flag cannot be set and unset at the same time (found AsfDisallowXRP). For more information see: "
+            "A flag cannot be set and unset at the same time (found AsfDisallowXRP). For more information see: https://xrpl.org/accountset.html"
+        );
+    }
+
+    #[test]
+    fn test_deserialized_flag_error() {
+        let json = r#"{"TransactionType":"AccountSet","Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","ClearFlag":3,"SetFlag":3}"#;
+        let account_set: AccountSet = serde_json::from_str(json).unwrap();
+
+        assert_eq!(account_set.set_flag, Some(AccountSetFlag::AsfDisallowXRP));
+        assert_eq!(account_set.clear_flag, Some(AccountSetFlag::AsfDisallowXRP));
+        assert_eq!(
+            account_set.validate().unwrap_err().to_string().as_str(),
+            "A flag cannot be set and unset at the same time (found AsfDisallowXRP). For more information see: https://xrpl.org/accountset.html"
         );
+        assert!(serde_json::from_str::<AccountSet>(
+            r#"{"TransactionType":"AccountSet","Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","SetFlag":99}"#
+        )
+        .is_err());
     }
 
     #[test]
     fn test_asf_authorized_nftoken_minter_error() {
         let mut account_set = AccountSet {
             transaction_type: TransactionType::AccountSet,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             clear_flag: None,
             domain: None,
             email_hash: None,
@@ -634,11 +708,11 @@ mod test_account_set_errors {
             tick_size: None,
             nftoken_minter: None,
         };
-        account_set.nftoken_minter = Some("rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK");
+        account_set.nftoken_minter = Some("rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into());
 
         assert_eq!(
             account_set.validate().unwrap_err().to_string().as_str(),
-            "For the field `set_flag` to be defined it is required to set the flag `AsfAuthorizedNFTokenMinter`. For more information see: "
+            "For the field `set_flag` to be defined it is required to set the flag `AsfAuthorizedNFTokenMinter`. For more information see: https://xrpl.org/accountset.html"
         );
 
         account_set.nftoken_minter = None;
@@ -646,24 +720,77 @@ mod test_account_set_errors {
 
         assert_eq!(
             account_set.validate().unwrap_err().to_string().as_str(),
-            "For the flag `AsfAuthorizedNFTokenMinter` to be set it is required to define the field `nftoken_minter`. For more information see: "
+            "For the flag `AsfAuthorizedNFTokenMinter` to be set it is required to define the field `nftoken_minter`. For more information see: https://xrpl.org/accountset.html"
         );
 
         account_set.set_flag = None;
-        account_set.nftoken_minter = Some("rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK");
+        account_set.nftoken_minter = Some("rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into());
         account_set.clear_flag = Some(AccountSetFlag::AsfAuthorizedNFTokenMinter);
 
         assert_eq!(
             account_set.validate().unwrap_err().to_string().as_str(),
-            "The field `nftoken_minter` cannot be defined if its required flag `AsfAuthorizedNFTokenMinter` is being unset. For more information see: "
+            "The field `nftoken_minter` cannot be defined if its required flag `AsfAuthorizedNFTokenMinter` is being unset. For more information see: https://xrpl.org/accountset.html"
         );
     }
 }
 
 #[cfg(test)]
 mod test_serde {
+    use alloc::string::String;
+    use alloc::vec;
+
+    use crate::models::Model;
+
     use super::*;
 
+    #[test]
+    fn test_borrowed_and_owned_fields() {
+        let borrowed = AccountSet {
+            common_fields: CommonFields {
+                account: "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+                ..Default::default()
+            },
+            domain: Some("6578616d706c652e636f6d".into()),
+            ..Default::default()
+        };
+        let owned = AccountSet {
+            domain: Some(String::from("6578616d706c652e636f6d").into()),
+            ..borrowed.clone()
+        };
+
+        assert_eq!(borrowed, owned);
+        assert!(owned.validate().is_ok());
+
+        let json = r#"{"TransactionType":"AccountSet","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Domain":"6578616d706c652e636f6d"}"#;
+        let deserialized: AccountSet = serde_json::from_str(json).unwrap();
+
+        assert_eq!(deserialized, borrowed);
+    }
+
+    #[test]
+    fn test_serialize_common_fields() {
+        let account_set = AccountSet {
+            common_fields: CommonFields {
+                account: "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+                fee: Some("12".into()),
+                sequence: Some(5),
+                account_txn_id: Some(
+                    "49647F0D748DC3FE26BDACBC57F251AADEFFF391403EC9BF87C97F67E9977FB0".into(),
+                ),
+                ticket_sequence: Some(4),
+                txn_signature: Some("3045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE".into()),
+                flags: Some(vec![AccountSetFlag::AsfDisallowXRP]),
+                ..Default::default()
+            },
+            domain: Some("6578616D706C652E636F6D".into()),
+            set_flag: Some(AccountSetFlag::AsfAccountTxnID),
+            ..Default::default()
+        };
+        let json = r#"{"TransactionType":"AccountSet","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Fee":"12","Sequence":5,"AccountTxnID":"49647F0D748DC3FE26BDACBC57F251AADEFFF391403EC9BF87C97F67E9977FB0","TicketSequence":4,"TxnSignature":"3045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE","Flags":3,"Domain":"6578616D706C652E636F6D","SetFlag":5}"#;
+
+        assert_eq!(serde_json::to_string(&account_set).unwrap(), json);
+    }
+
     #[test]
     fn test_serialize() {
         let default_txn = AccountSet::new(
@@ -680,9 +807,9 @@ mod test_serde {
             None,
             None,
             None,
-            Some("6578616D706C652E636F6D"),
+            Some("6578616D706C652E636F6D".into()),
             None,
-            Some("03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB"),
+            Some("03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB".into()),
             Some(AccountSetFlag::AsfAccountTxnID),
             None,
             None,
@@ -712,9 +839,9 @@ mod test_serde {
             None,
             None,
             None,
-            Some("6578616D706C652E636F6D"),
+            Some("6578616D706C652E636F6D".into()),
             None,
-            Some("03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB"),
+            Some("03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB".into()),
             Some(AccountSetFlag::AsfAccountTxnID),
             None,
             None,
@@ -1,11 +1,18 @@
+use crate::Err;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
 use alloc::vec::Vec;
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
 use crate::models::{
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_common_fields_error, CommonFields, Memo, Signer, Transaction, TransactionType,
+        TxnCommonFields,
+    },
 };
 
 /// Cancels an unredeemed Check, removing it from the ledger without
@@ -19,98 +26,56 @@ use crate::models::{
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct CheckCancel<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::check_cancel")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     // The custom fields for the CheckCancel model.
     //
     // See CheckCancel fields:
     // `<https://xrpl.org/checkcancel.html#checkcancel-fields>`
     #[serde(rename = "CheckID")]
-    pub check_id: &'a str,
+    #[serde(borrow)]
+    pub check_id: Cow<'a, str>,
 }
 
 impl<'a> Default for CheckCancel<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::CheckCancel,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             check_id: Default::default(),
         }
     }
 }
 
-impl<'a> Model for CheckCancel<'a> {}
+impl<'a> Model for CheckCancel<'a> {
+    fn get_errors(&self) -> Result<()> {
+        match get_common_fields_error(&self.common_fields) {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
 
 impl<'a> Transaction for CheckCancel<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> CheckCancel<'a> {
@@ -130,19 +95,31 @@ impl<'a> CheckCancel<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::CheckCancel,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
-            check_id,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
+            check_id: check_id.into(),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> CheckCancel<'static> {
+        CheckCancel {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            check_id: Cow::Owned(self.check_id.into_owned()),
         }
     }
 }
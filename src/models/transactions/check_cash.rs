@@ -1,4 +1,5 @@
 use crate::Err;
+use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -8,12 +9,20 @@ use alloc::string::ToString;
 
 use crate::models::amount::XRPAmount;
 use crate::models::transactions::XRPLCheckCashException;
+use crate::models::transactions::XRPLTransactionException;
 use crate::models::{
     amount::Amount,
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_amounts_error, get_common_fields_error, CommonFields, Memo, Signer, Transaction,
+        TransactionType, TxnCommonFields,
+    },
 };
 
+/// The documentation of the `CheckCash` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/checkcash.html";
+
 /// Cancels an unredeemed Check, removing it from the ledger without
 /// sending any money. The source or the destination of the check can
 /// cancel a Check at any time using this transaction type. If the Check
@@ -25,69 +34,23 @@ use crate::models::{
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct CheckCash<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::check_cash")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     /// The custom fields for the CheckCash model.
     ///
     /// See CheckCash fields:
     /// `<https://xrpl.org/checkcash.html#checkcash-fields>`
     #[serde(rename = "CheckID")]
-    pub check_id: &'a str,
+    #[serde(borrow)]
+    pub check_id: Cow<'a, str>,
     pub amount: Option<Amount<'a>>,
     pub deliver_min: Option<Amount<'a>>,
 }
@@ -96,18 +59,7 @@ impl<'a> Default for CheckCash<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::CheckCash,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             check_id: Default::default(),
             amount: Default::default(),
             deliver_min: Default::default(),
@@ -117,7 +69,15 @@ impl<'a> Default for CheckCash<'a> {
 
 impl<'a: 'static> Model for CheckCash<'a> {
     fn get_errors(&self) -> Result<()> {
-        match self._get_amount_and_deliver_min_error() {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            get_amounts_error(&[self.amount.as_ref(), self.deliver_min.as_ref()])?;
+            self._get_amount_and_deliver_min_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
             Err(error) => Err!(error),
             Ok(_no_error) => Ok(()),
         }
@@ -128,6 +88,14 @@ impl<'a> Transaction for CheckCash<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> CheckCashError for CheckCash<'a> {
@@ -138,7 +106,7 @@ impl<'a> CheckCashError for CheckCash<'a> {
             Err(XRPLCheckCashException::DefineExactlyOneOf {
                 field1: "amount",
                 field2: "deliver_min",
-                resource: "",
+                resource: RESOURCE,
             })
         } else {
             Ok(())
@@ -165,23 +133,37 @@ impl<'a> CheckCash<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::CheckCash,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
-            check_id,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
+            check_id: check_id.into(),
             amount,
             deliver_min,
         }
     }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> CheckCash<'static> {
+        CheckCash {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            check_id: Cow::Owned(self.check_id.into_owned()),
+            amount: self.amount.map(Amount::into_owned),
+            deliver_min: self.deliver_min.map(Amount::into_owned),
+        }
+    }
 }
 
 pub trait CheckCashError {
@@ -199,26 +181,28 @@ mod test_check_cash_error {
     fn test_amount_and_deliver_min_error() {
         let check_cash = CheckCash {
             transaction_type: TransactionType::CheckCash,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
-            check_id: "",
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
+            check_id: "".into(),
             amount: None,
             deliver_min: None,
         };
 
         assert_eq!(
             check_cash.validate().unwrap_err().to_string().as_str(),
-            "The field `amount` can not be defined with `deliver_min`. Define exactly one of them. For more information see: "
+            "The field `amount` can not be defined with `deliver_min`. Define exactly one of them. For more information see: https://xrpl.org/checkcash.html"
         );
     }
 }
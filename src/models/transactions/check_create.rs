@@ -1,4 +1,8 @@
+use crate::Err;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
 use alloc::vec::Vec;
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -6,9 +10,17 @@ use crate::models::amount::XRPAmount;
 use crate::models::{
     amount::Amount,
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_amounts_error, get_common_fields_error, get_destination_error, normalize_x_address,
+        CommonFields, Memo, Signer, Transaction, TransactionType, TxnCommonFields,
+        XRPLTransactionException, XRPLTransactionFieldException,
+    },
 };
 
+/// The documentation of the `CheckCreate` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/checkcreate.html";
+
 /// Create a Check object in the ledger, which is a deferred
 /// payment that can be cashed by its intended destination.
 ///
@@ -18,91 +30,35 @@ use crate::models::{
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct CheckCreate<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::check_create")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     /// The custom fields for the CheckCreate model.
     ///
     /// See CheckCreate fields:
     /// `<https://xrpl.org/checkcreate.html#checkcreate-fields>`
-    pub destination: &'a str,
+    #[serde(borrow)]
+    pub destination: Cow<'a, str>,
     pub send_max: Amount<'a>,
     pub destination_tag: Option<u32>,
     pub expiration: Option<u32>,
     #[serde(rename = "InvoiceID")]
-    pub invoice_id: Option<&'a str>,
+    #[serde(borrow)]
+    pub invoice_id: Option<Cow<'a, str>>,
 }
 
 impl<'a> Default for CheckCreate<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::CheckCreate,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             destination: Default::default(),
             send_max: Default::default(),
             destination_tag: Default::default(),
@@ -112,12 +68,35 @@ impl<'a> Default for CheckCreate<'a> {
     }
 }
 
-impl<'a> Model for CheckCreate<'a> {}
+impl<'a> Model for CheckCreate<'a> {
+    fn get_errors(&self) -> Result<()> {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            get_amounts_error(&[Some(&self.send_max)])?;
+            get_destination_error(&self.common_fields.account, &self.destination, RESOURCE)?;
+
+            Ok(())
+        };
+
+        match errors() {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
 
 impl<'a> Transaction for CheckCreate<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> CheckCreate<'a> {
@@ -141,25 +120,102 @@ impl<'a> CheckCreate<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::CheckCreate,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
-            destination,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
+            destination: destination.into(),
             send_max,
             destination_tag,
             expiration,
-            invoice_id,
+            invoice_id: invoice_id.map(Into::into),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> CheckCreate<'static> {
+        CheckCreate {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            destination: Cow::Owned(self.destination.into_owned()),
+            send_max: self.send_max.into_owned(),
+            destination_tag: self.destination_tag,
+            expiration: self.expiration,
+            invoice_id: self
+                .invoice_id
+                .map(|invoice_id| Cow::Owned(invoice_id.into_owned())),
+        }
+    }
+}
+
+impl<'a> CheckCreate<'a> {
+    /// Replaces X-Addresses in `account` and `destination` with their
+    /// classic addresses and moves the embedded tags into `source_tag`
+    /// and `destination_tag`. Errors if a tag is already defined with a
+    /// different value. Classic addresses are left unchanged.
+    pub fn normalize_x_address(self) -> Result<Self> {
+        match self._normalize_x_address() {
+            Err(error) => Err!(error),
+            Ok(check_create) => Ok(check_create),
         }
     }
+
+    fn _normalize_x_address(mut self) -> Result<Self, XRPLTransactionFieldException<'static>> {
+        self.common_fields = self.common_fields.normalize_x_address()?;
+        let (destination, destination_tag) = normalize_x_address(
+            &self.destination,
+            self.destination_tag,
+            "destination",
+            "destination_tag",
+            RESOURCE,
+        )?;
+        self.destination = destination.into();
+        self.destination_tag = destination_tag;
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod test_check_create_error {
+    use crate::models::amount::XRPAmount;
+    use crate::models::Model;
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_destination_error() {
+        let mut check_create = CheckCreate {
+            common_fields: CommonFields {
+                account: "rUn84CUYbNjRoTQ6mSW7BVJPSVJNLb1QLo".into(),
+                ..Default::default()
+            },
+            destination: "rUn84CUYbNjRoTQ6mSW7BVJPSVJNLb1QLo".into(),
+            send_max: Amount::XRPAmount(XRPAmount::from("100000000")),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            check_create.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `destination` is not allowed to be the same as the value of the field `account`. For more information see: https://xrpl.org/checkcreate.html"
+        );
+
+        check_create.destination = "rfkE1aSy9G8Upk4JssnwBxhEv5p4mn2KTy".into();
+
+        assert!(check_create.validate().is_ok());
+    }
 }
 
 #[cfg(test)]
@@ -223,3 +279,45 @@ mod test_serde {
         assert_eq!(txn_as_obj, default_txn);
     }
 }
+
+#[cfg(test)]
+mod test_x_address {
+    use crate::core::addresscodec::classic_address_to_xaddress;
+
+    use super::*;
+
+    const ACCOUNT: &str = "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb";
+    const DESTINATION: &str = "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK";
+
+    #[test]
+    fn test_extract_tags() {
+        let txn = CheckCreate {
+            common_fields: CommonFields {
+                account: classic_address_to_xaddress(ACCOUNT, Some(1), false)
+                    .unwrap()
+                    .into(),
+                ..Default::default()
+            },
+            destination: classic_address_to_xaddress(DESTINATION, Some(2), false)
+                .unwrap()
+                .into(),
+            send_max: Amount::XRPAmount(XRPAmount::from("100000000")),
+            ..Default::default()
+        }
+        .normalize_x_address()
+        .unwrap();
+
+        assert_eq!(txn.common_fields.account, ACCOUNT);
+        assert_eq!(txn.common_fields.source_tag, Some(1));
+        assert_eq!(txn.destination, DESTINATION);
+        assert_eq!(txn.destination_tag, Some(2));
+
+        let mut conflicting = txn.clone();
+        conflicting.destination = classic_address_to_xaddress(DESTINATION, Some(3), false)
+            .unwrap()
+            .into();
+
+        assert!(conflicting.normalize_x_address().is_err());
+        assert_eq!(txn.clone().normalize_x_address().unwrap(), txn);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::Err;
+use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -8,11 +9,19 @@ use alloc::string::ToString;
 
 use crate::models::amount::XRPAmount;
 use crate::models::transactions::XRPLDepositPreauthException;
+use crate::models::transactions::XRPLTransactionException;
 use crate::models::{
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_common_fields_error, CommonFields, Memo, Signer, Transaction, TransactionType,
+        TxnCommonFields,
+    },
 };
 
+/// The documentation of the `DepositPreauth` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/depositpreauth.html";
+
 /// A DepositPreauth transaction gives another account pre-approval
 /// to deliver payments to the sender of this transaction.
 ///
@@ -22,87 +31,31 @@ use crate::models::{
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct DepositPreauth<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::deposit_preauth")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     /// The custom fields for the DepositPreauth model.
     ///
     /// See DepositPreauth fields:
     /// `<https://xrpl.org/depositpreauth.html#depositpreauth-fields>`
-    pub authorize: Option<&'a str>,
-    pub unauthorize: Option<&'a str>,
+    #[serde(borrow)]
+    pub authorize: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub unauthorize: Option<Cow<'a, str>>,
 }
 
 impl<'a> Default for DepositPreauth<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::DepositPreauth,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             authorize: Default::default(),
             unauthorize: Default::default(),
         }
@@ -111,9 +64,16 @@ impl<'a> Default for DepositPreauth<'a> {
 
 impl<'a: 'static> Model for DepositPreauth<'a> {
     fn get_errors(&self) -> Result<()> {
-        match self._get_authorize_and_unauthorize_error() {
-            Ok(_no_error) => Ok(()),
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self._get_authorize_and_unauthorize_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
             Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
         }
     }
 }
@@ -122,6 +82,14 @@ impl<'a> Transaction for DepositPreauth<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> DepositPreauthError for DepositPreauth<'a> {
@@ -132,7 +100,7 @@ impl<'a> DepositPreauthError for DepositPreauth<'a> {
             Err(XRPLDepositPreauthException::DefineExactlyOneOf {
                 field1: "authorize",
                 field2: "unauthorize",
-                resource: "",
+                resource: RESOURCE,
             })
         } else {
             Ok(())
@@ -158,20 +126,37 @@ impl<'a> DepositPreauth<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::DepositPreauth,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
-            authorize,
-            unauthorize,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
+            authorize: authorize.map(Into::into),
+            unauthorize: unauthorize.map(Into::into),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> DepositPreauth<'static> {
+        DepositPreauth {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            authorize: self
+                .authorize
+                .map(|authorize| Cow::Owned(authorize.into_owned())),
+            unauthorize: self
+                .unauthorize
+                .map(|unauthorize| Cow::Owned(unauthorize.into_owned())),
         }
     }
 }
@@ -192,25 +177,27 @@ mod test_deposit_preauth_exception {
     fn test_authorize_and_unauthorize_error() {
         let deposit_preauth = DepositPreauth {
             transaction_type: TransactionType::DepositPreauth,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             authorize: None,
             unauthorize: None,
         };
 
         assert_eq!(
             deposit_preauth.validate().unwrap_err().to_string().as_str(),
-            "The field `authorize` can not be defined with `unauthorize`. Define exactly one of them. For more information see: "
+            "The field `authorize` can not be defined with `unauthorize`. Define exactly one of them. For more information see: https://xrpl.org/depositpreauth.html"
         );
     }
 }
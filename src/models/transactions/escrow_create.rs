@@ -1,4 +1,5 @@
 use crate::Err;
+use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -8,11 +9,19 @@ use alloc::string::ToString;
 
 use crate::models::amount::XRPAmount;
 use crate::models::transactions::XRPLEscrowCreateException;
+use crate::models::transactions::XRPLTransactionException;
 use crate::models::{
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_common_fields_error, normalize_x_address, CommonFields, Memo, Signer, Transaction,
+        TransactionType, TxnCommonFields, XRPLTransactionFieldException,
+    },
 };
 
+/// The documentation of the `EscrowCreate` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/escrowcreate.html";
+
 /// Creates an Escrow, which sequests XRP until the escrow process either finishes or is canceled.
 ///
 /// See EscrowCreate:
@@ -21,91 +30,35 @@ use crate::models::{
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct EscrowCreate<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::escrow_create")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     /// The custom fields for the EscrowCreate model.
     ///
     /// See EscrowCreate fields:
     /// `<https://xrpl.org/escrowcreate.html#escrowcreate-flags>`
     pub amount: XRPAmount<'a>,
-    pub destination: &'a str,
+    #[serde(borrow)]
+    pub destination: Cow<'a, str>,
     pub destination_tag: Option<u32>,
     pub cancel_after: Option<u32>,
     pub finish_after: Option<u32>,
-    pub condition: Option<&'a str>,
+    #[serde(borrow)]
+    pub condition: Option<Cow<'a, str>>,
 }
 
 impl<'a> Default for EscrowCreate<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::EscrowCreate,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             amount: Default::default(),
             destination: Default::default(),
             destination_tag: Default::default(),
@@ -118,9 +71,16 @@ impl<'a> Default for EscrowCreate<'a> {
 
 impl<'a: 'static> Model for EscrowCreate<'a> {
     fn get_errors(&self) -> Result<()> {
-        match self._get_finish_after_error() {
-            Ok(_) => Ok(()),
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self._get_finish_after_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
             Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
         }
     }
 }
@@ -129,6 +89,14 @@ impl<'a> Transaction for EscrowCreate<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> EscrowCreateError for EscrowCreate<'a> {
@@ -140,7 +108,7 @@ impl<'a> EscrowCreateError for EscrowCreate<'a> {
                     field2: "finish_after",
                     field1_val: cancel_after,
                     field2_val: finish_after,
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else {
                 Ok(())
@@ -173,24 +141,43 @@ impl<'a> EscrowCreate<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::EscrowCreate,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
             amount,
-            destination,
+            destination: destination.into(),
             destination_tag,
             cancel_after,
             finish_after,
-            condition,
+            condition: condition.map(Into::into),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> EscrowCreate<'static> {
+        EscrowCreate {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            amount: self.amount.into_owned(),
+            destination: Cow::Owned(self.destination.into_owned()),
+            destination_tag: self.destination_tag,
+            cancel_after: self.cancel_after,
+            finish_after: self.finish_after,
+            condition: self
+                .condition
+                .map(|condition| Cow::Owned(condition.into_owned())),
         }
     }
 }
@@ -199,6 +186,34 @@ pub trait EscrowCreateError {
     fn _get_finish_after_error(&self) -> Result<(), XRPLEscrowCreateException>;
 }
 
+impl<'a> EscrowCreate<'a> {
+    /// Replaces X-Addresses in `account` and `destination` with their
+    /// classic addresses and moves the embedded tags into `source_tag`
+    /// and `destination_tag`. Errors if a tag is already defined with a
+    /// different value. Classic addresses are left unchanged.
+    pub fn normalize_x_address(self) -> Result<Self> {
+        match self._normalize_x_address() {
+            Err(error) => Err!(error),
+            Ok(escrow_create) => Ok(escrow_create),
+        }
+    }
+
+    fn _normalize_x_address(mut self) -> Result<Self, XRPLTransactionFieldException<'static>> {
+        self.common_fields = self.common_fields.normalize_x_address()?;
+        let (destination, destination_tag) = normalize_x_address(
+            &self.destination,
+            self.destination_tag,
+            "destination",
+            "destination_tag",
+            RESOURCE,
+        )?;
+        self.destination = destination.into();
+        self.destination_tag = destination_tag;
+
+        Ok(self)
+    }
+}
+
 #[cfg(test)]
 mod test_escrow_create_errors {
     use crate::models::Model;
@@ -213,20 +228,22 @@ mod test_escrow_create_errors {
     fn test_cancel_after_error() {
         let escrow_create = EscrowCreate {
             transaction_type: TransactionType::EscrowCreate,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             amount: XRPAmount::from("100000000"),
-            destination: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
+            destination: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
             destination_tag: None,
             cancel_after: Some(13298498),
             finish_after: Some(14359039),
@@ -235,9 +252,27 @@ mod test_escrow_create_errors {
 
         assert_eq!(
             escrow_create.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `cancel_after` is not allowed to be below the value of the field `finish_after` (max 14359039, found 13298498). For more information see: "
+            "The value of the field `cancel_after` is not allowed to be below the value of the field `finish_after` (max 14359039, found 13298498). For more information see: https://xrpl.org/escrowcreate.html"
         );
     }
+
+    #[test]
+    fn test_destination_is_account() {
+        // Unlike checks and payment channels, escrows can be created
+        // with the sending account as destination.
+        let escrow_create = EscrowCreate {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            amount: XRPAmount::from("100000000"),
+            destination: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+            finish_after: Some(13298498),
+            ..Default::default()
+        };
+
+        assert!(escrow_create.validate().is_ok());
+    }
 }
 
 #[cfg(test)]
@@ -301,3 +336,45 @@ mod test_serde {
         assert_eq!(txn_as_obj, default_txn);
     }
 }
+
+#[cfg(test)]
+mod test_x_address {
+    use crate::core::addresscodec::classic_address_to_xaddress;
+
+    use super::*;
+
+    const ACCOUNT: &str = "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb";
+    const DESTINATION: &str = "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK";
+
+    #[test]
+    fn test_extract_tags() {
+        let txn = EscrowCreate {
+            common_fields: CommonFields {
+                account: classic_address_to_xaddress(ACCOUNT, Some(1), false)
+                    .unwrap()
+                    .into(),
+                ..Default::default()
+            },
+            destination: classic_address_to_xaddress(DESTINATION, Some(2), false)
+                .unwrap()
+                .into(),
+            amount: XRPAmount::from("10000"),
+            ..Default::default()
+        }
+        .normalize_x_address()
+        .unwrap();
+
+        assert_eq!(txn.common_fields.account, ACCOUNT);
+        assert_eq!(txn.common_fields.source_tag, Some(1));
+        assert_eq!(txn.destination, DESTINATION);
+        assert_eq!(txn.destination_tag, Some(2));
+
+        let mut conflicting = txn.clone();
+        conflicting.destination = classic_address_to_xaddress(DESTINATION, Some(3), false)
+            .unwrap()
+            .into();
+
+        assert!(conflicting.normalize_x_address().is_err());
+        assert_eq!(txn.clone().normalize_x_address().unwrap(), txn);
+    }
+}
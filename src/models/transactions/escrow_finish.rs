@@ -1,18 +1,31 @@
+use crate::utils::REFERENCE_FEE_DROPS;
 use crate::Err;
+use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use anyhow::Result;
+use core::convert::TryInto;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use alloc::string::ToString;
 
+use crate::models::amount::exceptions::XRPLAmountException;
 use crate::models::transactions::XRPLEscrowFinishException;
+use crate::models::transactions::XRPLTransactionException;
 use crate::models::{
     amount::XRPAmount,
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_common_fields_error, CommonFields, Memo, Signer, Transaction, TransactionType,
+        TxnCommonFields,
+    },
 };
 
+/// The documentation of the `EscrowFinish` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/escrowfinish.html";
+
 /// Finishes an Escrow and delivers XRP from a held payment to the recipient.
 ///
 /// See EscrowFinish:
@@ -21,89 +34,34 @@ use crate::models::{
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct EscrowFinish<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::escrow_finish")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     /// The custom fields for the EscrowFinish model.
     ///
     /// See EscrowFinish fields:
     /// `<https://xrpl.org/escrowfinish.html#escrowfinish-fields>`
-    pub owner: &'a str,
+    #[serde(borrow)]
+    pub owner: Cow<'a, str>,
     pub offer_sequence: u32,
-    pub condition: Option<&'a str>,
-    pub fulfillment: Option<&'a str>,
+    #[serde(borrow)]
+    pub condition: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub fulfillment: Option<Cow<'a, str>>,
 }
 
 impl<'a> Default for EscrowFinish<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::EscrowFinish,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             owner: Default::default(),
             offer_sequence: Default::default(),
             condition: Default::default(),
@@ -114,9 +72,16 @@ impl<'a> Default for EscrowFinish<'a> {
 
 impl<'a: 'static> Model for EscrowFinish<'a> {
     fn get_errors(&self) -> Result<()> {
-        match self._get_condition_and_fulfillment_error() {
-            Ok(_) => Ok(()),
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self._get_condition_and_fulfillment_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
             Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
         }
     }
 }
@@ -125,6 +90,22 @@ impl<'a> Transaction for EscrowFinish<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
+
+    fn default_fee(&self) -> XRPAmount<'static> {
+        let base_fee = Decimal::from(REFERENCE_FEE_DROPS);
+        let signer_count = self.common_fields.signers.as_ref().map_or(0, Vec::len);
+        let fee = self._suggested_fee(base_fee) + base_fee * Decimal::from(signer_count);
+
+        XRPAmount::from(fee.ceil().normalize().to_string())
+    }
 }
 
 impl<'a> EscrowFinishError for EscrowFinish<'a> {
@@ -135,7 +116,7 @@ impl<'a> EscrowFinishError for EscrowFinish<'a> {
             Err(XRPLEscrowFinishException::FieldRequiresField {
                 field1: "condition",
                 field2: "fulfillment",
-                resource: "",
+                resource: RESOURCE,
             })
         } else {
             Ok(())
@@ -163,22 +144,75 @@ impl<'a> EscrowFinish<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::EscrowFinish,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
-            owner,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
+            owner: owner.into(),
             offer_sequence,
-            condition,
-            fulfillment,
+            condition: condition.map(Into::into),
+            fulfillment: fulfillment.map(Into::into),
+        }
+    }
+
+    /// Calculates the transaction cost of this `EscrowFinish` for a given
+    /// reference transaction cost. A fulfillment adds a surcharge of
+    /// `base_fee * (33 + fulfillment_bytes / 16)`, rounded up to the next drop.
+    /// Without a fulfillment the `base_fee` is returned unchanged.
+    ///
+    /// See EscrowFinish fields:
+    /// `<https://xrpl.org/escrowfinish.html#escrowfinish-fields>`
+    pub fn suggested_fee(
+        &self,
+        base_fee: XRPAmount<'a>,
+    ) -> Result<XRPAmount<'a>, XRPLAmountException> {
+        let base_fee_decimal: Decimal = base_fee.clone().try_into()?;
+        match self.fulfillment {
+            Some(_) => {
+                let fee = self._suggested_fee(base_fee_decimal);
+
+                Ok(XRPAmount::from(fee.ceil().normalize().to_string()))
+            }
+            None => Ok(base_fee),
+        }
+    }
+
+    /// The transaction cost of `suggested_fee` before rounding.
+    fn _suggested_fee(&self, base_fee: Decimal) -> Decimal {
+        match self.fulfillment.as_deref() {
+            Some(fulfillment) => {
+                let fulfillment_bytes = Decimal::from(fulfillment.len() / 2);
+
+                base_fee * (Decimal::from(33) + fulfillment_bytes / Decimal::from(16))
+            }
+            None => base_fee,
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> EscrowFinish<'static> {
+        EscrowFinish {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            owner: Cow::Owned(self.owner.into_owned()),
+            offer_sequence: self.offer_sequence,
+            condition: self
+                .condition
+                .map(|condition| Cow::Owned(condition.into_owned())),
+            fulfillment: self
+                .fulfillment
+                .map(|fulfillment| Cow::Owned(fulfillment.into_owned())),
         }
     }
 }
@@ -199,29 +233,75 @@ mod test_escrow_finish_errors {
     fn test_condition_and_fulfillment_error() {
         let escrow_finish = EscrowFinish {
             transaction_type: TransactionType::EscrowCancel,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
-            owner: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
+            owner: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
             offer_sequence: 10,
             condition: Some(
-                "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100",
+                "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100"
+                    .into(),
             ),
             fulfillment: None,
         };
 
         assert_eq!(
             escrow_finish.validate().unwrap_err().to_string().as_str(),
-            "For the field `condition` to be defined it is required to also define the field `fulfillment`. For more information see: "
+            "For the field `condition` to be defined it is required to also define the field `fulfillment`. For more information see: https://xrpl.org/escrowfinish.html"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_escrow_finish_fee {
+    use super::*;
+
+    #[test]
+    fn test_suggested_fee() {
+        let mut escrow_finish = EscrowFinish {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            owner: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+            offer_sequence: 10,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            escrow_finish.suggested_fee("10".into()).unwrap(),
+            XRPAmount::from("10")
+        );
+
+        // 32 bytes of fulfillment: 10 * (33 + 32 / 16) = 350 drops
+        escrow_finish.condition = Some(
+            "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100".into(),
+        );
+        escrow_finish.fulfillment =
+            Some("A0028000A0028000A0028000A0028000A0028000A0028000A0028000A0028000".into());
+
+        assert_eq!(
+            escrow_finish.suggested_fee("10".into()).unwrap(),
+            XRPAmount::from("350")
+        );
+
+        // 4 bytes of fulfillment: 10 * (33 + 4 / 16) = 332.5, rounded up
+        escrow_finish.fulfillment = Some("A0028000".into());
+
+        assert_eq!(
+            escrow_finish.suggested_fee("10".into()).unwrap(),
+            XRPAmount::from("333")
         );
     }
 }
@@ -1,29 +1,247 @@
-use crate::models::transactions::{AccountSetFlag, PaymentFlag};
-use strum_macros::Display;
+use crate::models::amount::exceptions::XRPLAmountException;
+use crate::models::transactions::{
+    AccountSetFlag, MPTokenIssuanceCreateFlag, MPTokenIssuanceSetFlag, NFTokenMintFlag, PaymentFlag,
+};
 use thiserror_no_std::Error;
 
-#[derive(Debug, Clone, PartialEq, Eq, Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLTransactionException<'a> {
+    #[error("{0}")]
     XRPLAccountSetError(XRPLAccountSetException<'a>),
+    #[error("{0}")]
     XRPLCheckCashError(XRPLCheckCashException<'a>),
+    #[error("{0}")]
     XRPLDepositPreauthError(XRPLDepositPreauthException<'a>),
+    #[error("{0}")]
     XRPLEscrowCreateError(XRPLEscrowCreateException<'a>),
+    #[error("{0}")]
     XRPLEscrowFinishError(XRPLEscrowFinishException<'a>),
+    #[error("{0}")]
+    XRPLMPTokenIssuanceCreateError(XRPLMPTokenIssuanceCreateException<'a>),
+    #[error("{0}")]
+    XRPLMPTokenIssuanceSetError(XRPLMPTokenIssuanceSetException<'a>),
+    #[error("{0}")]
     XRPLNFTokenAcceptOfferError(XRPLNFTokenAcceptOfferException<'a>),
+    #[error("{0}")]
+    XRPLNFTokenBurnError(XRPLNFTokenBurnException<'a>),
+    #[error("{0}")]
     XRPLNFTokenCancelOfferError(XRPLNFTokenCancelOfferException<'a>),
+    #[error("{0}")]
     XRPLNFTokenCreateOfferError(XRPLNFTokenCreateOfferException<'a>),
+    #[error("{0}")]
     XRPLNFTokenMintError(XRPLNFTokenMintException<'a>),
+    #[error("{0}")]
+    XRPLNFTokenModifyError(XRPLNFTokenModifyException<'a>),
+    #[error("{0}")]
+    XRPLOfferCreateError(XRPLOfferCreateException<'a>),
+    #[error("{0}")]
     XRPLPaymentError(XRPLPaymentException<'a>),
+    #[error("{0}")]
+    XRPLPaymentChannelClaimError(XRPLPaymentChannelClaimException<'a>),
+    #[error("{0}")]
+    XRPLPaymentChannelCreateError(XRPLPaymentChannelCreateException<'a>),
+    #[error("{0}")]
+    XRPLPaymentChannelFundError(XRPLPaymentChannelFundException<'a>),
+    #[error("{0}")]
     XRPLSignerListSetError(XRPLSignerListSetException<'a>),
+    #[error("{0}")]
+    XRPLTransactionFieldError(XRPLTransactionFieldException<'a>),
+    #[error("{0}")]
+    XRPLTrustSetError(XRPLTrustSetException<'a>),
+    #[error("{0}")]
+    XRPLAmountError(XRPLAmountException),
+}
+
+impl<'a> From<XRPLAmountException> for XRPLTransactionException<'a> {
+    fn from(err: XRPLAmountException) -> Self {
+        XRPLTransactionException::XRPLAmountError(err)
+    }
+}
+
+impl<'a> From<XRPLAccountSetException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLAccountSetException<'a>) -> Self {
+        XRPLTransactionException::XRPLAccountSetError(err)
+    }
+}
+
+impl<'a> From<XRPLCheckCashException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLCheckCashException<'a>) -> Self {
+        XRPLTransactionException::XRPLCheckCashError(err)
+    }
+}
+
+impl<'a> From<XRPLDepositPreauthException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLDepositPreauthException<'a>) -> Self {
+        XRPLTransactionException::XRPLDepositPreauthError(err)
+    }
+}
+
+impl<'a> From<XRPLEscrowCreateException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLEscrowCreateException<'a>) -> Self {
+        XRPLTransactionException::XRPLEscrowCreateError(err)
+    }
+}
+
+impl<'a> From<XRPLEscrowFinishException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLEscrowFinishException<'a>) -> Self {
+        XRPLTransactionException::XRPLEscrowFinishError(err)
+    }
+}
+
+impl<'a> From<XRPLMPTokenIssuanceCreateException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLMPTokenIssuanceCreateException<'a>) -> Self {
+        XRPLTransactionException::XRPLMPTokenIssuanceCreateError(err)
+    }
+}
+
+impl<'a> From<XRPLMPTokenIssuanceSetException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLMPTokenIssuanceSetException<'a>) -> Self {
+        XRPLTransactionException::XRPLMPTokenIssuanceSetError(err)
+    }
+}
+
+impl<'a> From<XRPLNFTokenAcceptOfferException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLNFTokenAcceptOfferException<'a>) -> Self {
+        XRPLTransactionException::XRPLNFTokenAcceptOfferError(err)
+    }
+}
+
+impl<'a> From<XRPLNFTokenBurnException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLNFTokenBurnException<'a>) -> Self {
+        XRPLTransactionException::XRPLNFTokenBurnError(err)
+    }
+}
+
+impl<'a> From<XRPLNFTokenCancelOfferException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLNFTokenCancelOfferException<'a>) -> Self {
+        XRPLTransactionException::XRPLNFTokenCancelOfferError(err)
+    }
+}
+
+impl<'a> From<XRPLNFTokenCreateOfferException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLNFTokenCreateOfferException<'a>) -> Self {
+        XRPLTransactionException::XRPLNFTokenCreateOfferError(err)
+    }
+}
+
+impl<'a> From<XRPLNFTokenMintException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLNFTokenMintException<'a>) -> Self {
+        XRPLTransactionException::XRPLNFTokenMintError(err)
+    }
+}
+
+impl<'a> From<XRPLNFTokenModifyException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLNFTokenModifyException<'a>) -> Self {
+        XRPLTransactionException::XRPLNFTokenModifyError(err)
+    }
+}
+
+impl<'a> From<XRPLOfferCreateException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLOfferCreateException<'a>) -> Self {
+        XRPLTransactionException::XRPLOfferCreateError(err)
+    }
+}
+
+impl<'a> From<XRPLPaymentException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLPaymentException<'a>) -> Self {
+        XRPLTransactionException::XRPLPaymentError(err)
+    }
+}
+
+impl<'a> From<XRPLPaymentChannelClaimException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLPaymentChannelClaimException<'a>) -> Self {
+        XRPLTransactionException::XRPLPaymentChannelClaimError(err)
+    }
+}
+
+impl<'a> From<XRPLPaymentChannelCreateException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLPaymentChannelCreateException<'a>) -> Self {
+        XRPLTransactionException::XRPLPaymentChannelCreateError(err)
+    }
+}
+
+impl<'a> From<XRPLPaymentChannelFundException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLPaymentChannelFundException<'a>) -> Self {
+        XRPLTransactionException::XRPLPaymentChannelFundError(err)
+    }
+}
+
+impl<'a> From<XRPLSignerListSetException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLSignerListSetException<'a>) -> Self {
+        XRPLTransactionException::XRPLSignerListSetError(err)
+    }
+}
+
+impl<'a> From<XRPLTrustSetException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLTrustSetException<'a>) -> Self {
+        XRPLTransactionException::XRPLTrustSetError(err)
+    }
+}
+
+impl<'a> From<XRPLTransactionFieldException<'a>> for XRPLTransactionException<'a> {
+    fn from(err: XRPLTransactionFieldException<'a>) -> Self {
+        XRPLTransactionException::XRPLTransactionFieldError(err)
+    }
 }
 
 #[cfg(feature = "std")]
 impl<'a> alloc::error::Error for XRPLTransactionException<'a> {}
 
+/// Errors of fields that are shared by several transaction types.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLTransactionFieldException<'a> {
+    /// A fields value doesn't match its required format.
+    #[error("The value of the field `{field:?}` does not have the correct format (expected {format:?}, found {found:?}). For more information see: {resource}")]
+    InvalidValueFormat {
+        field: &'a str,
+        format: &'a str,
+        found: &'a str,
+        resource: &'a str,
+    },
+    /// A fields value exceeds its maximum size in bytes.
+    #[error("The value of the field `{field:?}` exceeds its maximum size in bytes (max {max:?}, found {found:?}). For more information see: {resource}")]
+    ValueTooLarge {
+        field: &'a str,
+        max: usize,
+        found: usize,
+        resource: &'a str,
+    },
+    /// The destination of a transaction is its sending account.
+    #[error("The value of the field `destination` is not allowed to be the same as the value of the field `account`. For more information see: {resource}")]
+    DestinationIsAccount { resource: &'a str },
+    /// Raw flags contain bits that are not defined for the transaction type.
+    #[error("The flags contain bits that are not defined for the transaction type (found {found:#010x}). For more information see: {resource}")]
+    UnknownFlags { found: u32, resource: &'a str },
+    /// A transaction using a ticket has a nonzero sequence number.
+    #[error("The value of the field `sequence` must be 0 or absent if the field `ticket_sequence` is set (found {found:?}). For more information see: {resource}")]
+    SequenceWithTicket { found: u32, resource: &'a str },
+    /// A transaction has a sequence number of 0 without using a ticket.
+    #[error("The value of the field `sequence` is only allowed to be 0 if the field `ticket_sequence` is set. For more information see: {resource}")]
+    ZeroSequenceWithoutTicket { resource: &'a str },
+    /// A field is not a valid classic address or X-Address.
+    #[error("The field `{field:?}` is not a valid classic address or X-Address. For more information see: {resource}")]
+    InvalidAddress { field: &'a str, resource: &'a str },
+    /// The tag embedded in an X-Address conflicts with an explicitly defined tag.
+    #[error("The tag embedded in the X-Address of the field `{field:?}` conflicts with the field `{tag_field:?}` (expected {expected:?}, found {found:?}). For more information see: {resource}")]
+    XAddressTagConflict {
+        field: &'a str,
+        tag_field: &'a str,
+        expected: u64,
+        found: u32,
+        resource: &'a str,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<'a> alloc::error::Error for XRPLTransactionFieldException<'a> {}
+
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLAccountSetException<'a> {
     /// A fields value exceeds its maximum value.
-    #[error("The value of the field `{field:?}` is defined above its maximum (max {max:?}, found {found:?}). For more information see: {resource:?}")]
+    #[error("The value of the field `{field:?}` is defined above its maximum (max {max:?}, found {found:?}). For more information see: {resource}")]
     ValueTooHigh {
         field: &'a str,
         max: u32,
@@ -31,7 +249,7 @@ pub enum XRPLAccountSetException<'a> {
         resource: &'a str,
     },
     /// A fields value exceeds its minimum value.
-    #[error("The value of the field `{field:?}` is defined below its minimum (min {min:?}, found {found:?}). For more information see: {resource:?}")]
+    #[error("The value of the field `{field:?}` is defined below its minimum (min {min:?}, found {found:?}). For more information see: {resource}")]
     ValueTooLow {
         field: &'a str,
         min: u32,
@@ -39,7 +257,7 @@ pub enum XRPLAccountSetException<'a> {
         resource: &'a str,
     },
     /// A fields value exceeds its maximum character length.
-    #[error("The value of the field `{field:?}` exceeds its maximum length of characters (max {max:?}, found {found:?}). For more information see: {resource:?}")]
+    #[error("The value of the field `{field:?}` exceeds its maximum length of characters (max {max:?}, found {found:?}). For more information see: {resource}")]
     ValueTooLong {
         field: &'a str,
         max: usize,
@@ -47,7 +265,7 @@ pub enum XRPLAccountSetException<'a> {
         resource: &'a str,
     },
     /// A fields value doesn't match its required format.
-    #[error("The value of the field `{field:?}` does not have the correct format (expected {format:?}, found {found:?}). For more information see: {resource:?}")]
+    #[error("The value of the field `{field:?}` does not have the correct format (expected {format:?}, found {found:?}). For more information see: {resource}")]
     InvalidValueFormat {
         field: &'a str,
         format: &'a str,
@@ -55,27 +273,27 @@ pub enum XRPLAccountSetException<'a> {
         resource: &'a str,
     },
     /// A field can only be defined if a transaction flag is set.
-    #[error("For the field `{field:?}` to be defined it is required to set the flag `{flag:?}`. For more information see: {resource:?}")]
+    #[error("For the field `{field:?}` to be defined it is required to set the flag `{flag:?}`. For more information see: {resource}")]
     FieldRequiresFlag {
         field: &'a str,
         flag: AccountSetFlag,
         resource: &'a str,
     },
     /// An account set flag can only be set if a field is defined.
-    #[error("For the flag `{flag:?}` to be set it is required to define the field `{field:?}`. For more information see: {resource:?}")]
+    #[error("For the flag `{flag:?}` to be set it is required to define the field `{field:?}`. For more information see: {resource}")]
     FlagRequiresField {
         flag: AccountSetFlag,
         field: &'a str,
         resource: &'a str,
     },
     /// Am account set flag can not be set and unset at the same time.
-    #[error("A flag cannot be set and unset at the same time (found {found:?}). For more information see: {resource:?}")]
+    #[error("A flag cannot be set and unset at the same time (found {found:?}). For more information see: {resource}")]
     SetAndUnsetSameFlag {
         found: AccountSetFlag,
         resource: &'a str,
     },
     /// A field was defined and an account set flag that is required for that field was unset.
-    #[error("The field `{field:?}` cannot be defined if its required flag `{flag:?}` is being unset. For more information see: {resource:?}")]
+    #[error("The field `{field:?}` cannot be defined if its required flag `{flag:?}` is being unset. For more information see: {resource}")]
     SetFieldWhenUnsetRequiredFlag {
         field: &'a str,
         flag: AccountSetFlag,
@@ -87,9 +305,10 @@ pub enum XRPLAccountSetException<'a> {
 impl<'a> alloc::error::Error for XRPLAccountSetException<'a> {}
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLCheckCashException<'a> {
     /// A field cannot be defined with other fields.
-    #[error("The field `{field1:?}` can not be defined with `{field2:?}`. Define exactly one of them. For more information see: {resource:?}")]
+    #[error("The field `{field1:?}` can not be defined with `{field2:?}`. Define exactly one of them. For more information see: {resource}")]
     DefineExactlyOneOf {
         field1: &'a str,
         field2: &'a str,
@@ -98,9 +317,10 @@ pub enum XRPLCheckCashException<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLDepositPreauthException<'a> {
     /// A field cannot be defined with other fields.
-    #[error("The field `{field1:?}` can not be defined with `{field2:?}`. Define exactly one of them. For more information see: {resource:?}")]
+    #[error("The field `{field1:?}` can not be defined with `{field2:?}`. Define exactly one of them. For more information see: {resource}")]
     DefineExactlyOneOf {
         field1: &'a str,
         field2: &'a str,
@@ -112,9 +332,10 @@ pub enum XRPLDepositPreauthException<'a> {
 impl<'a> alloc::error::Error for XRPLCheckCashException<'a> {}
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLEscrowCreateException<'a> {
     /// A fields value cannot be below another fields value.
-    #[error("The value of the field `{field1:?}` is not allowed to be below the value of the field `{field2:?}` (max {field2_val:?}, found {field1_val:?}). For more information see: {resource:?}")]
+    #[error("The value of the field `{field1:?}` is not allowed to be below the value of the field `{field2:?}` (max {field2_val:?}, found {field1_val:?}). For more information see: {resource}")]
     ValueBelowValue {
         field1: &'a str,
         field2: &'a str,
@@ -128,9 +349,10 @@ pub enum XRPLEscrowCreateException<'a> {
 impl<'a> alloc::error::Error for XRPLEscrowCreateException<'a> {}
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLEscrowFinishException<'a> {
     /// For a field to be defined it also needs another field to be defined.
-    #[error("For the field `{field1:?}` to be defined it is required to also define the field `{field2:?}`. For more information see: {resource:?}")]
+    #[error("For the field `{field1:?}` to be defined it is required to also define the field `{field2:?}`. For more information see: {resource}")]
     FieldRequiresField {
         field1: &'a str,
         field2: &'a str,
@@ -142,26 +364,115 @@ pub enum XRPLEscrowFinishException<'a> {
 impl<'a> alloc::error::Error for XRPLEscrowFinishException<'a> {}
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLMPTokenIssuanceCreateException<'a> {
+    /// A fields value exceeds its maximum value.
+    #[error("The field `{field:?}` exceeds its maximum value (max {max:?}, found {found:?}). For more information see: {resource}")]
+    ValueTooHigh {
+        field: &'a str,
+        max: u64,
+        found: u64,
+        resource: &'a str,
+    },
+    /// A fields value exceeds its maximum length in bytes.
+    #[error("The value of the field `{field:?}` exceeds its maximum length in bytes (max {max:?}, found {found:?}). For more information see: {resource}")]
+    ValueTooLong {
+        field: &'a str,
+        max: usize,
+        found: usize,
+        resource: &'a str,
+    },
+    /// A fields value doesn't match its required format.
+    #[error("The value of the field `{field:?}` does not have the correct format (expected {format:?}, found {found:?}). For more information see: {resource}")]
+    InvalidValueFormat {
+        field: &'a str,
+        format: &'a str,
+        found: &'a str,
+        resource: &'a str,
+    },
+    /// A field can only be defined if a transaction flag is set.
+    #[error("For the field `{field:?}` to be defined it is required to set the flag `{flag:?}`. For more information see: {resource}")]
+    FieldRequiresFlag {
+        field: &'a str,
+        flag: MPTokenIssuanceCreateFlag,
+        resource: &'a str,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<'a> alloc::error::Error for XRPLMPTokenIssuanceCreateException<'a> {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLMPTokenIssuanceSetException<'a> {
+    /// Two flags are not allowed to be set at the same time.
+    #[error("The flags `{flag1:?}` and `{flag2:?}` cannot be set at the same time. For more information see: {resource}")]
+    MutuallyExclusiveFlags {
+        flag1: MPTokenIssuanceSetFlag,
+        flag2: MPTokenIssuanceSetFlag,
+        resource: &'a str,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<'a> alloc::error::Error for XRPLMPTokenIssuanceSetException<'a> {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLNFTokenAcceptOfferException<'a> {
     /// Define at least one of the fields.
-    #[error("Define at least one of the fields `{field1:?}` and `{field2:?}`. For more information see: {resource:?}")]
+    #[error("Define at least one of the fields `{field1:?}` and `{field2:?}`. For more information see: {resource}")]
     DefineOneOf {
         field1: &'a str,
         field2: &'a str,
         resource: &'a str,
     },
     /// The value can not be zero.
-    #[error("The value of the field `{field:?}` is not allowed to be zero. For more information see: {resource:?}")]
+    #[error("The value of the field `{field:?}` is not allowed to be zero. For more information see: {resource}")]
     ValueZero { field: &'a str, resource: &'a str },
+    /// The currencies of two amounts do not match.
+    #[error("The currency of the field `{field1:?}` has to match the currency of `{field2:?}`. For more information see: {resource}")]
+    CurrencyMismatch {
+        field1: &'a str,
+        field2: &'a str,
+        resource: &'a str,
+    },
 }
 
 #[cfg(feature = "std")]
 impl<'a> alloc::error::Error for XRPLNFTokenAcceptOfferException<'a> {}
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLNFTokenBurnException<'a> {
+    /// A fields value is not allowed to be the same as another fields value.
+    #[error("The value of the field `{field1:?}` is not allowed to be the same as the value of the field `{field2:?}`. For more information see: {resource}")]
+    ValueEqualsValue {
+        field1: &'a str,
+        field2: &'a str,
+        resource: &'a str,
+    },
+    /// A fields value doesn't match its required format.
+    #[error("The value of the field `{field:?}` does not have the correct format (expected {format:?}, found {found:?}). For more information see: {resource}")]
+    InvalidValueFormat {
+        field: &'a str,
+        format: &'a str,
+        found: &'a str,
+        resource: &'a str,
+    },
+    /// A field is not a valid classic address.
+    #[error("The field `{field:?}` is not a valid classic address. For more information see: {resource}")]
+    InvalidAddress { field: &'a str, resource: &'a str },
+}
+
+#[cfg(feature = "std")]
+impl<'a> alloc::error::Error for XRPLNFTokenBurnException<'a> {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLNFTokenCancelOfferException<'a> {
     /// A collection was defined to be empty.
-    #[error("The value of the field `{field:?}` is not allowed to be empty (type `{r#type:?}`). If the field is optional, define it to be `None`. For more information see: {resource:?}")]
+    #[error("The value of the field `{field:?}` is not allowed to be empty (type `{r#type:?}`). If the field is optional, define it to be `None`. For more information see: {resource}")]
     CollectionEmpty {
         field: &'a str,
         r#type: &'a str,
@@ -173,26 +484,27 @@ pub enum XRPLNFTokenCancelOfferException<'a> {
 impl<'a> alloc::error::Error for XRPLNFTokenCancelOfferException<'a> {}
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLNFTokenCreateOfferException<'a> {
     /// The value can not be zero.
-    #[error("The value of the field `{field:?}` is not allowed to be zero. For more information see: {resource:?}")]
+    #[error("The value of the field `{field:?}` is not allowed to be zero. For more information see: {resource}")]
     ValueZero { field: &'a str, resource: &'a str },
     /// A fields value is not allowed to be the same as another fields value.
-    #[error("The value of the field `{field1:?}` is not allowed to be the same as the value of the field `{field2:?}`. For more information see: {resource:?}")]
+    #[error("The value of the field `{field1:?}` is not allowed to be the same as the value of the field `{field2:?}`. For more information see: {resource}")]
     ValueEqualsValue {
         field1: &'a str,
         field2: &'a str,
         resource: &'a str,
     },
     /// An optional value must be defined in a certain context.
-    #[error("The optional field `{field:?}` is required to be defined for {context:?}. For more information see: {resource:?}")]
+    #[error("The optional field `{field:?}` is required to be defined for {context:?}. For more information see: {resource}")]
     OptionRequired {
         field: &'a str,
         context: &'a str,
         resource: &'a str,
     },
     /// An optional value is not allowed to be defined in a certain context.
-    #[error("The optional field `{field:?}` is not allowed to be defined for {context:?}. For more information see: {resource:?}")]
+    #[error("The optional field `{field:?}` is not allowed to be defined for {context:?}. For more information see: {resource}")]
     IllegalOption {
         field: &'a str,
         context: &'a str,
@@ -204,16 +516,17 @@ pub enum XRPLNFTokenCreateOfferException<'a> {
 impl<'a> alloc::error::Error for XRPLNFTokenCreateOfferException<'a> {}
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLNFTokenMintException<'a> {
     /// A fields value is not allowed to be the same as another fields value.
-    #[error("The value of the field `{field1:?}` is not allowed to be the same as the value of the field `{field2:?}`. For more information see: {resource:?}")]
+    #[error("The value of the field `{field1:?}` is not allowed to be the same as the value of the field `{field2:?}`. For more information see: {resource}")]
     ValueEqualsValue {
         field1: &'a str,
         field2: &'a str,
         resource: &'a str,
     },
     /// A fields value exceeds its maximum value.
-    #[error("The field `{field:?}` exceeds its maximum value (max {max:?}, found {found:?}). For more information see: {resource:?}")]
+    #[error("The field `{field:?}` exceeds its maximum value (max {max:?}, found {found:?}). For more information see: {resource}")]
     ValueTooHigh {
         field: &'a str,
         max: u32,
@@ -221,7 +534,7 @@ pub enum XRPLNFTokenMintException<'a> {
         resource: &'a str,
     },
     /// A fields value exceeds its maximum character length.
-    #[error("The value of the field `{field:?}` exceeds its maximum length of characters (max {max:?}, found {found:?}). For more information see: {resource:?}")]
+    #[error("The value of the field `{field:?}` exceeds its maximum length of characters (max {max:?}, found {found:?}). For more information see: {resource}")]
     ValueTooLong {
         field: &'a str,
         max: usize,
@@ -234,52 +547,196 @@ pub enum XRPLNFTokenMintException<'a> {
 impl<'a> alloc::error::Error for XRPLNFTokenMintException<'a> {}
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLNFTokenModifyException<'a> {
+    /// A fields value is not allowed to be the same as another fields value.
+    #[error("The value of the field `{field1:?}` is not allowed to be the same as the value of the field `{field2:?}`. For more information see: {resource}")]
+    ValueEqualsValue {
+        field1: &'a str,
+        field2: &'a str,
+        resource: &'a str,
+    },
+    /// A fields value doesn't match its required format.
+    #[error("The value of the field `{field:?}` does not have the correct format (expected {format:?}, found {found:?}). For more information see: {resource}")]
+    InvalidValueFormat {
+        field: &'a str,
+        format: &'a str,
+        found: &'a str,
+        resource: &'a str,
+    },
+    /// A fields value exceeds its maximum character length.
+    #[error("The value of the field `{field:?}` exceeds its maximum length of characters (max {max:?}, found {found:?}). For more information see: {resource}")]
+    ValueTooLong {
+        field: &'a str,
+        max: usize,
+        found: usize,
+        resource: &'a str,
+    },
+    /// A fields value is defined to be empty.
+    #[error("The value of the field `{field:?}` is not allowed to be empty. If the field is optional, define it to be `None`. For more information see: {resource}")]
+    ValueEmpty { field: &'a str, resource: &'a str },
+    /// The NFToken was not minted with a flag the transaction requires.
+    #[error("The NFToken `{nftoken_id:?}` is required to be minted with the flag `{flag:?}`. For more information see: {resource}")]
+    NFTokenRequiresFlag {
+        nftoken_id: &'a str,
+        flag: NFTokenMintFlag,
+        resource: &'a str,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<'a> alloc::error::Error for XRPLNFTokenModifyException<'a> {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLOfferCreateException<'a> {
+    /// A fields value exceeds its minimum value.
+    #[error("The value of the field `{field:?}` is defined below its minimum (min {min:?}, found {found:?}). For more information see: {resource}")]
+    ValueTooLow {
+        field: &'a str,
+        min: u32,
+        found: u32,
+        resource: &'a str,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<'a> alloc::error::Error for XRPLOfferCreateException<'a> {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLPaymentException<'a> {
     /// An optional value must be defined in a certain context.
-    #[error("The optional field `{field:?}` is required to be defined for {context:?}. For more information see: {resource:?}")]
+    #[error("The optional field `{field:?}` is required to be defined for {context:?}. For more information see: {resource}")]
     OptionRequired {
         field: &'a str,
         context: &'a str,
         resource: &'a str,
     },
     /// An optional value is not allowed to be defined in a certain context.
-    #[error("The optional field `{field:?}` is not allowed to be defined for {context:?}.For more information see: {resource:?}")]
+    #[error("The optional field `{field:?}` is not allowed to be defined for {context:?}.For more information see: {resource}")]
     IllegalOption {
         field: &'a str,
         context: &'a str,
         resource: &'a str,
     },
     /// A fields value is not allowed to be the same as another fields value, in a certain context.
-    #[error("The value of the field `{field1:?}` is not allowed to be the same as the value of the field `{field2:?}`, for {context:?}. For more information see: {resource:?}")]
+    #[error("The value of the field `{field1:?}` is not allowed to be the same as the value of the field `{field2:?}`, for {context:?}. For more information see: {resource}")]
     ValueEqualsValueInContext {
         field1: &'a str,
         field2: &'a str,
         context: &'a str,
         resource: &'a str,
     },
+    /// A fields value is required to be the same as another fields value.
+    #[error("The value of the field `{field1:?}` is required to be the same as the value of the field `{field2:?}`. For more information see: {resource}")]
+    ValueNotEqualsValue {
+        field1: &'a str,
+        field2: &'a str,
+        resource: &'a str,
+    },
     /// An account set flag can only be set if a field is defined.
-    #[error("For the flag `{flag:?}` to be set it is required to define the field `{field:?}`. For more information see: {resource:?}")]
+    #[error("For the flag `{flag:?}` to be set it is required to define the field `{field:?}`. For more information see: {resource}")]
     FlagRequiresField {
         flag: PaymentFlag,
         field: &'a str,
         resource: &'a str,
     },
+    /// A fields value doesn't match its required format.
+    #[error("The value of the field `{field:?}` does not have the correct format (expected {format:?}, found {found:?}). For more information see: {resource}")]
+    InvalidValueFormat {
+        field: &'a str,
+        format: &'a str,
+        found: &'a str,
+        resource: &'a str,
+    },
 }
 
 #[cfg(feature = "std")]
 impl<'a> alloc::error::Error for XRPLPaymentException<'a> {}
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLPaymentChannelClaimException<'a> {
+    /// A fields value cannot be above another fields value.
+    #[error("The value of the field `{field1:?}` is not allowed to be above the value of the field `{field2:?}` (max {field2_val:?}, found {field1_val:?}). For more information see: {resource}")]
+    ValueAboveValue {
+        field1: &'a str,
+        field2: &'a str,
+        field1_val: u64,
+        field2_val: u64,
+        resource: &'a str,
+    },
+    /// A fields value doesn't match its required format.
+    #[error("The value of the field `{field:?}` does not have the correct format (expected {format:?}, found {found:?}). For more information see: {resource}")]
+    InvalidValueFormat {
+        field: &'a str,
+        format: &'a str,
+        found: &'a str,
+        resource: &'a str,
+    },
+    /// For a field to be defined it also needs another field to be defined.
+    #[error("For the field `{field1:?}` to be defined it is required to also define the field `{field2:?}`. For more information see: {resource}")]
+    FieldRequiresField {
+        field1: &'a str,
+        field2: &'a str,
+        resource: &'a str,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<'a> alloc::error::Error for XRPLPaymentChannelClaimException<'a> {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLPaymentChannelCreateException<'a> {
+    /// The value can not be zero.
+    #[error("The value of the field `{field:?}` is not allowed to be zero. For more information see: {resource}")]
+    ValueZero { field: &'a str, resource: &'a str },
+    /// A fields value doesn't match its required format.
+    #[error("The value of the field `{field:?}` does not have the correct format (expected {format:?}, found {found:?}). For more information see: {resource}")]
+    InvalidValueFormat {
+        field: &'a str,
+        format: &'a str,
+        found: &'a str,
+        resource: &'a str,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<'a> alloc::error::Error for XRPLPaymentChannelCreateException<'a> {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLPaymentChannelFundException<'a> {
+    /// The value can not be zero.
+    #[error("The value of the field `{field:?}` is not allowed to be zero. For more information see: {resource}")]
+    ValueZero { field: &'a str, resource: &'a str },
+    /// A fields value doesn't match its required format.
+    #[error("The value of the field `{field:?}` does not have the correct format (expected {format:?}, found {found:?}). For more information see: {resource}")]
+    InvalidValueFormat {
+        field: &'a str,
+        format: &'a str,
+        found: &'a str,
+        resource: &'a str,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<'a> alloc::error::Error for XRPLPaymentChannelFundException<'a> {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum XRPLSignerListSetException<'a> {
     /// A field was defined that another field definition would delete.
-    #[error("The value of the field `{field1:?}` can not be defined with the field `{field2:?}` because it would cause the deletion of `{field1:?}`. For more information see: {resource:?}")]
+    #[error("The value of the field `{field1:?}` can not be defined with the field `{field2:?}` because it would cause the deletion of `{field1:?}`. For more information see: {resource}")]
     ValueCausesValueDeletion {
         field1: &'a str,
         field2: &'a str,
         resource: &'a str,
     },
     /// A field is expected to have a certain value to be deleted.
-    #[error("The field `{field:?}` has the wrong value to be deleted (expected {expected:?}, found {found:?}). For more information see: {resource:?}")]
+    #[error("The field `{field:?}` has the wrong value to be deleted (expected {expected:?}, found {found:?}). For more information see: {resource}")]
     InvalidValueForValueDeletion {
         field: &'a str,
         expected: u32,
@@ -287,7 +744,7 @@ pub enum XRPLSignerListSetException<'a> {
         resource: &'a str,
     },
     /// A collection has too few items in it.
-    #[error("The value of the field `{field:?}` has too few items in it (min {min:?}, found {found:?}). For more information see: {resource:?}")]
+    #[error("The value of the field `{field:?}` has too few items in it (min {min:?}, found {found:?}). For more information see: {resource}")]
     CollectionTooFewItems {
         field: &'a str,
         min: usize,
@@ -295,7 +752,7 @@ pub enum XRPLSignerListSetException<'a> {
         resource: &'a str,
     },
     /// A collection has too many items in it.
-    #[error("The value of the field `{field:?}` has too many items in it (max {max:?}, found {found:?}). For more information see: {resource:?}")]
+    #[error("The value of the field `{field:?}` has too many items in it (max {max:?}, found {found:?}). For more information see: {resource}")]
     CollectionTooManyItems {
         field: &'a str,
         max: usize,
@@ -303,20 +760,20 @@ pub enum XRPLSignerListSetException<'a> {
         resource: &'a str,
     },
     /// A collection is not allowed to have duplicates in it.
-    #[error("The value of the field `{field:?}` has a duplicate in it (found {found:?}). For more information see: {resource:?}")]
+    #[error("The value of the field `{field:?}` has a duplicate in it (found {found:?}). For more information see: {resource}")]
     CollectionItemDuplicate {
         field: &'a str,
         found: &'a str,
         resource: &'a str,
     },
     /// A collection contains an invalid value.
-    #[error("The field `{field:?}` contains an invalid value (found {found:?}). For more information see: {resource:?}")]
+    #[error("The field `{field:?}` contains an invalid value (found {found:?}). For more information see: {resource}")]
     CollectionInvalidItem {
         field: &'a str,
         found: &'a str,
         resource: &'a str,
     },
-    #[error("The field `signer_quorum` must be below or equal to the sum of `signer_weight` in `signer_entries`. For more information see: {resource:?}")]
+    #[error("The field `signer_quorum` must be below or equal to the sum of `signer_weight` in `signer_entries`. For more information see: {resource}")]
     SignerQuorumExceedsSignerWeight {
         max: u32,
         found: u32,
@@ -326,3 +783,18 @@ pub enum XRPLSignerListSetException<'a> {
 
 #[cfg(feature = "std")]
 impl<'a> alloc::error::Error for XRPLSignerListSetException<'a> {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLTrustSetException<'a> {
+    /// A field's value is not allowed to be negative.
+    #[error("The value of the field `{field:?}` is not allowed to be negative (found {found:?}). For more information see: {resource}")]
+    ValueNegative {
+        field: &'a str,
+        found: &'a str,
+        resource: &'a str,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<'a> alloc::error::Error for XRPLTrustSetException<'a> {}
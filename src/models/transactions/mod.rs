@@ -8,11 +8,16 @@ pub mod escrow_cancel;
 pub mod escrow_create;
 pub mod escrow_finish;
 pub mod exceptions;
+pub mod mptoken_authorize;
+pub mod mptoken_issuance_create;
+pub mod mptoken_issuance_destroy;
+pub mod mptoken_issuance_set;
 pub mod nftoken_accept_offer;
 pub mod nftoken_burn;
 pub mod nftoken_cancel_offer;
 pub mod nftoken_create_offer;
 pub mod nftoken_mint;
+pub mod nftoken_modify;
 pub mod offer_cancel;
 pub mod offer_create;
 pub mod payment;
@@ -35,11 +40,16 @@ pub use escrow_cancel::*;
 pub use escrow_create::*;
 pub use escrow_finish::*;
 pub use exceptions::*;
+pub use mptoken_authorize::*;
+pub use mptoken_issuance_create::*;
+pub use mptoken_issuance_destroy::*;
+pub use mptoken_issuance_set::*;
 pub use nftoken_accept_offer::*;
 pub use nftoken_burn::*;
 pub use nftoken_cancel_offer::*;
 pub use nftoken_create_offer::*;
 pub use nftoken_mint::*;
+pub use nftoken_modify::*;
 pub use offer_cancel::*;
 pub use offer_create::*;
 pub use payment::*;
@@ -52,12 +62,32 @@ pub use signer_list_set::*;
 pub use ticket_create::*;
 pub use trust_set::*;
 
+use crate::_serde::{txn_flags, TxnFlags};
+use crate::constants::{MAX_MEMOS_SIZE, TF_FULLY_CANONICAL_SIG};
+use crate::core::addresscodec::{decode_classic_address, derive_address_from_x_address};
+use crate::core::binarycodec::{encode_for_multisigning, encode_for_signing};
+use crate::core::types::exceptions::XRPLTypeException;
+use crate::models::amount::exceptions::XRPLAmountException;
+use crate::models::amount::{Amount, IssuedCurrencyAmountError, XRPAmount};
 use crate::serde_with_tag;
+use crate::utils::hex::from_hex;
+use crate::utils::REFERENCE_FEE_DROPS;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryInto;
 use derive_new::new;
 use serde::ser::SerializeMap;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{Map, Value};
+use serde_with::skip_serializing_none;
+use strum::IntoEnumIterator;
 use strum_macros::{AsRefStr, Display};
 
+/// The documentation of the fields shared by all transactions,
+/// linked by their errors.
+const COMMON_FIELDS_RESOURCE: &str = "https://xrpl.org/transaction-common-fields.html";
+
 /// Enum containing the different Transaction types.
 #[derive(Debug, Clone, Serialize, Deserialize, Display, PartialEq, Eq)]
 pub enum TransactionType {
@@ -70,11 +100,16 @@ pub enum TransactionType {
     EscrowCancel,
     EscrowCreate,
     EscrowFinish,
+    MPTokenAuthorize,
+    MPTokenIssuanceCreate,
+    MPTokenIssuanceDestroy,
+    MPTokenIssuanceSet,
     NFTokenAcceptOffer,
     NFTokenBurn,
     NFTokenCancelOffer,
     NFTokenCreateOffer,
     NFTokenMint,
+    NFTokenModify,
     OfferCancel,
     OfferCreate,
     Payment,
@@ -122,6 +157,18 @@ impl TransactionType {
     fn escrow_finish() -> Self {
         TransactionType::EscrowFinish
     }
+    fn mptoken_authorize() -> Self {
+        TransactionType::MPTokenAuthorize
+    }
+    fn mptoken_issuance_create() -> Self {
+        TransactionType::MPTokenIssuanceCreate
+    }
+    fn mptoken_issuance_destroy() -> Self {
+        TransactionType::MPTokenIssuanceDestroy
+    }
+    fn mptoken_issuance_set() -> Self {
+        TransactionType::MPTokenIssuanceSet
+    }
     fn nftoken_accept_offer() -> Self {
         TransactionType::NFTokenAcceptOffer
     }
@@ -137,6 +184,9 @@ impl TransactionType {
     fn nftoken_mint() -> Self {
         TransactionType::NFTokenMint
     }
+    fn nftoken_modify() -> Self {
+        TransactionType::NFTokenModify
+    }
     fn offer_cancel() -> Self {
         TransactionType::OfferCancel
     }
@@ -191,27 +241,447 @@ serde_with_tag! {
 // `#[derive(Serialize)]` is defined in the macro
 #[derive(Debug, PartialEq, Eq, Default, Clone, new)]
 pub struct Memo<'a> {
-    pub memo_data: Option<&'a str>,
-    pub memo_format: Option<&'a str>,
-    pub memo_type: Option<&'a str>,
+    pub memo_data: Option<Cow<'a, str>>,
+    pub memo_format: Option<Cow<'a, str>>,
+    pub memo_type: Option<Cow<'a, str>>,
+}
+}
+
+impl<'a> Memo<'a> {
+    /// Returns the memo without borrowed data.
+    pub fn into_owned(self) -> Memo<'static> {
+        Memo {
+            memo_data: self.memo_data.map(|data| Cow::Owned(data.into_owned())),
+            memo_format: self
+                .memo_format
+                .map(|format| Cow::Owned(format.into_owned())),
+            memo_type: self.memo_type.map(|r#type| Cow::Owned(r#type.into_owned())),
+        }
+    }
+}
+
+/// Checks the `memos` field shared by all transactions. Every field of a
+/// `Memo` must be a hex string and the combined size of all memos is not
+/// allowed to exceed `MAX_MEMOS_SIZE` bytes.
+///
+/// See Memos Field:
+/// `<https://xrpl.org/transaction-common-fields.html#memos-field>`
+pub(crate) fn get_memos_error<'a>(
+    memos: &'a Option<Vec<Memo<'_>>>,
+) -> Result<(), XRPLTransactionFieldException<'a>> {
+    if let Some(memos) = memos {
+        let mut memos_size: usize = 0;
+        for memo in memos {
+            for (field, value) in [
+                ("memo_data", memo.memo_data.as_deref()),
+                ("memo_format", memo.memo_format.as_deref()),
+                ("memo_type", memo.memo_type.as_deref()),
+            ]
+            .iter()
+            {
+                if let Some(value) = value {
+                    if from_hex(value).is_err() {
+                        return Err(XRPLTransactionFieldException::InvalidValueFormat {
+                            field,
+                            format: "hex",
+                            found: value,
+                            resource: COMMON_FIELDS_RESOURCE,
+                        });
+                    }
+                    memos_size += value.len() / 2;
+                }
+            }
+        }
+        if memos_size > MAX_MEMOS_SIZE {
+            return Err(XRPLTransactionFieldException::ValueTooLarge {
+                field: "memos",
+                max: MAX_MEMOS_SIZE,
+                found: memos_size,
+                resource: COMMON_FIELDS_RESOURCE,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that a transaction using a ticket leaves its `sequence`
+/// at 0 or unset, as the ticket takes the place of the sequence
+/// number. A `sequence` of 0 means that a ticket is used, so it
+/// requires a `ticket_sequence`.
+///
+/// See Tickets:
+/// `<https://xrpl.org/tickets.html>`
+pub(crate) fn get_ticket_sequence_error<'a>(
+    sequence: Option<u32>,
+    ticket_sequence: Option<u32>,
+) -> Result<(), XRPLTransactionFieldException<'a>> {
+    match (sequence, ticket_sequence) {
+        (Some(sequence), Some(_)) if sequence != 0 => {
+            Err(XRPLTransactionFieldException::SequenceWithTicket {
+                found: sequence,
+                resource: COMMON_FIELDS_RESOURCE,
+            })
+        }
+        (Some(0), None) => Err(XRPLTransactionFieldException::ZeroSequenceWithoutTicket {
+            resource: COMMON_FIELDS_RESOURCE,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the fields shared by all transactions.
+pub(crate) fn get_common_fields_error<'a, F>(
+    common_fields: &'a CommonFields<'_, F>,
+) -> Result<(), XRPLTransactionFieldException<'a>> {
+    get_memos_error(&common_fields.memos)?;
+    get_ticket_sequence_error(common_fields.sequence, common_fields.ticket_sequence)
+}
+
+/// Checks that the `destination` of a transaction is not its sending
+/// `account`. Used by the transaction types for which the ledger
+/// rejects sending to oneself, like `CheckCreate` and
+/// `PaymentChannelCreate`, whose documentation is the `resource`.
+pub(crate) fn get_destination_error<'a>(
+    account: &str,
+    destination: &str,
+    resource: &'a str,
+) -> Result<(), XRPLTransactionFieldException<'a>> {
+    if account == destination {
+        Err(XRPLTransactionFieldException::DestinationIsAccount { resource })
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns the classic address of an `address` field, which can be a
+/// classic address or an X-Address, with the tag to use for its
+/// `tag_field`. The tag embedded in an X-Address takes the place of an
+/// undefined `tag`, but is not allowed to conflict with a defined one.
+pub(crate) fn normalize_x_address<'a>(
+    address: &str,
+    tag: Option<u32>,
+    field: &'a str,
+    tag_field: &'a str,
+    resource: &'a str,
+) -> Result<(String, Option<u32>), XRPLTransactionFieldException<'a>> {
+    let invalid_address = || XRPLTransactionFieldException::InvalidAddress { field, resource };
+    let (classic_address, embedded_tag) =
+        derive_address_from_x_address(address).map_err(|_| invalid_address())?;
+
+    match (embedded_tag, tag) {
+        (Some(embedded_tag), Some(tag)) if embedded_tag != tag as u64 => {
+            Err(XRPLTransactionFieldException::XAddressTagConflict {
+                field,
+                tag_field,
+                expected: embedded_tag,
+                found: tag,
+                resource,
+            })
+        }
+        (Some(embedded_tag), _) => Ok((
+            classic_address,
+            Some(embedded_tag.try_into().map_err(|_| invalid_address())?),
+        )),
+        (None, tag) => Ok((classic_address, tag)),
+    }
+}
+
+/// Checks the amounts of a transaction, like that no issued
+/// currency amount uses the currency code `XRP`. Unset optional
+/// amounts are skipped.
+pub(crate) fn get_amounts_error(amounts: &[Option<&Amount>]) -> Result<(), XRPLAmountException> {
+    for amount in amounts.iter().flatten() {
+        if let Amount::IssuedCurrencyAmount(issued_currency_amount) = amount {
+            issued_currency_amount._get_currency_error()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Conversion between the flag enum of a transaction type and
+/// the raw bit-flags of the `Flags` field, for use outside of
+/// serde. Not implemented by `AccountSetFlag`, whose values are
+/// used in `SetFlag` and `ClearFlag` rather than bit-flags.
+pub trait FlagBits: IntoEnumIterator + Sized {
+    /// Returns the bit-flag of the flag.
+    fn bits(&self) -> u32;
+
+    /// Combines flags into raw bit-flags.
+    fn to_bits(flags: &[Self]) -> u32 {
+        flags.iter().fold(0, |bits, flag| bits | flag.bits())
+    }
+
+    /// Decodes raw bit-flags into the set flags. Errors if a bit
+    /// is set that is not defined for the transaction type, apart
+    /// from the universal `TF_FULLY_CANONICAL_SIG`.
+    fn try_from_bits(bits: u32) -> Result<Vec<Self>, XRPLTransactionFieldException<'static>> {
+        let flags: Vec<Self> = Self::iter()
+            .filter(|flag| bits & flag.bits() == flag.bits())
+            .collect();
+        let unknown_bits = bits & !Self::to_bits(&flags) & !TF_FULLY_CANONICAL_SIG;
+
+        if unknown_bits != 0 {
+            Err(XRPLTransactionFieldException::UnknownFlags {
+                found: unknown_bits,
+                resource: COMMON_FIELDS_RESOURCE,
+            })
+        } else {
+            Ok(flags)
+        }
+    }
 }
+
+/// Adds `flag` to a transaction's set of flags, unless it is
+/// already set.
+pub(crate) fn set_txn_flag<F: PartialEq>(flags: &mut Option<Vec<F>>, flag: F) {
+    let flags = flags.get_or_insert_with(Vec::new);
+    if !flags.contains(&flag) {
+        flags.push(flag);
+    }
+}
+
+/// Removes `flag` from a transaction's set of flags. The flags
+/// are unset if no flag remains.
+pub(crate) fn clear_txn_flag<F: PartialEq>(flags: &mut Option<Vec<F>>, flag: &F) {
+    if let Some(flag_set) = flags {
+        flag_set.retain(|txn_flag| txn_flag != flag);
+        if flag_set.is_empty() {
+            *flags = None;
+        }
+    }
 }
 
+serde_with_tag! {
 /// One Signer in a multi-signature. A multi-signed transaction
 /// can have an array of up to 8 Signers, each contributing a
 /// signature, in the Signers field.
 ///
 /// See Signers Field:
 /// `<https://xrpl.org/transaction-common-fields.html#signers-field>`
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, new)]
-#[serde(rename_all = "PascalCase")]
+// `#[derive(Serialize)]` is defined in the macro
+#[derive(Debug, PartialEq, Eq, Default, Clone, new)]
 pub struct Signer<'a> {
-    account: &'a str,
-    txn_signature: &'a str,
-    signing_pub_key: &'a str,
+    pub account: Cow<'a, str>,
+    pub txn_signature: Cow<'a, str>,
+    pub signing_pub_key: Cow<'a, str>,
+}
+}
+
+impl<'a> Signer<'a> {
+    /// Returns the signer without borrowed data.
+    pub fn into_owned(self) -> Signer<'static> {
+        Signer {
+            account: Cow::Owned(self.account.into_owned()),
+            txn_signature: Cow::Owned(self.txn_signature.into_owned()),
+            signing_pub_key: Cow::Owned(self.signing_pub_key.into_owned()),
+        }
+    }
+}
+
+/// Serializes the `Signers` sorted by the numeric account ID of
+/// their `account`, the order rippled requires for multi-signed
+/// transactions. Signers with an invalid account are put first.
+fn serialize_signers<S>(signers: &Option<Vec<Signer<'_>>>, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match signers {
+        Some(signers) => {
+            let mut sorted_signers: Vec<&Signer> = signers.iter().collect();
+            sorted_signers
+                .sort_by_cached_key(|signer| decode_classic_address(&signer.account).ok());
+            s.collect_seq(sorted_signers)
+        }
+        None => s.serialize_none(),
+    }
+}
+
+/// The fields shared by all transaction models. Flattened into
+/// each transaction, so they serialize at the top level.
+///
+/// `F` is the type of the `flags` field, either the raw `u32`
+/// bit-flags or a `Vec` of the flags defined for the transaction.
+///
+/// See Transaction Common Fields:
+/// `<https://xrpl.org/transaction-common-fields.html>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+#[serde(bound(serialize = "F: TxnFlags", deserialize = "F: TxnFlags"))]
+pub struct CommonFields<'a, F> {
+    /// The unique address of the account that initiated the transaction.
+    #[serde(borrow)]
+    pub account: Cow<'a, str>,
+    /// Integer amount of XRP, in drops, to be destroyed as a cost
+    /// for distributing this transaction to the network. Some
+    /// transaction types have different minimum requirements.
+    /// See Transaction Cost for details.
+    pub fee: Option<XRPAmount<'a>>,
+    /// The sequence number of the account sending the transaction.
+    /// A transaction is only valid if the Sequence number is exactly
+    /// 1 greater than the previous transaction from the same account.
+    /// The special case 0 means the transaction is using a Ticket instead
+    /// and requires `ticket_sequence` to be set.
+    pub sequence: Option<u32>,
+    /// Highest ledger index this transaction can appear in.
+    /// Specifying this field places a strict upper limit on how long
+    /// the transaction can wait to be validated or rejected.
+    /// See Reliable Transaction Submission for more details.
+    pub last_ledger_sequence: Option<u32>,
+    /// Hash value identifying another transaction. If provided, this
+    /// transaction is only valid if the sending account's
+    /// previously-sent transaction matches the provided hash.
+    #[serde(rename = "AccountTxnID")]
+    pub account_txn_id: Option<Cow<'a, str>>,
+    /// Hex representation of the public key that corresponds to the
+    /// private key used to sign this transaction. If an empty string,
+    /// indicates a multi-signature is present in the Signers field instead.
+    pub signing_pub_key: Option<Cow<'a, str>>,
+    /// Arbitrary integer used to identify the reason for this
+    /// payment, or a sender on whose behalf this transaction
+    /// is made. Conventionally, a refund should specify the initial
+    /// payment's SourceTag as the refund payment's DestinationTag.
+    /// Like destination tags, 0 is a valid tag distinct from `None`.
+    pub source_tag: Option<u32>,
+    /// The sequence number of the ticket to use in place
+    /// of a Sequence number. If this is provided, Sequence must
+    /// be 0. Cannot be used with AccountTxnID.
+    pub ticket_sequence: Option<u32>,
+    /// The signature that verifies this transaction as originating
+    /// from the account it says it is from.
+    pub txn_signature: Option<Cow<'a, str>>,
+    /// Set of bit-flags for this transaction.
+    #[serde(default)]
+    #[serde(with = "txn_flags")]
+    pub flags: Option<F>,
+    /// Additional arbitrary information used to identify this transaction.
+    pub memos: Option<Vec<Memo<'a>>>,
+    /// Array of objects that represent a multi-signature which
+    /// authorizes this transaction.
+    #[serde(serialize_with = "serialize_signers")]
+    pub signers: Option<Vec<Signer<'a>>>,
+}
+
+impl<'a, F> Default for CommonFields<'a, F> {
+    fn default() -> Self {
+        Self {
+            account: Default::default(),
+            fee: Default::default(),
+            sequence: Default::default(),
+            last_ledger_sequence: Default::default(),
+            account_txn_id: Default::default(),
+            signing_pub_key: Default::default(),
+            source_tag: Default::default(),
+            ticket_sequence: Default::default(),
+            txn_signature: Default::default(),
+            flags: Default::default(),
+            memos: Default::default(),
+            signers: Default::default(),
+        }
+    }
+}
+
+impl<'a, F> CommonFields<'a, F> {
+    /// Uses the ticket with the given sequence number in place of
+    /// the account's sequence number, setting `sequence` to 0.
+    pub fn use_ticket(&mut self, ticket_sequence: u32) {
+        self.ticket_sequence = Some(ticket_sequence);
+        self.sequence = Some(0);
+    }
+
+    /// Replaces an X-Address `account` with its classic address
+    /// and moves the embedded tag into `source_tag`. Errors if
+    /// `source_tag` is already defined with a different tag.
+    /// Classic addresses are left unchanged.
+    pub fn normalize_x_address(mut self) -> Result<Self, XRPLTransactionFieldException<'static>> {
+        let (account, source_tag) = normalize_x_address(
+            &self.account,
+            self.source_tag,
+            "account",
+            "source_tag",
+            COMMON_FIELDS_RESOURCE,
+        )?;
+        self.account = account.into();
+        self.source_tag = source_tag;
+
+        Ok(self)
+    }
+
+    /// Returns the common fields without borrowed data.
+    pub fn into_owned(self) -> CommonFields<'static, F> {
+        CommonFields {
+            account: Cow::Owned(self.account.into_owned()),
+            fee: self.fee.map(XRPAmount::into_owned),
+            sequence: self.sequence,
+            last_ledger_sequence: self.last_ledger_sequence,
+            account_txn_id: self
+                .account_txn_id
+                .map(|account_txn_id| Cow::Owned(account_txn_id.into_owned())),
+            signing_pub_key: self
+                .signing_pub_key
+                .map(|signing_pub_key| Cow::Owned(signing_pub_key.into_owned())),
+            source_tag: self.source_tag,
+            ticket_sequence: self.ticket_sequence,
+            txn_signature: self
+                .txn_signature
+                .map(|txn_signature| Cow::Owned(txn_signature.into_owned())),
+            flags: self.flags,
+            memos: self
+                .memos
+                .map(|memos| memos.into_iter().map(Memo::into_owned).collect()),
+            signers: self
+                .signers
+                .map(|signers| signers.into_iter().map(Signer::into_owned).collect()),
+        }
+    }
+}
+
+/// The fields of `CommonFields` that do not depend on the type of
+/// its `flags`, so that `Transaction` can read and write them for
+/// every transaction model.
+pub trait TxnCommonFields {
+    fn fee(&self) -> Option<&XRPAmount<'_>>;
+
+    fn sequence(&self) -> Option<u32>;
+
+    fn set_sequence(&mut self, sequence: u32);
+
+    fn last_ledger_sequence(&self) -> Option<u32>;
+
+    fn signers(&self) -> Option<&[Signer<'_>]>;
+}
+
+impl<'a, F> TxnCommonFields for CommonFields<'a, F> {
+    fn fee(&self) -> Option<&XRPAmount<'_>> {
+        self.fee.as_ref()
+    }
+
+    fn sequence(&self) -> Option<u32> {
+        self.sequence
+    }
+
+    fn set_sequence(&mut self, sequence: u32) {
+        self.sequence = Some(sequence);
+    }
+
+    fn last_ledger_sequence(&self) -> Option<u32> {
+        self.last_ledger_sequence
+    }
+
+    fn signers(&self) -> Option<&[Signer<'_>]> {
+        self.signers.as_deref()
+    }
 }
 
 /// Standard functions for transactions.
+///
+/// The trait is object safe, so transactions of different types
+/// can be stored as `Box<dyn Transaction>`. The methods working
+/// on the serialized fields require `Self: Sized` and are not
+/// available on trait objects; use `AnyTransaction` to call them
+/// on transactions of different types.
 pub trait Transaction {
     // TODO: use generic type
     fn has_flag(&self, flag: &Flag) -> bool {
@@ -219,12 +689,138 @@ pub trait Transaction {
         false
     }
 
+    /// Sets `flag` on the transaction. Flags that do not belong
+    /// to the transaction type are ignored.
+    fn set_flag(&mut self, flag: &Flag) {
+        let _txn_flag = flag;
+    }
+
+    /// Clears `flag` from the transaction. Flags that do not
+    /// belong to the transaction type are ignored.
+    fn clear_flag(&mut self, flag: &Flag) {
+        let _txn_flag = flag;
+    }
+
     fn get_transaction_type(&self) -> TransactionType;
+
+    /// Returns the common fields of the transaction. `None` for
+    /// pseudo-transactions, which do not have them.
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields>;
+
+    /// Returns the common fields of the transaction to modify
+    /// them. `None` for pseudo-transactions.
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields>;
+
+    /// Returns whether the transaction is a pseudo-transaction.
+    /// Pseudo-transactions are applied by the network and can
+    /// not be signed or submitted.
+    ///
+    /// See Pseudo-Transactions:
+    /// `<https://xrpl.org/pseudo-transaction-types.html>`
+    fn is_pseudo_transaction(&self) -> bool {
+        false
+    }
+
+    /// Returns the transaction in the JSON format of the XRP
+    /// Ledger, as used by rippled and other XRPL libraries:
+    /// `Flags` are the raw bit-flags and amounts are in their
+    /// canonical representation. The inverse of
+    /// `transaction_from_json`.
+    fn to_xrpl_json(&self) -> Result<Value, serde_json::Error>
+    where
+        Self: Serialize + Sized,
+    {
+        serde_json::to_value(self)
+    }
+
+    /// Returns the bytes that are signed for the transaction.
+    /// Without a `signer_account` these are the single-signing
+    /// bytes of `encode_for_signing`, with one the bytes signed by
+    /// that signer of a multi-signed transaction, see
+    /// `encode_for_multisigning`.
+    fn get_field_bytes(&self, signer_account: Option<&str>) -> Result<Vec<u8>, XRPLTypeException>
+    where
+        Self: Serialize + Sized,
+    {
+        let signing_data = match signer_account {
+            Some(signer_account) => encode_for_multisigning(self, signer_account)?,
+            None => encode_for_signing(self)?,
+        };
+
+        Ok(from_hex(&signing_data)?)
+    }
+
+    /// Returns whether both transactions are equal apart from
+    /// their `TxnSignature`, `SigningPubKey` and `hash`. Use it to
+    /// verify that a transaction fetched from the ledger is the
+    /// one that was built and submitted.
+    fn matches_unsigned(&self, other: &Self) -> bool
+    where
+        Self: Serialize + Sized,
+    {
+        match (unsigned_fields(self), unsigned_fields(other)) {
+            (Some(fields), Some(other_fields)) => fields == other_fields,
+            _ => false,
+        }
+    }
+
+    /// Returns a transaction cost for signing without a connection
+    /// to a server, based on the reference cost of
+    /// `REFERENCE_FEE_DROPS`. Each of the `signers` of a
+    /// multi-signed transaction adds the reference cost and the
+    /// fulfillment of an `EscrowFinish` adds the surcharge of
+    /// `EscrowFinish::suggested_fee`. Load on the network is not
+    /// accounted for.
+    ///
+    /// See Transaction Cost:
+    /// `<https://xrpl.org/transaction-cost.html>`
+    fn default_fee(&self) -> XRPAmount<'static> {
+        let signer_count = self
+            .common_fields()
+            .and_then(|common_fields| common_fields.signers())
+            .map_or(0, <[Signer]>::len) as u64;
+
+        XRPAmount::from((REFERENCE_FEE_DROPS * (1 + signer_count)).to_string())
+    }
+
+    /// Returns whether the transaction can no longer be included
+    /// in a ledger because its `last_ledger_sequence` is below
+    /// `current_ledger_index`. Transactions without a
+    /// `last_ledger_sequence` never expire.
+    ///
+    /// See Reliable Transaction Submission:
+    /// `<https://xrpl.org/reliable-transaction-submission.html>`
+    fn is_expired(&self, current_ledger_index: u32) -> bool {
+        self.common_fields()
+            .and_then(|common_fields| common_fields.last_ledger_sequence())
+            .is_some_and(|last_ledger_sequence| last_ledger_sequence < current_ledger_index)
+    }
+}
+
+/// The fields differing between a transaction built locally
+/// and the signed transaction stored in the ledger.
+const SIGNATURE_FIELDS: [&str; 3] = ["TxnSignature", "SigningPubKey", "hash"];
+
+/// Returns the JSON fields of a transaction without its
+/// `SIGNATURE_FIELDS`.
+fn unsigned_fields<T: Serialize>(transaction: &T) -> Option<Map<String, Value>> {
+    match serde_json::to_value(transaction) {
+        Ok(Value::Object(mut fields)) => {
+            for field in SIGNATURE_FIELDS {
+                fields.remove(field);
+            }
+            Some(fields)
+        }
+        _ => None,
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Display, AsRefStr)]
 pub enum Flag {
     AccountSet(AccountSetFlag),
+    MPTokenAuthorize(MPTokenAuthorizeFlag),
+    MPTokenIssuanceCreate(MPTokenIssuanceCreateFlag),
+    MPTokenIssuanceSet(MPTokenIssuanceSetFlag),
     NFTokenCreateOffer(NFTokenCreateOfferFlag),
     NFTokenMint(NFTokenMintFlag),
     OfferCreate(OfferCreateFlag),
@@ -233,3 +829,1629 @@ pub enum Flag {
     TrustSet(TrustSetFlag),
     EnableAmendment(EnableAmendmentFlag),
 }
+
+/// Any transaction, including pseudo-transactions, deserialized
+/// into the model matching its `TransactionType`. Useful to read
+/// the transactions of a ledger or an account's history.
+///
+/// See Transaction Types:
+/// `<https://xrpl.org/transaction-types.html>`
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(tag = "TransactionType")]
+#[allow(clippy::large_enum_variant)]
+pub enum AnyTransaction<'a> {
+    AccountDelete(#[serde(borrow)] AccountDelete<'a>),
+    AccountSet(#[serde(borrow)] AccountSet<'a>),
+    CheckCancel(#[serde(borrow)] CheckCancel<'a>),
+    CheckCash(#[serde(borrow)] CheckCash<'a>),
+    CheckCreate(#[serde(borrow)] CheckCreate<'a>),
+    DepositPreauth(#[serde(borrow)] DepositPreauth<'a>),
+    EscrowCancel(#[serde(borrow)] EscrowCancel<'a>),
+    EscrowCreate(#[serde(borrow)] EscrowCreate<'a>),
+    EscrowFinish(#[serde(borrow)] EscrowFinish<'a>),
+    MPTokenAuthorize(#[serde(borrow)] MPTokenAuthorize<'a>),
+    MPTokenIssuanceCreate(#[serde(borrow)] MPTokenIssuanceCreate<'a>),
+    MPTokenIssuanceDestroy(#[serde(borrow)] MPTokenIssuanceDestroy<'a>),
+    MPTokenIssuanceSet(#[serde(borrow)] MPTokenIssuanceSet<'a>),
+    NFTokenAcceptOffer(#[serde(borrow)] NFTokenAcceptOffer<'a>),
+    NFTokenBurn(#[serde(borrow)] NFTokenBurn<'a>),
+    NFTokenCancelOffer(#[serde(borrow)] NFTokenCancelOffer<'a>),
+    NFTokenCreateOffer(#[serde(borrow)] NFTokenCreateOffer<'a>),
+    NFTokenMint(#[serde(borrow)] NFTokenMint<'a>),
+    NFTokenModify(#[serde(borrow)] NFTokenModify<'a>),
+    OfferCancel(#[serde(borrow)] OfferCancel<'a>),
+    OfferCreate(#[serde(borrow)] OfferCreate<'a>),
+    Payment(#[serde(borrow)] Payment<'a>),
+    PaymentChannelClaim(#[serde(borrow)] PaymentChannelClaim<'a>),
+    PaymentChannelCreate(#[serde(borrow)] PaymentChannelCreate<'a>),
+    PaymentChannelFund(#[serde(borrow)] PaymentChannelFund<'a>),
+    SetRegularKey(#[serde(borrow)] SetRegularKey<'a>),
+    SignerListSet(#[serde(borrow)] SignerListSet<'a>),
+    TicketCreate(#[serde(borrow)] TicketCreate<'a>),
+    TrustSet(#[serde(borrow)] TrustSet<'a>),
+
+    // Psuedo-Transaction types
+    EnableAmendment(#[serde(borrow)] EnableAmendment<'a>),
+    SetFee(#[serde(borrow)] SetFee<'a>),
+    UNLModify(#[serde(borrow)] UNLModify<'a>),
+}
+
+/// Deserializes a transaction in the JSON format of the XRP
+/// Ledger into the model matching its `TransactionType`, e.g.
+/// to use a transaction built by another XRPL library. Strings
+/// are borrowed from `value`.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::transactions::{transaction_from_json, Transaction, TransactionType};
+/// use serde_json::json;
+///
+/// let value = json!({
+///     "TransactionType": "AccountSet",
+///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     "Fee": "12",
+///     "Sequence": 5,
+/// });
+/// let transaction = transaction_from_json(&value).unwrap();
+///
+/// assert_eq!(transaction.get_transaction_type(), TransactionType::AccountSet);
+/// ```
+pub fn transaction_from_json(value: &Value) -> Result<AnyTransaction<'_>, serde_json::Error> {
+    AnyTransaction::deserialize(value)
+}
+
+impl<'a> Serialize for AnyTransaction<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            AnyTransaction::AccountDelete(transaction) => transaction.serialize(serializer),
+            AnyTransaction::AccountSet(transaction) => transaction.serialize(serializer),
+            AnyTransaction::CheckCancel(transaction) => transaction.serialize(serializer),
+            AnyTransaction::CheckCash(transaction) => transaction.serialize(serializer),
+            AnyTransaction::CheckCreate(transaction) => transaction.serialize(serializer),
+            AnyTransaction::DepositPreauth(transaction) => transaction.serialize(serializer),
+            AnyTransaction::EscrowCancel(transaction) => transaction.serialize(serializer),
+            AnyTransaction::EscrowCreate(transaction) => transaction.serialize(serializer),
+            AnyTransaction::EscrowFinish(transaction) => transaction.serialize(serializer),
+            AnyTransaction::MPTokenAuthorize(transaction) => transaction.serialize(serializer),
+            AnyTransaction::MPTokenIssuanceCreate(transaction) => transaction.serialize(serializer),
+            AnyTransaction::MPTokenIssuanceDestroy(transaction) => {
+                transaction.serialize(serializer)
+            }
+            AnyTransaction::MPTokenIssuanceSet(transaction) => transaction.serialize(serializer),
+            AnyTransaction::NFTokenAcceptOffer(transaction) => transaction.serialize(serializer),
+            AnyTransaction::NFTokenBurn(transaction) => transaction.serialize(serializer),
+            AnyTransaction::NFTokenCancelOffer(transaction) => transaction.serialize(serializer),
+            AnyTransaction::NFTokenCreateOffer(transaction) => transaction.serialize(serializer),
+            AnyTransaction::NFTokenMint(transaction) => transaction.serialize(serializer),
+            AnyTransaction::NFTokenModify(transaction) => transaction.serialize(serializer),
+            AnyTransaction::OfferCancel(transaction) => transaction.serialize(serializer),
+            AnyTransaction::OfferCreate(transaction) => transaction.serialize(serializer),
+            AnyTransaction::Payment(transaction) => transaction.serialize(serializer),
+            AnyTransaction::PaymentChannelClaim(transaction) => transaction.serialize(serializer),
+            AnyTransaction::PaymentChannelCreate(transaction) => transaction.serialize(serializer),
+            AnyTransaction::PaymentChannelFund(transaction) => transaction.serialize(serializer),
+            AnyTransaction::SetRegularKey(transaction) => transaction.serialize(serializer),
+            AnyTransaction::SignerListSet(transaction) => transaction.serialize(serializer),
+            AnyTransaction::TicketCreate(transaction) => transaction.serialize(serializer),
+            AnyTransaction::TrustSet(transaction) => transaction.serialize(serializer),
+            AnyTransaction::EnableAmendment(transaction) => transaction.serialize(serializer),
+            AnyTransaction::SetFee(transaction) => transaction.serialize(serializer),
+            AnyTransaction::UNLModify(transaction) => transaction.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> AnyTransaction<'a> {
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized from.
+    pub fn into_owned(self) -> AnyTransaction<'static> {
+        match self {
+            AnyTransaction::AccountDelete(transaction) => {
+                AnyTransaction::AccountDelete(transaction.into_owned())
+            }
+            AnyTransaction::AccountSet(transaction) => {
+                AnyTransaction::AccountSet(transaction.into_owned())
+            }
+            AnyTransaction::CheckCancel(transaction) => {
+                AnyTransaction::CheckCancel(transaction.into_owned())
+            }
+            AnyTransaction::CheckCash(transaction) => {
+                AnyTransaction::CheckCash(transaction.into_owned())
+            }
+            AnyTransaction::CheckCreate(transaction) => {
+                AnyTransaction::CheckCreate(transaction.into_owned())
+            }
+            AnyTransaction::DepositPreauth(transaction) => {
+                AnyTransaction::DepositPreauth(transaction.into_owned())
+            }
+            AnyTransaction::EscrowCancel(transaction) => {
+                AnyTransaction::EscrowCancel(transaction.into_owned())
+            }
+            AnyTransaction::EscrowCreate(transaction) => {
+                AnyTransaction::EscrowCreate(transaction.into_owned())
+            }
+            AnyTransaction::EscrowFinish(transaction) => {
+                AnyTransaction::EscrowFinish(transaction.into_owned())
+            }
+            AnyTransaction::MPTokenAuthorize(transaction) => {
+                AnyTransaction::MPTokenAuthorize(transaction.into_owned())
+            }
+            AnyTransaction::MPTokenIssuanceCreate(transaction) => {
+                AnyTransaction::MPTokenIssuanceCreate(transaction.into_owned())
+            }
+            AnyTransaction::MPTokenIssuanceDestroy(transaction) => {
+                AnyTransaction::MPTokenIssuanceDestroy(transaction.into_owned())
+            }
+            AnyTransaction::MPTokenIssuanceSet(transaction) => {
+                AnyTransaction::MPTokenIssuanceSet(transaction.into_owned())
+            }
+            AnyTransaction::NFTokenAcceptOffer(transaction) => {
+                AnyTransaction::NFTokenAcceptOffer(transaction.into_owned())
+            }
+            AnyTransaction::NFTokenBurn(transaction) => {
+                AnyTransaction::NFTokenBurn(transaction.into_owned())
+            }
+            AnyTransaction::NFTokenCancelOffer(transaction) => {
+                AnyTransaction::NFTokenCancelOffer(transaction.into_owned())
+            }
+            AnyTransaction::NFTokenCreateOffer(transaction) => {
+                AnyTransaction::NFTokenCreateOffer(transaction.into_owned())
+            }
+            AnyTransaction::NFTokenMint(transaction) => {
+                AnyTransaction::NFTokenMint(transaction.into_owned())
+            }
+            AnyTransaction::NFTokenModify(transaction) => {
+                AnyTransaction::NFTokenModify(transaction.into_owned())
+            }
+            AnyTransaction::OfferCancel(transaction) => {
+                AnyTransaction::OfferCancel(transaction.into_owned())
+            }
+            AnyTransaction::OfferCreate(transaction) => {
+                AnyTransaction::OfferCreate(transaction.into_owned())
+            }
+            AnyTransaction::Payment(transaction) => {
+                AnyTransaction::Payment(transaction.into_owned())
+            }
+            AnyTransaction::PaymentChannelClaim(transaction) => {
+                AnyTransaction::PaymentChannelClaim(transaction.into_owned())
+            }
+            AnyTransaction::PaymentChannelCreate(transaction) => {
+                AnyTransaction::PaymentChannelCreate(transaction.into_owned())
+            }
+            AnyTransaction::PaymentChannelFund(transaction) => {
+                AnyTransaction::PaymentChannelFund(transaction.into_owned())
+            }
+            AnyTransaction::SetRegularKey(transaction) => {
+                AnyTransaction::SetRegularKey(transaction.into_owned())
+            }
+            AnyTransaction::SignerListSet(transaction) => {
+                AnyTransaction::SignerListSet(transaction.into_owned())
+            }
+            AnyTransaction::TicketCreate(transaction) => {
+                AnyTransaction::TicketCreate(transaction.into_owned())
+            }
+            AnyTransaction::TrustSet(transaction) => {
+                AnyTransaction::TrustSet(transaction.into_owned())
+            }
+            AnyTransaction::EnableAmendment(transaction) => {
+                AnyTransaction::EnableAmendment(transaction.into_owned())
+            }
+            AnyTransaction::SetFee(transaction) => AnyTransaction::SetFee(transaction.into_owned()),
+            AnyTransaction::UNLModify(transaction) => {
+                AnyTransaction::UNLModify(transaction.into_owned())
+            }
+        }
+    }
+
+    /// Returns the transaction of the variant.
+    fn as_transaction(&self) -> &dyn Transaction {
+        match self {
+            AnyTransaction::AccountDelete(transaction) => transaction,
+            AnyTransaction::AccountSet(transaction) => transaction,
+            AnyTransaction::CheckCancel(transaction) => transaction,
+            AnyTransaction::CheckCash(transaction) => transaction,
+            AnyTransaction::CheckCreate(transaction) => transaction,
+            AnyTransaction::DepositPreauth(transaction) => transaction,
+            AnyTransaction::EscrowCancel(transaction) => transaction,
+            AnyTransaction::EscrowCreate(transaction) => transaction,
+            AnyTransaction::EscrowFinish(transaction) => transaction,
+            AnyTransaction::MPTokenAuthorize(transaction) => transaction,
+            AnyTransaction::MPTokenIssuanceCreate(transaction) => transaction,
+            AnyTransaction::MPTokenIssuanceDestroy(transaction) => transaction,
+            AnyTransaction::MPTokenIssuanceSet(transaction) => transaction,
+            AnyTransaction::NFTokenAcceptOffer(transaction) => transaction,
+            AnyTransaction::NFTokenBurn(transaction) => transaction,
+            AnyTransaction::NFTokenCancelOffer(transaction) => transaction,
+            AnyTransaction::NFTokenCreateOffer(transaction) => transaction,
+            AnyTransaction::NFTokenMint(transaction) => transaction,
+            AnyTransaction::NFTokenModify(transaction) => transaction,
+            AnyTransaction::OfferCancel(transaction) => transaction,
+            AnyTransaction::OfferCreate(transaction) => transaction,
+            AnyTransaction::Payment(transaction) => transaction,
+            AnyTransaction::PaymentChannelClaim(transaction) => transaction,
+            AnyTransaction::PaymentChannelCreate(transaction) => transaction,
+            AnyTransaction::PaymentChannelFund(transaction) => transaction,
+            AnyTransaction::SetRegularKey(transaction) => transaction,
+            AnyTransaction::SignerListSet(transaction) => transaction,
+            AnyTransaction::TicketCreate(transaction) => transaction,
+            AnyTransaction::TrustSet(transaction) => transaction,
+            AnyTransaction::EnableAmendment(transaction) => transaction,
+            AnyTransaction::SetFee(transaction) => transaction,
+            AnyTransaction::UNLModify(transaction) => transaction,
+        }
+    }
+
+    /// Returns the transaction of the variant mutably.
+    fn as_transaction_mut(&mut self) -> &mut dyn Transaction {
+        match self {
+            AnyTransaction::AccountDelete(transaction) => transaction,
+            AnyTransaction::AccountSet(transaction) => transaction,
+            AnyTransaction::CheckCancel(transaction) => transaction,
+            AnyTransaction::CheckCash(transaction) => transaction,
+            AnyTransaction::CheckCreate(transaction) => transaction,
+            AnyTransaction::DepositPreauth(transaction) => transaction,
+            AnyTransaction::EscrowCancel(transaction) => transaction,
+            AnyTransaction::EscrowCreate(transaction) => transaction,
+            AnyTransaction::EscrowFinish(transaction) => transaction,
+            AnyTransaction::MPTokenAuthorize(transaction) => transaction,
+            AnyTransaction::MPTokenIssuanceCreate(transaction) => transaction,
+            AnyTransaction::MPTokenIssuanceDestroy(transaction) => transaction,
+            AnyTransaction::MPTokenIssuanceSet(transaction) => transaction,
+            AnyTransaction::NFTokenAcceptOffer(transaction) => transaction,
+            AnyTransaction::NFTokenBurn(transaction) => transaction,
+            AnyTransaction::NFTokenCancelOffer(transaction) => transaction,
+            AnyTransaction::NFTokenCreateOffer(transaction) => transaction,
+            AnyTransaction::NFTokenMint(transaction) => transaction,
+            AnyTransaction::NFTokenModify(transaction) => transaction,
+            AnyTransaction::OfferCancel(transaction) => transaction,
+            AnyTransaction::OfferCreate(transaction) => transaction,
+            AnyTransaction::Payment(transaction) => transaction,
+            AnyTransaction::PaymentChannelClaim(transaction) => transaction,
+            AnyTransaction::PaymentChannelCreate(transaction) => transaction,
+            AnyTransaction::PaymentChannelFund(transaction) => transaction,
+            AnyTransaction::SetRegularKey(transaction) => transaction,
+            AnyTransaction::SignerListSet(transaction) => transaction,
+            AnyTransaction::TicketCreate(transaction) => transaction,
+            AnyTransaction::TrustSet(transaction) => transaction,
+            AnyTransaction::EnableAmendment(transaction) => transaction,
+            AnyTransaction::SetFee(transaction) => transaction,
+            AnyTransaction::UNLModify(transaction) => transaction,
+        }
+    }
+}
+
+impl<'a> Transaction for AnyTransaction<'a> {
+    fn has_flag(&self, flag: &Flag) -> bool {
+        self.as_transaction().has_flag(flag)
+    }
+
+    fn set_flag(&mut self, flag: &Flag) {
+        self.as_transaction_mut().set_flag(flag)
+    }
+
+    fn clear_flag(&mut self, flag: &Flag) {
+        self.as_transaction_mut().clear_flag(flag)
+    }
+
+    fn get_transaction_type(&self) -> TransactionType {
+        self.as_transaction().get_transaction_type()
+    }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        self.as_transaction().common_fields()
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        self.as_transaction_mut().common_fields_mut()
+    }
+
+    fn is_pseudo_transaction(&self) -> bool {
+        self.as_transaction().is_pseudo_transaction()
+    }
+
+    fn default_fee(&self) -> XRPAmount<'static> {
+        self.as_transaction().default_fee()
+    }
+}
+
+#[cfg(test)]
+mod test_reexports {
+    #[allow(unused_imports)]
+    use crate::models::transactions::{
+        AccountSetError, CheckCashError, DepositPreauthError, EscrowCreateError, EscrowFinishError,
+        MPTokenIssuanceCreateError, MPTokenIssuanceSetError, NFTokenAcceptOfferError,
+        NFTokenBurnError, NFTokenCancelOfferError, NFTokenCreateOfferError, NFTokenMintError,
+        NFTokenModifyError, OfferCreateError, PaymentChannelClaimError, PaymentChannelCreateError,
+        PaymentChannelFundError, PaymentError, SignerListSetError, TrustSetError,
+    };
+    use crate::models::transactions::{
+        AccountSetFlag, EnableAmendmentFlag, Flag, MPTokenAuthorizeFlag, MPTokenIssuanceCreateFlag,
+        MPTokenIssuanceSetFlag, NFTokenCreateOfferFlag, NFTokenMintFlag, OfferCreateFlag,
+        PaymentChannelClaimFlag, PaymentFlag, TrustSetFlag,
+    };
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_flags_at_module_root() {
+        assert!(AccountSetFlag::iter().count() > 0);
+        assert!(EnableAmendmentFlag::iter().count() > 0);
+        assert!(MPTokenAuthorizeFlag::iter().count() > 0);
+        assert!(MPTokenIssuanceCreateFlag::iter().count() > 0);
+        assert!(MPTokenIssuanceSetFlag::iter().count() > 0);
+        assert!(NFTokenCreateOfferFlag::iter().count() > 0);
+        assert!(NFTokenMintFlag::iter().count() > 0);
+        assert!(OfferCreateFlag::iter().count() > 0);
+        assert!(PaymentChannelClaimFlag::iter().count() > 0);
+        assert!(PaymentFlag::iter().count() > 0);
+        assert!(TrustSetFlag::iter().count() > 0);
+        assert!(matches!(
+            Flag::TrustSet(TrustSetFlag::TfSetAuth),
+            Flag::TrustSet(_)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_memos_error {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use super::*;
+    use crate::models::{amount::Amount, Model};
+
+    #[test]
+    fn test_memo_not_hex_error() {
+        let payment = Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                memos: Some(vec![Memo::new(
+                    Some("not hex".into()),
+                    None,
+                    Some("74657874".into()),
+                )]),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            payment.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `memo_data` does not have the correct format (expected hex, found not hex). For more information see: https://xrpl.org/transaction-common-fields.html"
+        );
+    }
+
+    #[test]
+    fn test_memos_too_large_error() {
+        let memo_data = "AB".repeat(MAX_MEMOS_SIZE + 1);
+        let memos = Some(vec![Memo::new(Some(memo_data.as_str().into()), None, None)]);
+
+        assert_eq!(
+            get_memos_error(&memos),
+            Err(XRPLTransactionFieldException::ValueTooLarge {
+                field: "memos",
+                max: MAX_MEMOS_SIZE,
+                found: MAX_MEMOS_SIZE + 1,
+                resource: COMMON_FIELDS_RESOURCE,
+            })
+        );
+
+        let memo_data = "AB".repeat(MAX_MEMOS_SIZE / 2);
+        let memos = Some(vec![
+            Memo::new(Some(memo_data.as_str().into()), None, None),
+            Memo::new(Some(memo_data.as_str().into()), None, None),
+        ]);
+
+        assert!(get_memos_error(&memos).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod test_ticket_sequence_error {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::models::{amount::Amount, Model};
+
+    fn payment<'a>(sequence: Option<u32>, ticket_sequence: Option<u32>) -> Payment<'a> {
+        Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                sequence,
+                ticket_sequence,
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_sequence_with_ticket_error() {
+        assert_eq!(
+            payment(Some(5), Some(4))
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The value of the field `sequence` must be 0 or absent if the field `ticket_sequence` is set (found 5). For more information see: https://xrpl.org/transaction-common-fields.html"
+        );
+    }
+
+    #[test]
+    fn test_zero_sequence_without_ticket_error() {
+        assert_eq!(
+            payment(Some(0), None)
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The value of the field `sequence` is only allowed to be 0 if the field `ticket_sequence` is set. For more information see: https://xrpl.org/transaction-common-fields.html"
+        );
+    }
+
+    #[test]
+    fn test_valid_ticket() {
+        assert!(payment(Some(0), Some(4)).validate().is_ok());
+        assert!(payment(None, Some(4)).validate().is_ok());
+        assert!(payment(Some(5), None).validate().is_ok());
+
+        let mut ticket_payment = payment(Some(5), None);
+        ticket_payment.common_fields.use_ticket(4);
+
+        assert_eq!(ticket_payment.common_fields.sequence, Some(0));
+        assert_eq!(ticket_payment.common_fields.ticket_sequence, Some(4));
+        assert!(ticket_payment.validate().is_ok());
+    }
+}
+
+#[cfg(test)]
+mod test_normalize_x_address {
+    use super::*;
+    use crate::core::addresscodec::classic_address_to_xaddress;
+
+    const ACCOUNT: &str = "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb";
+
+    fn common_fields<'a>(account: Cow<'a, str>, source_tag: Option<u32>) -> CommonFields<'a, u32> {
+        CommonFields {
+            account,
+            source_tag,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_extract_source_tag() {
+        let xaddress = classic_address_to_xaddress(ACCOUNT, Some(42), false).unwrap();
+        let normalized = common_fields(xaddress.into(), None)
+            .normalize_x_address()
+            .unwrap();
+
+        assert_eq!(normalized, common_fields(ACCOUNT.into(), Some(42)));
+        assert_eq!(
+            common_fields(ACCOUNT.into(), Some(7)).normalize_x_address(),
+            Ok(common_fields(ACCOUNT.into(), Some(7)))
+        );
+    }
+
+    #[test]
+    fn test_conflicting_source_tag() {
+        let xaddress = classic_address_to_xaddress(ACCOUNT, Some(42), false).unwrap();
+
+        assert_eq!(
+            common_fields(xaddress.into(), Some(1)).normalize_x_address(),
+            Err(XRPLTransactionFieldException::XAddressTagConflict {
+                field: "account",
+                tag_field: "source_tag",
+                expected: 42,
+                found: 1,
+                resource: COMMON_FIELDS_RESOURCE,
+            })
+        );
+        assert_eq!(
+            common_fields("invalid".into(), None).normalize_x_address(),
+            Err(XRPLTransactionFieldException::InvalidAddress {
+                field: "account",
+                resource: COMMON_FIELDS_RESOURCE,
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_fee {
+    use super::*;
+
+    fn common_fields<'a, F>() -> CommonFields<'a, F> {
+        CommonFields {
+            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+            fee: Some(XRPAmount::from("12")),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_serialized_fee() {
+        let signer_list_set = SignerListSet {
+            common_fields: common_fields(),
+            ..Default::default()
+        };
+        let offer_create = OfferCreate {
+            common_fields: common_fields(),
+            ..Default::default()
+        };
+
+        assert_eq!(signer_list_set.to_xrpl_json().unwrap()["Fee"], "12");
+        assert_eq!(offer_create.to_xrpl_json().unwrap()["Fee"], "12");
+
+        let signer_list_set: SignerListSet =
+            serde_json::from_str(r#"{"TransactionType":"SignerListSet","Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","Fee":"12","SignerQuorum":0}"#).unwrap();
+
+        assert_eq!(
+            signer_list_set.common_fields.fee,
+            Some(XRPAmount::from("12"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_default_fee {
+    use alloc::vec;
+
+    use super::*;
+    use crate::models::amount::Amount;
+
+    fn payment<'a>(signers: Option<Vec<Signer<'a>>>) -> Payment<'a> {
+        Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                signers,
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_single_signed() {
+        assert_eq!(payment(None).default_fee(), XRPAmount::from("10"));
+    }
+
+    #[test]
+    fn test_multi_signed() {
+        let signer = Signer::new(
+            "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".into(),
+            "30450221009C195DBBF7967E223D8626CA19CF02073667F2B22E206727BFE848FF42BEAC8A".into(),
+            "02B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF".into(),
+        );
+        let multi_signed = payment(Some(vec![signer.clone(), signer.clone(), signer]));
+
+        assert_eq!(multi_signed.default_fee(), XRPAmount::from("40"));
+    }
+
+    #[test]
+    fn test_escrow_finish_fulfillment() {
+        let escrow_finish = EscrowFinish {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            owner: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+            condition: Some(
+                "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100"
+                    .into(),
+            ),
+            fulfillment: Some("A0028000".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            escrow_finish.default_fee(),
+            escrow_finish.suggested_fee("10".into()).unwrap()
+        );
+        assert_eq!(escrow_finish.default_fee(), XRPAmount::from("333"));
+        assert_eq!(
+            AnyTransaction::EscrowFinish(escrow_finish.clone()).default_fee(),
+            XRPAmount::from("333")
+        );
+
+        let multi_signed = EscrowFinish {
+            common_fields: CommonFields {
+                signers: Some(vec![Signer::new(
+                    "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".into(),
+                    "30450221009C195DBBF7967E223D8626CA19CF02073667F2B22E206727BFE848FF42BEAC8A"
+                        .into(),
+                    "02B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF".into(),
+                )]),
+                ..escrow_finish.common_fields.clone()
+            },
+            ..escrow_finish
+        };
+
+        assert_eq!(multi_signed.default_fee(), XRPAmount::from("343"));
+    }
+}
+
+#[cfg(test)]
+mod test_serialize_signers {
+    use alloc::vec;
+
+    use super::*;
+    use crate::core::binarycodec::{decode, encode};
+    use crate::models::amount::Amount;
+
+    const LOW_ACCOUNT: &str = "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW";
+    const HIGH_ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+    fn signer(account: &str) -> Signer<'_> {
+        Signer::new(
+            account.into(),
+            "30450221009C195DBBF7967E223D8626CA19CF02073667F2B22E206727BFE848FF42BEAC8A".into(),
+            "02B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF".into(),
+        )
+    }
+
+    #[test]
+    fn test_sorted_by_account_id() {
+        let payment = Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                signing_pub_key: Some("".into()),
+                signers: Some(vec![signer(HIGH_ACCOUNT), signer(LOW_ACCOUNT)]),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        };
+        let fields = serde_json::to_value(&payment).unwrap();
+        let accounts: Vec<&str> = fields["Signers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|signer| signer["Signer"]["Account"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(accounts, vec![LOW_ACCOUNT, HIGH_ACCOUNT]);
+        assert_eq!(
+            payment.common_fields.signers,
+            Some(vec![signer(HIGH_ACCOUNT), signer(LOW_ACCOUNT)])
+        );
+    }
+
+    #[test]
+    fn test_encode_round_trip() {
+        let payment = Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: Some("30".into()),
+                sequence: Some(2),
+                signing_pub_key: Some("".into()),
+                signers: Some(vec![signer(HIGH_ACCOUNT), signer(LOW_ACCOUNT)]),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        };
+        let tx_blob = encode(&payment).unwrap();
+        let decoded = decode(&tx_blob).unwrap();
+
+        assert_eq!(
+            decoded["Signers"][0],
+            serde_json::to_value(signer(LOW_ACCOUNT)).unwrap()
+        );
+        match transaction_from_json(&decoded).unwrap() {
+            AnyTransaction::Payment(decoded_payment) => {
+                assert_eq!(
+                    decoded_payment.common_fields.signers,
+                    Some(vec![signer(LOW_ACCOUNT), signer(HIGH_ACCOUNT)])
+                );
+                assert_eq!(encode(&decoded_payment).unwrap(), tx_blob);
+            }
+            other => panic!("expected `Payment`, found {:?}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_object_safety {
+    use alloc::boxed::Box;
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_boxed_transactions() {
+        let transactions: Vec<Box<dyn Transaction>> = vec![
+            Box::new(Payment::default()),
+            Box::new(AccountSet::default()),
+        ];
+
+        assert_eq!(
+            transactions
+                .iter()
+                .map(|transaction| transaction.get_transaction_type())
+                .collect::<Vec<TransactionType>>(),
+            vec![TransactionType::Payment, TransactionType::AccountSet]
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_amounts_error {
+    use super::*;
+    use crate::models::amount::IssuedCurrencyAmount;
+    use crate::models::Model;
+    use alloc::string::ToString;
+
+    const ACCOUNT: &str = "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb";
+    const DESTINATION: &str = "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK";
+
+    fn common_fields<'a, F>() -> CommonFields<'a, F> {
+        CommonFields {
+            account: ACCOUNT.into(),
+            ..Default::default()
+        }
+    }
+
+    fn xrp_issued_currency<'a>() -> IssuedCurrencyAmount<'a> {
+        IssuedCurrencyAmount::new("xrp".into(), DESTINATION.into(), "1".into())
+    }
+
+    fn assert_reserved_currency_error(model: &dyn Model) {
+        assert_eq!(
+            model.validate().unwrap_err().to_string(),
+            "The currency code `xrp` is reserved for XRP and can not be used by issued currencies."
+        );
+    }
+
+    #[test]
+    fn test_xrp_currency_code() {
+        assert_reserved_currency_error(&Payment {
+            common_fields: common_fields(),
+            amount: xrp_issued_currency().into(),
+            destination: DESTINATION.into(),
+            ..Default::default()
+        });
+        assert_reserved_currency_error(&TrustSet {
+            common_fields: common_fields(),
+            limit_amount: xrp_issued_currency(),
+            ..Default::default()
+        });
+        assert_reserved_currency_error(&OfferCreate {
+            common_fields: common_fields(),
+            taker_gets: Amount::XRPAmount("1000000".into()),
+            taker_pays: xrp_issued_currency().into(),
+            ..Default::default()
+        });
+        assert_reserved_currency_error(&CheckCreate {
+            common_fields: common_fields(),
+            destination: DESTINATION.into(),
+            send_max: xrp_issued_currency().into(),
+            ..Default::default()
+        });
+        assert_reserved_currency_error(&CheckCash {
+            common_fields: common_fields(),
+            amount: Some(xrp_issued_currency().into()),
+            ..Default::default()
+        });
+        assert_reserved_currency_error(&NFTokenCreateOffer {
+            common_fields: common_fields(),
+            amount: xrp_issued_currency().into(),
+            ..Default::default()
+        });
+        assert_reserved_currency_error(&NFTokenAcceptOffer {
+            common_fields: common_fields(),
+            nftoken_broker_fee: Some(xrp_issued_currency().into()),
+            ..Default::default()
+        });
+    }
+}
+
+#[cfg(test)]
+mod test_is_expired {
+    use super::*;
+    use crate::models::amount::Amount;
+    use alloc::boxed::Box;
+
+    fn payment<'a>(last_ledger_sequence: Option<u32>) -> Payment<'a> {
+        Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                last_ledger_sequence,
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_expired() {
+        assert!(payment(Some(100)).is_expired(101));
+    }
+
+    #[test]
+    fn test_not_expired() {
+        assert!(!payment(Some(100)).is_expired(99));
+        assert!(!payment(Some(100)).is_expired(100));
+    }
+
+    #[test]
+    fn test_without_last_ledger_sequence() {
+        assert!(!payment(None).is_expired(u32::MAX));
+    }
+
+    #[test]
+    fn test_trait_object() {
+        let transaction: Box<dyn Transaction> = Box::new(payment(Some(100)));
+        let any_transaction = AnyTransaction::Payment(payment(Some(100)));
+
+        assert!(transaction.is_expired(101));
+        assert!(any_transaction.is_expired(101));
+        assert!(!any_transaction.is_expired(100));
+    }
+}
+
+#[cfg(test)]
+mod test_get_field_bytes {
+    use super::*;
+    use crate::constants::HashPrefix;
+    use crate::core::keypairs::{is_valid_message, sign};
+    use crate::models::amount::Amount;
+    use crate::wallet::Wallet;
+
+    const SEED: &str = "sEdTM1uX8pu2do5XvTnutH6HsouMaM2";
+
+    fn payment(signing_pub_key: &str) -> Payment<'_> {
+        Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: Some("12".into()),
+                sequence: Some(1),
+                signing_pub_key: Some(signing_pub_key.into()),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_single_signing() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let field_bytes = payment(&wallet.public_key).get_field_bytes(None).unwrap();
+
+        assert!(field_bytes.starts_with(&HashPrefix::TransactionSign.bytes()));
+        assert!(is_valid_message(
+            &field_bytes,
+            &sign(&field_bytes, &wallet.private_key).unwrap(),
+            &wallet.public_key,
+        ));
+    }
+
+    #[test]
+    fn test_multisigning() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let payment = payment(&wallet.public_key);
+        let field_bytes = payment
+            .get_field_bytes(Some(&wallet.classic_address))
+            .unwrap();
+        let mut blanked = payment.clone();
+        blanked.common_fields.signing_pub_key = Some("".into());
+
+        assert!(field_bytes.starts_with(&HashPrefix::TransactionMultiSign.bytes()));
+        assert!(field_bytes.ends_with(&decode_classic_address(&wallet.classic_address).unwrap()));
+        assert_eq!(
+            field_bytes,
+            blanked
+                .get_field_bytes(Some(&wallet.classic_address))
+                .unwrap()
+        );
+        assert_ne!(field_bytes, payment.get_field_bytes(None).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod test_any_transaction {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::constants::ACCOUNT_ZERO;
+
+    #[derive(Deserialize)]
+    struct Ledger<'a> {
+        ledger_index: &'a str,
+        #[serde(borrow)]
+        transactions: Vec<AnyTransaction<'a>>,
+    }
+
+    #[test]
+    fn test_deserialize_ledger_transactions() {
+        let json = r#"{"ledger_index":"3721729","transactions":[{"Account":"rrrrrrrrrrrrrrrrrrrrrhoLvTp","BaseFee":"000000000000000A","Fee":"0","LedgerSequence":3721729,"ReferenceFeeUnits":10,"ReserveBase":50000000,"ReserveIncrement":12500000,"Sequence":0,"SigningPubKey":"","TransactionType":"SetFee","hash":"1C15FEA3E1D50F96B6598607FC773FF1F6E0125F348F5A2CB4A8E5E4D0F4E2B9"},{"BaseFeeDrops":"10","Fee":"0","LedgerSequence":84803072,"ReserveBaseDrops":"10000000","ReserveIncrementDrops":"2000000","Sequence":0,"SigningPubKey":"","TransactionType":"SetFee"},{"Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","Amount":"1000000","Destination":"rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK","Fee":"12","Flags":0,"Sequence":2,"TransactionType":"Payment"}]}"#;
+        let ledger: Ledger = serde_json::from_str(json).unwrap();
+
+        assert_eq!(ledger.ledger_index, "3721729");
+        assert_eq!(ledger.transactions.len(), 3);
+        match &ledger.transactions[0] {
+            AnyTransaction::SetFee(set_fee) => {
+                assert_eq!(set_fee.account, ACCOUNT_ZERO);
+                assert_eq!(set_fee.reserve_base, Some(50000000));
+                assert_eq!(set_fee.ledger_sequence, 3721729);
+            }
+            other => panic!("expected `SetFee`, found {:?}", other),
+        }
+        match &ledger.transactions[1] {
+            AnyTransaction::SetFee(set_fee) => {
+                assert_eq!(set_fee.account, ACCOUNT_ZERO);
+                assert_eq!(set_fee.base_fee_drops, Some("10".into()));
+            }
+            other => panic!("expected `SetFee`, found {:?}", other),
+        }
+        assert_eq!(
+            ledger.transactions[2].get_transaction_type(),
+            TransactionType::Payment
+        );
+    }
+
+    #[test]
+    fn test_is_pseudo_transaction() {
+        let set_fee: SetFee = serde_json::from_str(r#"{"BaseFeeDrops":"10","Fee":"0","LedgerSequence":84803072,"ReserveBaseDrops":"10000000","ReserveIncrementDrops":"2000000","Sequence":0,"SigningPubKey":"","TransactionType":"SetFee"}"#).unwrap();
+        let payment = Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        };
+
+        assert!(set_fee.is_pseudo_transaction());
+        assert!(AnyTransaction::SetFee(set_fee).is_pseudo_transaction());
+        assert!(!payment.is_pseudo_transaction());
+        assert!(!AnyTransaction::Payment(payment).is_pseudo_transaction());
+    }
+
+    #[test]
+    fn test_flags() {
+        let mut offer_create = AnyTransaction::OfferCreate(OfferCreate {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let passive = Flag::OfferCreate(OfferCreateFlag::TfPassive);
+
+        assert!(!offer_create.has_flag(&passive));
+        offer_create.set_flag(&passive);
+        assert!(offer_create.has_flag(&passive));
+        match &offer_create {
+            AnyTransaction::OfferCreate(transaction) => {
+                assert_eq!(
+                    transaction.common_fields.flags,
+                    Some(vec![OfferCreateFlag::TfPassive])
+                );
+            }
+            other => panic!("expected `OfferCreate`, found {:?}", other),
+        }
+        offer_create.clear_flag(&passive);
+        assert!(!offer_create.has_flag(&passive));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let json = r#"{"TransactionType":"UNLModify","Account":"rrrrrrrrrrrrrrrrrrrrrhoLvTp","Fee":"0","Sequence":0,"SigningPubKey":"","LedgerSequence":1600000,"UNLModifyDisabling":1,"UNLModifyValidator":"ED6629D456285AE3613B285F65BBFF168D695BA3921F309949AFCD2CA7AFEC16FE"}"#;
+        let transaction: AnyTransaction = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            transaction.get_transaction_type(),
+            TransactionType::UNLModify
+        );
+        assert_eq!(serde_json::to_string(&transaction).unwrap(), json);
+    }
+
+    #[test]
+    fn test_xrpl_json_round_trip() {
+        let value = serde_json::json!({
+            "TransactionType": "Payment",
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Destination": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+            "Amount": {
+                "currency": "USD",
+                "value": "1",
+                "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+            },
+            "Fee": "12",
+            "Flags": 131072,
+            "Sequence": 2
+        });
+        let transaction = transaction_from_json(&value).unwrap();
+
+        match &transaction {
+            AnyTransaction::Payment(payment) => {
+                assert_eq!(
+                    payment.common_fields.flags,
+                    Some(vec![PaymentFlag::TfPartialPayment])
+                );
+            }
+            other => panic!("expected `Payment`, found {:?}", other),
+        }
+        assert_eq!(transaction.to_xrpl_json().unwrap(), value);
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let json = String::from(
+            r#"[{"Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","Fee":"12","LimitAmount":{"currency":"USD","issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","value":"100"},"TransactionType":"TrustSet"},{"Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","Fee":"12","TakerGets":"1000000","TakerPays":{"currency":"USD","issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","value":"1"},"TransactionType":"OfferCreate"},{"Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","Domain":"6578616D706C652E636F6D","Fee":"12","TransactionType":"AccountSet"},{"Account":"rrrrrrrrrrrrrrrrrrrrrhoLvTp","Amendment":"42426C4D4F1009EE67080A9B7965B44656D7714D104A72F9B4369F97ABF044EE","Fee":"0","LedgerSequence":21225473,"Sequence":0,"SigningPubKey":"","TransactionType":"EnableAmendment"}]"#,
+        );
+        let transactions: Vec<AnyTransaction> = serde_json::from_str(&json).unwrap();
+        let owned: Vec<AnyTransaction<'static>> = transactions
+            .into_iter()
+            .map(AnyTransaction::into_owned)
+            .collect();
+        drop(json);
+
+        assert_eq!(owned.len(), 4);
+        match &owned[0] {
+            AnyTransaction::TrustSet(trust_set) => {
+                assert_eq!(trust_set.limit_amount.value, "100");
+            }
+            other => panic!("expected `TrustSet`, found {:?}", other),
+        }
+        match &owned[2] {
+            AnyTransaction::AccountSet(account_set) => {
+                assert_eq!(
+                    account_set.domain.as_deref(),
+                    Some("6578616D706C652E636F6D")
+                );
+            }
+            other => panic!("expected `AccountSet`, found {:?}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde_field_names {
+    use alloc::borrow::Cow;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use serde_json::Value;
+
+    use super::*;
+    use crate::models::amount::{Amount, IssuedCurrencyAmount, XRPAmount};
+    use crate::models::PathStep;
+
+    const ACCOUNT: &str = "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb";
+    const DESTINATION: &str = "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK";
+    const HASH: &str = "49647F0D748DC3FE26BDACBC57F251AADEFFF391403EC9BF87C97F67E9977FB0";
+    const PUBLIC_KEY: &str = "03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB";
+    const MPT_ISSUANCE_ID: &str =
+        "00070C4495F14B0E44F78A264E41713C64B5F89242540EE255534400000000000000";
+    const SIGNATURE: &str = "3045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE";
+
+    const COMMON_FIELDS: [&str; 13] = [
+        "TransactionType",
+        "Account",
+        "Fee",
+        "Sequence",
+        "LastLedgerSequence",
+        "AccountTxnID",
+        "SigningPubKey",
+        "SourceTag",
+        "TicketSequence",
+        "TxnSignature",
+        "Flags",
+        "Memos",
+        "Signers",
+    ];
+
+    const PSEUDO_COMMON_FIELDS: [&str; 8] = [
+        "TransactionType",
+        "Account",
+        "Fee",
+        "Sequence",
+        "SigningPubKey",
+        "SourceTag",
+        "TxnSignature",
+        "Flags",
+    ];
+
+    /// Builds a transaction with every common field populated.
+    macro_rules! populated {
+        ($model:ident { transaction_type: $transaction_type:expr, flags: $flags:expr, $($field:ident: $value:expr),* $(,)? }) => {
+            serde_json::to_value($model {
+                transaction_type: $transaction_type,
+                common_fields: CommonFields {
+                    account: ACCOUNT.into(),
+                    fee: Some(XRPAmount::from("12")),
+                    sequence: Some(1),
+                    last_ledger_sequence: Some(2),
+                    account_txn_id: Some(HASH.into()),
+                    signing_pub_key: Some(PUBLIC_KEY.into()),
+                    source_tag: Some(3),
+                    ticket_sequence: Some(4),
+                    txn_signature: Some(SIGNATURE.into()),
+                    flags: $flags,
+                    memos: Some(vec![Memo::new(Some("72656e74".into()), None, Some("687474703a2f2f6578616d706c652e636f6d2f6d656d6f2f67656e65726963".into()))]),
+                    signers: Some(vec![Signer {
+                        account: DESTINATION.into(),
+                        txn_signature: SIGNATURE.into(),
+                        signing_pub_key: PUBLIC_KEY.into(),
+                    }]),
+                },
+                $($field: $value,)*
+            })
+            .unwrap()
+        };
+    }
+
+    fn xrp<'a>() -> Amount<'a> {
+        Amount::XRPAmount(XRPAmount::from("1000000"))
+    }
+
+    fn issued_currency<'a>() -> IssuedCurrencyAmount<'a> {
+        IssuedCurrencyAmount::new("USD".into(), DESTINATION.into(), "10".into())
+    }
+
+    fn keys(value: &Value) -> Vec<String> {
+        let mut keys: Vec<String> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        keys
+    }
+
+    fn expected(common: &[&str], specific: &[&str]) -> Vec<String> {
+        let mut keys: Vec<String> = common
+            .iter()
+            .chain(specific)
+            .map(|key| key.to_string())
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn test_field_names() {
+        let cases: Vec<(Value, &[&str])> = vec![
+            (
+                populated!(AccountDelete {
+                    transaction_type: TransactionType::AccountDelete,
+                    flags: Some(0),
+                    destination: DESTINATION.into(),
+                    destination_tag: Some(5),
+                }),
+                &["Destination", "DestinationTag"],
+            ),
+            (
+                populated!(AccountSet {
+                    transaction_type: TransactionType::AccountSet,
+                    flags: Some(vec![AccountSetFlag::AsfDisallowXRP]),
+                    clear_flag: Some(AccountSetFlag::AsfDefaultRipple),
+                    domain: Some("6578616d706c652e636f6d".into()),
+                    email_hash: Some("98B4375E1D753E5B91627516F6D70977".into()),
+                    message_key: Some(PUBLIC_KEY.into()),
+                    nftoken_minter: Some(DESTINATION.into()),
+                    set_flag: Some(AccountSetFlag::AsfAuthorizedNFTokenMinter),
+                    transfer_rate: Some(1000000001),
+                    tick_size: Some(5),
+                }),
+                &[
+                    "ClearFlag",
+                    "Domain",
+                    "EmailHash",
+                    "MessageKey",
+                    "NFTokenMinter",
+                    "SetFlag",
+                    "TransferRate",
+                    "TickSize",
+                ],
+            ),
+            (
+                populated!(CheckCancel {
+                    transaction_type: TransactionType::CheckCancel,
+                    flags: Some(0),
+                    check_id: HASH.into(),
+                }),
+                &["CheckID"],
+            ),
+            (
+                populated!(CheckCash {
+                    transaction_type: TransactionType::CheckCash,
+                    flags: Some(0),
+                    check_id: HASH.into(),
+                    amount: Some(xrp()),
+                    deliver_min: Some(xrp()),
+                }),
+                &["CheckID", "Amount", "DeliverMin"],
+            ),
+            (
+                populated!(CheckCreate {
+                    transaction_type: TransactionType::CheckCreate,
+                    flags: Some(0),
+                    destination: DESTINATION.into(),
+                    send_max: xrp(),
+                    destination_tag: Some(5),
+                    expiration: Some(6),
+                    invoice_id: Some(HASH.into()),
+                }),
+                &[
+                    "Destination",
+                    "SendMax",
+                    "DestinationTag",
+                    "Expiration",
+                    "InvoiceID",
+                ],
+            ),
+            (
+                populated!(DepositPreauth {
+                    transaction_type: TransactionType::DepositPreauth,
+                    flags: Some(0),
+                    authorize: Some(DESTINATION.into()),
+                    unauthorize: Some(DESTINATION.into()),
+                }),
+                &["Authorize", "Unauthorize"],
+            ),
+            (
+                populated!(EscrowCancel {
+                    transaction_type: TransactionType::EscrowCancel,
+                    flags: Some(0),
+                    owner: DESTINATION.into(),
+                    offer_sequence: 7,
+                }),
+                &["Owner", "OfferSequence"],
+            ),
+            (
+                populated!(EscrowCreate {
+                    transaction_type: TransactionType::EscrowCreate,
+                    flags: Some(0),
+                    amount: XRPAmount::from("1000000"),
+                    destination: DESTINATION.into(),
+                    destination_tag: Some(5),
+                    cancel_after: Some(9),
+                    finish_after: Some(8),
+                    condition: Some("A0258020".into()),
+                }),
+                &[
+                    "Amount",
+                    "Destination",
+                    "DestinationTag",
+                    "CancelAfter",
+                    "FinishAfter",
+                    "Condition",
+                ],
+            ),
+            (
+                populated!(EscrowFinish {
+                    transaction_type: TransactionType::EscrowFinish,
+                    flags: Some(0),
+                    owner: DESTINATION.into(),
+                    offer_sequence: 7,
+                    condition: Some("A0258020".into()),
+                    fulfillment: Some("A0028000".into()),
+                }),
+                &["Owner", "OfferSequence", "Condition", "Fulfillment"],
+            ),
+            (
+                populated!(MPTokenAuthorize {
+                    transaction_type: TransactionType::MPTokenAuthorize,
+                    flags: Some(vec![MPTokenAuthorizeFlag::TfMPTUnauthorize]),
+                    mptoken_issuance_id: MPT_ISSUANCE_ID.into(),
+                    holder: Some(DESTINATION.into()),
+                }),
+                &["MPTokenIssuanceID", "Holder"],
+            ),
+            (
+                populated!(MPTokenIssuanceCreate {
+                    transaction_type: TransactionType::MPTokenIssuanceCreate,
+                    flags: Some(vec![MPTokenIssuanceCreateFlag::TfMPTCanTransfer]),
+                    asset_scale: Some(2),
+                    transfer_fee: Some(314),
+                    maximum_amount: Some("50000000".into()),
+                    mptoken_metadata: Some("464F4F".into()),
+                }),
+                &[
+                    "AssetScale",
+                    "TransferFee",
+                    "MaximumAmount",
+                    "MPTokenMetadata",
+                ],
+            ),
+            (
+                populated!(MPTokenIssuanceDestroy {
+                    transaction_type: TransactionType::MPTokenIssuanceDestroy,
+                    flags: Some(0),
+                    mptoken_issuance_id: MPT_ISSUANCE_ID.into(),
+                }),
+                &["MPTokenIssuanceID"],
+            ),
+            (
+                populated!(MPTokenIssuanceSet {
+                    transaction_type: TransactionType::MPTokenIssuanceSet,
+                    flags: Some(vec![MPTokenIssuanceSetFlag::TfMPTLock]),
+                    mptoken_issuance_id: MPT_ISSUANCE_ID.into(),
+                    holder: Some(DESTINATION.into()),
+                }),
+                &["MPTokenIssuanceID", "Holder"],
+            ),
+            (
+                populated!(NFTokenAcceptOffer {
+                    transaction_type: TransactionType::NFTokenAcceptOffer,
+                    flags: Some(0),
+                    nftoken_sell_offer: Some(HASH.into()),
+                    nftoken_buy_offer: Some(HASH.into()),
+                    nftoken_broker_fee: Some(xrp()),
+                }),
+                &["NFTokenSellOffer", "NFTokenBuyOffer", "NFTokenBrokerFee"],
+            ),
+            (
+                populated!(NFTokenBurn {
+                    transaction_type: TransactionType::NFTokenBurn,
+                    flags: Some(0),
+                    nftoken_id: HASH.into(),
+                    owner: Some(DESTINATION.into()),
+                }),
+                &["NFTokenID", "Owner"],
+            ),
+            (
+                populated!(NFTokenCancelOffer {
+                    transaction_type: TransactionType::NFTokenCancelOffer,
+                    flags: Some(0),
+                    nftoken_offers: vec![HASH.into()],
+                }),
+                &["NFTokenOffers"],
+            ),
+            (
+                populated!(NFTokenCreateOffer {
+                    transaction_type: TransactionType::NFTokenCreateOffer,
+                    flags: Some(vec![NFTokenCreateOfferFlag::TfSellOffer]),
+                    nftoken_id: HASH.into(),
+                    amount: xrp(),
+                    owner: Some(DESTINATION.into()),
+                    expiration: Some(6),
+                    destination: Some(DESTINATION.into()),
+                }),
+                &["NFTokenID", "Amount", "Owner", "Expiration", "Destination"],
+            ),
+            (
+                populated!(NFTokenMint {
+                    transaction_type: TransactionType::NFTokenMint,
+                    flags: Some(vec![NFTokenMintFlag::TfTransferable]),
+                    nftoken_taxon: 0,
+                    issuer: Some(DESTINATION.into()),
+                    transfer_fee: Some(314),
+                    uri: Some("697066733A2F2F".into()),
+                }),
+                &["NFTokenTaxon", "Issuer", "TransferFee", "URI"],
+            ),
+            (
+                populated!(NFTokenModify {
+                    transaction_type: TransactionType::NFTokenModify,
+                    flags: Some(0),
+                    nftoken_id: HASH.into(),
+                    owner: Some(DESTINATION.into()),
+                    uri: Some("697066733A2F2F".into()),
+                }),
+                &["NFTokenID", "Owner", "URI"],
+            ),
+            (
+                populated!(OfferCancel {
+                    transaction_type: TransactionType::OfferCancel,
+                    flags: Some(0),
+                    offer_sequence: 7,
+                }),
+                &["OfferSequence"],
+            ),
+            (
+                populated!(OfferCreate {
+                    transaction_type: TransactionType::OfferCreate,
+                    flags: Some(vec![OfferCreateFlag::TfPassive]),
+                    taker_gets: xrp(),
+                    taker_pays: Amount::IssuedCurrencyAmount(issued_currency()),
+                    expiration: Some(6),
+                    offer_sequence: Some(7),
+                }),
+                &["TakerGets", "TakerPays", "Expiration", "OfferSequence"],
+            ),
+            (
+                populated!(Payment {
+                    transaction_type: TransactionType::Payment,
+                    flags: Some(vec![PaymentFlag::TfPartialPayment]),
+                    amount: Amount::IssuedCurrencyAmount(issued_currency()),
+                    destination: DESTINATION.into(),
+                    destination_tag: Some(5),
+                    invoice_id: Some(HASH.into()),
+                    paths: Some(vec![vec![PathStep::default()]]),
+                    send_max: Some(xrp()),
+                    deliver_min: Some(Amount::IssuedCurrencyAmount(issued_currency())),
+                    deliver_max: Some(Amount::IssuedCurrencyAmount(issued_currency())),
+                }),
+                &[
+                    "Amount",
+                    "Destination",
+                    "DestinationTag",
+                    "InvoiceID",
+                    "Paths",
+                    "SendMax",
+                    "DeliverMin",
+                    "DeliverMax",
+                ],
+            ),
+            (
+                populated!(PaymentChannelClaim {
+                    transaction_type: TransactionType::PaymentChannelClaim,
+                    flags: Some(vec![PaymentChannelClaimFlag::TfRenew]),
+                    channel: HASH.into(),
+                    balance: Some("1000000".into()),
+                    amount: Some("1000000".into()),
+                    signature: Some(SIGNATURE.into()),
+                    public_key: Some(PUBLIC_KEY.into()),
+                }),
+                &["Channel", "Balance", "Amount", "Signature", "PublicKey"],
+            ),
+            (
+                populated!(PaymentChannelCreate {
+                    transaction_type: TransactionType::PaymentChannelCreate,
+                    flags: Some(0),
+                    amount: XRPAmount::from("1000000"),
+                    destination: DESTINATION.into(),
+                    settle_delay: 86400,
+                    public_key: PUBLIC_KEY.into(),
+                    cancel_after: Some(9),
+                    destination_tag: Some(5),
+                }),
+                &[
+                    "Amount",
+                    "Destination",
+                    "SettleDelay",
+                    "PublicKey",
+                    "CancelAfter",
+                    "DestinationTag",
+                ],
+            ),
+            (
+                populated!(PaymentChannelFund {
+                    transaction_type: TransactionType::PaymentChannelFund,
+                    flags: Some(0),
+                    amount: XRPAmount::from("1000000"),
+                    channel: HASH.into(),
+                    expiration: Some(6),
+                }),
+                &["Amount", "Channel", "Expiration"],
+            ),
+            (
+                populated!(SetRegularKey {
+                    transaction_type: TransactionType::SetRegularKey,
+                    flags: Some(0),
+                    regular_key: Some(DESTINATION.into()),
+                }),
+                &["RegularKey"],
+            ),
+            (
+                populated!(SignerListSet {
+                    transaction_type: TransactionType::SignerListSet,
+                    flags: Some(0),
+                    signer_quorum: 1,
+                    signer_entries: Some(vec![SignerEntry {
+                        account: Cow::Borrowed(DESTINATION),
+                        signer_weight: 1,
+                    }]),
+                }),
+                &["SignerQuorum", "SignerEntries"],
+            ),
+            (
+                populated!(TicketCreate {
+                    transaction_type: TransactionType::TicketCreate,
+                    flags: Some(0),
+                    ticket_count: 1,
+                }),
+                &["TicketCount"],
+            ),
+            (
+                populated!(TrustSet {
+                    transaction_type: TransactionType::TrustSet,
+                    flags: Some(vec![TrustSetFlag::TfSetNoRipple]),
+                    limit_amount: issued_currency(),
+                    quality_in: Some(1),
+                    quality_out: Some(1),
+                }),
+                &["LimitAmount", "QualityIn", "QualityOut"],
+            ),
+        ];
+
+        for (value, specific_fields) in cases {
+            assert_eq!(
+                keys(&value),
+                expected(&COMMON_FIELDS, specific_fields),
+                "{}",
+                value["TransactionType"]
+            );
+        }
+
+        let pseudo_cases: Vec<(Value, &[&str])> = vec![
+            (
+                serde_json::to_value(EnableAmendment {
+                    transaction_type: TransactionType::EnableAmendment,
+                    account: ACCOUNT.into(),
+                    fee: Some(XRPAmount::from("0")),
+                    sequence: Some(0),
+                    signing_pub_key: Some("".into()),
+                    source_tag: Some(3),
+                    txn_signature: Some(SIGNATURE.into()),
+                    flags: Some(vec![EnableAmendmentFlag::TfGotMajority]),
+                    amendment: HASH.into(),
+                    ledger_sequence: 21225473,
+                })
+                .unwrap(),
+                &["Amendment", "LedgerSequence"],
+            ),
+            (
+                serde_json::to_value(SetFee {
+                    transaction_type: TransactionType::SetFee,
+                    account: ACCOUNT.into(),
+                    fee: Some(XRPAmount::from("0")),
+                    sequence: Some(0),
+                    signing_pub_key: Some("".into()),
+                    source_tag: Some(3),
+                    txn_signature: Some(SIGNATURE.into()),
+                    flags: Some(0),
+                    base_fee: Some(XRPAmount::from("000000000000000A")),
+                    reference_fee_units: Some(10),
+                    reserve_base: Some(20000000),
+                    reserve_increment: Some(5000000),
+                    base_fee_drops: None,
+                    reserve_base_drops: None,
+                    reserve_increment_drops: None,
+                    ledger_sequence: 21225473,
+                })
+                .unwrap(),
+                &[
+                    "BaseFee",
+                    "ReferenceFeeUnits",
+                    "ReserveBase",
+                    "ReserveIncrement",
+                    "LedgerSequence",
+                ],
+            ),
+            (
+                serde_json::to_value(UNLModify {
+                    transaction_type: TransactionType::UNLModify,
+                    account: ACCOUNT.into(),
+                    fee: Some(XRPAmount::from("0")),
+                    sequence: Some(0),
+                    signing_pub_key: Some("".into()),
+                    source_tag: Some(3),
+                    txn_signature: Some(SIGNATURE.into()),
+                    flags: Some(0),
+                    ledger_sequence: 21225473,
+                    unlmodify_disabling: UNLModifyDisabling::Enable,
+                    unlmodify_validator: PUBLIC_KEY.into(),
+                })
+                .unwrap(),
+                &["LedgerSequence", "UNLModifyDisabling", "UNLModifyValidator"],
+            ),
+        ];
+
+        for (value, specific_fields) in pseudo_cases {
+            assert_eq!(
+                keys(&value),
+                expected(&PSEUDO_COMMON_FIELDS, specific_fields),
+                "{}",
+                value["TransactionType"]
+            );
+        }
+    }
+}
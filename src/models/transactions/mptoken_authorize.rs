@@ -0,0 +1,214 @@
+use crate::Err;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use serde_with::skip_serializing_none;
+use strum_macros::{AsRefStr, Display, EnumIter};
+
+use crate::models::{
+    model::Model,
+    transactions::{
+        clear_txn_flag, get_common_fields_error, set_txn_flag, CommonFields, Flag, FlagBits, Memo,
+        Signer, Transaction, TransactionType, TxnCommonFields,
+    },
+};
+
+use crate::models::amount::XRPAmount;
+
+/// Transactions of the MPTokenAuthorize type support additional values
+/// in the Flags field. This enum represents those options.
+///
+/// See MPTokenAuthorize flags:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/mptokenauthorize#mptokenauthorize-flags>`
+#[derive(
+    Debug, Eq, PartialEq, Clone, Serialize_repr, Deserialize_repr, Display, AsRefStr, EnumIter,
+)]
+#[repr(u32)]
+pub enum MPTokenAuthorizeFlag {
+    /// If set by a holder, the holder no longer wants to hold the
+    /// token. If set by the issuer, the holder is unauthorized.
+    TfMPTUnauthorize = 0x00000001,
+}
+
+impl FlagBits for MPTokenAuthorizeFlag {
+    fn bits(&self) -> u32 {
+        self.clone() as u32
+    }
+}
+
+/// The MPTokenAuthorize transaction is used by a holder to opt in to
+/// hold a Multi-Purpose Token, or by the issuer to authorize a holder
+/// of an issuance requiring authorization.
+///
+/// See MPTokenAuthorize:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/mptokenauthorize>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MPTokenAuthorize<'a> {
+    /// The type of transaction.
+    #[serde(default = "TransactionType::mptoken_authorize")]
+    pub transaction_type: TransactionType,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, Vec<MPTokenAuthorizeFlag>>,
+    /// The custom fields for the MPTokenAuthorize model.
+    ///
+    /// See MPTokenAuthorize fields:
+    /// `<https://xrpl.org/docs/references/protocol/transactions/types/mptokenauthorize#mptokenauthorize-fields>`
+    #[serde(rename = "MPTokenIssuanceID")]
+    #[serde(borrow)]
+    pub mptoken_issuance_id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub holder: Option<Cow<'a, str>>,
+}
+
+impl<'a> Default for MPTokenAuthorize<'a> {
+    fn default() -> Self {
+        Self {
+            transaction_type: TransactionType::MPTokenAuthorize,
+            common_fields: Default::default(),
+            mptoken_issuance_id: Default::default(),
+            holder: Default::default(),
+        }
+    }
+}
+
+impl<'a> Model for MPTokenAuthorize<'a> {
+    fn get_errors(&self) -> Result<()> {
+        match get_common_fields_error(&self.common_fields) {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
+
+impl<'a> Transaction for MPTokenAuthorize<'a> {
+    fn has_flag(&self, flag: &Flag) -> bool {
+        let mut flags = &Vec::new();
+
+        if let Some(flag_set) = self.common_fields.flags.as_ref() {
+            flags = flag_set;
+        }
+
+        match flag {
+            Flag::MPTokenAuthorize(mptoken_authorize_flag) => {
+                flags.contains(mptoken_authorize_flag)
+            }
+            _ => false,
+        }
+    }
+
+    fn set_flag(&mut self, flag: &Flag) {
+        if let Flag::MPTokenAuthorize(mptoken_authorize_flag) = flag {
+            set_txn_flag(
+                &mut self.common_fields.flags,
+                mptoken_authorize_flag.clone(),
+            );
+        }
+    }
+
+    fn clear_flag(&mut self, flag: &Flag) {
+        if let Flag::MPTokenAuthorize(mptoken_authorize_flag) = flag {
+            clear_txn_flag(&mut self.common_fields.flags, mptoken_authorize_flag);
+        }
+    }
+
+    fn get_transaction_type(&self) -> TransactionType {
+        self.transaction_type.clone()
+    }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
+}
+
+impl<'a> MPTokenAuthorize<'a> {
+    fn new(
+        account: &'a str,
+        mptoken_issuance_id: &'a str,
+        fee: Option<XRPAmount<'a>>,
+        sequence: Option<u32>,
+        last_ledger_sequence: Option<u32>,
+        account_txn_id: Option<&'a str>,
+        signing_pub_key: Option<&'a str>,
+        source_tag: Option<u32>,
+        ticket_sequence: Option<u32>,
+        txn_signature: Option<&'a str>,
+        flags: Option<Vec<MPTokenAuthorizeFlag>>,
+        memos: Option<Vec<Memo<'a>>>,
+        signers: Option<Vec<Signer<'a>>>,
+        holder: Option<&'a str>,
+    ) -> Self {
+        Self {
+            transaction_type: TransactionType::MPTokenAuthorize,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags,
+                memos,
+                signers,
+            },
+            mptoken_issuance_id: mptoken_issuance_id.into(),
+            holder: holder.map(Into::into),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> MPTokenAuthorize<'static> {
+        MPTokenAuthorize {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            mptoken_issuance_id: Cow::Owned(self.mptoken_issuance_id.into_owned()),
+            holder: self.holder.map(|holder| Cow::Owned(holder.into_owned())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let default_txn = MPTokenAuthorize {
+            common_fields: CommonFields {
+                account: "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                fee: Some("10".into()),
+                flags: Some(vec![MPTokenAuthorizeFlag::TfMPTUnauthorize]),
+                ..Default::default()
+            },
+            mptoken_issuance_id:
+                "00070C4495F14B0E44F78A264E41713C64B5F89242540EE255534400000000000000".into(),
+            holder: Some("rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into()),
+            ..Default::default()
+        };
+        let default_json = r#"{"TransactionType":"MPTokenAuthorize","Account":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","Fee":"10","Flags":1,"MPTokenIssuanceID":"00070C4495F14B0E44F78A264E41713C64B5F89242540EE255534400000000000000","Holder":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb"}"#;
+
+        let txn_as_string = serde_json::to_string(&default_txn).unwrap();
+        let txn_json = txn_as_string.as_str();
+
+        assert_eq!(txn_json, default_json);
+    }
+}
@@ -0,0 +1,451 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use serde_with::skip_serializing_none;
+use strum_macros::{AsRefStr, Display, EnumIter};
+
+use alloc::string::ToString;
+
+use crate::{
+    constants::{MAX_MPTOKEN_METADATA_LENGTH, MAX_MPT_AMOUNT, MAX_TRANSFER_FEE},
+    models::{
+        model::Model,
+        transactions::{
+            clear_txn_flag, get_common_fields_error, set_txn_flag, CommonFields, Flag, FlagBits,
+            Memo, Signer, Transaction, TransactionType, TxnCommonFields,
+        },
+    },
+    Err,
+};
+
+use crate::models::amount::XRPAmount;
+use crate::models::transactions::{XRPLMPTokenIssuanceCreateException, XRPLTransactionException};
+use crate::utils::hex::from_hex;
+
+/// The documentation of the `MPTokenIssuanceCreate` transaction,
+/// linked by its errors.
+const RESOURCE: &str =
+    "https://xrpl.org/docs/references/protocol/transactions/types/mptokenissuancecreate";
+
+/// Transactions of the MPTokenIssuanceCreate type support additional values
+/// in the Flags field. This enum represents those options.
+///
+/// See MPTokenIssuanceCreate flags:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/mptokenissuancecreate#mptokenissuancecreate-flags>`
+#[derive(
+    Debug, Eq, PartialEq, Clone, Serialize_repr, Deserialize_repr, Display, AsRefStr, EnumIter,
+)]
+#[repr(u32)]
+pub enum MPTokenIssuanceCreateFlag {
+    /// The issuer can lock the balances of the token, individually
+    /// or globally.
+    TfMPTCanLock = 0x00000002,
+    /// Individual holders must be authorized by the issuer
+    /// to hold the token.
+    TfMPTRequireAuth = 0x00000004,
+    /// Holders can place their balances into an escrow.
+    TfMPTCanEscrow = 0x00000008,
+    /// Holders can trade their balances using the decentralized exchange.
+    TfMPTCanTrade = 0x00000010,
+    /// Tokens can be transferred to accounts other than the issuer.
+    TfMPTCanTransfer = 0x00000020,
+    /// The issuer can claw back tokens from holders.
+    TfMPTCanClawback = 0x00000040,
+}
+
+impl FlagBits for MPTokenIssuanceCreateFlag {
+    fn bits(&self) -> u32 {
+        self.clone() as u32
+    }
+}
+
+/// The MPTokenIssuanceCreate transaction creates a new
+/// Multi-Purpose Token issuance.
+///
+/// See MPTokenIssuanceCreate:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/mptokenissuancecreate>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MPTokenIssuanceCreate<'a> {
+    /// The type of transaction.
+    #[serde(default = "TransactionType::mptoken_issuance_create")]
+    pub transaction_type: TransactionType,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, Vec<MPTokenIssuanceCreateFlag>>,
+    /// The custom fields for the MPTokenIssuanceCreate model.
+    ///
+    /// See MPTokenIssuanceCreate fields:
+    /// `<https://xrpl.org/docs/references/protocol/transactions/types/mptokenissuancecreate#mptokenissuancecreate-fields>`
+    pub asset_scale: Option<u8>,
+    pub transfer_fee: Option<u32>,
+    #[serde(borrow)]
+    pub maximum_amount: Option<Cow<'a, str>>,
+    #[serde(rename = "MPTokenMetadata")]
+    #[serde(borrow)]
+    pub mptoken_metadata: Option<Cow<'a, str>>,
+}
+
+impl<'a> Default for MPTokenIssuanceCreate<'a> {
+    fn default() -> Self {
+        Self {
+            transaction_type: TransactionType::MPTokenIssuanceCreate,
+            common_fields: Default::default(),
+            asset_scale: Default::default(),
+            transfer_fee: Default::default(),
+            maximum_amount: Default::default(),
+            mptoken_metadata: Default::default(),
+        }
+    }
+}
+
+impl<'a: 'static> Model for MPTokenIssuanceCreate<'a> {
+    fn get_errors(&self) -> Result<()> {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self._get_maximum_amount_error()?;
+            self._get_transfer_fee_error()?;
+            self._get_mptoken_metadata_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
+
+impl<'a> Transaction for MPTokenIssuanceCreate<'a> {
+    fn has_flag(&self, flag: &Flag) -> bool {
+        let mut flags = &Vec::new();
+
+        if let Some(flag_set) = self.common_fields.flags.as_ref() {
+            flags = flag_set;
+        }
+
+        match flag {
+            Flag::MPTokenIssuanceCreate(mptoken_issuance_create_flag) => {
+                flags.contains(mptoken_issuance_create_flag)
+            }
+            _ => false,
+        }
+    }
+
+    fn set_flag(&mut self, flag: &Flag) {
+        if let Flag::MPTokenIssuanceCreate(mptoken_issuance_create_flag) = flag {
+            set_txn_flag(
+                &mut self.common_fields.flags,
+                mptoken_issuance_create_flag.clone(),
+            );
+        }
+    }
+
+    fn clear_flag(&mut self, flag: &Flag) {
+        if let Flag::MPTokenIssuanceCreate(mptoken_issuance_create_flag) = flag {
+            clear_txn_flag(&mut self.common_fields.flags, mptoken_issuance_create_flag);
+        }
+    }
+
+    fn get_transaction_type(&self) -> TransactionType {
+        self.transaction_type.clone()
+    }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
+}
+
+impl<'a> MPTokenIssuanceCreateError for MPTokenIssuanceCreate<'a> {
+    fn _get_maximum_amount_error(&self) -> Result<(), XRPLMPTokenIssuanceCreateException<'_>> {
+        if let Some(maximum_amount) = self.maximum_amount.as_deref() {
+            match maximum_amount.parse::<u64>() {
+                Ok(amount) if amount > MAX_MPT_AMOUNT => {
+                    Err(XRPLMPTokenIssuanceCreateException::ValueTooHigh {
+                        field: "maximum_amount",
+                        max: MAX_MPT_AMOUNT,
+                        found: amount,
+                        resource: RESOURCE,
+                    })
+                }
+                Ok(_amount) => Ok(()),
+                Err(_parse_error) => Err(XRPLMPTokenIssuanceCreateException::InvalidValueFormat {
+                    field: "maximum_amount",
+                    format: "unsigned integer string",
+                    found: maximum_amount,
+                    resource: RESOURCE,
+                }),
+            }
+        } else {
+            Ok(())
+        }
+    }
+
+    fn _get_transfer_fee_error(&self) -> Result<(), XRPLMPTokenIssuanceCreateException<'_>> {
+        if let Some(transfer_fee) = self.transfer_fee {
+            if transfer_fee > MAX_TRANSFER_FEE {
+                Err(XRPLMPTokenIssuanceCreateException::ValueTooHigh {
+                    field: "transfer_fee",
+                    max: MAX_TRANSFER_FEE.into(),
+                    found: transfer_fee.into(),
+                    resource: RESOURCE,
+                })
+            } else if transfer_fee > 0
+                && !self.has_flag(&Flag::MPTokenIssuanceCreate(
+                    MPTokenIssuanceCreateFlag::TfMPTCanTransfer,
+                ))
+            {
+                Err(XRPLMPTokenIssuanceCreateException::FieldRequiresFlag {
+                    field: "transfer_fee",
+                    flag: MPTokenIssuanceCreateFlag::TfMPTCanTransfer,
+                    resource: RESOURCE,
+                })
+            } else {
+                Ok(())
+            }
+        } else {
+            Ok(())
+        }
+    }
+
+    fn _get_mptoken_metadata_error(&self) -> Result<(), XRPLMPTokenIssuanceCreateException<'_>> {
+        if let Some(mptoken_metadata) = self.mptoken_metadata.as_deref() {
+            if from_hex(mptoken_metadata).is_err() {
+                Err(XRPLMPTokenIssuanceCreateException::InvalidValueFormat {
+                    field: "mptoken_metadata",
+                    format: "hex",
+                    found: mptoken_metadata,
+                    resource: RESOURCE,
+                })
+            } else if mptoken_metadata.len() / 2 > MAX_MPTOKEN_METADATA_LENGTH {
+                Err(XRPLMPTokenIssuanceCreateException::ValueTooLong {
+                    field: "mptoken_metadata",
+                    max: MAX_MPTOKEN_METADATA_LENGTH,
+                    found: mptoken_metadata.len() / 2,
+                    resource: RESOURCE,
+                })
+            } else {
+                Ok(())
+            }
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'a> MPTokenIssuanceCreate<'a> {
+    fn new(
+        account: &'a str,
+        fee: Option<XRPAmount<'a>>,
+        sequence: Option<u32>,
+        last_ledger_sequence: Option<u32>,
+        account_txn_id: Option<&'a str>,
+        signing_pub_key: Option<&'a str>,
+        source_tag: Option<u32>,
+        ticket_sequence: Option<u32>,
+        txn_signature: Option<&'a str>,
+        flags: Option<Vec<MPTokenIssuanceCreateFlag>>,
+        memos: Option<Vec<Memo<'a>>>,
+        signers: Option<Vec<Signer<'a>>>,
+        asset_scale: Option<u8>,
+        transfer_fee: Option<u32>,
+        maximum_amount: Option<&'a str>,
+        mptoken_metadata: Option<&'a str>,
+    ) -> Self {
+        Self {
+            transaction_type: TransactionType::MPTokenIssuanceCreate,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags,
+                memos,
+                signers,
+            },
+            asset_scale,
+            transfer_fee,
+            maximum_amount: maximum_amount.map(Into::into),
+            mptoken_metadata: mptoken_metadata.map(Into::into),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> MPTokenIssuanceCreate<'static> {
+        MPTokenIssuanceCreate {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            asset_scale: self.asset_scale,
+            transfer_fee: self.transfer_fee,
+            maximum_amount: self
+                .maximum_amount
+                .map(|maximum_amount| Cow::Owned(maximum_amount.into_owned())),
+            mptoken_metadata: self
+                .mptoken_metadata
+                .map(|mptoken_metadata| Cow::Owned(mptoken_metadata.into_owned())),
+        }
+    }
+}
+
+pub trait MPTokenIssuanceCreateError {
+    fn _get_maximum_amount_error(&self) -> Result<(), XRPLMPTokenIssuanceCreateException<'_>>;
+    fn _get_transfer_fee_error(&self) -> Result<(), XRPLMPTokenIssuanceCreateException<'_>>;
+    fn _get_mptoken_metadata_error(&self) -> Result<(), XRPLMPTokenIssuanceCreateException<'_>>;
+}
+
+#[cfg(test)]
+mod test_mptoken_issuance_create_error {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use crate::models::Model;
+
+    use super::*;
+
+    #[test]
+    fn test_maximum_amount_error() {
+        let mut mptoken_issuance_create = MPTokenIssuanceCreate {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            maximum_amount: Some("-1".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            mptoken_issuance_create.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `maximum_amount` does not have the correct format (expected unsigned integer string, found -1). For more information see: https://xrpl.org/docs/references/protocol/transactions/types/mptokenissuancecreate"
+        );
+
+        mptoken_issuance_create.maximum_amount = Some("9223372036854775808".into());
+
+        assert_eq!(
+            mptoken_issuance_create.validate().unwrap_err().to_string().as_str(),
+            "The field `maximum_amount` exceeds its maximum value (max 9223372036854775807, found 9223372036854775808). For more information see: https://xrpl.org/docs/references/protocol/transactions/types/mptokenissuancecreate"
+        );
+
+        mptoken_issuance_create.maximum_amount = Some("9223372036854775807".into());
+
+        assert!(mptoken_issuance_create.validate().is_ok());
+    }
+
+    #[test]
+    fn test_transfer_fee_error() {
+        let mut mptoken_issuance_create = MPTokenIssuanceCreate {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            transfer_fee: Some(314),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            mptoken_issuance_create.validate().unwrap_err().to_string().as_str(),
+            "For the field `transfer_fee` to be defined it is required to set the flag `TfMPTCanTransfer`. For more information see: https://xrpl.org/docs/references/protocol/transactions/types/mptokenissuancecreate"
+        );
+
+        mptoken_issuance_create.common_fields.flags =
+            Some(vec![MPTokenIssuanceCreateFlag::TfMPTCanTransfer]);
+        mptoken_issuance_create.transfer_fee = Some(50001);
+
+        assert_eq!(
+            mptoken_issuance_create.validate().unwrap_err().to_string().as_str(),
+            "The field `transfer_fee` exceeds its maximum value (max 50000, found 50001). For more information see: https://xrpl.org/docs/references/protocol/transactions/types/mptokenissuancecreate"
+        );
+    }
+
+    #[test]
+    fn test_mptoken_metadata_error() {
+        let mptoken_issuance_create = MPTokenIssuanceCreate {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            mptoken_metadata: Some("not hex".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            mptoken_issuance_create.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `mptoken_metadata` does not have the correct format (expected hex, found not hex). For more information see: https://xrpl.org/docs/references/protocol/transactions/types/mptokenissuancecreate"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let default_txn = MPTokenIssuanceCreate {
+            common_fields: CommonFields {
+                account: "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                fee: Some("10".into()),
+                flags: Some(vec![
+                    MPTokenIssuanceCreateFlag::TfMPTCanTransfer,
+                    MPTokenIssuanceCreateFlag::TfMPTCanTrade,
+                ]),
+                ..Default::default()
+            },
+            asset_scale: Some(2),
+            transfer_fee: Some(314),
+            maximum_amount: Some("50000000".into()),
+            mptoken_metadata: Some("464F4F".into()),
+            ..Default::default()
+        };
+        let default_json = r#"{"TransactionType":"MPTokenIssuanceCreate","Account":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","Fee":"10","Flags":48,"AssetScale":2,"TransferFee":314,"MaximumAmount":"50000000","MPTokenMetadata":"464F4F"}"#;
+
+        let txn_as_string = serde_json::to_string(&default_txn).unwrap();
+        let txn_json = txn_as_string.as_str();
+
+        assert_eq!(txn_json, default_json);
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let default_txn = MPTokenIssuanceCreate {
+            common_fields: CommonFields {
+                account: "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                fee: Some("10".into()),
+                flags: Some(vec![
+                    MPTokenIssuanceCreateFlag::TfMPTCanTrade,
+                    MPTokenIssuanceCreateFlag::TfMPTCanTransfer,
+                ]),
+                ..Default::default()
+            },
+            asset_scale: Some(2),
+            transfer_fee: Some(314),
+            maximum_amount: Some("50000000".into()),
+            mptoken_metadata: Some("464F4F".into()),
+            ..Default::default()
+        };
+        let default_json = r#"{"TransactionType":"MPTokenIssuanceCreate","Account":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","MaximumAmount":"50000000","AssetScale":2,"TransferFee":314,"Flags":48,"Fee":"10","MPTokenMetadata":"464F4F"}"#;
+
+        let txn_as_obj: MPTokenIssuanceCreate = serde_json::from_str(default_json).unwrap();
+
+        assert_eq!(txn_as_obj, default_txn);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::Err;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{
+    model::Model,
+    transactions::{
+        get_common_fields_error, CommonFields, Memo, Signer, Transaction, TransactionType,
+        TxnCommonFields,
+    },
+};
+
+use crate::models::amount::XRPAmount;
+
+/// The MPTokenIssuanceDestroy transaction deletes a Multi-Purpose
+/// Token issuance. It is only allowed if no holder owns any tokens
+/// of the issuance.
+///
+/// See MPTokenIssuanceDestroy:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/mptokenissuancedestroy>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MPTokenIssuanceDestroy<'a> {
+    /// The type of transaction.
+    #[serde(default = "TransactionType::mptoken_issuance_destroy")]
+    pub transaction_type: TransactionType,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
+    /// The custom fields for the MPTokenIssuanceDestroy model.
+    ///
+    /// See MPTokenIssuanceDestroy fields:
+    /// `<https://xrpl.org/docs/references/protocol/transactions/types/mptokenissuancedestroy#mptokenissuancedestroy-fields>`
+    #[serde(rename = "MPTokenIssuanceID")]
+    #[serde(borrow)]
+    pub mptoken_issuance_id: Cow<'a, str>,
+}
+
+impl<'a> Default for MPTokenIssuanceDestroy<'a> {
+    fn default() -> Self {
+        Self {
+            transaction_type: TransactionType::MPTokenIssuanceDestroy,
+            common_fields: Default::default(),
+            mptoken_issuance_id: Default::default(),
+        }
+    }
+}
+
+impl<'a> Model for MPTokenIssuanceDestroy<'a> {
+    fn get_errors(&self) -> Result<()> {
+        match get_common_fields_error(&self.common_fields) {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
+
+impl<'a> Transaction for MPTokenIssuanceDestroy<'a> {
+    fn get_transaction_type(&self) -> TransactionType {
+        self.transaction_type.clone()
+    }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
+}
+
+impl<'a> MPTokenIssuanceDestroy<'a> {
+    fn new(
+        account: &'a str,
+        mptoken_issuance_id: &'a str,
+        fee: Option<XRPAmount<'a>>,
+        sequence: Option<u32>,
+        last_ledger_sequence: Option<u32>,
+        account_txn_id: Option<&'a str>,
+        signing_pub_key: Option<&'a str>,
+        source_tag: Option<u32>,
+        ticket_sequence: Option<u32>,
+        txn_signature: Option<&'a str>,
+        flags: Option<u32>,
+        memos: Option<Vec<Memo<'a>>>,
+        signers: Option<Vec<Signer<'a>>>,
+    ) -> Self {
+        Self {
+            transaction_type: TransactionType::MPTokenIssuanceDestroy,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags,
+                memos,
+                signers,
+            },
+            mptoken_issuance_id: mptoken_issuance_id.into(),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> MPTokenIssuanceDestroy<'static> {
+        MPTokenIssuanceDestroy {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            mptoken_issuance_id: Cow::Owned(self.mptoken_issuance_id.into_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let default_txn = MPTokenIssuanceDestroy {
+            common_fields: CommonFields {
+                account: "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                fee: Some("10".into()),
+                ..Default::default()
+            },
+            mptoken_issuance_id:
+                "00070C4495F14B0E44F78A264E41713C64B5F89242540EE255534400000000000000".into(),
+            ..Default::default()
+        };
+        let default_json = r#"{"TransactionType":"MPTokenIssuanceDestroy","Account":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","Fee":"10","MPTokenIssuanceID":"00070C4495F14B0E44F78A264E41713C64B5F89242540EE255534400000000000000"}"#;
+
+        let txn_as_string = serde_json::to_string(&default_txn).unwrap();
+        let txn_json = txn_as_string.as_str();
+
+        assert_eq!(txn_json, default_json);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::Err;
+use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use anyhow::Result;
 use core::convert::TryInto;
@@ -11,12 +12,20 @@ use alloc::string::ToString;
 use crate::models::amount::exceptions::XRPLAmountException;
 use crate::models::amount::XRPAmount;
 use crate::models::transactions::XRPLNFTokenAcceptOfferException;
+use crate::models::transactions::XRPLTransactionException;
 use crate::models::{
     amount::Amount,
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_amounts_error, get_common_fields_error, CommonFields, Memo, Signer, Transaction,
+        TransactionType, TxnCommonFields,
+    },
 };
 
+/// The documentation of the `NFTokenAcceptOffer` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/nftokenacceptoffer.html";
+
 /// Accept offers to buy or sell an NFToken.
 ///
 /// See NFTokenAcceptOffer:
@@ -25,71 +34,26 @@ use crate::models::{
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct NFTokenAcceptOffer<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::nftoken_accept_offer")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     /// The custom fields for the NFTokenAcceptOffer model.
     ///
     /// See NFTokenAcceptOffer fields:
     /// `<https://xrpl.org/nftokenacceptoffer.html#nftokenacceptoffer-fields>`
     #[serde(rename = "NFTokenSellOffer")]
-    pub nftoken_sell_offer: Option<&'a str>,
+    #[serde(borrow)]
+    pub nftoken_sell_offer: Option<Cow<'a, str>>,
     #[serde(rename = "NFTokenBuyOffer")]
-    pub nftoken_buy_offer: Option<&'a str>,
+    #[serde(borrow)]
+    pub nftoken_buy_offer: Option<Cow<'a, str>>,
     #[serde(rename = "NFTokenBrokerFee")]
     pub nftoken_broker_fee: Option<Amount<'a>>,
 }
@@ -98,18 +62,7 @@ impl<'a> Default for NFTokenAcceptOffer<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::NFTokenAcceptOffer,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             nftoken_sell_offer: Default::default(),
             nftoken_buy_offer: Default::default(),
             nftoken_broker_fee: Default::default(),
@@ -119,12 +72,17 @@ impl<'a> Default for NFTokenAcceptOffer<'a> {
 
 impl<'a: 'static> Model for NFTokenAcceptOffer<'a> {
     fn get_errors(&self) -> Result<()> {
-        match self._get_brokered_mode_error() {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            get_amounts_error(&[self.nftoken_broker_fee.as_ref()])?;
+            self._get_brokered_mode_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
             Err(error) => Err!(error),
-            Ok(_no_error) => match self._get_nftoken_broker_fee_error() {
-                Err(error) => Err!(error),
-                Ok(_no_error) => Ok(()),
-            },
+            Ok(_no_error) => self._get_nftoken_broker_fee_error(),
         }
     }
 }
@@ -133,6 +91,14 @@ impl<'a> Transaction for NFTokenAcceptOffer<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> NFTokenAcceptOfferError for NFTokenAcceptOffer<'a> {
@@ -144,7 +110,7 @@ impl<'a> NFTokenAcceptOfferError for NFTokenAcceptOffer<'a> {
             Err(XRPLNFTokenAcceptOfferException::DefineOneOf {
                 field1: "nftoken_sell_offer",
                 field2: "nftoken_buy_offer",
-                resource: "",
+                resource: RESOURCE,
             })
         } else {
             Ok(())
@@ -159,7 +125,7 @@ impl<'a> NFTokenAcceptOfferError for NFTokenAcceptOffer<'a> {
                     if nftoken_broker_fee_dec.is_zero() {
                         Err!(XRPLNFTokenAcceptOfferException::ValueZero {
                             field: "nftoken_broker_fee",
-                            resource: "",
+                            resource: RESOURCE,
                         })
                     } else {
                         Ok(())
@@ -171,9 +137,49 @@ impl<'a> NFTokenAcceptOfferError for NFTokenAcceptOffer<'a> {
             Ok(())
         }
     }
+
+    fn _get_broker_fee_currency_error(
+        &self,
+        sell_offer_amount: &Amount,
+        buy_offer_amount: &Amount,
+    ) -> Result<(), XRPLNFTokenAcceptOfferException<'_>> {
+        if let Some(nftoken_broker_fee) = &self.nftoken_broker_fee {
+            if !nftoken_broker_fee.is_same_currency(sell_offer_amount) {
+                return Err(XRPLNFTokenAcceptOfferException::CurrencyMismatch {
+                    field1: "nftoken_broker_fee",
+                    field2: "nftoken_sell_offer",
+                    resource: RESOURCE,
+                });
+            }
+            if !nftoken_broker_fee.is_same_currency(buy_offer_amount) {
+                return Err(XRPLNFTokenAcceptOfferException::CurrencyMismatch {
+                    field1: "nftoken_broker_fee",
+                    field2: "nftoken_buy_offer",
+                    resource: RESOURCE,
+                });
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a> NFTokenAcceptOffer<'a> {
+    /// Checks that the `nftoken_broker_fee` is denominated in the same
+    /// currency as the brokered offers. The offers are only referenced
+    /// by their IDs, so their amounts have to be looked up by the caller
+    /// (for example with a `LedgerEntry` request) and passed in.
+    pub fn validate_broker_fee_currency(
+        &self,
+        sell_offer_amount: &Amount,
+        buy_offer_amount: &Amount,
+    ) -> Result<()> {
+        match self._get_broker_fee_currency_error(sell_offer_amount, buy_offer_amount) {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+
     fn new(
         account: &'a str,
         fee: Option<XRPAmount<'a>>,
@@ -192,28 +198,51 @@ impl<'a> NFTokenAcceptOffer<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::NFTokenAcceptOffer,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
-            nftoken_sell_offer,
-            nftoken_buy_offer,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
+            nftoken_sell_offer: nftoken_sell_offer.map(Into::into),
+            nftoken_buy_offer: nftoken_buy_offer.map(Into::into),
             nftoken_broker_fee,
         }
     }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> NFTokenAcceptOffer<'static> {
+        NFTokenAcceptOffer {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            nftoken_sell_offer: self
+                .nftoken_sell_offer
+                .map(|nftoken_sell_offer| Cow::Owned(nftoken_sell_offer.into_owned())),
+            nftoken_buy_offer: self
+                .nftoken_buy_offer
+                .map(|nftoken_buy_offer| Cow::Owned(nftoken_buy_offer.into_owned())),
+            nftoken_broker_fee: self.nftoken_broker_fee.map(Amount::into_owned),
+        }
+    }
 }
 
 pub trait NFTokenAcceptOfferError {
     fn _get_brokered_mode_error(&self) -> Result<(), XRPLNFTokenAcceptOfferException>;
     fn _get_nftoken_broker_fee_error(&self) -> Result<()>;
+    fn _get_broker_fee_currency_error(
+        &self,
+        sell_offer_amount: &Amount,
+        buy_offer_amount: &Amount,
+    ) -> Result<(), XRPLNFTokenAcceptOfferException<'_>>;
 }
 
 #[cfg(test)]
@@ -222,7 +251,7 @@ mod test_nftoken_accept_offer_error {
     use alloc::string::ToString;
 
     use crate::models::{
-        amount::{Amount, XRPAmount},
+        amount::{Amount, IssuedCurrencyAmount, XRPAmount},
         Model,
     };
 
@@ -232,18 +261,20 @@ mod test_nftoken_accept_offer_error {
     fn test_brokered_mode_error() {
         let nftoken_accept_offer = NFTokenAcceptOffer {
             transaction_type: TransactionType::NFTokenAcceptOffer,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             nftoken_sell_offer: None,
             nftoken_buy_offer: None,
             nftoken_broker_fee: Some(Amount::XRPAmount(XRPAmount::from("100"))),
@@ -251,7 +282,7 @@ mod test_nftoken_accept_offer_error {
 
         assert_eq!(
             nftoken_accept_offer.validate().unwrap_err().to_string().as_str(),
-            "Define at least one of the fields `nftoken_sell_offer` and `nftoken_buy_offer`. For more information see: "
+            "Define at least one of the fields `nftoken_sell_offer` and `nftoken_buy_offer`. For more information see: https://xrpl.org/nftokenacceptoffer.html"
         );
     }
 
@@ -259,28 +290,91 @@ mod test_nftoken_accept_offer_error {
     fn test_broker_fee_error() {
         let nftoken_accept_offer = NFTokenAcceptOffer {
             transaction_type: TransactionType::NFTokenAcceptOffer,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
-            nftoken_sell_offer: Some(""),
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
+            nftoken_sell_offer: Some("".into()),
             nftoken_buy_offer: None,
             nftoken_broker_fee: Some(Amount::XRPAmount(XRPAmount::from("0"))),
         };
 
         assert_eq!(
             nftoken_accept_offer.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `nftoken_broker_fee` is not allowed to be zero. For more information see: "
+            "The value of the field `nftoken_broker_fee` is not allowed to be zero. For more information see: https://xrpl.org/nftokenacceptoffer.html"
         );
     }
+
+    #[test]
+    fn test_broker_fee_currency_error() {
+        let usd = |issuer: &'static str| {
+            Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                issuer.into(),
+                "10".into(),
+            ))
+        };
+        let xrp = Amount::XRPAmount(XRPAmount::from("1000000"));
+        let nftoken_accept_offer = NFTokenAcceptOffer {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            nftoken_sell_offer: Some(
+                "68CD1F6F906494EA08C9CB5CAFA64DFA90D4E834B7151899B73231DE5A0C3B77".into(),
+            ),
+            nftoken_buy_offer: Some(
+                "C8D3CDE8A24A0E33B1E4D6AC7E6BD2BBD4CDF5EC7E5A6A5E3F4C2C1B0A9F8E7D".into(),
+            ),
+            nftoken_broker_fee: Some(usd("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")),
+            ..Default::default()
+        };
+
+        assert!(nftoken_accept_offer
+            .validate_broker_fee_currency(
+                &usd("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+                &usd("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")
+            )
+            .is_ok());
+        assert_eq!(
+            nftoken_accept_offer
+                .validate_broker_fee_currency(&xrp, &usd("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"))
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The currency of the field `nftoken_broker_fee` has to match the currency of `nftoken_sell_offer`. For more information see: https://xrpl.org/nftokenacceptoffer.html"
+        );
+        assert_eq!(
+            nftoken_accept_offer
+                .validate_broker_fee_currency(
+                    &usd("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+                    &usd("rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK")
+                )
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The currency of the field `nftoken_broker_fee` has to match the currency of `nftoken_buy_offer`. For more information see: https://xrpl.org/nftokenacceptoffer.html"
+        );
+
+        let without_broker_fee = NFTokenAcceptOffer {
+            nftoken_broker_fee: None,
+            ..nftoken_accept_offer
+        };
+
+        assert!(without_broker_fee
+            .validate_broker_fee_currency(&xrp, &usd("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"))
+            .is_ok());
+    }
 }
 
 #[cfg(test)]
@@ -302,7 +396,10 @@ mod test_serde {
             None,
             None,
             Some(vec![Memo::new(
-                Some("61356534373538372D633134322D346663382D616466362D393666383562356435386437"),
+                Some(
+                    "61356534373538372D633134322D346663382D616466362D393666383562356435386437"
+                        .into(),
+                ),
                 None,
                 None,
             )]),
@@ -332,7 +429,10 @@ mod test_serde {
             None,
             None,
             Some(vec![Memo::new(
-                Some("61356534373538372D633134322D346663382D616466362D393666383562356435386437"),
+                Some(
+                    "61356534373538372D633134322D346663382D616466362D393666383562356435386437"
+                        .into(),
+                ),
                 None,
                 None,
             )]),
@@ -1,12 +1,28 @@
+use crate::Err;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
 use alloc::vec::Vec;
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::core::addresscodec::is_valid_classic_address;
 use crate::models::amount::XRPAmount;
 use crate::models::{
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_common_fields_error, CommonFields, Memo, Signer, Transaction, TransactionType,
+        TxnCommonFields, XRPLNFTokenBurnException, XRPLTransactionException,
+    },
 };
+use crate::utils::hex::from_hex;
+
+/// The documentation of the `NFTokenBurn` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/nftokenburn.html";
+
+/// The length of an `NFTokenID` in bytes.
+const NFTOKEN_ID_LENGTH: usize = 32;
 
 /// Removes a NFToken object from the NFTokenPage in which it is being held,
 /// effectively removing the token from the ledger (burning it).
@@ -17,100 +33,104 @@ use crate::models::{
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct NFTokenBurn<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::nftoken_burn")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     /// The custom fields for the NFTokenBurn model.
     ///
     /// See NFTokenBurn fields:
     /// `<https://xrpl.org/nftokenburn.html#nftokenburn-fields>`
     #[serde(rename = "NFTokenID")]
-    pub nftoken_id: &'a str,
-    pub owner: Option<&'a str>,
+    #[serde(borrow)]
+    pub nftoken_id: Cow<'a, str>,
+    /// The owner of the NFToken, if it is burned by its issuer
+    /// or an authorized minter rather than its owner. Must be a
+    /// classic address other than the `account`; the owner of
+    /// an NFToken burns it by leaving this field undefined.
+    #[serde(borrow)]
+    pub owner: Option<Cow<'a, str>>,
 }
 
 impl<'a> Default for NFTokenBurn<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::NFTokenBurn,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             nftoken_id: Default::default(),
             owner: Default::default(),
         }
     }
 }
 
-impl<'a> Model for NFTokenBurn<'a> {}
+impl<'a: 'static> Model for NFTokenBurn<'a> {
+    fn get_errors(&self) -> Result<()> {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self._get_nftoken_id_error()?;
+            self._get_owner_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
 
 impl<'a> Transaction for NFTokenBurn<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
+}
+
+impl<'a> NFTokenBurnError for NFTokenBurn<'a> {
+    fn _get_nftoken_id_error(&self) -> Result<(), XRPLNFTokenBurnException<'_>> {
+        match from_hex(&*self.nftoken_id) {
+            Ok(bytes) if bytes.len() == NFTOKEN_ID_LENGTH => Ok(()),
+            _ => Err(XRPLNFTokenBurnException::InvalidValueFormat {
+                field: "nftoken_id",
+                format: "32 byte hex",
+                found: &self.nftoken_id,
+                resource: RESOURCE,
+            }),
+        }
+    }
+
+    fn _get_owner_error(&self) -> Result<(), XRPLNFTokenBurnException<'_>> {
+        match self.owner.as_deref() {
+            Some(owner) if !is_valid_classic_address(owner) => {
+                Err(XRPLNFTokenBurnException::InvalidAddress {
+                    field: "owner",
+                    resource: RESOURCE,
+                })
+            }
+            Some(owner) if owner == self.common_fields.account => {
+                Err(XRPLNFTokenBurnException::ValueEqualsValue {
+                    field1: "owner",
+                    field2: "account",
+                    resource: RESOURCE,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 impl<'a> NFTokenBurn<'a> {
@@ -131,24 +151,102 @@ impl<'a> NFTokenBurn<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::NFTokenBurn,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
-            nftoken_id,
-            owner,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
+            nftoken_id: nftoken_id.into(),
+            owner: owner.map(Into::into),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> NFTokenBurn<'static> {
+        NFTokenBurn {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            nftoken_id: Cow::Owned(self.nftoken_id.into_owned()),
+            owner: self.owner.map(|owner| Cow::Owned(owner.into_owned())),
         }
     }
 }
 
+pub trait NFTokenBurnError {
+    fn _get_nftoken_id_error(&self) -> Result<(), XRPLNFTokenBurnException<'_>>;
+    fn _get_owner_error(&self) -> Result<(), XRPLNFTokenBurnException<'_>>;
+}
+
+#[cfg(test)]
+mod test_nftoken_burn_error {
+    use alloc::string::ToString;
+
+    use crate::models::Model;
+
+    use super::*;
+
+    const NFTOKEN_ID: &str = "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65";
+
+    fn nftoken_burn(nftoken_id: &'static str, owner: Option<&'static str>) -> NFTokenBurn<'static> {
+        NFTokenBurn {
+            common_fields: CommonFields {
+                account: "rNCFjv8Ek5oDrNiMJ3pw6eLLFtMjZLJnf2".into(),
+                ..Default::default()
+            },
+            nftoken_id: nftoken_id.into(),
+            owner: owner.map(Into::into),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_nftoken_id_error() {
+        assert_eq!(
+            nftoken_burn("000B013A95F14B00", None)
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The value of the field `nftoken_id` does not have the correct format (expected 32 byte hex, found 000B013A95F14B00). For more information see: https://xrpl.org/nftokenburn.html"
+        );
+    }
+
+    #[test]
+    fn test_owner_error() {
+        assert!(
+            nftoken_burn(NFTOKEN_ID, Some("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"))
+                .validate()
+                .is_ok()
+        );
+        assert_eq!(
+            nftoken_burn(NFTOKEN_ID, Some("rNCFjv8Ek5oDrNiMJ3pw6eLLFtMjZLJnf2"))
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The value of the field `owner` is not allowed to be the same as the value of the field `account`. For more information see: https://xrpl.org/nftokenburn.html"
+        );
+        assert_eq!(
+            nftoken_burn(NFTOKEN_ID, Some("rInvalid"))
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The field `owner` is not a valid classic address. For more information see: https://xrpl.org/nftokenburn.html"
+        );
+    }
+}
+
 #[cfg(test)]
 mod test_serde {
     use super::*;
@@ -1,4 +1,5 @@
 use crate::Err;
+use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -8,11 +9,19 @@ use alloc::string::ToString;
 
 use crate::models::amount::XRPAmount;
 use crate::models::transactions::XRPLNFTokenCancelOfferException;
+use crate::models::transactions::XRPLTransactionException;
 use crate::models::{
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_common_fields_error, CommonFields, Memo, Signer, Transaction, TransactionType,
+        TxnCommonFields,
+    },
 };
 
+/// The documentation of the `NFTokenCancelOffer` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/nftokencanceloffer.html";
+
 /// Cancels existing token offers created using NFTokenCreateOffer.
 ///
 /// See NFTokenCancelOffer:
@@ -21,63 +30,16 @@ use crate::models::{
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct NFTokenCancelOffer<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::nftoken_cancel_offer")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     /// The custom fields for the NFTokenCancelOffer model.
     ///
     /// See NFTokenCancelOffer fields:
@@ -85,25 +47,14 @@ pub struct NFTokenCancelOffer<'a> {
     /// Lifetime issue
     #[serde(borrow)]
     #[serde(rename = "NFTokenOffers")]
-    pub nftoken_offers: Vec<&'a str>,
+    pub nftoken_offers: Vec<Cow<'a, str>>,
 }
 
 impl<'a> Default for NFTokenCancelOffer<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::NFTokenCancelOffer,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             nftoken_offers: Default::default(),
         }
     }
@@ -111,9 +62,16 @@ impl<'a> Default for NFTokenCancelOffer<'a> {
 
 impl<'a: 'static> Model for NFTokenCancelOffer<'a> {
     fn get_errors(&self) -> Result<()> {
-        match self._get_nftoken_offers_error() {
-            Ok(_) => Ok(()),
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self._get_nftoken_offers_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
             Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
         }
     }
 }
@@ -122,6 +80,14 @@ impl<'a> Transaction for NFTokenCancelOffer<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> NFTokenCancelOfferError for NFTokenCancelOffer<'a> {
@@ -130,7 +96,7 @@ impl<'a> NFTokenCancelOfferError for NFTokenCancelOffer<'a> {
             Err(XRPLNFTokenCancelOfferException::CollectionEmpty {
                 field: "nftoken_offers",
                 r#type: stringify!(Vec),
-                resource: "",
+                resource: RESOURCE,
             })
         } else {
             Ok(())
@@ -155,19 +121,35 @@ impl<'a> NFTokenCancelOffer<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::NFTokenCancelOffer,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
-            nftoken_offers,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
+            nftoken_offers: nftoken_offers.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> NFTokenCancelOffer<'static> {
+        NFTokenCancelOffer {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            nftoken_offers: self
+                .nftoken_offers
+                .into_iter()
+                .map(|nftoken_offer| Cow::Owned(nftoken_offer.into_owned()))
+                .collect(),
         }
     }
 }
@@ -189,24 +171,26 @@ mod test_nftoken_cancel_offer_error {
     fn test_nftoken_offer_error() {
         let nftoken_cancel_offer = NFTokenCancelOffer {
             transaction_type: TransactionType::NFTokenCancelOffer,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             nftoken_offers: Vec::new(),
         };
 
         assert_eq!(
             nftoken_cancel_offer.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `nftoken_offers` is not allowed to be empty (type `Vec`). If the field is optional, define it to be `None`. For more information see: "
+            "The value of the field `nftoken_offers` is not allowed to be empty (type `Vec`). If the field is optional, define it to be `None`. For more information see: https://xrpl.org/nftokencanceloffer.html"
         );
     }
 }
@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use anyhow::Result;
 use core::convert::TryInto;
@@ -9,16 +10,26 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 
 use alloc::string::ToString;
 
+use crate::models::transactions::{
+    FlagBits, XRPLTransactionException, XRPLTransactionFieldException,
+};
 use crate::models::{
     model::Model,
-    transactions::{Flag, Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        clear_txn_flag, get_amounts_error, get_common_fields_error, get_destination_error,
+        set_txn_flag, CommonFields, Flag, Memo, Signer, Transaction, TransactionType,
+        TxnCommonFields,
+    },
 };
 
-use crate::Err;
-use crate::_serde::txn_flags;
 use crate::models::amount::exceptions::XRPLAmountException;
 use crate::models::amount::{Amount, XRPAmount};
 use crate::models::transactions::XRPLNFTokenCreateOfferException;
+use crate::Err;
+
+/// The documentation of the `NFTokenCreateOffer` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/nftokencreateoffer.html";
 
 /// Transactions of the NFTokenCreateOffer type support additional values
 /// in the Flags field. This enum represents those options.
@@ -35,6 +46,12 @@ pub enum NFTokenCreateOfferFlag {
     TfSellOffer = 0x00000001,
 }
 
+impl FlagBits for NFTokenCreateOfferFlag {
+    fn bits(&self) -> u32 {
+        self.clone() as u32
+    }
+}
+
 /// Creates either a new Sell offer for an NFToken owned by
 /// the account executing the transaction, or a new Buy
 /// offer for an NFToken owned by another account.
@@ -45,93 +62,36 @@ pub enum NFTokenCreateOfferFlag {
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct NFTokenCreateOffer<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::nftoken_create_offer")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    #[serde(default)]
-    #[serde(with = "txn_flags")]
-    pub flags: Option<Vec<NFTokenCreateOfferFlag>>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, Vec<NFTokenCreateOfferFlag>>,
     /// The custom fields for the NFTokenCreateOffer model.
     ///
     /// See NFTokenCreateOffer fields:
     /// `<https://xrpl.org/nftokencreateoffer.html#nftokencreateoffer-fields>`
     #[serde(rename = "NFTokenID")]
-    pub nftoken_id: &'a str,
+    #[serde(borrow)]
+    pub nftoken_id: Cow<'a, str>,
     pub amount: Amount<'a>,
-    pub owner: Option<&'a str>,
+    #[serde(borrow)]
+    pub owner: Option<Cow<'a, str>>,
     pub expiration: Option<u32>,
-    pub destination: Option<&'a str>,
+    #[serde(borrow)]
+    pub destination: Option<Cow<'a, str>>,
 }
 
 impl<'a> Default for NFTokenCreateOffer<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::NFTokenCreateOffer,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             nftoken_id: Default::default(),
             amount: Default::default(),
             owner: Default::default(),
@@ -143,14 +103,20 @@ impl<'a> Default for NFTokenCreateOffer<'a> {
 
 impl<'a: 'static> Model for NFTokenCreateOffer<'a> {
     fn get_errors(&self) -> Result<()> {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            get_amounts_error(&[Some(&self.amount)])?;
+            self._get_destination_error()?;
+            self._get_owner_error()?;
+
+            Ok(())
+        };
+
         match self._get_amount_error() {
-            Err(error) => Err!(error),
-            Ok(_no_error) => match self._get_destination_error() {
+            Err(error) => Err(error),
+            Ok(_no_error) => match errors() {
                 Err(error) => Err!(error),
-                Ok(_no_error) => match self._get_owner_error() {
-                    Err(error) => Err!(error),
-                    Ok(_no_error) => Ok(()),
-                },
+                Ok(_no_error) => Ok(()),
             },
         }
     }
@@ -160,7 +126,7 @@ impl<'a> Transaction for NFTokenCreateOffer<'a> {
     fn has_flag(&self, flag: &Flag) -> bool {
         let mut flags = &Vec::new();
 
-        if let Some(flag_set) = self.flags.as_ref() {
+        if let Some(flag_set) = self.common_fields.flags.as_ref() {
             flags = flag_set;
         }
 
@@ -176,9 +142,32 @@ impl<'a> Transaction for NFTokenCreateOffer<'a> {
         }
     }
 
+    fn set_flag(&mut self, flag: &Flag) {
+        if let Flag::NFTokenCreateOffer(nftoken_create_offer_flag) = flag {
+            set_txn_flag(
+                &mut self.common_fields.flags,
+                nftoken_create_offer_flag.clone(),
+            );
+        }
+    }
+
+    fn clear_flag(&mut self, flag: &Flag) {
+        if let Flag::NFTokenCreateOffer(nftoken_create_offer_flag) = flag {
+            clear_txn_flag(&mut self.common_fields.flags, nftoken_create_offer_flag);
+        }
+    }
+
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> NFTokenCreateOfferError for NFTokenCreateOffer<'a> {
@@ -193,7 +182,7 @@ impl<'a> NFTokenCreateOfferError for NFTokenCreateOffer<'a> {
                 {
                     Err!(XRPLNFTokenCreateOfferException::ValueZero {
                         field: "amount",
-                        resource: "",
+                        resource: RESOURCE,
                     })
                 } else {
                     Ok(())
@@ -205,37 +194,30 @@ impl<'a> NFTokenCreateOfferError for NFTokenCreateOffer<'a> {
         }
     }
 
-    fn _get_destination_error(&self) -> Result<(), XRPLNFTokenCreateOfferException> {
-        if let Some(destination) = self.destination {
-            if destination == self.account {
-                Err(XRPLNFTokenCreateOfferException::ValueEqualsValue {
-                    field1: "destination",
-                    field2: "account",
-                    resource: "",
-                })
-            } else {
-                Ok(())
+    fn _get_destination_error(&self) -> Result<(), XRPLTransactionFieldException<'_>> {
+        match self.destination.as_deref() {
+            Some(destination) => {
+                get_destination_error(&self.common_fields.account, destination, RESOURCE)
             }
-        } else {
-            Ok(())
+            None => Ok(()),
         }
     }
 
     fn _get_owner_error(&self) -> Result<(), XRPLNFTokenCreateOfferException> {
-        if let Some(owner) = self.owner {
+        if let Some(owner) = self.owner.as_deref() {
             if self.has_flag(&Flag::NFTokenCreateOffer(
                 NFTokenCreateOfferFlag::TfSellOffer,
             )) {
                 Err(XRPLNFTokenCreateOfferException::IllegalOption {
                     field: "owner",
                     context: "NFToken sell offers",
-                    resource: "",
+                    resource: RESOURCE,
                 })
-            } else if owner == self.account {
+            } else if owner == self.common_fields.account {
                 Err(XRPLNFTokenCreateOfferException::ValueEqualsValue {
                     field1: "owner",
                     field2: "account",
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else {
                 Ok(())
@@ -246,7 +228,7 @@ impl<'a> NFTokenCreateOfferError for NFTokenCreateOffer<'a> {
             Err(XRPLNFTokenCreateOfferException::OptionRequired {
                 field: "owner",
                 context: "NFToken buy offers",
-                resource: "",
+                resource: RESOURCE,
             })
         } else {
             Ok(())
@@ -276,30 +258,48 @@ impl<'a> NFTokenCreateOffer<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::NFTokenCreateOffer,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags,
-            memos,
-            signers,
-            nftoken_id,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags,
+                memos,
+                signers,
+            },
+            nftoken_id: nftoken_id.into(),
             amount,
-            owner,
+            owner: owner.map(Into::into),
             expiration,
-            destination,
+            destination: destination.map(Into::into),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> NFTokenCreateOffer<'static> {
+        NFTokenCreateOffer {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            nftoken_id: Cow::Owned(self.nftoken_id.into_owned()),
+            amount: self.amount.into_owned(),
+            owner: self.owner.map(|owner| Cow::Owned(owner.into_owned())),
+            expiration: self.expiration,
+            destination: self
+                .destination
+                .map(|destination| Cow::Owned(destination.into_owned())),
         }
     }
 }
 
 pub trait NFTokenCreateOfferError {
     fn _get_amount_error(&self) -> Result<()>;
-    fn _get_destination_error(&self) -> Result<(), XRPLNFTokenCreateOfferException>;
+    fn _get_destination_error(&self) -> Result<(), XRPLTransactionFieldException<'_>>;
     fn _get_owner_error(&self) -> Result<(), XRPLNFTokenCreateOfferException>;
 }
 
@@ -319,19 +319,21 @@ mod test_nftoken_create_offer_error {
     fn test_amount_error() {
         let nftoken_create_offer = NFTokenCreateOffer {
             transaction_type: TransactionType::NFTokenCreateOffer,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
-            nftoken_id: "",
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
+            nftoken_id: "".into(),
             amount: Amount::XRPAmount(XRPAmount::from("0")),
             owner: None,
             expiration: None,
@@ -344,7 +346,7 @@ mod test_nftoken_create_offer_error {
                 .unwrap_err()
                 .to_string()
                 .as_str(),
-            "The value of the field `amount` is not allowed to be zero. For more information see: "
+            "The value of the field `amount` is not allowed to be zero. For more information see: https://xrpl.org/nftokencreateoffer.html"
         );
     }
 
@@ -352,28 +354,30 @@ mod test_nftoken_create_offer_error {
     fn test_destination_error() {
         let nftoken_create_offer = NFTokenCreateOffer {
             transaction_type: TransactionType::NFTokenCreateOffer,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
-            nftoken_id: "",
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
+            nftoken_id: "".into(),
             amount: Amount::XRPAmount(XRPAmount::from("1")),
             owner: None,
             expiration: None,
-            destination: Some("rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb"),
+            destination: Some("rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into()),
         };
 
         assert_eq!(
             nftoken_create_offer.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `destination` is not allowed to be the same as the value of the field `account`. For more information see: "
+            "The value of the field `destination` is not allowed to be the same as the value of the field `account`. For more information see: https://xrpl.org/nftokencreateoffer.html"
         );
     }
 
@@ -381,45 +385,47 @@ mod test_nftoken_create_offer_error {
     fn test_owner_error() {
         let mut nftoken_create_offer = NFTokenCreateOffer {
             transaction_type: TransactionType::NFTokenCreateOffer,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
-            nftoken_id: "",
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
+            nftoken_id: "".into(),
             amount: Amount::XRPAmount(XRPAmount::from("1")),
-            owner: Some("rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK"),
+            owner: Some("rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into()),
             expiration: None,
             destination: None,
         };
         let sell_flag = vec![NFTokenCreateOfferFlag::TfSellOffer];
-        nftoken_create_offer.flags = Some(sell_flag);
+        nftoken_create_offer.common_fields.flags = Some(sell_flag);
 
         assert_eq!(
             nftoken_create_offer.validate().unwrap_err().to_string().as_str(),
-            "The optional field `owner` is not allowed to be defined for NFToken sell offers. For more information see: "
+            "The optional field `owner` is not allowed to be defined for NFToken sell offers. For more information see: https://xrpl.org/nftokencreateoffer.html"
         );
 
-        nftoken_create_offer.flags = None;
+        nftoken_create_offer.common_fields.flags = None;
         nftoken_create_offer.owner = None;
 
         assert_eq!(
             nftoken_create_offer.validate().unwrap_err().to_string().as_str(),
-            "The optional field `owner` is required to be defined for NFToken buy offers. For more information see: "
+            "The optional field `owner` is required to be defined for NFToken buy offers. For more information see: https://xrpl.org/nftokencreateoffer.html"
         );
 
-        nftoken_create_offer.owner = Some("rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb");
+        nftoken_create_offer.owner = Some("rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into());
 
         assert_eq!(
             nftoken_create_offer.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `owner` is not allowed to be the same as the value of the field `account`. For more information see: "
+            "The value of the field `owner` is not allowed to be the same as the value of the field `account`. For more information see: https://xrpl.org/nftokencreateoffer.html"
         );
     }
 }
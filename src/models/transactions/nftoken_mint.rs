@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -11,14 +12,20 @@ use crate::{
     constants::{MAX_TRANSFER_FEE, MAX_URI_LENGTH},
     models::{
         model::Model,
-        transactions::{Flag, Memo, Signer, Transaction, TransactionType},
+        transactions::{
+            clear_txn_flag, get_common_fields_error, set_txn_flag, CommonFields, Flag, FlagBits,
+            Memo, Signer, Transaction, TransactionType, TxnCommonFields,
+        },
     },
     Err,
 };
 
-use crate::_serde::txn_flags;
 use crate::models::amount::XRPAmount;
-use crate::models::transactions::XRPLNFTokenMintException;
+use crate::models::transactions::{XRPLNFTokenMintException, XRPLTransactionException};
+
+/// The documentation of the `NFTokenMint` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/nftokenmint.html";
 
 /// Transactions of the NFTokenMint type support additional values
 /// in the Flags field. This enum represents those options.
@@ -40,6 +47,15 @@ pub enum NFTokenMintFlag {
     /// The minted NFToken can be transferred to others. If this flag is not
     /// enabled, the token can still be transferred from or to the issuer.
     TfTransferable = 0x00000008,
+    /// The URI of the minted NFToken can be updated with an
+    /// `NFTokenModify` transaction. Requires the DynamicNFT amendment.
+    TfMutable = 0x00000010,
+}
+
+impl FlagBits for NFTokenMintFlag {
+    fn bits(&self) -> u32 {
+        self.clone() as u32
+    }
 }
 
 /// The NFTokenMint transaction creates a non-fungible token and adds it to
@@ -51,93 +67,35 @@ pub enum NFTokenMintFlag {
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct NFTokenMint<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::nftoken_mint")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    #[serde(default)]
-    #[serde(with = "txn_flags")]
-    pub flags: Option<Vec<NFTokenMintFlag>>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, Vec<NFTokenMintFlag>>,
     /// The custom fields for the NFTokenMint model.
     ///
     /// See NFTokenMint fields:
     /// `<https://xrpl.org/nftokenmint.html#nftokenmint-fields>`
     #[serde(rename = "NFTokenTaxon")]
     pub nftoken_taxon: u32,
-    pub issuer: Option<&'a str>,
+    #[serde(borrow)]
+    pub issuer: Option<Cow<'a, str>>,
     pub transfer_fee: Option<u32>,
     #[serde(rename = "URI")]
-    pub uri: Option<&'a str>,
+    #[serde(borrow)]
+    pub uri: Option<Cow<'a, str>>,
 }
 
 impl<'a> Default for NFTokenMint<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::NFTokenMint,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             nftoken_taxon: Default::default(),
             issuer: Default::default(),
             transfer_fee: Default::default(),
@@ -148,15 +106,18 @@ impl<'a> Default for NFTokenMint<'a> {
 
 impl<'a: 'static> Model for NFTokenMint<'a> {
     fn get_errors(&self) -> Result<()> {
-        match self._get_issuer_error() {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self._get_issuer_error()?;
+            self._get_transfer_fee_error()?;
+            self._get_uri_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
             Err(error) => Err!(error),
-            Ok(_no_error) => match self._get_transfer_fee_error() {
-                Err(error) => Err!(error),
-                Ok(_no_error) => match self._get_uri_error() {
-                    Err(error) => Err!(error),
-                    Ok(_no_error) => Ok(()),
-                },
-            },
+            Ok(_no_error) => Ok(()),
         }
     }
 }
@@ -165,7 +126,7 @@ impl<'a> Transaction for NFTokenMint<'a> {
     fn has_flag(&self, flag: &Flag) -> bool {
         let mut flags = &Vec::new();
 
-        if let Some(flag_set) = self.flags.as_ref() {
+        if let Some(flag_set) = self.common_fields.flags.as_ref() {
             flags = flag_set;
         }
 
@@ -174,24 +135,45 @@ impl<'a> Transaction for NFTokenMint<'a> {
                 NFTokenMintFlag::TfBurnable => flags.contains(&NFTokenMintFlag::TfBurnable),
                 NFTokenMintFlag::TfOnlyXRP => flags.contains(&NFTokenMintFlag::TfOnlyXRP),
                 NFTokenMintFlag::TfTransferable => flags.contains(&NFTokenMintFlag::TfTransferable),
+                NFTokenMintFlag::TfMutable => flags.contains(&NFTokenMintFlag::TfMutable),
             },
             _ => false,
         }
     }
 
+    fn set_flag(&mut self, flag: &Flag) {
+        if let Flag::NFTokenMint(nftoken_mint_flag) = flag {
+            set_txn_flag(&mut self.common_fields.flags, nftoken_mint_flag.clone());
+        }
+    }
+
+    fn clear_flag(&mut self, flag: &Flag) {
+        if let Flag::NFTokenMint(nftoken_mint_flag) = flag {
+            clear_txn_flag(&mut self.common_fields.flags, nftoken_mint_flag);
+        }
+    }
+
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> NFTokenMintError for NFTokenMint<'a> {
     fn _get_issuer_error(&self) -> Result<(), XRPLNFTokenMintException> {
-        if let Some(issuer) = self.issuer {
-            if issuer == self.account {
+        if let Some(issuer) = self.issuer.as_deref() {
+            if issuer == self.common_fields.account {
                 Err(XRPLNFTokenMintException::ValueEqualsValue {
                     field1: "issuer",
                     field2: "account",
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else {
                 Ok(())
@@ -208,7 +190,7 @@ impl<'a> NFTokenMintError for NFTokenMint<'a> {
                     field: "transfer_fee",
                     max: MAX_TRANSFER_FEE,
                     found: transfer_fee,
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else {
                 Ok(())
@@ -219,13 +201,13 @@ impl<'a> NFTokenMintError for NFTokenMint<'a> {
     }
 
     fn _get_uri_error(&self) -> Result<(), XRPLNFTokenMintException> {
-        if let Some(uri) = self.uri {
+        if let Some(uri) = self.uri.as_deref() {
             if uri.len() > MAX_URI_LENGTH {
                 Err(XRPLNFTokenMintException::ValueTooLong {
                     field: "uri",
                     max: MAX_URI_LENGTH,
                     found: uri.len(),
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else {
                 Ok(())
@@ -257,22 +239,37 @@ impl<'a> NFTokenMint<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::NFTokenMint,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags,
-            memos,
-            signers,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags,
+                memos,
+                signers,
+            },
             nftoken_taxon,
-            issuer,
+            issuer: issuer.map(Into::into),
             transfer_fee,
-            uri,
+            uri: uri.map(Into::into),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> NFTokenMint<'static> {
+        NFTokenMint {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            nftoken_taxon: self.nftoken_taxon,
+            issuer: self.issuer.map(|issuer| Cow::Owned(issuer.into_owned())),
+            transfer_fee: self.transfer_fee,
+            uri: self.uri.map(|uri| Cow::Owned(uri.into_owned())),
         }
     }
 }
@@ -295,27 +292,29 @@ mod test_nftoken_mint_error {
     fn test_issuer_error() {
         let nftoken_mint = NFTokenMint {
             transaction_type: TransactionType::NFTokenMint,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             nftoken_taxon: 0,
-            issuer: Some("rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb"),
+            issuer: Some("rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into()),
             transfer_fee: None,
             uri: None,
         };
 
         assert_eq!(
             nftoken_mint.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `issuer` is not allowed to be the same as the value of the field `account`. For more information see: "
+            "The value of the field `issuer` is not allowed to be the same as the value of the field `account`. For more information see: https://xrpl.org/nftokenmint.html"
         );
     }
 
@@ -323,18 +322,20 @@ mod test_nftoken_mint_error {
     fn test_transfer_fee_error() {
         let nftoken_mint = NFTokenMint {
             transaction_type: TransactionType::NFTokenMint,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             nftoken_taxon: 0,
             issuer: None,
             transfer_fee: Some(50001),
@@ -343,7 +344,7 @@ mod test_nftoken_mint_error {
 
         assert_eq!(
             nftoken_mint.validate().unwrap_err().to_string().as_str(),
-            "The field `transfer_fee` exceeds its maximum value (max 50000, found 50001). For more information see: "
+            "The field `transfer_fee` exceeds its maximum value (max 50000, found 50001). For more information see: https://xrpl.org/nftokenmint.html"
         );
     }
 
@@ -351,27 +352,29 @@ mod test_nftoken_mint_error {
     fn test_uri_error() {
         let nftoken_mint = NFTokenMint {
             transaction_type: TransactionType::NFTokenMint,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             nftoken_taxon: 0,
             issuer: None,
             transfer_fee: None,
-            uri: Some("wss://xrplcluster.com/aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
+            uri: Some("wss://xrplcluster.com/aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".into()),
         };
 
         assert_eq!(
             nftoken_mint.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `uri` exceeds its maximum length of characters (max 512, found 513). For more information see: "
+            "The value of the field `uri` exceeds its maximum length of characters (max 512, found 513). For more information see: https://xrpl.org/nftokenmint.html"
         );
     }
 }
@@ -396,7 +399,7 @@ mod test_serde {
             None,
             None,
             Some(vec![NFTokenMintFlag::TfTransferable]),
-            Some(vec![Memo::new(Some("72656E74"), None, Some("687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963"))]),
+            Some(vec![Memo::new(Some("72656E74".into()), None, Some("687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963".into()))]),
             None,
             None,
             Some(314),
@@ -424,7 +427,7 @@ mod test_serde {
             None,
             None,
             Some(vec![NFTokenMintFlag::TfTransferable]),
-            Some(vec![Memo::new(Some("72656E74"), None, Some("687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963"))]),
+            Some(vec![Memo::new(Some("72656E74".into()), None, Some("687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963".into()))]),
             None,
             None,
             Some(314),
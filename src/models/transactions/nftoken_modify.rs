@@ -0,0 +1,368 @@
+use crate::Err;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::constants::MAX_URI_LENGTH;
+use crate::models::amount::XRPAmount;
+use crate::models::{
+    model::Model,
+    transactions::{
+        get_common_fields_error, CommonFields, Memo, NFTokenMintFlag, Signer, Transaction,
+        TransactionType, TxnCommonFields, XRPLNFTokenModifyException, XRPLTransactionException,
+    },
+};
+use crate::utils::hex::from_hex;
+
+/// The documentation of the `NFTokenModify` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/docs/references/protocol/transactions/types/nftokenmodify";
+
+/// The length of an `NFTokenID` in bytes.
+const NFTOKEN_ID_LENGTH: usize = 32;
+
+/// Updates the URI of an NFToken minted with the `TfMutable`
+/// flag. Requires the DynamicNFT amendment.
+///
+/// See NFTokenModify:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/nftokenmodify>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct NFTokenModify<'a> {
+    /// The type of transaction.
+    #[serde(default = "TransactionType::nftoken_modify")]
+    pub transaction_type: TransactionType,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
+    /// The custom fields for the NFTokenModify model.
+    ///
+    /// See NFTokenModify fields:
+    /// `<https://xrpl.org/docs/references/protocol/transactions/types/nftokenmodify#nftokenmodify-fields>`
+    #[serde(rename = "NFTokenID")]
+    #[serde(borrow)]
+    pub nftoken_id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub owner: Option<Cow<'a, str>>,
+    /// The new URI of the NFToken, in hexadecimal. The URI is
+    /// removed if it is not defined.
+    #[serde(rename = "URI")]
+    #[serde(borrow)]
+    pub uri: Option<Cow<'a, str>>,
+}
+
+impl<'a> Default for NFTokenModify<'a> {
+    fn default() -> Self {
+        Self {
+            transaction_type: TransactionType::NFTokenModify,
+            common_fields: Default::default(),
+            nftoken_id: Default::default(),
+            owner: Default::default(),
+            uri: Default::default(),
+        }
+    }
+}
+
+impl<'a: 'static> Model for NFTokenModify<'a> {
+    fn get_errors(&self) -> Result<()> {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self._get_nftoken_id_error()?;
+            self._get_owner_error()?;
+            self._get_uri_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
+
+impl<'a> Transaction for NFTokenModify<'a> {
+    fn get_transaction_type(&self) -> TransactionType {
+        self.transaction_type.clone()
+    }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
+}
+
+impl<'a> NFTokenModifyError for NFTokenModify<'a> {
+    /// The flags the NFToken was minted with are encoded into the
+    /// first two bytes of its `NFTokenID`.
+    fn _get_nftoken_id_error(&self) -> Result<(), XRPLNFTokenModifyException<'_>> {
+        match from_hex(&*self.nftoken_id) {
+            Ok(bytes) if bytes.len() == NFTOKEN_ID_LENGTH => {
+                let flags = u16::from_be_bytes([bytes[0], bytes[1]]);
+                if flags & NFTokenMintFlag::TfMutable as u16 == 0 {
+                    Err(XRPLNFTokenModifyException::NFTokenRequiresFlag {
+                        nftoken_id: &self.nftoken_id,
+                        flag: NFTokenMintFlag::TfMutable,
+                        resource: RESOURCE,
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+            _ => Err(XRPLNFTokenModifyException::InvalidValueFormat {
+                field: "nftoken_id",
+                format: "32 byte hex",
+                found: &self.nftoken_id,
+                resource: RESOURCE,
+            }),
+        }
+    }
+
+    fn _get_owner_error(&self) -> Result<(), XRPLNFTokenModifyException<'_>> {
+        if self.owner.as_deref() == Some(&self.common_fields.account) {
+            Err(XRPLNFTokenModifyException::ValueEqualsValue {
+                field1: "owner",
+                field2: "account",
+                resource: RESOURCE,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn _get_uri_error(&self) -> Result<(), XRPLNFTokenModifyException<'_>> {
+        if let Some(uri) = self.uri.as_deref() {
+            if uri.is_empty() {
+                Err(XRPLNFTokenModifyException::ValueEmpty {
+                    field: "uri",
+                    resource: RESOURCE,
+                })
+            } else if from_hex(uri).is_err() {
+                Err(XRPLNFTokenModifyException::InvalidValueFormat {
+                    field: "uri",
+                    format: "hex",
+                    found: uri,
+                    resource: RESOURCE,
+                })
+            } else if uri.len() > MAX_URI_LENGTH {
+                Err(XRPLNFTokenModifyException::ValueTooLong {
+                    field: "uri",
+                    max: MAX_URI_LENGTH,
+                    found: uri.len(),
+                    resource: RESOURCE,
+                })
+            } else {
+                Ok(())
+            }
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'a> NFTokenModify<'a> {
+    fn new(
+        account: &'a str,
+        nftoken_id: &'a str,
+        fee: Option<XRPAmount<'a>>,
+        sequence: Option<u32>,
+        last_ledger_sequence: Option<u32>,
+        account_txn_id: Option<&'a str>,
+        signing_pub_key: Option<&'a str>,
+        source_tag: Option<u32>,
+        ticket_sequence: Option<u32>,
+        txn_signature: Option<&'a str>,
+        memos: Option<Vec<Memo<'a>>>,
+        signers: Option<Vec<Signer<'a>>>,
+        owner: Option<&'a str>,
+        uri: Option<&'a str>,
+    ) -> Self {
+        Self {
+            transaction_type: TransactionType::NFTokenModify,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
+            nftoken_id: nftoken_id.into(),
+            owner: owner.map(Into::into),
+            uri: uri.map(Into::into),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> NFTokenModify<'static> {
+        NFTokenModify {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            nftoken_id: Cow::Owned(self.nftoken_id.into_owned()),
+            owner: self.owner.map(|owner| Cow::Owned(owner.into_owned())),
+            uri: self.uri.map(|uri| Cow::Owned(uri.into_owned())),
+        }
+    }
+}
+
+pub trait NFTokenModifyError {
+    fn _get_nftoken_id_error(&self) -> Result<(), XRPLNFTokenModifyException<'_>>;
+    fn _get_owner_error(&self) -> Result<(), XRPLNFTokenModifyException<'_>>;
+    fn _get_uri_error(&self) -> Result<(), XRPLNFTokenModifyException<'_>>;
+}
+
+#[cfg(test)]
+mod test_nftoken_modify_error {
+    use alloc::string::ToString;
+
+    use crate::models::Model;
+
+    use super::*;
+
+    const MUTABLE_NFTOKEN_ID: &str =
+        "00180000A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004";
+
+    fn nftoken_modify(nftoken_id: &'static str) -> NFTokenModify<'static> {
+        NFTokenModify {
+            common_fields: CommonFields {
+                account: "rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm".into(),
+                ..Default::default()
+            },
+            nftoken_id: nftoken_id.into(),
+            uri: Some("697066733A2F2F62616679".into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_nftoken_id_error() {
+        assert!(nftoken_modify(MUTABLE_NFTOKEN_ID).validate().is_ok());
+        assert_eq!(
+            nftoken_modify("00010000A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004")
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The NFToken `00010000A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004` is required to be minted with the flag `TfMutable`. For more information see: https://xrpl.org/docs/references/protocol/transactions/types/nftokenmodify"
+        );
+        assert_eq!(
+            nftoken_modify("0018").validate().unwrap_err().to_string().as_str(),
+            "The value of the field `nftoken_id` does not have the correct format (expected 32 byte hex, found 0018). For more information see: https://xrpl.org/docs/references/protocol/transactions/types/nftokenmodify"
+        );
+    }
+
+    #[test]
+    fn test_owner_error() {
+        let mut nftoken_modify = nftoken_modify(MUTABLE_NFTOKEN_ID);
+        nftoken_modify.owner = Some("rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm".into());
+
+        assert_eq!(
+            nftoken_modify.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `owner` is not allowed to be the same as the value of the field `account`. For more information see: https://xrpl.org/docs/references/protocol/transactions/types/nftokenmodify"
+        );
+    }
+
+    #[test]
+    fn test_uri_error() {
+        let mut nftoken_modify = nftoken_modify(MUTABLE_NFTOKEN_ID);
+        nftoken_modify.uri = Some("".into());
+
+        assert_eq!(
+            nftoken_modify.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `uri` is not allowed to be empty. If the field is optional, define it to be `None`. For more information see: https://xrpl.org/docs/references/protocol/transactions/types/nftokenmodify"
+        );
+
+        nftoken_modify.uri = Some("ipfs://".into());
+
+        assert_eq!(
+            nftoken_modify.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `uri` does not have the correct format (expected hex, found ipfs://). For more information see: https://xrpl.org/docs/references/protocol/transactions/types/nftokenmodify"
+        );
+
+        let uri = "AB".repeat(MAX_URI_LENGTH / 2 + 1);
+        nftoken_modify.uri = Some(uri.into());
+
+        assert_eq!(
+            nftoken_modify.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `uri` exceeds its maximum length of characters (max 512, found 514). For more information see: https://xrpl.org/docs/references/protocol/transactions/types/nftokenmodify"
+        );
+
+        nftoken_modify.uri = None;
+
+        assert!(nftoken_modify.validate().is_ok());
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let default_txn = NFTokenModify::new(
+            "rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm",
+            "00180000A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004",
+            Some("10".into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"),
+            Some("697066733A2F2F62616679"),
+        );
+        let default_json = r#"{"TransactionType":"NFTokenModify","Account":"rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm","Fee":"10","NFTokenID":"00180000A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004","Owner":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","URI":"697066733A2F2F62616679"}"#;
+
+        let txn_as_string = serde_json::to_string(&default_txn).unwrap();
+        let txn_json = txn_as_string.as_str();
+
+        assert_eq!(txn_json, default_json);
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let default_txn = NFTokenModify::new(
+            "rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm",
+            "00180000A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004",
+            Some("10".into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some("697066733A2F2F62616679"),
+        );
+        let default_json = r#"{"TransactionType":"NFTokenModify","Account":"rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm","URI":"697066733A2F2F62616679","Fee":"10","NFTokenID":"00180000A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004"}"#;
+
+        let txn_as_obj: NFTokenModify = serde_json::from_str(default_json).unwrap();
+
+        assert_eq!(txn_as_obj, default_txn);
+    }
+}
@@ -1,4 +1,7 @@
+use crate::Err;
+use alloc::string::ToString;
 use alloc::vec::Vec;
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::skip_serializing_none;
@@ -7,12 +10,21 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 use crate::models::{
     amount::Amount,
     model::Model,
-    transactions::{Flag, Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        clear_txn_flag, get_amounts_error, get_common_fields_error, set_txn_flag, CommonFields,
+        Flag, FlagBits, Memo, Signer, Transaction, TransactionType, TxnCommonFields,
+        XRPLOfferCreateException, XRPLTransactionException,
+    },
 };
 
-use crate::_serde::txn_flags;
 use crate::models::amount::XRPAmount;
 
+/// The documentation of the `OfferCreate` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/offercreate.html";
+#[cfg(feature = "ledger")]
+use crate::models::ledger::Offer;
+
 /// Transactions of the OfferCreate type support additional values
 /// in the Flags field. This enum represents those options.
 ///
@@ -44,6 +56,12 @@ pub enum OfferCreateFlag {
     TfSell = 0x00080000,
 }
 
+impl FlagBits for OfferCreateFlag {
+    fn bits(&self) -> u32 {
+        self.clone() as u32
+    }
+}
+
 /// Places an Offer in the decentralized exchange.
 ///
 /// See OfferCreate:
@@ -52,65 +70,16 @@ pub enum OfferCreateFlag {
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct OfferCreate<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::offer_create")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    #[serde(default)]
-    #[serde(with = "txn_flags")]
-    pub flags: Option<Vec<OfferCreateFlag>>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, Vec<OfferCreateFlag>>,
     /// The custom fields for the OfferCreate model.
     ///
     /// See OfferCreate fields:
@@ -125,18 +94,7 @@ impl<'a> Default for OfferCreate<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::OfferCreate,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             taker_gets: Default::default(),
             taker_pays: Default::default(),
             expiration: Default::default(),
@@ -145,13 +103,27 @@ impl<'a> Default for OfferCreate<'a> {
     }
 }
 
-impl<'a> Model for OfferCreate<'a> {}
+impl<'a> Model for OfferCreate<'a> {
+    fn get_errors(&self) -> Result<()> {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            get_amounts_error(&[Some(&self.taker_gets), Some(&self.taker_pays)])?;
+
+            Ok(())
+        };
+
+        match errors() {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
 
 impl<'a> Transaction for OfferCreate<'a> {
     fn has_flag(&self, flag: &Flag) -> bool {
         let mut flags = &Vec::new();
 
-        if let Some(flag_set) = self.flags.as_ref() {
+        if let Some(flag_set) = self.common_fields.flags.as_ref() {
             flags = flag_set;
         }
 
@@ -168,12 +140,73 @@ impl<'a> Transaction for OfferCreate<'a> {
         }
     }
 
+    fn set_flag(&mut self, flag: &Flag) {
+        if let Flag::OfferCreate(offer_create_flag) = flag {
+            set_txn_flag(&mut self.common_fields.flags, offer_create_flag.clone());
+        }
+    }
+
+    fn clear_flag(&mut self, flag: &Flag) {
+        if let Flag::OfferCreate(offer_create_flag) = flag {
+            clear_txn_flag(&mut self.common_fields.flags, offer_create_flag);
+        }
+    }
+
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
+}
+
+impl<'a> OfferCreateError for OfferCreate<'a> {
+    fn _get_expiration_error(&self, ripple_time: u32) -> Result<(), XRPLOfferCreateException<'_>> {
+        match self.expiration {
+            Some(expiration) if expiration <= ripple_time => {
+                Err(XRPLOfferCreateException::ValueTooLow {
+                    field: "expiration",
+                    min: ripple_time.saturating_add(1),
+                    found: expiration,
+                    resource: RESOURCE,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 impl<'a> OfferCreate<'a> {
+    /// Sets the `offer_sequence` so that this `OfferCreate` replaces
+    /// the given `Offer` ledger object. The `Offer` has to be owned by
+    /// the `account` of this transaction.
+    #[cfg(feature = "ledger")]
+    pub fn replace_offer(&mut self, offer: &Offer) {
+        self.offer_sequence = Some(offer.sequence);
+    }
+
+    /// Validates the model like `Model::validate`. If `strict` is set,
+    /// the `expiration` is additionally required to lie after
+    /// `ripple_time`, the current time in seconds since the Ripple Epoch.
+    /// As the crate is `no_std` the current time has to be provided by
+    /// the caller, for example by using `utils::posix_to_ripple_time`.
+    pub fn validate_expiration(&self, strict: bool, ripple_time: u32) -> Result<()> {
+        self.validate()?;
+        if !strict {
+            return Ok(());
+        }
+
+        match self._get_expiration_error(ripple_time) {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+
     fn new(
         account: &'a str,
         taker_gets: Amount<'a>,
@@ -194,24 +227,89 @@ impl<'a> OfferCreate<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::OfferCreate,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags,
-            memos,
-            signers,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags,
+                memos,
+                signers,
+            },
             taker_gets,
             taker_pays,
             expiration,
             offer_sequence,
         }
     }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> OfferCreate<'static> {
+        OfferCreate {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            taker_gets: self.taker_gets.into_owned(),
+            taker_pays: self.taker_pays.into_owned(),
+            expiration: self.expiration,
+            offer_sequence: self.offer_sequence,
+        }
+    }
+}
+
+pub trait OfferCreateError {
+    fn _get_expiration_error(&self, ripple_time: u32) -> Result<(), XRPLOfferCreateException<'_>>;
+}
+
+#[cfg(test)]
+mod test_offer_create_error {
+    use alloc::string::ToString;
+
+    use crate::models::amount::{Amount, IssuedCurrencyAmount, XRPAmount};
+
+    use super::*;
+
+    fn offer_create<'a>(expiration: Option<u32>) -> OfferCreate<'a> {
+        OfferCreate {
+            common_fields: CommonFields {
+                account: "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe".into(),
+                ..Default::default()
+            },
+            taker_gets: Amount::XRPAmount(XRPAmount::from("1000000")),
+            taker_pays: Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                "10".into(),
+            )),
+            expiration,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_expiration_error() {
+        let ripple_time = 743271000;
+        let past = offer_create(Some(ripple_time - 60));
+        let future = offer_create(Some(ripple_time + 60));
+
+        assert!(past.validate_expiration(false, ripple_time).is_ok());
+        assert_eq!(
+            past.validate_expiration(true, ripple_time)
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The value of the field `expiration` is defined below its minimum (min 743271001, found 743270940). For more information see: https://xrpl.org/offercreate.html"
+        );
+        assert!(future.validate_expiration(true, ripple_time).is_ok());
+        assert!(offer_create(None)
+            .validate_expiration(true, ripple_time)
+            .is_ok());
+    }
 }
 
 #[cfg(test)]
@@ -225,18 +323,20 @@ mod test {
     fn test_has_flag() {
         let txn: OfferCreate = OfferCreate {
             transaction_type: TransactionType::OfferCreate,
-            account: "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe",
-            fee: Some("10".into()),
-            sequence: Some(1),
-            last_ledger_sequence: Some(72779837),
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: Some(vec![OfferCreateFlag::TfImmediateOrCancel]),
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe".into(),
+                fee: Some("10".into()),
+                sequence: Some(1),
+                last_ledger_sequence: Some(72779837),
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: Some(vec![OfferCreateFlag::TfImmediateOrCancel]),
+                memos: None,
+                signers: None,
+            },
             taker_gets: Amount::XRPAmount(XRPAmount::from("1000000")),
             taker_pays: Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
                 "USD".into(),
@@ -254,18 +354,20 @@ mod test {
     fn test_get_transaction_type() {
         let txn: OfferCreate = OfferCreate {
             transaction_type: TransactionType::OfferCreate,
-            account: "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe",
-            fee: Some("10".into()),
-            sequence: Some(1),
-            last_ledger_sequence: Some(72779837),
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: Some(vec![OfferCreateFlag::TfImmediateOrCancel]),
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe".into(),
+                fee: Some("10".into()),
+                sequence: Some(1),
+                last_ledger_sequence: Some(72779837),
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: Some(vec![OfferCreateFlag::TfImmediateOrCancel]),
+                memos: None,
+                signers: None,
+            },
             taker_gets: Amount::XRPAmount(XRPAmount::from("1000000")),
             taker_pays: Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
                 "USD".into(),
@@ -281,6 +383,96 @@ mod test {
     }
 }
 
+#[cfg(test)]
+mod test_flag_bits {
+    use alloc::vec;
+
+    use super::*;
+    use crate::constants::TF_FULLY_CANONICAL_SIG;
+    use crate::models::transactions::XRPLTransactionFieldException;
+
+    #[test]
+    fn test_try_from_bits() {
+        let bits = 0x00010000 | 0x00080000;
+
+        assert_eq!(
+            OfferCreateFlag::try_from_bits(bits),
+            Ok(vec![OfferCreateFlag::TfPassive, OfferCreateFlag::TfSell])
+        );
+        assert_eq!(
+            OfferCreateFlag::try_from_bits(bits | TF_FULLY_CANONICAL_SIG),
+            Ok(vec![OfferCreateFlag::TfPassive, OfferCreateFlag::TfSell])
+        );
+        assert_eq!(OfferCreateFlag::try_from_bits(0), Ok(vec![]));
+        assert_eq!(
+            OfferCreateFlag::to_bits(&[OfferCreateFlag::TfPassive, OfferCreateFlag::TfSell]),
+            bits
+        );
+    }
+
+    #[test]
+    fn test_serialized_flags() {
+        let flags = vec![OfferCreateFlag::TfPassive, OfferCreateFlag::TfSell];
+        let offer_create = OfferCreate {
+            common_fields: CommonFields {
+                account: "rBqb89MRQJnMPq8wTwEbtz4kvxrEDfcYvt".into(),
+                flags: Some(flags.clone()),
+                ..Default::default()
+            },
+            taker_gets: Amount::XRPAmount(XRPAmount::from("80000000000")),
+            taker_pays: Amount::XRPAmount(XRPAmount::from("1")),
+            ..Default::default()
+        };
+        let fields = serde_json::to_value(&offer_create).unwrap();
+
+        assert_eq!(fields["Flags"], OfferCreateFlag::to_bits(&flags));
+        assert_eq!(
+            OfferCreateFlag::try_from_bits(fields["Flags"].as_u64().unwrap() as u32),
+            Ok(flags)
+        );
+    }
+
+    #[test]
+    fn test_unknown_bits() {
+        assert_eq!(
+            OfferCreateFlag::try_from_bits(0x00010000 | 0x00100000),
+            Err(XRPLTransactionFieldException::UnknownFlags {
+                found: 0x00100000,
+                resource: "https://xrpl.org/transaction-common-fields.html",
+            })
+        );
+    }
+}
+
+#[cfg(feature = "ledger")]
+#[cfg(test)]
+mod test_replace_offer {
+    use crate::models::amount::IssuedCurrencyAmount;
+
+    use super::*;
+
+    #[test]
+    fn test_replace_offer() {
+        let offer: Offer = serde_json::from_str(r#"{"LedgerEntryType":"Offer","Flags":131072,"index":"96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797","Account":"rBqb89MRQJnMPq8wTwEbtz4kvxrEDfcYvt","BookDirectory":"ACC27DE91DBA86FC509069EAF4BC511D73128B780F2E54BF5E07A369E2446000","BookNode":"0000000000000000","OwnerNode":"0000000000000000","PreviousTxnID":"F0AB71E777B2DA54B86231E19B82554EF1F8211F92ECA473121C655BFC5329BF","PreviousTxnLgrSeq":14524914,"Sequence":866,"TakerGets":{"currency":"XAG","issuer":"r9Dr5xwkeLegBeXq6ujinjSBLQzQ1zQGjH","value":"37"},"TakerPays":"79550000000"}"#).unwrap();
+        let mut offer_create = OfferCreate {
+            common_fields: CommonFields {
+                account: "rBqb89MRQJnMPq8wTwEbtz4kvxrEDfcYvt".into(),
+                ..Default::default()
+            },
+            taker_gets: Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "XAG".into(),
+                "r9Dr5xwkeLegBeXq6ujinjSBLQzQ1zQGjH".into(),
+                "40".into(),
+            )),
+            taker_pays: Amount::XRPAmount(XRPAmount::from("80000000000")),
+            ..Default::default()
+        };
+        offer_create.replace_offer(&offer);
+
+        assert_eq!(offer_create.offer_sequence, Some(866));
+    }
+}
+
 #[cfg(test)]
 mod test_serde {
     use crate::models::amount::{IssuedCurrencyAmount, XRPAmount};
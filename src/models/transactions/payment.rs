@@ -1,22 +1,37 @@
+use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use anyhow::Result;
+use core::convert::TryFrom;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::skip_serializing_none;
 use strum_macros::{AsRefStr, Display, EnumIter};
 
+use crate::models::transactions::XRPLTransactionException;
 use crate::models::{
     amount::Amount,
     model::Model,
-    transactions::{Flag, Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        clear_txn_flag, get_amounts_error, get_common_fields_error, get_destination_error,
+        normalize_x_address, set_txn_flag, CommonFields, Flag, FlagBits, Memo, Signer, Transaction,
+        TransactionType, TxnCommonFields, XRPLTransactionFieldException,
+    },
     PathStep,
 };
 use alloc::string::ToString;
 
-use crate::Err;
-use crate::_serde::txn_flags;
 use crate::models::amount::XRPAmount;
+
+/// The documentation of the `Payment` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/payment.html";
+/// The length of an `InvoiceID` in bytes.
+const INVOICE_ID_LENGTH: usize = 32;
+#[cfg(feature = "ledger")]
+use crate::models::ledger::AccountRootFlag;
 use crate::models::transactions::XRPLPaymentException;
+use crate::utils::hex::from_hex;
+use crate::Err;
 
 /// Transactions of the Payment type support additional values
 /// in the Flags field. This enum represents those options.
@@ -42,6 +57,12 @@ pub enum PaymentFlag {
     TfLimitQuality = 0x00040000,
 }
 
+impl FlagBits for PaymentFlag {
+    fn bits(&self) -> u32 {
+        self.clone() as u32
+    }
+}
+
 /// Transfers value from one account to another.
 ///
 /// See Payment:
@@ -49,95 +70,99 @@ pub enum PaymentFlag {
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
+#[serde(try_from = "PaymentFields<'a>", bound(deserialize = "'de: 'a"))]
 pub struct Payment<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::payment")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    #[serde(default)]
-    #[serde(with = "txn_flags")]
-    pub flags: Option<Vec<PaymentFlag>>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, Vec<PaymentFlag>>,
     /// The custom fields for the Payment model.
     ///
     /// See Payment fields:
     /// `<https://xrpl.org/payment.html#payment-fields>`
     pub amount: Amount<'a>,
-    pub destination: &'a str,
+    #[serde(borrow)]
+    pub destination: Cow<'a, str>,
+    /// Any value from 0 to 4294967295 is valid; `Some(0)` is
+    /// serialized and differs from omitting the tag with `None`.
     pub destination_tag: Option<u32>,
-    pub invoice_id: Option<u32>,
+    /// A 256-bit hash, as 64 hex characters, identifying the
+    /// reason for the payment.
+    #[serde(rename = "InvoiceID")]
+    #[serde(borrow)]
+    pub invoice_id: Option<Cow<'a, str>>,
     pub paths: Option<Vec<Vec<PathStep<'a>>>>,
     pub send_max: Option<Amount<'a>>,
     pub deliver_min: Option<Amount<'a>>,
+    /// The API v2 synonym of `amount`, returned by servers in
+    /// place of `Amount`. If it is defined, it must equal `amount`.
+    /// A payment deserialized with only `DeliverMax` takes its
+    /// `amount` from it.
+    ///
+    /// See API v2 Changes:
+    /// `<https://xrpl.org/request-formatting.html#api-versioning>`
+    pub deliver_max: Option<Amount<'a>>,
+}
+
+/// The fields of a deserialized `Payment`, whose `Amount` is
+/// missing in API v2 responses that only contain `DeliverMax`.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PaymentFields<'a> {
+    #[serde(default = "TransactionType::payment")]
+    transaction_type: TransactionType,
+    #[serde(flatten)]
+    #[serde(borrow)]
+    common_fields: CommonFields<'a, Vec<PaymentFlag>>,
+    amount: Option<Amount<'a>>,
+    #[serde(borrow)]
+    destination: Cow<'a, str>,
+    destination_tag: Option<u32>,
+    #[serde(rename = "InvoiceID")]
+    #[serde(borrow)]
+    invoice_id: Option<Cow<'a, str>>,
+    paths: Option<Vec<Vec<PathStep<'a>>>>,
+    send_max: Option<Amount<'a>>,
+    deliver_min: Option<Amount<'a>>,
+    deliver_max: Option<Amount<'a>>,
+}
+
+impl<'a> TryFrom<PaymentFields<'a>> for Payment<'a> {
+    type Error = &'static str;
+
+    fn try_from(fields: PaymentFields<'a>) -> Result<Self, Self::Error> {
+        let amount = match (fields.amount, &fields.deliver_max) {
+            (Some(amount), _) => amount,
+            (None, Some(deliver_max)) => deliver_max.clone(),
+            (None, None) => return Err("missing field `Amount`"),
+        };
+
+        Ok(Self {
+            transaction_type: fields.transaction_type,
+            common_fields: fields.common_fields,
+            amount,
+            destination: fields.destination,
+            destination_tag: fields.destination_tag,
+            invoice_id: fields.invoice_id,
+            paths: fields.paths,
+            send_max: fields.send_max,
+            deliver_min: fields.deliver_min,
+            deliver_max: fields.deliver_max,
+        })
+    }
 }
 
 impl<'a> Default for Payment<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::Payment,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             amount: Default::default(),
             destination: Default::default(),
             destination_tag: Default::default(),
@@ -145,21 +170,39 @@ impl<'a> Default for Payment<'a> {
             paths: Default::default(),
             send_max: Default::default(),
             deliver_min: Default::default(),
+            deliver_max: Default::default(),
         }
     }
 }
 
 impl<'a: 'static> Model for Payment<'a> {
     fn get_errors(&self) -> Result<()> {
-        match self._get_xrp_transaction_error() {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            get_amounts_error(&[
+                Some(&self.amount),
+                self.send_max.as_ref(),
+                self.deliver_min.as_ref(),
+                self.deliver_max.as_ref(),
+            ])?;
+            self._get_xrp_transaction_error()?;
+            // Only XRP to XRP payments can't be sent to oneself, other
+            // payments to oneself are exchanges.
+            if self.amount.is_xrp() && self.send_max.is_none() {
+                get_destination_error(&self.common_fields.account, &self.destination, RESOURCE)?;
+            }
+            self._get_partial_payment_error()?;
+            self._get_exchange_error()?;
+            self._get_mpt_payment_error()?;
+            self._get_deliver_max_error()?;
+            self._get_invoice_id_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
             Err(error) => Err!(error),
-            Ok(_no_error) => match self._get_partial_payment_error() {
-                Err(error) => Err!(error),
-                Ok(_no_error) => match self._get_exchange_error() {
-                    Err(error) => Err!(error),
-                    Ok(_no_error) => Ok(()),
-                },
-            },
+            Ok(_no_error) => Ok(()),
         }
     }
 }
@@ -168,7 +211,7 @@ impl<'a> Transaction for Payment<'a> {
     fn has_flag(&self, flag: &Flag) -> bool {
         let mut flags = &Vec::new();
 
-        if let Some(flag_set) = self.flags.as_ref() {
+        if let Some(flag_set) = self.common_fields.flags.as_ref() {
             flags = flag_set;
         }
 
@@ -182,9 +225,29 @@ impl<'a> Transaction for Payment<'a> {
         }
     }
 
+    fn set_flag(&mut self, flag: &Flag) {
+        if let Flag::Payment(payment_flag) = flag {
+            set_txn_flag(&mut self.common_fields.flags, payment_flag.clone());
+        }
+    }
+
+    fn clear_flag(&mut self, flag: &Flag) {
+        if let Flag::Payment(payment_flag) = flag {
+            clear_txn_flag(&mut self.common_fields.flags, payment_flag);
+        }
+    }
+
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> PaymentError for Payment<'a> {
@@ -194,14 +257,7 @@ impl<'a> PaymentError for Payment<'a> {
                 Err(XRPLPaymentException::IllegalOption {
                     field: "paths",
                     context: "XRP to XRP payments",
-                    resource: "",
-                })
-            } else if self.account == self.destination {
-                Err(XRPLPaymentException::ValueEqualsValueInContext {
-                    field1: "account",
-                    field2: "destination",
-                    context: "XRP to XRP Payments",
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else {
                 Ok(())
@@ -220,7 +276,7 @@ impl<'a> PaymentError for Payment<'a> {
                 Err(XRPLPaymentException::IllegalOption {
                     field: "send_max",
                     context: "XRP to XRP non-partial payments",
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else {
                 Ok(())
@@ -229,14 +285,14 @@ impl<'a> PaymentError for Payment<'a> {
             Err(XRPLPaymentException::FlagRequiresField {
                 flag: PaymentFlag::TfPartialPayment,
                 field: "send_max",
-                resource: "",
+                resource: RESOURCE,
             })
         } else if !self.has_flag(&Flag::Payment(PaymentFlag::TfPartialPayment)) {
             if let Some(_deliver_min) = &self.deliver_min {
                 Err(XRPLPaymentException::IllegalOption {
                     field: "deliver_min",
                     context: "XRP to XRP non-partial payments",
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else {
                 Ok(())
@@ -247,23 +303,163 @@ impl<'a> PaymentError for Payment<'a> {
     }
 
     fn _get_exchange_error(&self) -> Result<(), XRPLPaymentException> {
-        if self.account == self.destination && self.send_max.is_none() {
+        if self.common_fields.account == self.destination && self.send_max.is_none() {
             return Err(XRPLPaymentException::OptionRequired {
                 field: "send_max",
                 context: "exchanges",
-                resource: "",
+                resource: RESOURCE,
             });
         }
 
         Ok(())
     }
+
+    fn _get_mpt_payment_error(&self) -> Result<(), XRPLPaymentException<'_>> {
+        if self.amount.is_mpt() && self.paths.is_some() {
+            Err(XRPLPaymentException::IllegalOption {
+                field: "paths",
+                context: "MPT payments",
+                resource: RESOURCE,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn _get_deliver_max_error(&self) -> Result<(), XRPLPaymentException<'_>> {
+        match &self.deliver_max {
+            Some(deliver_max) if deliver_max != &self.amount => {
+                Err(XRPLPaymentException::ValueNotEqualsValue {
+                    field1: "deliver_max",
+                    field2: "amount",
+                    resource: RESOURCE,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn _get_invoice_id_error(&self) -> Result<(), XRPLPaymentException<'_>> {
+        match self.invoice_id.as_deref() {
+            Some(invoice_id) if !matches!(from_hex(invoice_id), Ok(bytes) if bytes.len() == INVOICE_ID_LENGTH) => {
+                Err(XRPLPaymentException::InvalidValueFormat {
+                    field: "invoice_id",
+                    format: "32 byte hex",
+                    found: invoice_id,
+                    resource: RESOURCE,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _get_destination_tag_error(
+        &self,
+        destination_flags: &[AccountRootFlag],
+    ) -> Result<(), XRPLPaymentException<'_>> {
+        if destination_flags.contains(&AccountRootFlag::LsfRequireDestTag)
+            && self.destination_tag.is_none()
+        {
+            Err(XRPLPaymentException::OptionRequired {
+                field: "destination_tag",
+                context: "destinations with `LsfRequireDestTag` set",
+                resource: RESOURCE,
+            })
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl<'a> Payment<'a> {
+    /// Checks that a `destination_tag` is defined if the destination
+    /// account has `LsfRequireDestTag` set, which would otherwise fail
+    /// with `tecDST_TAG_NEEDED`. The `destination_flags` are the `flags`
+    /// of the destination's `AccountRoot` and have to be looked up by the
+    /// caller (for example with an `AccountInfo` request) and passed in.
+    #[cfg(feature = "ledger")]
+    pub fn validate_destination_tag(&self, destination_flags: &[AccountRootFlag]) -> Result<()> {
+        match self._get_destination_tag_error(destination_flags) {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+
+    /// Replaces X-Addresses in `account` and `destination` with their
+    /// classic addresses and moves the embedded tags into `source_tag`
+    /// and `destination_tag`. Errors if a tag is already defined with a
+    /// different value. Classic addresses are left unchanged.
+    pub fn normalize_x_address(self) -> Result<Self> {
+        match self._normalize_x_address() {
+            Err(error) => Err!(error),
+            Ok(payment) => Ok(payment),
+        }
+    }
+
+    fn _normalize_x_address(mut self) -> Result<Self, XRPLTransactionFieldException<'static>> {
+        self.common_fields = self.common_fields.normalize_x_address()?;
+        let (destination, destination_tag) = normalize_x_address(
+            &self.destination,
+            self.destination_tag,
+            "destination",
+            "destination_tag",
+            RESOURCE,
+        )?;
+        self.destination = destination.into();
+        self.destination_tag = destination_tag;
+
+        Ok(self)
+    }
+
+    /// Returns the payment without borrowed data, so that it can
+    /// outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> Payment<'static> {
+        Payment {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            amount: self.amount.into_owned(),
+            destination: Cow::Owned(self.destination.into_owned()),
+            destination_tag: self.destination_tag,
+            invoice_id: self
+                .invoice_id
+                .map(|invoice_id| Cow::Owned(invoice_id.into_owned())),
+            paths: self.paths.map(|paths| {
+                paths
+                    .into_iter()
+                    .map(|path| path.into_iter().map(PathStep::into_owned).collect())
+                    .collect()
+            }),
+            send_max: self.send_max.map(Amount::into_owned),
+            deliver_min: self.deliver_min.map(Amount::into_owned),
+            deliver_max: self.deliver_max.map(Amount::into_owned),
+        }
+    }
+
+    /// Returns a payment of `refund_amount` refunding this payment:
+    /// the accounts are swapped, the refund's `destination_tag` is
+    /// this payment's `source_tag` and its `source_tag` is this
+    /// payment's `destination_tag`, so that the sender can credit
+    /// the refund to the right customer. The other fields have to
+    /// be set before signing.
+    pub fn build_refund(&self, refund_amount: Amount<'a>) -> Payment<'a> {
+        Payment {
+            common_fields: CommonFields {
+                account: self.destination.clone(),
+                source_tag: self.destination_tag,
+                ..Default::default()
+            },
+            amount: refund_amount,
+            destination: self.common_fields.account.clone(),
+            destination_tag: self.common_fields.source_tag,
+            ..Default::default()
+        }
+    }
+
     fn new(
         account: &'a str,
         amount: Amount<'a>,
-        destination: &'a str,
+        destination: Cow<'a, str>,
         fee: Option<XRPAmount<'a>>,
         sequence: Option<u32>,
         last_ledger_sequence: Option<u32>,
@@ -276,32 +472,36 @@ impl<'a> Payment<'a> {
         memos: Option<Vec<Memo<'a>>>,
         signers: Option<Vec<Signer<'a>>>,
         destination_tag: Option<u32>,
-        invoice_id: Option<u32>,
+        invoice_id: Option<&'a str>,
         paths: Option<Vec<Vec<PathStep<'a>>>>,
         send_max: Option<Amount<'a>>,
         deliver_min: Option<Amount<'a>>,
+        deliver_max: Option<Amount<'a>>,
     ) -> Self {
         Self {
             transaction_type: TransactionType::Payment,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags,
-            memos,
-            signers,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags,
+                memos,
+                signers,
+            },
             amount,
             destination,
             destination_tag,
-            invoice_id,
+            invoice_id: invoice_id.map(Into::into),
             paths,
             send_max,
             deliver_min,
+            deliver_max,
         }
     }
 }
@@ -310,6 +510,14 @@ pub trait PaymentError {
     fn _get_xrp_transaction_error(&self) -> Result<(), XRPLPaymentException>;
     fn _get_partial_payment_error(&self) -> Result<(), XRPLPaymentException>;
     fn _get_exchange_error(&self) -> Result<(), XRPLPaymentException>;
+    fn _get_mpt_payment_error(&self) -> Result<(), XRPLPaymentException<'_>>;
+    fn _get_deliver_max_error(&self) -> Result<(), XRPLPaymentException<'_>>;
+    fn _get_invoice_id_error(&self) -> Result<(), XRPLPaymentException<'_>>;
+    #[cfg(feature = "ledger")]
+    fn _get_destination_tag_error(
+        &self,
+        destination_flags: &[AccountRootFlag],
+    ) -> Result<(), XRPLPaymentException<'_>>;
 }
 
 #[cfg(test)]
@@ -318,7 +526,7 @@ mod test_payment_error {
     use alloc::vec;
 
     use crate::models::{
-        amount::{Amount, IssuedCurrencyAmount, XRPAmount},
+        amount::{Amount, IssuedCurrencyAmount, MPTAmount, XRPAmount},
         Model, PathStep,
     };
 
@@ -328,24 +536,26 @@ mod test_payment_error {
     fn test_xrp_to_xrp_error() {
         let mut payment = Payment {
             transaction_type: TransactionType::Payment,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             amount: Amount::XRPAmount(XRPAmount::from("1000000")),
-            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK",
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
             destination_tag: None,
             invoice_id: None,
             paths: Some(vec![vec![PathStep {
-                account: Some("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"),
+                account: Some("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into()),
                 currency: None,
                 issuer: None,
                 r#type: None,
@@ -353,11 +563,12 @@ mod test_payment_error {
             }]]),
             send_max: None,
             deliver_min: None,
+            deliver_max: None,
         };
 
         assert_eq!(
             payment.validate().unwrap_err().to_string().as_str(),
-            "The optional field `paths` is not allowed to be defined for XRP to XRP payments.For more information see: "
+            "The optional field `paths` is not allowed to be defined for XRP to XRP payments.For more information see: https://xrpl.org/payment.html"
         );
 
         payment.paths = None;
@@ -365,15 +576,15 @@ mod test_payment_error {
 
         assert_eq!(
             payment.validate().unwrap_err().to_string().as_str(),
-            "The optional field `send_max` is not allowed to be defined for XRP to XRP non-partial payments.For more information see: "
+            "The optional field `send_max` is not allowed to be defined for XRP to XRP non-partial payments.For more information see: https://xrpl.org/payment.html"
         );
 
         payment.send_max = None;
-        payment.destination = "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb";
+        payment.destination = "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into();
 
         assert_eq!(
             payment.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `account` is not allowed to be the same as the value of the field `destination`, for XRP to XRP Payments. For more information see: "
+            "The value of the field `destination` is not allowed to be the same as the value of the field `account`. For more information see: https://xrpl.org/payment.html"
         );
     }
 
@@ -381,39 +592,42 @@ mod test_payment_error {
     fn test_partial_payments_eror() {
         let mut payment = Payment {
             transaction_type: TransactionType::Payment,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             amount: Amount::XRPAmount("1000000".into()),
-            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK",
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
             destination_tag: None,
             invoice_id: None,
             paths: None,
             send_max: None,
             deliver_min: None,
+            deliver_max: None,
         };
-        payment.flags = Some(vec![PaymentFlag::TfPartialPayment]);
+        payment.common_fields.flags = Some(vec![PaymentFlag::TfPartialPayment]);
 
         assert_eq!(
             payment.validate().unwrap_err().to_string().as_str(),
-            "For the flag `TfPartialPayment` to be set it is required to define the field `send_max`. For more information see: "
+            "For the flag `TfPartialPayment` to be set it is required to define the field `send_max`. For more information see: https://xrpl.org/payment.html"
         );
 
-        payment.flags = None;
+        payment.common_fields.flags = None;
         payment.deliver_min = Some(Amount::XRPAmount("99999".into()));
 
         assert_eq!(
             payment.validate().unwrap_err().to_string().as_str(),
-            "The optional field `deliver_min` is not allowed to be defined for XRP to XRP non-partial payments.For more information see: "
+            "The optional field `deliver_min` is not allowed to be defined for XRP to XRP non-partial payments.For more information see: https://xrpl.org/payment.html"
         );
     }
 
@@ -421,46 +635,289 @@ mod test_payment_error {
     fn test_exchange_error() {
         let payment = Payment {
             transaction_type: TransactionType::Payment,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             amount: Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
                 "USD".into(),
                 "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
                 "10".into(),
             )),
-            destination: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
+            destination: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
             destination_tag: None,
             invoice_id: None,
             paths: None,
             send_max: None,
             deliver_min: None,
+            deliver_max: None,
+        };
+
+        assert_eq!(
+            payment.validate().unwrap_err().to_string().as_str(),
+            "The optional field `send_max` is required to be defined for exchanges. For more information see: https://xrpl.org/payment.html"
+        );
+    }
+
+    #[test]
+    fn test_mpt_payment_error() {
+        let payment = Payment::new(
+            "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
+            Amount::MPTAmount(MPTAmount::new(
+                "00002403C84A0A28E0190E208E982C352BBD5006600555CF".into(),
+                "100".into(),
+            )),
+            "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(vec![vec![PathStep {
+                account: Some("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into()),
+                ..Default::default()
+            }]]),
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(
+            payment.validate().unwrap_err().to_string().as_str(),
+            "The optional field `paths` is not allowed to be defined for MPT payments.For more information see: https://xrpl.org/payment.html"
+        );
+    }
+
+    #[test]
+    fn test_deliver_max_error() {
+        let mut payment = Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount(XRPAmount::from("1000000")),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            deliver_max: Some(Amount::XRPAmount(XRPAmount::from("1000000"))),
+            ..Default::default()
+        };
+
+        assert!(payment.validate().is_ok());
+
+        payment.deliver_max = Some(Amount::XRPAmount(XRPAmount::from("999999")));
+
+        assert_eq!(
+            payment.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `deliver_max` is required to be the same as the value of the field `amount`. For more information see: https://xrpl.org/payment.html"
+        );
+    }
+
+    #[test]
+    fn test_invoice_id_error() {
+        let payment = Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount(XRPAmount::from("1000000")),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            invoice_id: Some("6F1DFD1D".into()),
+            ..Default::default()
         };
 
         assert_eq!(
             payment.validate().unwrap_err().to_string().as_str(),
-            "The optional field `send_max` is required to be defined for exchanges. For more information see: "
+            "The value of the field `invoice_id` does not have the correct format (expected 32 byte hex, found 6F1DFD1D). For more information see: https://xrpl.org/payment.html"
         );
     }
+
+    #[cfg(feature = "ledger")]
+    #[test]
+    fn test_destination_tag_error() {
+        use crate::models::ledger::AccountRootFlag;
+
+        let mut payment = Payment::new(
+            "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
+            Amount::XRPAmount(XRPAmount::from("1000000")),
+            "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let destination_flags = vec![
+            AccountRootFlag::LsfDefaultRipple,
+            AccountRootFlag::LsfRequireDestTag,
+        ];
+
+        assert!(payment
+            .validate_destination_tag(&[AccountRootFlag::LsfDefaultRipple])
+            .is_ok());
+        assert_eq!(
+            payment
+                .validate_destination_tag(&destination_flags)
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The optional field `destination_tag` is required to be defined for destinations with `LsfRequireDestTag` set. For more information see: https://xrpl.org/payment.html"
+        );
+
+        payment.destination_tag = Some(12345);
+
+        assert!(payment.validate_destination_tag(&destination_flags).is_ok());
+    }
 }
 
 #[cfg(test)]
 mod test_serde {
+    use alloc::string::String;
     use alloc::vec;
 
-    use crate::models::amount::{Amount, IssuedCurrencyAmount};
+    use crate::core::binarycodec::{decode, encode, to_canonical_json};
+    use crate::models::amount::{Amount, IssuedCurrencyAmount, MPTAmount};
+    use crate::models::transactions::{transaction_from_json, AnyTransaction};
+    use crate::models::Model;
 
     use super::*;
 
+    #[test]
+    fn test_serialize_transaction_type() {
+        let payment = Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount(XRPAmount::from("1000000")),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        };
+        let payment_json = serde_json::to_string(&payment).unwrap();
+
+        assert!(payment_json.contains(r#""TransactionType":"Payment""#));
+        assert_eq!(
+            decode(&encode(&payment).unwrap()).unwrap()["TransactionType"],
+            "Payment"
+        );
+    }
+
+    #[test]
+    fn test_borrowed_and_owned_fields() {
+        let borrowed = Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        };
+        let owned = Payment {
+            destination: String::from("rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK").into(),
+            ..borrowed.clone()
+        };
+
+        assert_eq!(borrowed, owned);
+        assert!(owned.validate().is_ok());
+
+        let json = r#"{"TransactionType":"Payment","Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","Amount":"1000000","Destination":"rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK"}"#;
+        let deserialized: Payment = serde_json::from_str(json).unwrap();
+
+        assert!(matches!(deserialized.destination, Cow::Borrowed(_)));
+        assert_eq!(deserialized, borrowed);
+    }
+
+    #[test]
+    fn test_tags_zero() {
+        let mut payment = Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                source_tag: Some(0),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            destination_tag: Some(0),
+            ..Default::default()
+        };
+        let json = r#"{"TransactionType":"Payment","Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","SourceTag":0,"Amount":"1000000","Destination":"rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK","DestinationTag":0}"#;
+
+        assert_eq!(serde_json::to_string(&payment).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Payment>(json).unwrap(), payment);
+        assert_eq!(
+            decode(&encode(&payment).unwrap()).unwrap()["DestinationTag"],
+            0
+        );
+
+        payment.common_fields.source_tag = None;
+        payment.destination_tag = None;
+
+        assert_eq!(
+            serde_json::to_string(&payment).unwrap(),
+            r#"{"TransactionType":"Payment","Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","Amount":"1000000","Destination":"rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK"}"#
+        );
+    }
+
+    #[test]
+    fn test_serialize_common_fields() {
+        let payment = Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: Some("12".into()),
+                sequence: Some(2),
+                last_ledger_sequence: Some(7108682),
+                signing_pub_key: Some(
+                    "03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB".into(),
+                ),
+                source_tag: Some(1),
+                flags: Some(vec![PaymentFlag::TfPartialPayment]),
+                memos: Some(vec![Memo::new(
+                    Some("72656e74".into()),
+                    None,
+                    Some("687474703a2f2f6578616d706c652e636f6d2f6d656d6f2f67656e65726963".into()),
+                )]),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            destination_tag: Some(13),
+            ..Default::default()
+        };
+        let json = r#"{"TransactionType":"Payment","Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","Fee":"12","Sequence":2,"LastLedgerSequence":7108682,"SigningPubKey":"03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB","SourceTag":1,"Flags":131072,"Memos":[{"Memo":{"MemoData":"72656e74","MemoFormat":null,"MemoType":"687474703a2f2f6578616d706c652e636f6d2f6d656d6f2f67656e65726963"}}],"Amount":"1000000","Destination":"rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK","DestinationTag":13}"#;
+
+        assert_eq!(serde_json::to_string(&payment).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Payment>(json).unwrap(), payment);
+    }
+
     #[test]
     fn test_serialize() {
         let default_txn = Payment::new(
@@ -470,7 +927,7 @@ mod test_serde {
                 "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
                 "1".into(),
             )),
-            "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+            "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX".into(),
             Some("12".into()),
             Some(2),
             None,
@@ -487,6 +944,7 @@ mod test_serde {
             None,
             None,
             None,
+            None,
         );
         let default_json = r#"{"TransactionType":"Payment","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Fee":"12","Sequence":2,"Flags":131072,"Amount":{"currency":"USD","issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","value":"1"},"Destination":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX"}"#;
 
@@ -505,7 +963,7 @@ mod test_serde {
                 "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
                 "1".into(),
             )),
-            "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+            "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX".into(),
             Some("12".into()),
             Some(2),
             None,
@@ -522,6 +980,7 @@ mod test_serde {
             None,
             None,
             None,
+            None,
         );
         let default_json = r#"{"TransactionType":"Payment","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Destination":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX","Amount":{"currency":"USD","value":"1","issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"},"Fee":"12","Flags":131072,"Sequence":2}"#;
 
@@ -529,4 +988,299 @@ mod test_serde {
 
         assert_eq!(txn_as_obj, default_txn);
     }
+
+    #[test]
+    fn test_deserialize_api_v2() {
+        let json = r#"{"TransactionType":"Payment","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Destination":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX","DeliverMax":"1000000","Fee":"12","Sequence":2}"#;
+        let payment: Payment = serde_json::from_str(json).unwrap();
+
+        assert_eq!(payment.amount, Amount::XRPAmount("1000000".into()));
+        assert_eq!(payment.deliver_max, Some(payment.amount.clone()));
+        assert!(payment.validate().is_ok());
+
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            transaction_from_json(&value).unwrap(),
+            AnyTransaction::Payment(payment)
+        );
+    }
+
+    #[test]
+    fn test_serde_invoice_id() {
+        let json = r#"{"TransactionType":"Payment","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Amount":"1000000","Destination":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX","InvoiceID":"6F1DFD1D0FE8A32E40E1F2C05CF1C15545BAB56B617F9C6C2D63A6B704BEF59B"}"#;
+        let payment: Payment = serde_json::from_str(json).unwrap();
+
+        assert!(payment.validate().is_ok());
+        assert_eq!(serde_json::to_string(&payment).unwrap(), json);
+        assert_eq!(
+            decode(&encode(&payment).unwrap()).unwrap()["InvoiceID"],
+            "6F1DFD1D0FE8A32E40E1F2C05CF1C15545BAB56B617F9C6C2D63A6B704BEF59B"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_without_amount() {
+        let json = r#"{"TransactionType":"Payment","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Destination":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX"}"#;
+
+        assert!(serde_json::from_str::<Payment>(json).is_err());
+    }
+
+    #[test]
+    fn test_serde_mpt_amount() {
+        let default_txn = Payment::new(
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            Amount::MPTAmount(MPTAmount::new(
+                "00002403C84A0A28E0190E208E982C352BBD5006600555CF".into(),
+                "100".into(),
+            )),
+            "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX".into(),
+            Some("12".into()),
+            Some(2),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let default_json = r#"{"TransactionType":"Payment","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Fee":"12","Sequence":2,"Amount":{"mpt_issuance_id":"00002403C84A0A28E0190E208E982C352BBD5006600555CF","value":"100"},"Destination":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX"}"#;
+
+        let txn_as_string = serde_json::to_string(&default_txn).unwrap();
+        assert_eq!(txn_as_string.as_str(), default_json);
+
+        let txn_as_obj: Payment = serde_json::from_str(default_json).unwrap();
+        assert_eq!(txn_as_obj, default_txn);
+        assert!(txn_as_obj.amount.is_mpt());
+    }
+
+    #[test]
+    fn test_canonical_json() {
+        let txn_json = r#"{"TransactionType":"Payment","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Fee":"12","Sequence":2,"Flags":131072,"Amount":{"currency":"USD","issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","value":"1"},"Destination":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX"}"#;
+        let reordered_txn_json = r#"{"Destination":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX","Amount":{"value":"1","issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","currency":"USD"},"Flags":131072,"Sequence":2,"Fee":"12","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","TransactionType":"Payment"}"#;
+        let canonical_json = r#"{"TransactionType":"Payment","Flags":131072,"Sequence":2,"Amount":{"currency":"USD","issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","value":"1"},"Fee":"12","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Destination":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX"}"#;
+
+        let txn: Payment = serde_json::from_str(txn_json).unwrap();
+        let reordered_txn: Payment = serde_json::from_str(reordered_txn_json).unwrap();
+
+        assert_eq!(to_canonical_json(&txn).unwrap(), canonical_json);
+        assert_eq!(to_canonical_json(&reordered_txn).unwrap(), canonical_json);
+    }
+}
+
+#[cfg(test)]
+mod test_flags {
+    use alloc::vec;
+
+    use super::*;
+    use crate::models::transactions::OfferCreateFlag;
+
+    #[test]
+    fn test_toggle_partial_payment() {
+        let mut payment = Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        };
+        let partial_payment = Flag::Payment(PaymentFlag::TfPartialPayment);
+
+        assert!(!payment.has_flag(&partial_payment));
+
+        payment.set_flag(&partial_payment);
+        payment.set_flag(&partial_payment);
+        assert!(payment.has_flag(&partial_payment));
+        assert_eq!(
+            payment.common_fields.flags,
+            Some(vec![PaymentFlag::TfPartialPayment])
+        );
+
+        payment.set_flag(&Flag::OfferCreate(OfferCreateFlag::TfPassive));
+        assert_eq!(
+            payment.common_fields.flags,
+            Some(vec![PaymentFlag::TfPartialPayment])
+        );
+
+        payment.clear_flag(&partial_payment);
+        assert!(!payment.has_flag(&partial_payment));
+        assert_eq!(payment.common_fields.flags, None);
+    }
+}
+
+#[cfg(test)]
+mod test_x_address {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::core::addresscodec::classic_address_to_xaddress;
+
+    const DESTINATION: &str = "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK";
+
+    fn payment<'a>(destination: Cow<'a, str>, destination_tag: Option<u32>) -> Payment<'a> {
+        Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination,
+            destination_tag,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_extract_destination_tag() {
+        let xaddress = classic_address_to_xaddress(DESTINATION, Some(12345), false).unwrap();
+        let normalized = payment(xaddress.into(), None)
+            .normalize_x_address()
+            .unwrap();
+
+        assert_eq!(normalized.destination, DESTINATION);
+        assert_eq!(normalized.destination_tag, Some(12345));
+
+        let xaddress = classic_address_to_xaddress(DESTINATION, Some(12345), false).unwrap();
+        let normalized = payment(xaddress.into(), Some(12345))
+            .normalize_x_address()
+            .unwrap();
+
+        assert_eq!(normalized.destination_tag, Some(12345));
+    }
+
+    #[test]
+    fn test_classic_address_unchanged() {
+        let normalized = payment(DESTINATION.into(), Some(7))
+            .normalize_x_address()
+            .unwrap();
+
+        assert_eq!(normalized, payment(DESTINATION.into(), Some(7)));
+    }
+
+    #[test]
+    fn test_conflicting_destination_tag() {
+        let xaddress = classic_address_to_xaddress(DESTINATION, Some(12345), false).unwrap();
+
+        assert_eq!(
+            payment(xaddress.into(), Some(1))
+                .normalize_x_address()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The tag embedded in the X-Address of the field `destination` conflicts with the field `destination_tag` (expected 12345, found 1). For more information see: https://xrpl.org/payment.html"
+        );
+        assert!(payment("invalid".into(), None)
+            .normalize_x_address()
+            .is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_into_owned {
+    use alloc::string::String;
+
+    use super::*;
+
+    #[test]
+    fn test_outlives_input() {
+        let owned = {
+            let json = String::from(
+                r#"{"TransactionType":"Payment","Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","Fee":"12","Memos":[{"Memo":{"MemoData":"72656e74"}}],"Amount":{"currency":"USD","issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","value":"1"},"Destination":"rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK"}"#,
+            );
+            let payment: Payment = serde_json::from_str(&json).unwrap();
+            let owned = payment.clone().into_owned();
+            assert_eq!(owned, payment);
+
+            owned
+        };
+
+        assert_eq!(
+            owned.common_fields.account,
+            "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb"
+        );
+        assert_eq!(owned.destination, "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK");
+        assert!(owned.get_errors().is_ok());
+    }
+}
+
+#[cfg(test)]
+mod test_matches_unsigned {
+    use super::*;
+
+    fn payment<'a>() -> Payment<'a> {
+        Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: Some("12".into()),
+                sequence: Some(2),
+                ..Default::default()
+            },
+            amount: Amount::XRPAmount("1000000".into()),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_signed_matches_unsigned() {
+        let unsigned = payment();
+        let mut signed = payment();
+        signed.common_fields.signing_pub_key =
+            Some("03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB".into());
+        signed.common_fields.txn_signature = Some("3045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE".into());
+
+        assert_ne!(unsigned, signed);
+        assert!(unsigned.matches_unsigned(&signed));
+        assert!(signed.matches_unsigned(&unsigned));
+    }
+
+    #[test]
+    fn test_different_fields() {
+        let mut other = payment();
+        other.destination_tag = Some(1);
+
+        assert!(!payment().matches_unsigned(&other));
+    }
+}
+
+#[cfg(test)]
+mod test_build_refund {
+    use super::*;
+    use crate::models::amount::XRPAmount;
+
+    #[test]
+    fn test_build_refund() {
+        let payment = Payment {
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: Some("12".into()),
+                sequence: Some(2),
+                source_tag: Some(123),
+                ..Default::default()
+            },
+            amount: XRPAmount::from("1000000").into(),
+            destination: "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            destination_tag: Some(456),
+            ..Default::default()
+        };
+        let refund = payment.build_refund(XRPAmount::from("400000").into());
+
+        assert_eq!(refund.common_fields.account, payment.destination);
+        assert_eq!(refund.destination, payment.common_fields.account);
+        assert_eq!(refund.destination_tag, Some(123));
+        assert_eq!(refund.common_fields.source_tag, Some(456));
+        assert_eq!(refund.amount, XRPAmount::from("400000").into());
+        assert_eq!(refund.common_fields.sequence, None);
+        assert_eq!(refund.get_transaction_type(), TransactionType::Payment);
+    }
 }
@@ -1,4 +1,8 @@
+use crate::Err;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
 use alloc::vec::Vec;
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::skip_serializing_none;
@@ -6,11 +10,18 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 
 use crate::models::{
     model::Model,
-    transactions::{Flag, Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        clear_txn_flag, get_common_fields_error, set_txn_flag, CommonFields, Flag, FlagBits, Memo,
+        Signer, Transaction, TransactionType, TxnCommonFields,
+    },
 };
 
-use crate::_serde::txn_flags;
 use crate::models::amount::XRPAmount;
+use crate::models::transactions::{XRPLPaymentChannelClaimException, XRPLTransactionException};
+
+/// The documentation of the `PaymentChannelClaim` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/paymentchannelclaim.html";
 
 /// Transactions of the PaymentChannelClaim type support additional values
 /// in the Flags field. This enum represents those options.
@@ -39,6 +50,12 @@ pub enum PaymentChannelClaimFlag {
     TfClose = 0x00020000,
 }
 
+impl FlagBits for PaymentChannelClaimFlag {
+    fn bits(&self) -> u32 {
+        self.clone() as u32
+    }
+}
+
 /// Claim XRP from a payment channel, adjust
 /// the payment channel's expiration, or both.
 ///
@@ -48,92 +65,35 @@ pub enum PaymentChannelClaimFlag {
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct PaymentChannelClaim<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::payment_channel_claim")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    #[serde(default)]
-    #[serde(with = "txn_flags")]
-    pub flags: Option<Vec<PaymentChannelClaimFlag>>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, Vec<PaymentChannelClaimFlag>>,
     /// The custom fields for the PaymentChannelClaim model.
     ///
     /// See PaymentChannelClaim fields:
     /// `<https://xrpl.org/paymentchannelclaim.html#paymentchannelclaim-fields>`
-    pub channel: &'a str,
-    pub balance: Option<&'a str>,
-    pub amount: Option<&'a str>,
-    pub signature: Option<&'a str>,
-    pub public_key: Option<&'a str>,
+    #[serde(borrow)]
+    pub channel: Cow<'a, str>,
+    pub balance: Option<XRPAmount<'a>>,
+    pub amount: Option<XRPAmount<'a>>,
+    #[serde(borrow)]
+    pub signature: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub public_key: Option<Cow<'a, str>>,
 }
 
 impl<'a> Default for PaymentChannelClaim<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::PaymentChannelClaim,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             channel: Default::default(),
             balance: Default::default(),
             amount: Default::default(),
@@ -143,13 +103,27 @@ impl<'a> Default for PaymentChannelClaim<'a> {
     }
 }
 
-impl<'a> Model for PaymentChannelClaim<'a> {}
+impl<'a: 'static> Model for PaymentChannelClaim<'a> {
+    fn get_errors(&self) -> Result<()> {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self._get_balance_error()?;
+            self._get_signature_error()?;
+            Ok(())
+        };
+
+        match errors() {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
 
 impl<'a> Transaction for PaymentChannelClaim<'a> {
     fn has_flag(&self, flag: &Flag) -> bool {
         let mut flags = &Vec::new();
 
-        if let Some(flag_set) = self.flags.as_ref() {
+        if let Some(flag_set) = self.common_fields.flags.as_ref() {
             flags = flag_set;
         }
 
@@ -168,9 +142,83 @@ impl<'a> Transaction for PaymentChannelClaim<'a> {
         }
     }
 
+    fn set_flag(&mut self, flag: &Flag) {
+        if let Flag::PaymentChannelClaim(payment_channel_claim_flag) = flag {
+            set_txn_flag(
+                &mut self.common_fields.flags,
+                payment_channel_claim_flag.clone(),
+            );
+        }
+    }
+
+    fn clear_flag(&mut self, flag: &Flag) {
+        if let Flag::PaymentChannelClaim(payment_channel_claim_flag) = flag {
+            clear_txn_flag(&mut self.common_fields.flags, payment_channel_claim_flag);
+        }
+    }
+
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
+}
+
+impl<'a> PaymentChannelClaimError for PaymentChannelClaim<'a> {
+    fn _get_balance_error(&self) -> Result<(), XRPLPaymentChannelClaimException<'_>> {
+        if let (Some(balance), Some(amount)) = (&self.balance, &self.amount) {
+            let balance_val = _parse_drops("balance", balance)?;
+            let amount_val = _parse_drops("amount", amount)?;
+            if balance_val > amount_val {
+                return Err(XRPLPaymentChannelClaimException::ValueAboveValue {
+                    field1: "balance",
+                    field2: "amount",
+                    field1_val: balance_val,
+                    field2_val: amount_val,
+                    resource: RESOURCE,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn _get_signature_error(&self) -> Result<(), XRPLPaymentChannelClaimException<'_>> {
+        match (self.signature.as_deref(), self.public_key.as_deref()) {
+            (Some(_), None) => Err(XRPLPaymentChannelClaimException::FieldRequiresField {
+                field1: "signature",
+                field2: "public_key",
+                resource: RESOURCE,
+            }),
+            (None, Some(_)) => Err(XRPLPaymentChannelClaimException::FieldRequiresField {
+                field1: "public_key",
+                field2: "signature",
+                resource: RESOURCE,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn _parse_drops<'a>(
+    field: &'a str,
+    drops: &'a XRPAmount<'_>,
+) -> Result<u64, XRPLPaymentChannelClaimException<'a>> {
+    drops
+        .0
+        .parse::<u64>()
+        .map_err(|_| XRPLPaymentChannelClaimException::InvalidValueFormat {
+            field,
+            format: "drops",
+            found: &drops.0,
+            resource: RESOURCE,
+        })
 }
 
 impl<'a> PaymentChannelClaim<'a> {
@@ -188,34 +236,125 @@ impl<'a> PaymentChannelClaim<'a> {
         flags: Option<Vec<PaymentChannelClaimFlag>>,
         memos: Option<Vec<Memo<'a>>>,
         signers: Option<Vec<Signer<'a>>>,
-        balance: Option<&'a str>,
-        amount: Option<&'a str>,
+        balance: Option<XRPAmount<'a>>,
+        amount: Option<XRPAmount<'a>>,
         signature: Option<&'a str>,
         public_key: Option<&'a str>,
     ) -> Self {
         Self {
             transaction_type: TransactionType::PaymentChannelClaim,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags,
-            memos,
-            signers,
-            channel,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags,
+                memos,
+                signers,
+            },
+            channel: channel.into(),
             balance,
             amount,
-            signature,
-            public_key,
+            signature: signature.map(Into::into),
+            public_key: public_key.map(Into::into),
+        }
+    }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> PaymentChannelClaim<'static> {
+        PaymentChannelClaim {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            channel: Cow::Owned(self.channel.into_owned()),
+            balance: self.balance.map(XRPAmount::into_owned),
+            amount: self.amount.map(XRPAmount::into_owned),
+            signature: self
+                .signature
+                .map(|signature| Cow::Owned(signature.into_owned())),
+            public_key: self
+                .public_key
+                .map(|public_key| Cow::Owned(public_key.into_owned())),
         }
     }
 }
 
+pub trait PaymentChannelClaimError {
+    fn _get_balance_error(&self) -> Result<(), XRPLPaymentChannelClaimException<'_>>;
+    fn _get_signature_error(&self) -> Result<(), XRPLPaymentChannelClaimException<'_>>;
+}
+
+#[cfg(test)]
+mod test_payment_channel_claim_error {
+    use alloc::string::ToString;
+
+    use crate::models::Model;
+
+    use super::*;
+
+    #[test]
+    fn test_balance_error() {
+        let mut payment_channel_claim = PaymentChannelClaim {
+            common_fields: CommonFields {
+                account: "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX".into(),
+                ..Default::default()
+            },
+            channel: "C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198".into(),
+            balance: Some("1000001".into()),
+            amount: Some("1000000".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            payment_channel_claim.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `balance` is not allowed to be above the value of the field `amount` (max 1000000, found 1000001). For more information see: https://xrpl.org/paymentchannelclaim.html"
+        );
+
+        payment_channel_claim.balance = Some("1.5".into());
+
+        assert_eq!(
+            payment_channel_claim.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `balance` does not have the correct format (expected drops, found 1.5). For more information see: https://xrpl.org/paymentchannelclaim.html"
+        );
+
+        payment_channel_claim.balance = Some("1000000".into());
+
+        assert!(payment_channel_claim.validate().is_ok());
+    }
+
+    #[test]
+    fn test_signature_error() {
+        let mut payment_channel_claim = PaymentChannelClaim {
+            common_fields: CommonFields {
+                account: "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX".into(),
+                ..Default::default()
+            },
+            channel: "C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198".into(),
+            signature: Some("30440220718D264EF05CAED7C781FF6DE298DCAC68D002562C9BF3A07C1E721B420C0DAB02203A5A4779EF4D2CCC7BC3EF886676D803A9981B928D3B8ACA483B80ECA3CD7B9B".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            payment_channel_claim.validate().unwrap_err().to_string().as_str(),
+            "For the field `signature` to be defined it is required to also define the field `public_key`. For more information see: https://xrpl.org/paymentchannelclaim.html"
+        );
+
+        payment_channel_claim.signature = None;
+        payment_channel_claim.public_key =
+            Some("32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A".into());
+
+        assert_eq!(
+            payment_channel_claim.validate().unwrap_err().to_string().as_str(),
+            "For the field `public_key` to be defined it is required to also define the field `signature`. For more information see: https://xrpl.org/paymentchannelclaim.html"
+        );
+    }
+}
+
 #[cfg(test)]
 mod test_serde {
     use super::*;
@@ -236,8 +375,8 @@ mod test_serde {
             None,
             None,
             None,
-            Some("1000000"),
-            Some("1000000"),
+            Some("1000000".into()),
+            Some("1000000".into()),
             Some("30440220718D264EF05CAED7C781FF6DE298DCAC68D002562C9BF3A07C1E721B420C0DAB02203A5A4779EF4D2CCC7BC3EF886676D803A9981B928D3B8ACA483B80ECA3CD7B9B"),
             Some("32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A"),
         );
@@ -265,8 +404,8 @@ mod test_serde {
             None,
             None,
             None,
-            Some("1000000"),
-            Some("1000000"),
+            Some("1000000".into()),
+            Some("1000000".into()),
             Some("30440220718D264EF05CAED7C781FF6DE298DCAC68D002562C9BF3A07C1E721B420C0DAB02203A5A4779EF4D2CCC7BC3EF886676D803A9981B928D3B8ACA483B80ECA3CD7B9B"),
             Some("32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A"),
         );
@@ -276,4 +415,19 @@ mod test_serde {
 
         assert_eq!(txn_as_obj, default_txn);
     }
+
+    #[test]
+    fn test_amount_fields() {
+        let json = r#"{"TransactionType":"PaymentChannelClaim","Account":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX","Channel":"C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198","Balance":"1000000","Amount":"2000000"}"#;
+        let txn: PaymentChannelClaim = serde_json::from_str(json).unwrap();
+
+        assert_eq!(txn.balance, Some(XRPAmount::from("1000000")));
+        assert_eq!(txn.amount, Some(XRPAmount::from("2000000")));
+
+        let value = serde_json::to_value(&txn).unwrap();
+
+        assert_eq!(value["Balance"], "1000000");
+        assert_eq!(value["Amount"], "2000000");
+        assert_eq!(serde_json::to_string(&txn).unwrap(), json);
+    }
 }
@@ -1,12 +1,29 @@
+use crate::Err;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
 use alloc::vec::Vec;
+use anyhow::Result;
+use core::str::FromStr;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
 use crate::models::{
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_common_fields_error, get_destination_error, normalize_x_address, CommonFields, Memo,
+        Signer, Transaction, TransactionType, TxnCommonFields, XRPLPaymentChannelCreateException,
+        XRPLTransactionException, XRPLTransactionFieldException,
+    },
 };
+use crate::utils::hex::from_hex;
+
+/// The documentation of the `PaymentChannelCreate` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/paymentchannelcreate.html";
+
+/// The length of a public key in bytes.
+const PUBLIC_KEY_LENGTH: usize = 33;
 
 /// Create a unidirectional channel and fund it with XRP.
 ///
@@ -16,71 +33,26 @@ use crate::models::{
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct PaymentChannelCreate<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::payment_channel_create")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     /// The custom fields for the PaymentChannelCreate model.
     ///
     /// See PaymentChannelCreate fields:
     /// `<https://xrpl.org/paymentchannelcreate.html#paymentchannelcreate-fields>`
     pub amount: XRPAmount<'a>,
-    pub destination: &'a str,
+    #[serde(borrow)]
+    pub destination: Cow<'a, str>,
     pub settle_delay: u32,
-    pub public_key: &'a str,
+    #[serde(borrow)]
+    pub public_key: Cow<'a, str>,
     pub cancel_after: Option<u32>,
     pub destination_tag: Option<u32>,
 }
@@ -89,18 +61,7 @@ impl<'a> Default for PaymentChannelCreate<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::PaymentChannelCreate,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             amount: Default::default(),
             destination: Default::default(),
             settle_delay: Default::default(),
@@ -111,12 +72,68 @@ impl<'a> Default for PaymentChannelCreate<'a> {
     }
 }
 
-impl<'a> Model for PaymentChannelCreate<'a> {}
+impl<'a> Model for PaymentChannelCreate<'a> {
+    fn get_errors(&self) -> Result<()> {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            get_destination_error(&self.common_fields.account, &self.destination, RESOURCE)?;
+            self._get_amount_error()?;
+            self._get_public_key_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
 
 impl<'a> Transaction for PaymentChannelCreate<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
+}
+
+impl<'a> PaymentChannelCreateError for PaymentChannelCreate<'a> {
+    fn _get_amount_error(&self) -> Result<(), XRPLPaymentChannelCreateException<'_>> {
+        match XRPAmount::from_str(&self.amount.0) {
+            Ok(_) if self.amount.0.trim_start_matches('0').is_empty() => {
+                Err(XRPLPaymentChannelCreateException::ValueZero {
+                    field: "amount",
+                    resource: RESOURCE,
+                })
+            }
+            Ok(_) => Ok(()),
+            Err(_) => Err(XRPLPaymentChannelCreateException::InvalidValueFormat {
+                field: "amount",
+                format: "drops",
+                found: &self.amount.0,
+                resource: RESOURCE,
+            }),
+        }
+    }
+
+    fn _get_public_key_error(&self) -> Result<(), XRPLPaymentChannelCreateException<'_>> {
+        match from_hex(&*self.public_key) {
+            Ok(public_key) if public_key.len() == PUBLIC_KEY_LENGTH => Ok(()),
+            _ => Err(XRPLPaymentChannelCreateException::InvalidValueFormat {
+                field: "public_key",
+                format: "33 byte hex encoded public key",
+                found: &self.public_key,
+                resource: RESOURCE,
+            }),
+        }
+    }
 }
 
 impl<'a> PaymentChannelCreate<'a> {
@@ -141,26 +158,177 @@ impl<'a> PaymentChannelCreate<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::PaymentChannelCreate,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
             amount,
-            destination,
+            destination: destination.into(),
             settle_delay,
-            public_key,
+            public_key: public_key.into(),
             cancel_after,
             destination_tag,
         }
     }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> PaymentChannelCreate<'static> {
+        PaymentChannelCreate {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            amount: self.amount.into_owned(),
+            destination: Cow::Owned(self.destination.into_owned()),
+            settle_delay: self.settle_delay,
+            public_key: Cow::Owned(self.public_key.into_owned()),
+            cancel_after: self.cancel_after,
+            destination_tag: self.destination_tag,
+        }
+    }
+}
+
+pub trait PaymentChannelCreateError {
+    fn _get_amount_error(&self) -> Result<(), XRPLPaymentChannelCreateException<'_>>;
+    fn _get_public_key_error(&self) -> Result<(), XRPLPaymentChannelCreateException<'_>>;
+}
+
+impl<'a> PaymentChannelCreate<'a> {
+    /// Replaces X-Addresses in `account` and `destination` with their
+    /// classic addresses and moves the embedded tags into `source_tag`
+    /// and `destination_tag`. Errors if a tag is already defined with a
+    /// different value. Classic addresses are left unchanged.
+    pub fn normalize_x_address(self) -> Result<Self> {
+        match self._normalize_x_address() {
+            Err(error) => Err!(error),
+            Ok(payment_channel_create) => Ok(payment_channel_create),
+        }
+    }
+
+    fn _normalize_x_address(mut self) -> Result<Self, XRPLTransactionFieldException<'static>> {
+        self.common_fields = self.common_fields.normalize_x_address()?;
+        let (destination, destination_tag) = normalize_x_address(
+            &self.destination,
+            self.destination_tag,
+            "destination",
+            "destination_tag",
+            RESOURCE,
+        )?;
+        self.destination = destination.into();
+        self.destination_tag = destination_tag;
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod test_payment_channel_create_error {
+    use crate::models::Model;
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn payment_channel_create<'a>() -> PaymentChannelCreate<'a> {
+        PaymentChannelCreate {
+            common_fields: CommonFields {
+                account: "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+                ..Default::default()
+            },
+            amount: XRPAmount::from("10000"),
+            destination: "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".into(),
+            settle_delay: 86400,
+            public_key: "32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A".into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_amount_error() {
+        let mut payment_channel_create = payment_channel_create();
+        payment_channel_create.amount = XRPAmount::from("0");
+
+        assert_eq!(
+            payment_channel_create
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The value of the field `amount` is not allowed to be zero. For more information see: https://xrpl.org/paymentchannelcreate.html"
+        );
+
+        payment_channel_create.amount = XRPAmount::from("1.5");
+
+        assert_eq!(
+            payment_channel_create
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The value of the field `amount` does not have the correct format (expected drops, found 1.5). For more information see: https://xrpl.org/paymentchannelcreate.html"
+        );
+    }
+
+    #[test]
+    fn test_public_key_error() {
+        let mut payment_channel_create = payment_channel_create();
+        payment_channel_create.public_key =
+            "32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F".into();
+
+        assert_eq!(
+            payment_channel_create
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The value of the field `public_key` does not have the correct format (expected 33 byte hex encoded public key, found 32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F). For more information see: https://xrpl.org/paymentchannelcreate.html"
+        );
+
+        payment_channel_create.public_key = "not hex".into();
+
+        assert!(payment_channel_create.validate().is_err());
+
+        payment_channel_create.public_key =
+            "ED5F5AC8B98974A3CA843326D9B88CEBD0560177B973EE0B149F782CFAA06DC66A".into();
+
+        assert!(payment_channel_create.validate().is_ok());
+    }
+
+    #[test]
+    fn test_destination_error() {
+        let mut payment_channel_create = PaymentChannelCreate {
+            common_fields: CommonFields {
+                account: "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+                ..Default::default()
+            },
+            amount: XRPAmount::from("10000"),
+            destination: "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+            settle_delay: 86400,
+            public_key: "32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A".into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            payment_channel_create
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The value of the field `destination` is not allowed to be the same as the value of the field `account`. For more information see: https://xrpl.org/paymentchannelcreate.html"
+        );
+
+        payment_channel_create.destination = "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".into();
+
+        assert!(payment_channel_create.validate().is_ok());
+    }
 }
 
 #[cfg(test)]
@@ -224,3 +392,45 @@ mod test_serde {
         assert_eq!(txn_as_obj, default_txn);
     }
 }
+
+#[cfg(test)]
+mod test_x_address {
+    use crate::core::addresscodec::classic_address_to_xaddress;
+
+    use super::*;
+
+    const ACCOUNT: &str = "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb";
+    const DESTINATION: &str = "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK";
+
+    #[test]
+    fn test_extract_tags() {
+        let txn = PaymentChannelCreate {
+            common_fields: CommonFields {
+                account: classic_address_to_xaddress(ACCOUNT, Some(1), false)
+                    .unwrap()
+                    .into(),
+                ..Default::default()
+            },
+            destination: classic_address_to_xaddress(DESTINATION, Some(2), false)
+                .unwrap()
+                .into(),
+            amount: XRPAmount::from("10000"),
+            ..Default::default()
+        }
+        .normalize_x_address()
+        .unwrap();
+
+        assert_eq!(txn.common_fields.account, ACCOUNT);
+        assert_eq!(txn.common_fields.source_tag, Some(1));
+        assert_eq!(txn.destination, DESTINATION);
+        assert_eq!(txn.destination_tag, Some(2));
+
+        let mut conflicting = txn.clone();
+        conflicting.destination = classic_address_to_xaddress(DESTINATION, Some(3), false)
+            .unwrap()
+            .into();
+
+        assert!(conflicting.normalize_x_address().is_err());
+        assert_eq!(txn.clone().normalize_x_address().unwrap(), txn);
+    }
+}
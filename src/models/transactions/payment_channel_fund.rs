@@ -1,12 +1,28 @@
+use crate::Err;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
 use alloc::vec::Vec;
+use anyhow::Result;
+use core::str::FromStr;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::{
     amount::XRPAmount,
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_common_fields_error, CommonFields, Memo, Signer, Transaction, TransactionType,
+        TxnCommonFields, XRPLPaymentChannelFundException, XRPLTransactionException,
+    },
 };
+use crate::utils::hex::from_hex;
+
+/// The documentation of the `PaymentChannelFund` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/paymentchannelfund.html";
+
+/// The length of a channel ID in bytes.
+const CHANNEL_ID_LENGTH: usize = 32;
 
 /// Add additional XRP to an open payment channel,
 /// and optionally update the expiration time of the channel.
@@ -17,69 +33,27 @@ use crate::models::{
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct PaymentChannelFund<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::payment_channel_fund")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     /// The custom fields for the PaymentChannelFund model.
     ///
     /// See PaymentChannelFund fields:
     /// `<https://xrpl.org/paymentchannelfund.html#paymentchannelfund-fields>`
     pub amount: XRPAmount<'a>,
-    pub channel: &'a str,
+    #[serde(borrow)]
+    pub channel: Cow<'a, str>,
+    /// The new expiration time of the channel. It has to be at
+    /// least the channel's `SettleDelay` after the close time of
+    /// the previous ledger, which is not known locally and
+    /// therefore not validated.
     pub expiration: Option<u32>,
 }
 
@@ -87,18 +61,7 @@ impl<'a> Default for PaymentChannelFund<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::PaymentChannelFund,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             amount: Default::default(),
             channel: Default::default(),
             expiration: Default::default(),
@@ -106,12 +69,67 @@ impl<'a> Default for PaymentChannelFund<'a> {
     }
 }
 
-impl<'a> Model for PaymentChannelFund<'a> {}
+impl<'a> Model for PaymentChannelFund<'a> {
+    fn get_errors(&self) -> Result<()> {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self._get_amount_error()?;
+            self._get_channel_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
 
 impl<'a> Transaction for PaymentChannelFund<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
+}
+
+impl<'a> PaymentChannelFundError for PaymentChannelFund<'a> {
+    fn _get_amount_error(&self) -> Result<(), XRPLPaymentChannelFundException<'_>> {
+        match XRPAmount::from_str(&self.amount.0) {
+            Ok(_) if self.amount.0.trim_start_matches('0').is_empty() => {
+                Err(XRPLPaymentChannelFundException::ValueZero {
+                    field: "amount",
+                    resource: RESOURCE,
+                })
+            }
+            Ok(_) => Ok(()),
+            Err(_) => Err(XRPLPaymentChannelFundException::InvalidValueFormat {
+                field: "amount",
+                format: "drops",
+                found: &self.amount.0,
+                resource: RESOURCE,
+            }),
+        }
+    }
+
+    fn _get_channel_error(&self) -> Result<(), XRPLPaymentChannelFundException<'_>> {
+        match from_hex(&*self.channel) {
+            Ok(channel) if channel.len() == CHANNEL_ID_LENGTH => Ok(()),
+            _ => Err(XRPLPaymentChannelFundException::InvalidValueFormat {
+                field: "channel",
+                format: "32 byte hex",
+                found: &self.channel,
+                resource: RESOURCE,
+            }),
+        }
+    }
 }
 
 impl<'a> PaymentChannelFund<'a> {
@@ -133,23 +151,96 @@ impl<'a> PaymentChannelFund<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::PaymentChannelFund,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
             amount,
-            channel,
+            channel: channel.into(),
             expiration,
         }
     }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> PaymentChannelFund<'static> {
+        PaymentChannelFund {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            amount: self.amount.into_owned(),
+            channel: Cow::Owned(self.channel.into_owned()),
+            expiration: self.expiration,
+        }
+    }
+}
+
+pub trait PaymentChannelFundError {
+    fn _get_amount_error(&self) -> Result<(), XRPLPaymentChannelFundException<'_>>;
+    fn _get_channel_error(&self) -> Result<(), XRPLPaymentChannelFundException<'_>>;
+}
+
+#[cfg(test)]
+mod test_payment_channel_fund_error {
+    use crate::models::Model;
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn payment_channel_fund<'a>() -> PaymentChannelFund<'a> {
+        PaymentChannelFund {
+            common_fields: CommonFields {
+                account: "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+                ..Default::default()
+            },
+            amount: XRPAmount::from("200000"),
+            channel: "C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198".into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_amount_error() {
+        let mut payment_channel_fund = payment_channel_fund();
+
+        assert!(payment_channel_fund.validate().is_ok());
+
+        payment_channel_fund.amount = XRPAmount::from("0");
+
+        assert_eq!(
+            payment_channel_fund
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The value of the field `amount` is not allowed to be zero. For more information see: https://xrpl.org/paymentchannelfund.html"
+        );
+    }
+
+    #[test]
+    fn test_channel_error() {
+        let mut payment_channel_fund = payment_channel_fund();
+        payment_channel_fund.channel =
+            "C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA61".into();
+
+        assert_eq!(
+            payment_channel_fund
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The value of the field `channel` does not have the correct format (expected 32 byte hex, found C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA61). For more information see: https://xrpl.org/paymentchannelfund.html"
+        );
+    }
 }
 
 #[cfg(test)]
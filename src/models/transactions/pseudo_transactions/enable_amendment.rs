@@ -1,4 +1,5 @@
 use crate::_serde::txn_flags;
+use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
@@ -8,7 +9,10 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 use crate::models::amount::XRPAmount;
 use crate::models::{
     model::Model,
-    transactions::{Flag, Transaction, TransactionType},
+    transactions::{
+        account_zero, clear_txn_flag, set_txn_flag, Flag, FlagBits, Transaction, TransactionType,
+        TxnCommonFields,
+    },
 };
 
 #[derive(
@@ -24,6 +28,12 @@ pub enum EnableAmendmentFlag {
     TfLostMajority = 0x00020000,
 }
 
+impl FlagBits for EnableAmendmentFlag {
+    fn bits(&self) -> u32 {
+        self.clone() as u32
+    }
+}
+
 /// See EnableAmendment:
 /// `<https://xrpl.org/enableamendment.html>`
 #[skip_serializing_none]
@@ -39,9 +49,12 @@ pub struct EnableAmendment<'a> {
     // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::enable_amendment")]
-    transaction_type: TransactionType,
+    pub transaction_type: TransactionType,
     /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
+    /// Defaults to `ACCOUNT_ZERO` for pseudo-transactions.
+    #[serde(default = "account_zero")]
+    #[serde(borrow)]
+    pub account: Cow<'a, str>,
     /// Integer amount of XRP, in drops, to be destroyed as a cost
     /// for distributing this transaction to the network. Some
     /// transaction types have different minimum requirements.
@@ -55,7 +68,8 @@ pub struct EnableAmendment<'a> {
     /// Hex representation of the public key that corresponds to the
     /// private key used to sign this transaction. If an empty string,
     /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
+    #[serde(borrow)]
+    pub signing_pub_key: Option<Cow<'a, str>>,
     /// Arbitrary integer used to identify the reason for this
     /// payment, or a sender on whose behalf this transaction
     /// is made. Conventionally, a refund should specify the initial
@@ -63,7 +77,8 @@ pub struct EnableAmendment<'a> {
     pub source_tag: Option<u32>,
     /// The signature that verifies this transaction as originating
     /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
+    #[serde(borrow)]
+    pub txn_signature: Option<Cow<'a, str>>,
     /// Set of bit-flags for this transaction.
     #[serde(default)]
     #[serde(with = "txn_flags")]
@@ -72,7 +87,8 @@ pub struct EnableAmendment<'a> {
     ///
     /// See EnableAmendment fields:
     /// `<https://xrpl.org/enableamendment.html#enableamendment-fields>`
-    pub amendment: &'a str,
+    #[serde(borrow)]
+    pub amendment: Cow<'a, str>,
     pub ledger_sequence: u32,
 }
 
@@ -97,9 +113,33 @@ impl<'a> Transaction for EnableAmendment<'a> {
         }
     }
 
+    fn set_flag(&mut self, flag: &Flag) {
+        if let Flag::EnableAmendment(enable_amendment_flag) = flag {
+            set_txn_flag(&mut self.flags, enable_amendment_flag.clone());
+        }
+    }
+
+    fn clear_flag(&mut self, flag: &Flag) {
+        if let Flag::EnableAmendment(enable_amendment_flag) = flag {
+            clear_txn_flag(&mut self.flags, enable_amendment_flag);
+        }
+    }
+
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        None
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        None
+    }
+
+    fn is_pseudo_transaction(&self) -> bool {
+        true
+    }
 }
 
 impl<'a> EnableAmendment<'a> {
@@ -116,15 +156,36 @@ impl<'a> EnableAmendment<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::EnableAmendment,
-            account,
+            account: account.into(),
             fee,
             sequence,
-            signing_pub_key,
+            signing_pub_key: signing_pub_key.map(Into::into),
             source_tag,
-            txn_signature,
+            txn_signature: txn_signature.map(Into::into),
             flags,
-            amendment,
+            amendment: amendment.into(),
             ledger_sequence,
         }
     }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> EnableAmendment<'static> {
+        EnableAmendment {
+            transaction_type: self.transaction_type,
+            account: Cow::Owned(self.account.into_owned()),
+            fee: self.fee.map(XRPAmount::into_owned),
+            sequence: self.sequence,
+            signing_pub_key: self
+                .signing_pub_key
+                .map(|signing_pub_key| Cow::Owned(signing_pub_key.into_owned())),
+            source_tag: self.source_tag,
+            txn_signature: self
+                .txn_signature
+                .map(|txn_signature| Cow::Owned(txn_signature.into_owned())),
+            flags: self.flags,
+            amendment: Cow::Owned(self.amendment.into_owned()),
+            ledger_sequence: self.ledger_sequence,
+        }
+    }
 }
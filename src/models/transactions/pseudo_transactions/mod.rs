@@ -5,3 +5,12 @@ pub mod unl_modify;
 pub use enable_amendment::*;
 pub use set_fee::*;
 pub use unl_modify::*;
+
+use crate::constants::ACCOUNT_ZERO;
+use alloc::borrow::Cow;
+
+/// For use with serde defaults. Pseudo-transactions are sent
+/// by no account.
+pub(crate) fn account_zero<'a>() -> Cow<'a, str> {
+    ACCOUNT_ZERO.into()
+}
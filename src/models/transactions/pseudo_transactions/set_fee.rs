@@ -1,10 +1,11 @@
+use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
 use crate::models::{
     model::Model,
-    transactions::{Transaction, TransactionType},
+    transactions::{account_zero, Transaction, TransactionType, TxnCommonFields},
 };
 
 /// See SetFee:
@@ -24,7 +25,10 @@ pub struct SetFee<'a> {
     #[serde(default = "TransactionType::set_fee")]
     pub transaction_type: TransactionType,
     /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
+    /// Defaults to `ACCOUNT_ZERO` for pseudo-transactions.
+    #[serde(default = "account_zero")]
+    #[serde(borrow)]
+    pub account: Cow<'a, str>,
     /// Integer amount of XRP, in drops, to be destroyed as a cost
     /// for distributing this transaction to the network. Some
     /// transaction types have different minimum requirements.
@@ -38,7 +42,8 @@ pub struct SetFee<'a> {
     /// Hex representation of the public key that corresponds to the
     /// private key used to sign this transaction. If an empty string,
     /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
+    #[serde(borrow)]
+    pub signing_pub_key: Option<Cow<'a, str>>,
     /// Arbitrary integer used to identify the reason for this
     /// payment, or a sender on whose behalf this transaction
     /// is made. Conventionally, a refund should specify the initial
@@ -46,17 +51,25 @@ pub struct SetFee<'a> {
     pub source_tag: Option<u32>,
     /// The signature that verifies this transaction as originating
     /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
+    #[serde(borrow)]
+    pub txn_signature: Option<Cow<'a, str>>,
     /// Set of bit-flags for this transaction.
     pub flags: Option<u32>,
     /// The custom fields for the SetFee model.
     ///
     /// See SetFee fields:
     /// `<https://xrpl.org/setfee.html#setfee-fields>`
-    pub base_fee: XRPAmount<'a>,
-    pub reference_fee_units: u32,
-    pub reserve_base: u32,
-    pub reserve_increment: u32,
+    ///
+    /// Before the XRPFees amendment the fees are defined by
+    /// `base_fee`, `reference_fee_units`, `reserve_base` and
+    /// `reserve_increment`. Afterwards by the `*_drops` fields.
+    pub base_fee: Option<XRPAmount<'a>>,
+    pub reference_fee_units: Option<u32>,
+    pub reserve_base: Option<u32>,
+    pub reserve_increment: Option<u32>,
+    pub base_fee_drops: Option<XRPAmount<'a>>,
+    pub reserve_base_drops: Option<XRPAmount<'a>>,
+    pub reserve_increment_drops: Option<XRPAmount<'a>>,
     pub ledger_sequence: u32,
 }
 
@@ -66,16 +79,31 @@ impl<'a> Transaction for SetFee<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        None
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        None
+    }
+
+    fn is_pseudo_transaction(&self) -> bool {
+        true
+    }
 }
 
 impl<'a> SetFee<'a> {
     fn new(
         account: &'a str,
-        base_fee: XRPAmount<'a>,
-        reference_fee_units: u32,
-        reserve_base: u32,
-        reserve_increment: u32,
         ledger_sequence: u32,
+        base_fee: Option<XRPAmount<'a>>,
+        reference_fee_units: Option<u32>,
+        reserve_base: Option<u32>,
+        reserve_increment: Option<u32>,
+        base_fee_drops: Option<XRPAmount<'a>>,
+        reserve_base_drops: Option<XRPAmount<'a>>,
+        reserve_increment_drops: Option<XRPAmount<'a>>,
         fee: Option<XRPAmount<'a>>,
         sequence: Option<u32>,
         signing_pub_key: Option<&'a str>,
@@ -84,18 +112,48 @@ impl<'a> SetFee<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::SetFee,
-            account,
+            account: account.into(),
             fee,
             sequence,
-            signing_pub_key,
+            signing_pub_key: signing_pub_key.map(Into::into),
             source_tag,
-            txn_signature,
+            txn_signature: txn_signature.map(Into::into),
             flags: None,
             base_fee,
             reference_fee_units,
             reserve_base,
             reserve_increment,
+            base_fee_drops,
+            reserve_base_drops,
+            reserve_increment_drops,
             ledger_sequence,
         }
     }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> SetFee<'static> {
+        SetFee {
+            transaction_type: self.transaction_type,
+            account: Cow::Owned(self.account.into_owned()),
+            fee: self.fee.map(XRPAmount::into_owned),
+            sequence: self.sequence,
+            signing_pub_key: self
+                .signing_pub_key
+                .map(|signing_pub_key| Cow::Owned(signing_pub_key.into_owned())),
+            source_tag: self.source_tag,
+            txn_signature: self
+                .txn_signature
+                .map(|txn_signature| Cow::Owned(txn_signature.into_owned())),
+            flags: self.flags,
+            base_fee: self.base_fee.map(XRPAmount::into_owned),
+            reference_fee_units: self.reference_fee_units,
+            reserve_base: self.reserve_base,
+            reserve_increment: self.reserve_increment,
+            base_fee_drops: self.base_fee_drops.map(XRPAmount::into_owned),
+            reserve_base_drops: self.reserve_base_drops.map(XRPAmount::into_owned),
+            reserve_increment_drops: self.reserve_increment_drops.map(XRPAmount::into_owned),
+            ledger_sequence: self.ledger_sequence,
+        }
+    }
 }
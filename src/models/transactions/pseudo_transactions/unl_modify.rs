@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::skip_serializing_none;
@@ -6,7 +7,7 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 use crate::models::{
     amount::XRPAmount,
     model::Model,
-    transactions::{Transaction, TransactionType},
+    transactions::{account_zero, Transaction, TransactionType, TxnCommonFields},
 };
 
 #[derive(
@@ -35,7 +36,10 @@ pub struct UNLModify<'a> {
     #[serde(default = "TransactionType::unl_modify")]
     pub transaction_type: TransactionType,
     /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
+    /// Defaults to `ACCOUNT_ZERO` for pseudo-transactions.
+    #[serde(default = "account_zero")]
+    #[serde(borrow)]
+    pub account: Cow<'a, str>,
     /// Integer amount of XRP, in drops, to be destroyed as a cost
     /// for distributing this transaction to the network. Some
     /// transaction types have different minimum requirements.
@@ -49,7 +53,8 @@ pub struct UNLModify<'a> {
     /// Hex representation of the public key that corresponds to the
     /// private key used to sign this transaction. If an empty string,
     /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
+    #[serde(borrow)]
+    pub signing_pub_key: Option<Cow<'a, str>>,
     /// Arbitrary integer used to identify the reason for this
     /// payment, or a sender on whose behalf this transaction
     /// is made. Conventionally, a refund should specify the initial
@@ -57,7 +62,8 @@ pub struct UNLModify<'a> {
     pub source_tag: Option<u32>,
     /// The signature that verifies this transaction as originating
     /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
+    #[serde(borrow)]
+    pub txn_signature: Option<Cow<'a, str>>,
     /// Set of bit-flags for this transaction.
     pub flags: Option<u32>,
     /// The custom fields for the UNLModify model.
@@ -65,8 +71,11 @@ pub struct UNLModify<'a> {
     /// See UNLModify fields:
     /// `<https://xrpl.org/unlmodify.html#unlmodify-fields>`
     pub ledger_sequence: u32,
+    #[serde(rename = "UNLModifyDisabling")]
     pub unlmodify_disabling: UNLModifyDisabling,
-    pub unlmodify_validator: &'a str,
+    #[serde(rename = "UNLModifyValidator")]
+    #[serde(borrow)]
+    pub unlmodify_validator: Cow<'a, str>,
 }
 
 impl<'a> Model for UNLModify<'a> {}
@@ -75,6 +84,18 @@ impl<'a> Transaction for UNLModify<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        None
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        None
+    }
+
+    fn is_pseudo_transaction(&self) -> bool {
+        true
+    }
 }
 
 impl<'a> UNLModify<'a> {
@@ -91,16 +112,91 @@ impl<'a> UNLModify<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::UNLModify,
-            account,
+            account: account.into(),
             fee,
             sequence,
-            signing_pub_key,
+            signing_pub_key: signing_pub_key.map(Into::into),
             source_tag,
-            txn_signature,
+            txn_signature: txn_signature.map(Into::into),
             flags: None,
             ledger_sequence,
             unlmodify_disabling,
-            unlmodify_validator,
+            unlmodify_validator: unlmodify_validator.into(),
         }
     }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> UNLModify<'static> {
+        UNLModify {
+            transaction_type: self.transaction_type,
+            account: Cow::Owned(self.account.into_owned()),
+            fee: self.fee.map(XRPAmount::into_owned),
+            sequence: self.sequence,
+            signing_pub_key: self
+                .signing_pub_key
+                .map(|signing_pub_key| Cow::Owned(signing_pub_key.into_owned())),
+            source_tag: self.source_tag,
+            txn_signature: self
+                .txn_signature
+                .map(|txn_signature| Cow::Owned(txn_signature.into_owned())),
+            flags: self.flags,
+            ledger_sequence: self.ledger_sequence,
+            unlmodify_disabling: self.unlmodify_disabling,
+            unlmodify_validator: Cow::Owned(self.unlmodify_validator.into_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+    use crate::core::definitions::get_field_instance;
+
+    #[test]
+    fn test_serialize() {
+        let default_txn = UNLModify::new(
+            "rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+            84803072,
+            UNLModifyDisabling::Enable,
+            "ED6629D456285AE3613B285F65BBFF168D695BA3921F309949AFCD2CA7AFEC16FE",
+            Some("0".into()),
+            Some(0),
+            Some(""),
+            None,
+            None,
+        );
+        let default_json = r#"{"TransactionType":"UNLModify","Account":"rrrrrrrrrrrrrrrrrrrrrhoLvTp","Fee":"0","Sequence":0,"SigningPubKey":"","LedgerSequence":84803072,"UNLModifyDisabling":1,"UNLModifyValidator":"ED6629D456285AE3613B285F65BBFF168D695BA3921F309949AFCD2CA7AFEC16FE"}"#;
+
+        let txn_as_string = serde_json::to_string(&default_txn).unwrap();
+        let txn_json = txn_as_string.as_str();
+
+        assert_eq!(txn_json, default_json);
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let default_json = r#"{"TransactionType":"UNLModify","Account":"rrrrrrrrrrrrrrrrrrrrrhoLvTp","Fee":"0","Sequence":0,"SigningPubKey":"","LedgerSequence":84803072,"UNLModifyDisabling":0,"UNLModifyValidator":"ED6629D456285AE3613B285F65BBFF168D695BA3921F309949AFCD2CA7AFEC16FE"}"#;
+
+        let txn_as_obj: UNLModify = serde_json::from_str(default_json).unwrap();
+
+        assert_eq!(txn_as_obj.ledger_sequence, 84803072);
+        assert_eq!(txn_as_obj.unlmodify_disabling, UNLModifyDisabling::Disable);
+    }
+
+    #[test]
+    fn test_field_types() {
+        assert_eq!(
+            get_field_instance("LedgerSequence")
+                .unwrap()
+                .associated_type,
+            "UInt32"
+        );
+        assert_eq!(
+            get_field_instance("UNLModifyDisabling")
+                .unwrap()
+                .associated_type,
+            "UInt8"
+        );
+    }
 }
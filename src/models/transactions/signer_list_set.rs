@@ -8,13 +8,21 @@ use serde_with::skip_serializing_none;
 use alloc::string::ToString;
 
 use crate::models::transactions::XRPLSignerListSetException;
+use crate::models::transactions::XRPLTransactionException;
 use crate::models::{
     amount::XRPAmount,
     model::Model,
-    transactions::{Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        get_common_fields_error, CommonFields, Memo, Signer, Transaction, TransactionType,
+        TxnCommonFields,
+    },
 };
 use crate::{serde_with_tag, Err};
 
+/// The documentation of the `SignerListSet` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/signerlistset.html";
+
 serde_with_tag! {
     #[derive(Debug, PartialEq, Eq, Default, Clone, new)]
     #[skip_serializing_none]
@@ -35,63 +43,16 @@ serde_with_tag! {
 #[serde(rename_all = "PascalCase")]
 #[skip_serializing_none]
 pub struct SignerListSet<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::signer_list_set")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    pub flags: Option<u32>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, u32>,
     /// The custom fields for the TicketCreate model.
     ///
     /// See TicketCreate fields:
@@ -104,18 +65,7 @@ impl<'a> Default for SignerListSet<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::SignerListSet,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             signer_quorum: Default::default(),
             signer_entries: Default::default(),
         }
@@ -124,12 +74,17 @@ impl<'a> Default for SignerListSet<'a> {
 
 impl<'a> Model for SignerListSet<'a> {
     fn get_errors(&self) -> Result<()> {
-        match self._get_signer_entries_error() {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self._get_signer_entries_error()?;
+            self._get_signer_quorum_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
             Err(error) => Err!(error),
-            Ok(_no_error) => match self._get_signer_quorum_error() {
-                Err(error) => Err!(error),
-                Ok(_no_error) => Ok(()),
-            },
+            Ok(_no_error) => Ok(()),
         }
     }
 }
@@ -138,6 +93,14 @@ impl<'a> Transaction for SignerListSet<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
 }
 
 impl<'a> SignerListSetError for SignerListSet<'a> {
@@ -147,21 +110,21 @@ impl<'a> SignerListSetError for SignerListSet<'a> {
                 Err(XRPLSignerListSetException::ValueCausesValueDeletion {
                     field1: "signer_entries",
                     field2: "signer_quorum",
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else if signer_entries.is_empty() {
                 Err(XRPLSignerListSetException::CollectionTooFewItems {
                     field: "signer_entries",
                     min: 1_usize,
                     found: signer_entries.len(),
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else if signer_entries.len() > 8 {
                 Err(XRPLSignerListSetException::CollectionTooManyItems {
                     field: "signer_entries",
                     max: 8_usize,
                     found: signer_entries.len(),
-                    resource: "",
+                    resource: RESOURCE,
                 })
             } else {
                 Ok(())
@@ -188,25 +151,25 @@ impl<'a> SignerListSetError for SignerListSet<'a> {
                 return Err(XRPLSignerListSetException::CollectionItemDuplicate {
                     field: "signer_entries",
                     found: account,
-                    resource: "",
+                    resource: RESOURCE,
                 });
             } else {
                 check_account.push(account);
             }
         }
         if let Some(_signer_entries) = &self.signer_entries {
-            if accounts.contains(&&Cow::Borrowed(self.account)) {
+            if accounts.contains(&&self.common_fields.account) {
                 Err(XRPLSignerListSetException::CollectionInvalidItem {
                     field: "signer_entries",
-                    found: self.account,
-                    resource: "",
+                    found: &self.common_fields.account,
+                    resource: RESOURCE,
                 })
             } else if self.signer_quorum > signer_weight_sum {
                 Err(
                     XRPLSignerListSetException::SignerQuorumExceedsSignerWeight {
                         max: signer_weight_sum,
                         found: self.signer_quorum,
-                        resource: "",
+                        resource: RESOURCE,
                     },
                 )
             } else {
@@ -217,7 +180,7 @@ impl<'a> SignerListSetError for SignerListSet<'a> {
                 field: "signer_quorum",
                 expected: 0,
                 found: self.signer_quorum,
-                resource: "",
+                resource: RESOURCE,
             })
         } else {
             Ok(())
@@ -243,22 +206,35 @@ impl<'a> SignerListSet<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::SignerListSet,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags: None,
-            memos,
-            signers,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags: None,
+                memos,
+                signers,
+            },
             signer_quorum,
             signer_entries,
         }
     }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> SignerListSet<'static> {
+        SignerListSet {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            signer_quorum: self.signer_quorum,
+            signer_entries: self.signer_entries,
+        }
+    }
 }
 
 pub trait SignerListSetError {
@@ -280,18 +256,20 @@ mod test_signer_list_set_error {
     fn test_signer_list_deleted_error() {
         let mut signer_list_set = SignerListSet {
             transaction_type: TransactionType::SignerListSet,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             signer_quorum: 0,
             signer_entries: Some(vec![SignerEntry {
                 account: Borrowed("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW"),
@@ -301,7 +279,7 @@ mod test_signer_list_set_error {
 
         assert_eq!(
             signer_list_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `signer_entries` can not be defined with the field `signer_quorum` because it would cause the deletion of `signer_entries`. For more information see: "
+            "The value of the field `signer_entries` can not be defined with the field `signer_quorum` because it would cause the deletion of `signer_entries`. For more information see: https://xrpl.org/signerlistset.html"
         );
 
         signer_list_set.signer_quorum = 3;
@@ -309,7 +287,7 @@ mod test_signer_list_set_error {
 
         assert_eq!(
             signer_list_set.validate().unwrap_err().to_string().as_str(),
-            "The field `signer_quorum` has the wrong value to be deleted (expected 0, found 3). For more information see: "
+            "The field `signer_quorum` has the wrong value to be deleted (expected 0, found 3). For more information see: https://xrpl.org/signerlistset.html"
         );
     }
 
@@ -317,25 +295,27 @@ mod test_signer_list_set_error {
     fn test_signer_entries_error() {
         let mut signer_list_set = SignerListSet {
             transaction_type: TransactionType::SignerListSet,
-            account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
-            fee: None,
-            sequence: None,
-            last_ledger_sequence: None,
-            account_txn_id: None,
-            signing_pub_key: None,
-            source_tag: None,
-            ticket_sequence: None,
-            txn_signature: None,
-            flags: None,
-            memos: None,
-            signers: None,
+            common_fields: CommonFields {
+                account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+                fee: None,
+                sequence: None,
+                last_ledger_sequence: None,
+                account_txn_id: None,
+                signing_pub_key: None,
+                source_tag: None,
+                ticket_sequence: None,
+                txn_signature: None,
+                flags: None,
+                memos: None,
+                signers: None,
+            },
             signer_quorum: 3,
             signer_entries: Some(vec![]),
         };
 
         assert_eq!(
             signer_list_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `signer_entries` has too few items in it (min 1, found 0). For more information see: "
+            "The value of the field `signer_entries` has too few items in it (min 1, found 0). For more information see: https://xrpl.org/signerlistset.html"
         );
 
         signer_list_set.signer_entries = Some(vec![
@@ -379,7 +359,7 @@ mod test_signer_list_set_error {
 
         assert_eq!(
             signer_list_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `signer_entries` has too many items in it (max 8, found 9). For more information see: "
+            "The value of the field `signer_entries` has too many items in it (max 8, found 9). For more information see: https://xrpl.org/signerlistset.html"
         );
 
         signer_list_set.signer_entries = Some(vec![
@@ -399,7 +379,7 @@ mod test_signer_list_set_error {
 
         assert_eq!(
             signer_list_set.validate().unwrap_err().to_string().as_str(),
-            "The field `signer_entries` contains an invalid value (found rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb). For more information see: "
+            "The field `signer_entries` contains an invalid value (found rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb). For more information see: https://xrpl.org/signerlistset.html"
         );
 
         signer_list_set.signer_entries = Some(vec![SignerEntry {
@@ -410,7 +390,7 @@ mod test_signer_list_set_error {
 
         assert_eq!(
             signer_list_set.validate().unwrap_err().to_string().as_str(),
-            "The field `signer_quorum` must be below or equal to the sum of `signer_weight` in `signer_entries`. For more information see: "
+            "The field `signer_quorum` must be below or equal to the sum of `signer_weight` in `signer_entries`. For more information see: https://xrpl.org/signerlistset.html"
         );
 
         signer_list_set.signer_entries = Some(vec![
@@ -427,7 +407,7 @@ mod test_signer_list_set_error {
 
         assert_eq!(
             signer_list_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `signer_entries` has a duplicate in it (found rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW). For more information see: "
+            "The value of the field `signer_entries` has a duplicate in it (found rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW). For more information see: https://xrpl.org/signerlistset.html"
         );
     }
 }
@@ -460,7 +440,7 @@ mod test_serde {
                 SignerEntry::new(Borrowed("raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n"), 1),
             ]),
         );
-        let default_json = r#"{"TransactionType":"SignerListSet","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Fee":"12","Sequence":null,"LastLedgerSequence":null,"AccountTxnID":null,"SigningPubKey":null,"SourceTag":null,"TicketSequence":null,"TxnSignature":null,"Flags":null,"Memos":null,"Signers":null,"SignerQuorum":3,"SignerEntries":[{"SignerEntry":{"Account":"rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW","SignerWeight":2}},{"SignerEntry":{"Account":"rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v","SignerWeight":1}},{"SignerEntry":{"Account":"raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n","SignerWeight":1}}]}"#;
+        let default_json = r#"{"TransactionType":"SignerListSet","Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Fee":"12","SignerQuorum":3,"SignerEntries":[{"SignerEntry":{"Account":"rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW","SignerWeight":2}},{"SignerEntry":{"Account":"rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v","SignerWeight":1}},{"SignerEntry":{"Account":"raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n","SignerWeight":1}}]}"#;
 
         let txn_as_string = serde_json::to_string(&default_txn).unwrap();
         let txn_json = txn_as_string.as_str();
@@ -495,4 +475,12 @@ mod test_serde {
 
         assert_eq!(txn_as_obj, default_txn);
     }
+
+    #[test]
+    fn test_deserialize_without_transaction_type() {
+        let json = r#"{"Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","SignerQuorum":0}"#;
+        let txn_as_obj: SignerListSet = serde_json::from_str(json).unwrap();
+
+        assert_eq!(txn_as_obj.transaction_type, TransactionType::SignerListSet);
+    }
 }
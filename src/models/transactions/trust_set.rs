@@ -1,16 +1,26 @@
+use crate::Err;
+use alloc::string::ToString;
 use alloc::vec::Vec;
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::skip_serializing_none;
 use strum_macros::{AsRefStr, Display, EnumIter};
 
+use crate::models::transactions::{FlagBits, XRPLTransactionException, XRPLTrustSetException};
 use crate::models::{
     model::Model,
-    transactions::{Flag, Memo, Signer, Transaction, TransactionType},
+    transactions::{
+        clear_txn_flag, get_common_fields_error, set_txn_flag, CommonFields, Flag, Memo, Signer,
+        Transaction, TransactionType, TxnCommonFields,
+    },
 };
 
-use crate::_serde::txn_flags;
-use crate::models::amount::{IssuedCurrencyAmount, XRPAmount};
+use crate::models::amount::{IssuedCurrencyAmount, IssuedCurrencyAmountError, XRPAmount};
+
+/// The documentation of the `TrustSet` transaction,
+/// linked by its errors.
+const RESOURCE: &str = "https://xrpl.org/trustset.html";
 
 /// Transactions of the TrustSet type support additional values
 /// in the Flags field. This enum represents those options.
@@ -35,6 +45,12 @@ pub enum TrustSetFlag {
     TfClearFreeze = 0x00200000,
 }
 
+impl FlagBits for TrustSetFlag {
+    fn bits(&self) -> u32 {
+        self.clone() as u32
+    }
+}
+
 /// Create or modify a trust line linking two accounts.
 ///
 /// See TrustSet:
@@ -43,65 +59,16 @@ pub enum TrustSetFlag {
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct TrustSet<'a> {
-    // The base fields for all transaction models.
-    //
-    // See Transaction Types:
-    // `<https://xrpl.org/transaction-types.html>`
-    //
-    // See Transaction Common Fields:
-    // `<https://xrpl.org/transaction-common-fields.html>`
     /// The type of transaction.
     #[serde(default = "TransactionType::trust_set")]
     pub transaction_type: TransactionType,
-    /// The unique address of the account that initiated the transaction.
-    pub account: &'a str,
-    /// Integer amount of XRP, in drops, to be destroyed as a cost
-    /// for distributing this transaction to the network. Some
-    /// transaction types have different minimum requirements.
-    /// See Transaction Cost for details.
-    pub fee: Option<XRPAmount<'a>>,
-    /// The sequence number of the account sending the transaction.
-    /// A transaction is only valid if the Sequence number is exactly
-    /// 1 greater than the previous transaction from the same account.
-    /// The special case 0 means the transaction is using a Ticket instead.
-    pub sequence: Option<u32>,
-    /// Highest ledger index this transaction can appear in.
-    /// Specifying this field places a strict upper limit on how long
-    /// the transaction can wait to be validated or rejected.
-    /// See Reliable Transaction Submission for more details.
-    pub last_ledger_sequence: Option<u32>,
-    /// Hash value identifying another transaction. If provided, this
-    /// transaction is only valid if the sending account's
-    /// previously-sent transaction matches the provided hash.
-    #[serde(rename = "AccountTxnID")]
-    pub account_txn_id: Option<&'a str>,
-    /// Hex representation of the public key that corresponds to the
-    /// private key used to sign this transaction. If an empty string,
-    /// indicates a multi-signature is present in the Signers field instead.
-    pub signing_pub_key: Option<&'a str>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction
-    /// is made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub source_tag: Option<u32>,
-    /// The sequence number of the ticket to use in place
-    /// of a Sequence number. If this is provided, Sequence must
-    /// be 0. Cannot be used with AccountTxnID.
-    pub ticket_sequence: Option<u32>,
-    /// The signature that verifies this transaction as originating
-    /// from the account it says it is from.
-    pub txn_signature: Option<&'a str>,
-    /// Set of bit-flags for this transaction.
-    #[serde(default)]
-    #[serde(with = "txn_flags")]
-    pub flags: Option<Vec<TrustSetFlag>>,
-    /// Additional arbitrary information used to identify this transaction.
-    pub memos: Option<Vec<Memo<'a>>>,
-    /// Arbitrary integer used to identify the reason for this
-    /// payment, or a sender on whose behalf this transaction is
-    /// made. Conventionally, a refund should specify the initial
-    /// payment's SourceTag as the refund payment's DestinationTag.
-    pub signers: Option<Vec<Signer<'a>>>,
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    #[serde(borrow)]
+    pub common_fields: CommonFields<'a, Vec<TrustSetFlag>>,
     /// The custom fields for the TrustSet model.
     ///
     /// See TrustSet fields:
@@ -115,18 +82,7 @@ impl<'a> Default for TrustSet<'a> {
     fn default() -> Self {
         Self {
             transaction_type: TransactionType::TrustSet,
-            account: Default::default(),
-            fee: Default::default(),
-            sequence: Default::default(),
-            last_ledger_sequence: Default::default(),
-            account_txn_id: Default::default(),
-            signing_pub_key: Default::default(),
-            source_tag: Default::default(),
-            ticket_sequence: Default::default(),
-            txn_signature: Default::default(),
-            flags: Default::default(),
-            memos: Default::default(),
-            signers: Default::default(),
+            common_fields: Default::default(),
             limit_amount: Default::default(),
             quality_in: Default::default(),
             quality_out: Default::default(),
@@ -134,13 +90,28 @@ impl<'a> Default for TrustSet<'a> {
     }
 }
 
-impl<'a> Model for TrustSet<'a> {}
+impl<'a: 'static> Model for TrustSet<'a> {
+    fn get_errors(&self) -> Result<()> {
+        let errors = || -> Result<(), XRPLTransactionException> {
+            get_common_fields_error(&self.common_fields)?;
+            self.limit_amount._get_currency_error()?;
+            self._get_limit_amount_error()?;
+
+            Ok(())
+        };
+
+        match errors() {
+            Err(error) => Err!(error),
+            Ok(_no_error) => Ok(()),
+        }
+    }
+}
 
 impl<'a> Transaction for TrustSet<'a> {
     fn has_flag(&self, flag: &Flag) -> bool {
         let mut flags = &Vec::new();
 
-        if let Some(flag_set) = self.flags.as_ref() {
+        if let Some(flag_set) = self.common_fields.flags.as_ref() {
             flags = flag_set;
         }
 
@@ -156,9 +127,43 @@ impl<'a> Transaction for TrustSet<'a> {
         }
     }
 
+    fn set_flag(&mut self, flag: &Flag) {
+        if let Flag::TrustSet(trust_set_flag) = flag {
+            set_txn_flag(&mut self.common_fields.flags, trust_set_flag.clone());
+        }
+    }
+
+    fn clear_flag(&mut self, flag: &Flag) {
+        if let Flag::TrustSet(trust_set_flag) = flag {
+            clear_txn_flag(&mut self.common_fields.flags, trust_set_flag);
+        }
+    }
+
     fn get_transaction_type(&self) -> TransactionType {
         self.transaction_type.clone()
     }
+
+    fn common_fields(&self) -> Option<&dyn TxnCommonFields> {
+        Some(&self.common_fields)
+    }
+
+    fn common_fields_mut(&mut self) -> Option<&mut dyn TxnCommonFields> {
+        Some(&mut self.common_fields)
+    }
+}
+
+impl<'a> TrustSetError for TrustSet<'a> {
+    fn _get_limit_amount_error(&self) -> Result<(), XRPLTrustSetException<'_>> {
+        if self.limit_amount.is_negative() {
+            Err(XRPLTrustSetException::ValueNegative {
+                field: "limit_amount",
+                found: &self.limit_amount.value,
+                resource: RESOURCE,
+            })
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl<'a> TrustSet<'a> {
@@ -181,23 +186,83 @@ impl<'a> TrustSet<'a> {
     ) -> Self {
         Self {
             transaction_type: TransactionType::TrustSet,
-            account,
-            fee,
-            sequence,
-            last_ledger_sequence,
-            account_txn_id,
-            signing_pub_key,
-            source_tag,
-            ticket_sequence,
-            txn_signature,
-            flags,
-            memos,
-            signers,
+            common_fields: CommonFields {
+                account: account.into(),
+                fee,
+                sequence,
+                last_ledger_sequence,
+                account_txn_id: account_txn_id.map(Into::into),
+                signing_pub_key: signing_pub_key.map(Into::into),
+                source_tag,
+                ticket_sequence,
+                txn_signature: txn_signature.map(Into::into),
+                flags,
+                memos,
+                signers,
+            },
             limit_amount,
             quality_in,
             quality_out,
         }
     }
+
+    /// Returns the transaction without borrowed data, so that it
+    /// can outlive the data it was deserialized or built from.
+    pub fn into_owned(self) -> TrustSet<'static> {
+        TrustSet {
+            transaction_type: self.transaction_type,
+            common_fields: self.common_fields.into_owned(),
+            limit_amount: self.limit_amount.into_owned(),
+            quality_in: self.quality_in,
+            quality_out: self.quality_out,
+        }
+    }
+}
+
+pub trait TrustSetError {
+    /// The limit of a trust line is not allowed to be negative.
+    fn _get_limit_amount_error(&self) -> Result<(), XRPLTrustSetException<'_>>;
+}
+
+#[cfg(test)]
+mod test_trust_set_error {
+    use crate::models::{amount::IssuedCurrencyAmount, transactions::CommonFields, Model};
+    use alloc::string::ToString;
+
+    use super::TrustSet;
+
+    fn trust_set(value: &'static str) -> TrustSet<'static> {
+        TrustSet {
+            common_fields: CommonFields {
+                account: "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX".into(),
+                ..Default::default()
+            },
+            limit_amount: IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rsP3mgGb2tcYUrxiLFiHJiQXhsziegtwBc".into(),
+                value.into(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_negative_limit_amount_error() {
+        assert_eq!(
+            trust_set("-100").validate().unwrap_err().to_string().as_str(),
+            "The value of the field `limit_amount` is not allowed to be negative (found -100). For more information see: https://xrpl.org/trustset.html"
+        );
+        assert_eq!(
+            trust_set("-1e-30").validate().unwrap_err().to_string().as_str(),
+            "The value of the field `limit_amount` is not allowed to be negative (found -1e-30). For more information see: https://xrpl.org/trustset.html"
+        );
+    }
+
+    #[test]
+    fn test_valid_limit_amount() {
+        assert!(trust_set("100").validate().is_ok());
+        assert!(trust_set("0").validate().is_ok());
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,18 @@
+//! Convenience re-exports of the most commonly used types.
+//!
+//! ```
+//! use xrpl::prelude::*;
+//! ```
+//!
+//! Network clients will be re-exported here once they
+//! are implemented.
+
+#[cfg(feature = "models")]
+pub use crate::models::amount::{Amount, IssuedCurrencyAmount, MPTAmount, XRPAmount};
+#[cfg(feature = "models")]
+pub use crate::models::currency::{Currency, IssuedCurrency, XRP};
+#[cfg(feature = "models")]
+pub use crate::models::transactions::*;
+#[cfg(feature = "models")]
+pub use crate::models::Model;
+pub use crate::wallet::Wallet;
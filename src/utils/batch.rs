@@ -0,0 +1,369 @@
+//! Submitting many transactions of one account with
+//! consecutive sequence numbers.
+
+use crate::models::transactions::Transaction;
+use crate::utils::exceptions::XRPLBatchException;
+use crate::utils::retry::{classify_engine_result, EngineResultClass};
+use crate::wallet::signing::OfflineSigner;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::Serialize;
+
+/// A transaction submitted by a `BatchSubmitter`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchEntry {
+    /// The position of the transaction in the batch.
+    pub index: usize,
+    /// The sequence number assigned to the transaction.
+    pub sequence: u32,
+    /// The preliminary engine result of the submission.
+    pub engine_result: String,
+    /// Whether the transaction is included in a validated ledger.
+    pub validated: bool,
+}
+
+/// Signs and submits transactions of one account with
+/// consecutive sequence numbers and tracks which of them
+/// validated.
+///
+/// A transaction that is not applied or queued leaves a gap in
+/// the sequence numbers, so every later transaction of the batch
+/// could not succeed. The submitter halts at the first such
+/// submission, like a `tem`, `tef` or `tel` result, and reports
+/// it; its sequence number is reused by the next submission.
+/// A `tec` result is a failure too, but the transaction is
+/// included in a ledger and consumes its sequence number, so it
+/// is recorded and the batch goes on.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::transactions::{AccountSet, CommonFields};
+/// use xrpl::utils::batch::BatchSubmitter;
+/// use xrpl::wallet::signing::OfflineSigner;
+/// use xrpl::wallet::Wallet;
+///
+/// let wallet = Wallet::create(None).unwrap();
+/// let account_set = AccountSet {
+///     common_fields: CommonFields {
+///         account: wallet.classic_address.clone().into(),
+///         fee: Some("12".into()),
+///         last_ledger_sequence: Some(100),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// let mut transactions = vec![account_set.clone(), account_set];
+///
+/// let mut submitter = BatchSubmitter::new(10);
+/// let result = submitter.submit(
+///     &mut transactions,
+///     &OfflineSigner::new(&wallet),
+///     |_tx_blob| "tesSUCCESS".to_string(),
+/// );
+///
+/// assert!(result.is_ok());
+/// assert_eq!(transactions[1].common_fields.sequence, Some(11));
+/// assert_eq!(submitter.next_sequence(), 12);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchSubmitter {
+    next_sequence: u32,
+    entries: Vec<BatchEntry>,
+}
+
+impl BatchSubmitter {
+    /// Create a submitter assigning sequence numbers starting
+    /// at `first_sequence`, usually the account's current
+    /// `Sequence` from `account_info`.
+    pub fn new(first_sequence: u32) -> Self {
+        Self {
+            next_sequence: first_sequence,
+            entries: Vec::new(),
+        }
+    }
+
+    /// The sequence number assigned to the next submission.
+    pub fn next_sequence(&self) -> u32 {
+        self.next_sequence
+    }
+
+    /// The transactions submitted so far that consumed their
+    /// sequence number, including those failed with a `tec` result.
+    pub fn entries(&self) -> &[BatchEntry] {
+        &self.entries
+    }
+
+    /// The transactions that consumed their sequence number but
+    /// failed with a `tec` result.
+    pub fn failed(&self) -> impl Iterator<Item = &BatchEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.engine_result.starts_with("tec"))
+    }
+
+    /// The transactions submitted so far that are not yet
+    /// validated.
+    pub fn pending(&self) -> impl Iterator<Item = &BatchEntry> {
+        self.entries.iter().filter(|entry| !entry.validated)
+    }
+
+    /// Sign and submit the transactions in order.
+    ///
+    /// Each transaction gets the next sequence number and is
+    /// signed with `signer`. `submit` is called with the hex
+    /// encoded `tx_blob`, submits it and returns the preliminary
+    /// engine result. Halts at the first submission that is
+    /// neither applied, queued nor failed with a `tec` result,
+    /// and before a transaction that can not be signed or would
+    /// overflow the sequence number.
+    pub fn submit<T, F>(
+        &mut self,
+        transactions: &mut [T],
+        signer: &OfflineSigner,
+        mut submit: F,
+    ) -> Result<(), XRPLBatchException>
+    where
+        T: Transaction + Serialize,
+        F: FnMut(&str) -> String,
+    {
+        let first_index = self.entries.len();
+        for (offset, transaction) in transactions.iter_mut().enumerate() {
+            let index = first_index + offset;
+            let sequence = self.next_sequence;
+            let next_sequence = sequence
+                .checked_add(1)
+                .ok_or(XRPLBatchException::SequenceOverflow { index, sequence })?;
+            if let Some(common_fields) = transaction.common_fields_mut() {
+                common_fields.set_sequence(sequence);
+            }
+            let tx_blob = signer.sign(transaction)?;
+            let engine_result = submit(&tx_blob);
+            if classify_engine_result(&engine_result) != EngineResultClass::Success
+                && !engine_result.starts_with("tec")
+            {
+                return Err(XRPLBatchException::SequenceGap {
+                    index,
+                    sequence,
+                    engine_result,
+                });
+            }
+            self.entries.push(BatchEntry {
+                index,
+                sequence,
+                engine_result,
+                validated: false,
+            });
+            self.next_sequence = next_sequence;
+        }
+
+        Ok(())
+    }
+
+    /// Mark the transaction with the given sequence number as
+    /// validated. Returns `false` if no submitted transaction
+    /// has this sequence number.
+    pub fn mark_validated(&mut self, sequence: u32) -> bool {
+        match self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.sequence == sequence)
+        {
+            Some(entry) => {
+                entry.validated = true;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::binarycodec::decode;
+    use crate::models::transactions::{AccountSet, CommonFields};
+    use crate::wallet::exceptions::XRPLSignException;
+    use crate::wallet::Wallet;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    const SEED: &str = "sEdTM1uX8pu2do5XvTnutH6HsouMaM2";
+
+    fn account_sets(wallet: &Wallet, count: usize) -> Vec<AccountSet<'_>> {
+        let account_set = AccountSet {
+            common_fields: CommonFields {
+                account: wallet.classic_address.as_str().into(),
+                fee: Some("12".into()),
+                last_ledger_sequence: Some(100),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        vec![account_set; count]
+    }
+
+    fn sequence_of(tx_blob: &str) -> u32 {
+        decode(tx_blob).unwrap()["Sequence"].as_u64().unwrap() as u32
+    }
+
+    #[test]
+    fn test_submit() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let mut transactions = account_sets(&wallet, 3);
+        let mut submitter = BatchSubmitter::new(10);
+        let mut sequences = Vec::new();
+        let result = submitter.submit(&mut transactions, &OfflineSigner::new(&wallet), |tx_blob| {
+            sequences.push(sequence_of(tx_blob));
+            "tesSUCCESS".to_string()
+        });
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(sequences, vec![10, 11, 12]);
+        assert_eq!(
+            transactions
+                .iter()
+                .map(|transaction| transaction.common_fields.sequence)
+                .collect::<Vec<Option<u32>>>(),
+            vec![Some(10), Some(11), Some(12)]
+        );
+        assert_eq!(submitter.next_sequence(), 13);
+        assert_eq!(submitter.pending().count(), 3);
+
+        assert!(submitter.mark_validated(11));
+        assert!(!submitter.mark_validated(13));
+        assert_eq!(
+            submitter
+                .pending()
+                .map(|entry| entry.sequence)
+                .collect::<Vec<u32>>(),
+            vec![10, 12]
+        );
+    }
+
+    #[test]
+    fn test_sequence_gap() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let signer = OfflineSigner::new(&wallet);
+        let mut transactions = account_sets(&wallet, 3);
+        let mut submitter = BatchSubmitter::new(10);
+        let mut engine_results = vec!["tesSUCCESS", "temBAD_FEE", "tesSUCCESS"].into_iter();
+        let mut submissions = 0;
+        let result = submitter.submit(&mut transactions, &signer, |_tx_blob| {
+            submissions += 1;
+            engine_results.next().unwrap().to_string()
+        });
+
+        assert_eq!(
+            result,
+            Err(XRPLBatchException::SequenceGap {
+                index: 1,
+                sequence: 11,
+                engine_result: "temBAD_FEE".to_string(),
+            })
+        );
+        assert_eq!(submissions, 2);
+        assert_eq!(submitter.entries().len(), 1);
+        assert_eq!(submitter.next_sequence(), 11);
+
+        let mut sequences = Vec::new();
+        let result = submitter.submit(&mut transactions[1..], &signer, |tx_blob| {
+            sequences.push(sequence_of(tx_blob));
+            "terQUEUED".to_string()
+        });
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(sequences, vec![11, 12]);
+        assert_eq!(
+            submitter
+                .entries()
+                .iter()
+                .map(|entry| (entry.index, entry.sequence))
+                .collect::<Vec<(usize, u32)>>(),
+            vec![(0, 10), (1, 11), (2, 12)]
+        );
+    }
+
+    #[test]
+    fn test_claimed_failure() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let mut transactions = account_sets(&wallet, 3);
+        let mut submitter = BatchSubmitter::new(10);
+        let mut engine_results =
+            vec!["tesSUCCESS", "tecUNFUNDED_PAYMENT", "tesSUCCESS"].into_iter();
+        let mut sequences = Vec::new();
+        let result = submitter.submit(&mut transactions, &OfflineSigner::new(&wallet), |tx_blob| {
+            sequences.push(sequence_of(tx_blob));
+            engine_results.next().unwrap().to_string()
+        });
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(sequences, vec![10, 11, 12]);
+        assert_eq!(submitter.next_sequence(), 13);
+        assert_eq!(submitter.entries().len(), 3);
+        assert_eq!(
+            submitter.failed().collect::<Vec<&BatchEntry>>(),
+            vec![&BatchEntry {
+                index: 1,
+                sequence: 11,
+                engine_result: "tecUNFUNDED_PAYMENT".to_string(),
+                validated: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_sequence_overflow() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let mut transactions = account_sets(&wallet, 1);
+        let mut submitter = BatchSubmitter::new(u32::MAX);
+        let mut submissions = 0;
+        let result = submitter.submit(
+            &mut transactions,
+            &OfflineSigner::new(&wallet),
+            |_tx_blob| {
+                submissions += 1;
+                "tesSUCCESS".to_string()
+            },
+        );
+
+        assert_eq!(
+            result,
+            Err(XRPLBatchException::SequenceOverflow {
+                index: 0,
+                sequence: u32::MAX,
+            })
+        );
+        assert_eq!(submissions, 0);
+        assert_eq!(submitter.next_sequence(), u32::MAX);
+    }
+
+    #[test]
+    fn test_sign_error() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let mut transactions = account_sets(&wallet, 2);
+        transactions[1].common_fields.fee = None;
+        let mut submitter = BatchSubmitter::new(10);
+        let mut submissions = 0;
+        let result = submitter.submit(
+            &mut transactions,
+            &OfflineSigner::new(&wallet),
+            |_tx_blob| {
+                submissions += 1;
+                "tesSUCCESS".to_string()
+            },
+        );
+
+        assert_eq!(
+            result,
+            Err(XRPLBatchException::SignError(
+                XRPLSignException::MissingField { field: "Fee" }
+            ))
+        );
+        assert_eq!(submissions, 1);
+        assert_eq!(submitter.next_sequence(), 11);
+    }
+}
@@ -1,5 +1,7 @@
 //! Exception for invalid XRP Ledger amount data.
 
+#[cfg(feature = "models")]
+use crate::wallet::exceptions::XRPLSignException;
 use alloc::string::String;
 use strum_macros::Display;
 
@@ -55,6 +57,54 @@ pub enum JSONParseException {
     },
 }
 
+#[derive(Debug, Clone, PartialEq, Display)]
+#[non_exhaustive]
+pub enum XRPLFeeException {
+    FeeExceedsMaxFee { max: String, found: String },
+    DecimalError(rust_decimal::Error),
+}
+
+#[derive(Debug, Clone, PartialEq, Display)]
+#[non_exhaustive]
+pub enum XRPLRetryException {
+    SubmissionFailed {
+        engine_result: String,
+    },
+    RetriesExhausted {
+        attempts: u32,
+        engine_result: String,
+    },
+}
+
+#[cfg(feature = "models")]
+#[derive(Debug, PartialEq, Display)]
+#[non_exhaustive]
+pub enum XRPLBatchException {
+    SequenceGap {
+        index: usize,
+        sequence: u32,
+        engine_result: String,
+    },
+    SequenceOverflow {
+        index: usize,
+        sequence: u32,
+    },
+    SignError(XRPLSignException),
+}
+
+#[cfg(feature = "models")]
+impl From<XRPLSignException> for XRPLBatchException {
+    fn from(err: XRPLSignException) -> Self {
+        XRPLBatchException::SignError(err)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Display)]
+#[non_exhaustive]
+pub enum XRPLSubscriptionBufferException {
+    BufferFull { capacity: usize },
+}
+
 impl From<rust_decimal::Error> for XRPRangeException {
     fn from(err: rust_decimal::Error) -> Self {
         XRPRangeException::DecimalError(err)
@@ -85,6 +135,12 @@ impl From<hex::FromHexError> for ISOCodeException {
     }
 }
 
+impl From<rust_decimal::Error> for XRPLFeeException {
+    fn from(err: rust_decimal::Error) -> Self {
+        XRPLFeeException::DecimalError(err)
+    }
+}
+
 impl From<XRPRangeException> for JSONParseException {
     fn from(err: XRPRangeException) -> Self {
         JSONParseException::XRPRangeError(err)
@@ -111,3 +167,12 @@ impl alloc::error::Error for XRPRangeException {}
 
 #[cfg(feature = "std")]
 impl alloc::error::Error for ISOCodeException {}
+
+#[cfg(feature = "std")]
+impl alloc::error::Error for XRPLFeeException {}
+
+#[cfg(feature = "std")]
+impl alloc::error::Error for XRPLRetryException {}
+
+#[cfg(all(feature = "std", feature = "models"))]
+impl alloc::error::Error for XRPLBatchException {}
@@ -0,0 +1,117 @@
+//! Calculation of transaction costs.
+
+use crate::utils::exceptions::XRPLFeeException;
+use alloc::format;
+use alloc::string::{String, ToString};
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
+
+/// The reference transaction cost of the XRP Ledger in drops,
+/// the cost of a single-signed transaction without load.
+pub const REFERENCE_FEE_DROPS: u64 = 10;
+
+/// The default maximum transaction cost of 2 XRP in drops.
+pub const DEFAULT_MAX_FEE_DROPS: u64 = 2000000;
+
+/// The transaction cost of 1 XRP in drops, above which an
+/// explicitly set fee is most likely a typo. Enforced by
+/// `OfflineSigner` when signing.
+pub const HIGH_FEE_CEILING_DROPS: u64 = 1000000;
+
+/// Calculate the transaction cost in drops by scaling the
+/// `base_fee` in drops by the server's `load_factor`, rounded
+/// up to the next drop. Errors if the cost exceeds `max_fee`
+/// in drops, which defaults to `DEFAULT_MAX_FEE_DROPS`, to not
+/// overpay during load spikes. A cost too large for a `Decimal`
+/// errors with a `DecimalError`, like a `load_factor` too large
+/// to parse.
+///
+/// See Transaction Cost:
+/// `<https://xrpl.org/transaction-cost.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::calculate_fee;
+/// use xrpl::utils::exceptions::XRPLFeeException;
+///
+/// assert_eq!(calculate_fee("10", "1.5", None), Ok("15".to_string()));
+/// assert!(matches!(
+///     calculate_fee("10", "1000000", None),
+///     Err(XRPLFeeException::FeeExceedsMaxFee { max: _, found: _ })
+/// ));
+/// ```
+pub fn calculate_fee(
+    base_fee: &str,
+    load_factor: &str,
+    max_fee: Option<&str>,
+) -> Result<String, XRPLFeeException> {
+    let base_fee_d = Decimal::from_str(base_fee)?;
+    let load_factor_d = Decimal::from_str(load_factor)?;
+    let max_fee_d = match max_fee {
+        Some(max_fee) => Decimal::from_str(max_fee)?,
+        None => Decimal::from(DEFAULT_MAX_FEE_DROPS),
+    };
+    let fee = base_fee_d
+        .checked_mul(load_factor_d)
+        .ok_or(rust_decimal::Error::ExceedsMaximumPossibleValue)?
+        .ceil();
+
+    if fee > max_fee_d {
+        Err(XRPLFeeException::FeeExceedsMaxFee {
+            max: max_fee_d.to_string(),
+            found: fee.to_string(),
+        })
+    } else {
+        Ok(format!("{}", fee.normalize()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_calculate_fee() {
+        assert_eq!(calculate_fee("10", "1", None), Ok("10".to_string()));
+        assert_eq!(calculate_fee("10", "2.56", None), Ok("26".to_string()));
+        assert_eq!(
+            calculate_fee("10", "200000", None),
+            Ok("2000000".to_string())
+        );
+        assert_eq!(
+            calculate_fee("10", "1000", Some("10000")),
+            Ok("10000".to_string())
+        );
+    }
+
+    #[test]
+    fn test_calculate_fee_exceeds_max_fee() {
+        assert_eq!(
+            calculate_fee("10", "200001", None),
+            Err(XRPLFeeException::FeeExceedsMaxFee {
+                max: "2000000".to_string(),
+                found: "2000010".to_string(),
+            })
+        );
+        assert_eq!(
+            calculate_fee("10", "1001", Some("10000")),
+            Err(XRPLFeeException::FeeExceedsMaxFee {
+                max: "10000".to_string(),
+                found: "10010".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_calculate_fee_overflow() {
+        assert_eq!(
+            calculate_fee("10", "79228162514264337593543950335", None),
+            Err(XRPLFeeException::DecimalError(
+                rust_decimal::Error::ExceedsMaximumPossibleValue
+            ))
+        );
+    }
+}
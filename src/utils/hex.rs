@@ -0,0 +1,113 @@
+//! Hex and base64 conversion of binary data such as memos,
+//! conditions, signatures and manifests.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub use ::base64::DecodeError;
+pub use ::hex::FromHexError;
+
+/// Encode bytes as an uppercase hex string, the format used
+/// by the XRP Ledger.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::hex::to_hex;
+///
+/// assert_eq!(to_hex([0xDE, 0xAD, 0xBE, 0xEF]), "DEADBEEF");
+/// ```
+pub fn to_hex<T: AsRef<[u8]>>(bytes: T) -> String {
+    ::hex::encode_upper(bytes)
+}
+
+/// Decode a hex string of either case. Errors on an odd
+/// number of digits or characters that are not hex digits.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::hex::from_hex;
+///
+/// assert_eq!(from_hex("deadBEEF"), Ok(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+/// assert!(from_hex("ABC").is_err());
+/// ```
+pub fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Vec<u8>, FromHexError> {
+    ::hex::decode(hex)
+}
+
+/// Encode bytes as a padded standard base64 string.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::hex::to_base64;
+///
+/// assert_eq!(to_base64("xrpl"), "eHJwbA==");
+/// ```
+pub fn to_base64<T: AsRef<[u8]>>(bytes: T) -> String {
+    ::base64::encode(bytes)
+}
+
+/// Decode a padded standard base64 string.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::hex::from_base64;
+///
+/// assert_eq!(from_base64("eHJwbA=="), Ok(b"xrpl".to_vec()));
+/// ```
+pub fn from_base64<T: AsRef<[u8]>>(base64: T) -> Result<Vec<u8>, DecodeError> {
+    ::base64::decode(base64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_hex_round_trip() {
+        let bytes = vec![0x00, 0x0A, 0xBC, 0xFF];
+
+        assert_eq!(to_hex(&bytes), "000ABCFF");
+        assert_eq!(from_hex(to_hex(&bytes)), Ok(bytes));
+        assert_eq!(from_hex("000abcff"), from_hex("000ABCFF"));
+        assert_eq!(to_hex([]), "");
+        assert_eq!(from_hex(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_invalid_hex() {
+        assert_eq!(from_hex("ABC"), Err(FromHexError::OddLength));
+        assert_eq!(
+            from_hex("XY"),
+            Err(FromHexError::InvalidHexCharacter { c: 'X', index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_base64_round_trip() {
+        let bytes = vec![0x00, 0x0A, 0xBC, 0xFF, 0x10];
+
+        assert_eq!(to_base64(&bytes), "AAq8/xA=");
+        assert_eq!(from_base64(to_base64(&bytes)), Ok(bytes));
+        assert_eq!(to_base64([]), "");
+        assert_eq!(from_base64(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_invalid_base64() {
+        assert!(from_base64("AAq8/x").is_err());
+        assert!(from_base64("AA*8").is_err());
+    }
+}
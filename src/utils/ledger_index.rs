@@ -0,0 +1,197 @@
+//! Computation of the deterministic IDs of ledger objects,
+//! the `index` to look them up with `ledger_entry`.
+//!
+//! See Ledger Object IDs:
+//! `<https://xrpl.org/ledger-object-ids.html>`
+
+use crate::core::keypairs::utils::sha512_first_half;
+use crate::core::types::exceptions::XRPLHashException;
+use crate::core::types::{AccountId, Currency};
+use crate::utils::hex::to_hex;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+/// The namespaces separating the IDs of the ledger object types.
+#[derive(Debug, Clone, Copy)]
+#[repr(u16)]
+enum LedgerSpace {
+    Account = 0x0061,
+    Check = 0x0043,
+    Escrow = 0x0075,
+    Offer = 0x006F,
+    RippleState = 0x0072,
+    Ticket = 0x0054,
+}
+
+/// Hashes the namespace and the data of a ledger object ID.
+fn _ledger_index(space: LedgerSpace, data: &[&[u8]]) -> String {
+    let mut bytes: Vec<u8> = (space as u16).to_be_bytes().to_vec();
+    for part in data {
+        bytes.extend_from_slice(part);
+    }
+
+    to_hex(sha512_first_half(&bytes))
+}
+
+/// Computes the ID of an object created by the transaction of
+/// `account` with `sequence`.
+fn _sequence_index(
+    space: LedgerSpace,
+    account: &str,
+    sequence: u32,
+) -> Result<String, XRPLHashException> {
+    let account_id = AccountId::try_from(account)?;
+
+    Ok(_ledger_index(
+        space,
+        &[account_id.as_ref(), &sequence.to_be_bytes()],
+    ))
+}
+
+/// Computes the ID of the AccountRoot object of `account`.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::ledger_index::account_root_index;
+///
+/// assert_eq!(
+///     account_root_index("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap(),
+///     "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+/// );
+/// ```
+pub fn account_root_index(account: &str) -> Result<String, XRPLHashException> {
+    let account_id = AccountId::try_from(account)?;
+
+    Ok(_ledger_index(LedgerSpace::Account, &[account_id.as_ref()]))
+}
+
+/// Computes the ID of the Offer created by the `OfferCreate`
+/// transaction of `account` with `sequence`.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::ledger_index::offer_index;
+///
+/// assert_eq!(
+///     offer_index("rBqb89MRQJnMPq8wTwEbtz4kvxrEDfcYvt", 866).unwrap(),
+///     "96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797",
+/// );
+/// ```
+pub fn offer_index(account: &str, sequence: u32) -> Result<String, XRPLHashException> {
+    _sequence_index(LedgerSpace::Offer, account, sequence)
+}
+
+/// Computes the ID of the Check created by the `CheckCreate`
+/// transaction of `account` with `sequence`.
+pub fn check_index(account: &str, sequence: u32) -> Result<String, XRPLHashException> {
+    _sequence_index(LedgerSpace::Check, account, sequence)
+}
+
+/// Computes the ID of the Escrow created by the `EscrowCreate`
+/// transaction of `account` with `sequence`.
+pub fn escrow_index(account: &str, sequence: u32) -> Result<String, XRPLHashException> {
+    _sequence_index(LedgerSpace::Escrow, account, sequence)
+}
+
+/// Computes the ID of the Ticket of `account` with
+/// `ticket_sequence`.
+pub fn ticket_index(account: &str, ticket_sequence: u32) -> Result<String, XRPLHashException> {
+    _sequence_index(LedgerSpace::Ticket, account, ticket_sequence)
+}
+
+/// Computes the ID of the RippleState object of the trust line
+/// between two accounts for `currency`. The accounts can be given
+/// in any order, they are sorted into the low and high account.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::ledger_index::ripple_state_index;
+///
+/// assert_eq!(
+///     ripple_state_index(
+///         "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+///         "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///         "USD",
+///     )
+///     .unwrap(),
+///     "9CA88CDEDFF9252B3DE183CE35B038F57282BC9503CDFA1923EF9A95DF0D6F7B",
+/// );
+/// ```
+pub fn ripple_state_index(
+    account: &str,
+    other_account: &str,
+    currency: &str,
+) -> Result<String, XRPLHashException> {
+    let account_id = AccountId::try_from(account)?;
+    let other_account_id = AccountId::try_from(other_account)?;
+    let currency = Currency::try_from(currency)?;
+    let (low, high) = if account_id.as_ref() < other_account_id.as_ref() {
+        (account_id, other_account_id)
+    } else {
+        (other_account_id, account_id)
+    };
+
+    Ok(_ledger_index(
+        LedgerSpace::RippleState,
+        &[low.as_ref(), high.as_ref(), currency.as_ref()],
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+    const RIPPLE_STATE_INDEX: &str =
+        "9CA88CDEDFF9252B3DE183CE35B038F57282BC9503CDFA1923EF9A95DF0D6F7B";
+
+    #[test]
+    fn test_account_root_index() {
+        assert_eq!(
+            account_root_index(ACCOUNT).unwrap(),
+            "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+        );
+    }
+
+    #[test]
+    fn test_offer_index() {
+        assert_eq!(
+            offer_index("rBqb89MRQJnMPq8wTwEbtz4kvxrEDfcYvt", 866).unwrap(),
+            "96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797"
+        );
+    }
+
+    #[test]
+    fn test_ripple_state_index() {
+        let low = "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW";
+
+        assert_eq!(
+            ripple_state_index(low, ACCOUNT, "USD").unwrap(),
+            RIPPLE_STATE_INDEX
+        );
+        assert_eq!(
+            ripple_state_index(ACCOUNT, low, "USD").unwrap(),
+            RIPPLE_STATE_INDEX
+        );
+        assert_ne!(
+            ripple_state_index(low, ACCOUNT, "EUR").unwrap(),
+            RIPPLE_STATE_INDEX
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(account_root_index("invalid").is_err());
+        assert!(ripple_state_index(ACCOUNT, ACCOUNT, "invalid").is_err());
+    }
+}
@@ -1,9 +1,17 @@
 //! Convenience utilities for the XRP Ledger
 
+#[cfg(feature = "models")]
+pub mod batch;
 pub mod exceptions;
+pub mod fee;
+pub mod hex;
+pub mod ledger_index;
+pub mod retry;
+pub mod subscription_buffer;
 pub mod time_conversion;
 pub mod xrpl_conversion;
 
+pub use self::fee::*;
 pub use self::time_conversion::*;
 pub use self::xrpl_conversion::*;
 
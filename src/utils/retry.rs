@@ -0,0 +1,247 @@
+//! Retrying transaction submissions that failed transiently.
+
+use crate::utils::exceptions::XRPLRetryException;
+use alloc::string::String;
+
+/// What has to be done before resubmitting a transaction
+/// whose submission failed transiently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryReason {
+    /// The transaction cost was too low. Re-fetch the fee
+    /// and re-sign the transaction.
+    Fee,
+    /// The sequence number was already used or is not yet
+    /// valid. Re-fetch the account sequence and re-sign the
+    /// transaction.
+    Sequence,
+    /// The server could not process the transaction right
+    /// now. Resubmit it unchanged.
+    Transient,
+}
+
+/// The outcome of a transaction submission based on its
+/// preliminary engine result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineResultClass {
+    /// The transaction was applied or queued.
+    Success,
+    /// The transaction failed for a reason that may go away
+    /// when resubmitting it.
+    Retry(RetryReason),
+    /// The transaction failed for good and should not be
+    /// resubmitted, like `tecUNFUNDED`.
+    Failure,
+}
+
+/// Classify the preliminary engine result of a submission.
+///
+/// See Transaction Results:
+/// `<https://xrpl.org/transaction-results.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::retry::{classify_engine_result, EngineResultClass, RetryReason};
+///
+/// assert_eq!(
+///     classify_engine_result("tefPAST_SEQ"),
+///     EngineResultClass::Retry(RetryReason::Sequence)
+/// );
+/// assert_eq!(classify_engine_result("tecUNFUNDED"), EngineResultClass::Failure);
+/// ```
+pub fn classify_engine_result(engine_result: &str) -> EngineResultClass {
+    match engine_result {
+        "tesSUCCESS" | "terQUEUED" => EngineResultClass::Success,
+        "telINSUF_FEE_P" | "telCAN_NOT_QUEUE_FEE" | "terINSUF_FEE_B" => {
+            EngineResultClass::Retry(RetryReason::Fee)
+        }
+        "tefPAST_SEQ" | "terPRE_SEQ" => EngineResultClass::Retry(RetryReason::Sequence),
+        "telLOCAL_ERROR" | "telCAN_NOT_QUEUE" | "telCAN_NOT_QUEUE_FULL" | "terRETRY" => {
+            EngineResultClass::Retry(RetryReason::Transient)
+        }
+        _ => EngineResultClass::Failure,
+    }
+}
+
+/// Configures which transient failures `submit_with_retry`
+/// retries and how often.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of submissions, including the first one.
+    pub max_attempts: u32,
+    /// Whether to retry after an insufficient fee.
+    pub retry_fee: bool,
+    /// Whether to retry after a sequence number error.
+    pub retry_sequence: bool,
+    /// Whether to retry after a transient server error.
+    pub retry_transient: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            retry_fee: true,
+            retry_sequence: true,
+            retry_transient: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns whether this policy retries failures of the given reason.
+    pub fn retries(&self, reason: RetryReason) -> bool {
+        match reason {
+            RetryReason::Fee => self.retry_fee,
+            RetryReason::Sequence => self.retry_sequence,
+            RetryReason::Transient => self.retry_transient,
+        }
+    }
+}
+
+/// Submit a transaction and resubmit it according to `policy`
+/// as long as it fails transiently.
+///
+/// `submit` submits the transaction and returns the preliminary
+/// engine result. It is called with `None` for the first attempt
+/// and with the `RetryReason` of the previous failure afterwards,
+/// so it can re-autofill the fee or sequence and re-sign the
+/// transaction before resubmitting it. Returns the engine result
+/// of the successful submission.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::retry::{submit_with_retry, RetryPolicy};
+///
+/// let mut engine_results = vec!["tefPAST_SEQ", "tesSUCCESS"].into_iter();
+/// let engine_result = submit_with_retry(&RetryPolicy::default(), |_reason| {
+///     engine_results.next().unwrap().to_string()
+/// });
+///
+/// assert_eq!(engine_result, Ok("tesSUCCESS".to_string()));
+/// ```
+pub fn submit_with_retry<F>(
+    policy: &RetryPolicy,
+    mut submit: F,
+) -> Result<String, XRPLRetryException>
+where
+    F: FnMut(Option<RetryReason>) -> String,
+{
+    let mut reason = None;
+    let mut attempts = 0;
+    loop {
+        let engine_result = submit(reason);
+        attempts += 1;
+        match classify_engine_result(&engine_result) {
+            EngineResultClass::Success => return Ok(engine_result),
+            EngineResultClass::Retry(retry_reason) if policy.retries(retry_reason) => {
+                if attempts >= policy.max_attempts {
+                    return Err(XRPLRetryException::RetriesExhausted {
+                        attempts,
+                        engine_result,
+                    });
+                }
+                reason = Some(retry_reason);
+            }
+            _ => return Err(XRPLRetryException::SubmissionFailed { engine_result }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_classify_engine_result() {
+        assert_eq!(
+            classify_engine_result("tesSUCCESS"),
+            EngineResultClass::Success
+        );
+        assert_eq!(
+            classify_engine_result("telINSUF_FEE_P"),
+            EngineResultClass::Retry(RetryReason::Fee)
+        );
+        assert_eq!(
+            classify_engine_result("telLOCAL_ERROR"),
+            EngineResultClass::Retry(RetryReason::Transient)
+        );
+        assert_eq!(
+            classify_engine_result("tecUNFUNDED"),
+            EngineResultClass::Failure
+        );
+        assert_eq!(
+            classify_engine_result("temBAD_AMOUNT"),
+            EngineResultClass::Failure
+        );
+    }
+
+    #[test]
+    fn test_retry_past_sequence() {
+        let mut engine_results = vec!["tefPAST_SEQ", "tesSUCCESS"].into_iter();
+        let mut reasons = Vec::new();
+        let engine_result = submit_with_retry(&RetryPolicy::default(), |reason| {
+            reasons.push(reason);
+            engine_results.next().unwrap().to_string()
+        });
+
+        assert_eq!(engine_result, Ok("tesSUCCESS".to_string()));
+        assert_eq!(reasons, vec![None, Some(RetryReason::Sequence)]);
+    }
+
+    #[test]
+    fn test_no_retry_terminal_failure() {
+        let mut attempts = 0;
+        let engine_result = submit_with_retry(&RetryPolicy::default(), |_reason| {
+            attempts += 1;
+            "tecUNFUNDED".to_string()
+        });
+
+        assert_eq!(
+            engine_result,
+            Err(XRPLRetryException::SubmissionFailed {
+                engine_result: "tecUNFUNDED".to_string()
+            })
+        );
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_retries_exhausted() {
+        let engine_result = submit_with_retry(&RetryPolicy::default(), |_reason| {
+            "telLOCAL_ERROR".to_string()
+        });
+
+        assert_eq!(
+            engine_result,
+            Err(XRPLRetryException::RetriesExhausted {
+                attempts: 3,
+                engine_result: "telLOCAL_ERROR".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_policy_disables_reason() {
+        let policy = RetryPolicy {
+            retry_sequence: false,
+            ..Default::default()
+        };
+        let engine_result = submit_with_retry(&policy, |_reason| "tefPAST_SEQ".to_string());
+
+        assert_eq!(
+            engine_result,
+            Err(XRPLRetryException::SubmissionFailed {
+                engine_result: "tefPAST_SEQ".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! Bounded buffering of the messages of a subscription.
+
+use crate::utils::exceptions::XRPLSubscriptionBufferException;
+use alloc::collections::VecDeque;
+
+/// The number of messages a `SubscriptionBuffer` holds by default.
+pub const DEFAULT_SUBSCRIPTION_BUFFER_SIZE: usize = 1024;
+
+/// What a `SubscriptionBuffer` does with a message arriving
+/// while it is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Drop the oldest buffered message to make room for the
+    /// new one. Suits streams where only recent messages matter,
+    /// like `ledger` or `book_changes`.
+    #[default]
+    DropOldest,
+    /// Reject the new message with a `BufferFull` error. Suits
+    /// streams where no message may be lost silently, like
+    /// `transactions` of an account.
+    Error,
+}
+
+/// Buffers the messages of a subscription stream until they are
+/// consumed, holding at most `capacity` messages. A subscriber
+/// consuming messages slower than they arrive can not grow the
+/// buffer without bounds; the `OverflowPolicy` decides what
+/// happens to messages arriving while the buffer is full.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::subscription_buffer::{OverflowPolicy, SubscriptionBuffer};
+///
+/// let mut buffer = SubscriptionBuffer::with_subscription_buffer(2);
+/// buffer.push(1).unwrap();
+/// buffer.push(2).unwrap();
+/// buffer.push(3).unwrap();
+///
+/// assert_eq!(buffer.overflow_policy(), OverflowPolicy::DropOldest);
+/// assert_eq!(buffer.dropped(), 1);
+/// assert_eq!(buffer.pop(), Some(2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionBuffer<T> {
+    messages: VecDeque<T>,
+    capacity: usize,
+    overflow_policy: OverflowPolicy,
+    dropped: usize,
+}
+
+impl<T> Default for SubscriptionBuffer<T> {
+    fn default() -> Self {
+        Self::with_subscription_buffer(DEFAULT_SUBSCRIPTION_BUFFER_SIZE)
+    }
+}
+
+impl<T> SubscriptionBuffer<T> {
+    /// Create a buffer holding at most `size` messages, dropping
+    /// the oldest message on overflow. A `size` of zero is
+    /// raised to one.
+    pub fn with_subscription_buffer(size: usize) -> Self {
+        Self {
+            messages: VecDeque::new(),
+            capacity: size.max(1),
+            overflow_policy: OverflowPolicy::default(),
+            dropped: 0,
+        }
+    }
+
+    /// Set what happens to messages arriving while the buffer
+    /// is full.
+    pub fn with_overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Self {
+        self.overflow_policy = overflow_policy;
+        self
+    }
+
+    /// The maximum number of buffered messages.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// What happens to messages arriving while the buffer is full.
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+
+    /// The number of messages dropped by the `DropOldest` policy.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// The number of buffered messages.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Whether no message is buffered.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Buffer a message that arrived on the stream, applying the
+    /// `OverflowPolicy` if the buffer is full.
+    pub fn push(&mut self, message: T) -> Result<(), XRPLSubscriptionBufferException> {
+        if self.messages.len() >= self.capacity {
+            match self.overflow_policy {
+                OverflowPolicy::DropOldest => {
+                    self.messages.pop_front();
+                    self.dropped += 1;
+                }
+                OverflowPolicy::Error => {
+                    return Err(XRPLSubscriptionBufferException::BufferFull {
+                        capacity: self.capacity,
+                    });
+                }
+            }
+        }
+        self.messages.push_back(message);
+
+        Ok(())
+    }
+
+    /// Consume the oldest buffered message.
+    pub fn pop(&mut self) -> Option<T> {
+        self.messages.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn drain(buffer: &mut SubscriptionBuffer<u32>) -> Vec<u32> {
+        core::iter::from_fn(|| buffer.pop()).collect()
+    }
+
+    #[test]
+    fn test_drop_oldest() {
+        let mut buffer = SubscriptionBuffer::with_subscription_buffer(3);
+        for message in 1..=5 {
+            assert_eq!(buffer.push(message), Ok(()));
+        }
+
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.dropped(), 2);
+        assert_eq!(drain(&mut buffer), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_error() {
+        let mut buffer = SubscriptionBuffer::with_subscription_buffer(3)
+            .with_overflow_policy(OverflowPolicy::Error);
+        for message in 1..=3 {
+            assert_eq!(buffer.push(message), Ok(()));
+        }
+
+        assert_eq!(
+            buffer.push(4),
+            Err(XRPLSubscriptionBufferException::BufferFull { capacity: 3 })
+        );
+        assert_eq!(buffer.dropped(), 0);
+        assert_eq!(buffer.pop(), Some(1));
+        assert_eq!(buffer.push(4), Ok(()));
+        assert_eq!(drain(&mut buffer), vec![2, 3, 4]);
+    }
+}
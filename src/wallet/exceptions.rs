@@ -0,0 +1,67 @@
+//! XRPL wallet exceptions.
+
+use crate::core::keypairs::exceptions::XRPLKeypairsException;
+use crate::core::types::exceptions::XRPLTypeException;
+use alloc::string::String;
+use strum_macros::Display;
+use thiserror_no_std::Error;
+
+#[derive(Debug, PartialEq, Display)]
+#[non_exhaustive]
+pub enum XRPLFaucetException {
+    RateLimited,
+    UnexpectedStatus { status: u16 },
+    InvalidResponse,
+    KeypairsError(XRPLKeypairsException),
+}
+
+impl From<XRPLKeypairsException> for XRPLFaucetException {
+    fn from(err: XRPLKeypairsException) -> Self {
+        XRPLFaucetException::KeypairsError(err)
+    }
+}
+
+impl From<serde_json::Error> for XRPLFaucetException {
+    fn from(_: serde_json::Error) -> Self {
+        XRPLFaucetException::InvalidResponse
+    }
+}
+
+#[cfg(feature = "std")]
+impl alloc::error::Error for XRPLFaucetException {}
+
+#[derive(Debug, PartialEq, Error)]
+#[non_exhaustive]
+pub enum XRPLSignException {
+    #[error("Offline signing requires the field `{field}` to be set.")]
+    MissingField { field: &'static str },
+    #[error("The fee of {found} drops exceeds the ceiling of {max} drops. Allow it with `allow_high_fee` if it is intended.")]
+    FeeTooHigh { max: u64, found: String },
+    #[error("Unable to sign the transaction: {0}")]
+    KeypairsError(#[from] XRPLKeypairsException),
+    #[error("Unable to encode the transaction: {0}")]
+    TypeError(#[from] XRPLTypeException),
+    #[error("Unable to serialize the transaction: {0:?}")]
+    SerdeJsonError(serde_json::error::Category),
+    #[error("The encoded signing data is not valid hex.")]
+    FromHexError,
+    #[error(
+        "Pseudo-transactions are created by validators and can not be signed (found {found})."
+    )]
+    PseudoTransaction { found: String },
+}
+
+impl From<serde_json::Error> for XRPLSignException {
+    fn from(err: serde_json::Error) -> Self {
+        XRPLSignException::SerdeJsonError(err.classify())
+    }
+}
+
+impl From<hex::FromHexError> for XRPLSignException {
+    fn from(_: hex::FromHexError) -> Self {
+        XRPLSignException::FromHexError
+    }
+}
+
+#[cfg(feature = "std")]
+impl alloc::error::Error for XRPLSignException {}
@@ -0,0 +1,150 @@
+//! Funding of wallets on the test networks.
+//!
+//! The faucet is called by POSTing a `FaucetRequest` as JSON to one of
+//! the faucet URLs. The HTTP response is turned into a funded `Wallet`
+//! with `wallet_from_faucet_response`.
+
+use crate::wallet::exceptions::XRPLFaucetException;
+use crate::wallet::Wallet;
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// The faucet funding accounts on the Testnet.
+pub const TESTNET_FAUCET_URL: &str = "https://faucet.altnet.rippletest.net/accounts";
+/// The faucet funding accounts on the Devnet.
+pub const DEVNET_FAUCET_URL: &str = "https://faucet.devnet.rippletest.net/accounts";
+
+/// The body of a request to the faucet. Without a `destination`
+/// the faucet creates and funds a new account.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FaucetRequest<'a> {
+    /// The address of an existing account to fund.
+    pub destination: Option<&'a str>,
+    /// Identifies the library calling the faucet.
+    pub user_agent: Option<&'a str>,
+}
+
+/// The account funded by the faucet.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FaucetAccount {
+    pub x_address: String,
+    pub classic_address: String,
+    pub secret: String,
+}
+
+/// The response of the faucet.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct FaucetResponse {
+    pub account: FaucetAccount,
+    /// The amount of XRP sent to the account.
+    pub amount: u64,
+    /// The balance of the account in XRP after funding.
+    pub balance: u64,
+}
+
+/// Turns the HTTP status and body returned by the faucet into the
+/// funded wallet and its starting balance in XRP.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::wallet::faucet::wallet_from_faucet_response;
+///
+/// let body: &str = r#"{
+///     "account": {
+///         "xAddress": "T7jkn8zYC2NhPdcbVxkiEXZGy56YiEE4P7uXRgpy5j4Q6S1",
+///         "classicAddress": "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD",
+///         "secret": "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r"
+///     },
+///     "amount": 1000,
+///     "balance": 1000
+/// }"#;
+/// let (wallet, balance) = wallet_from_faucet_response(200, body).unwrap();
+///
+/// assert_eq!("rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD", wallet.classic_address);
+/// assert_eq!(1000, balance);
+/// ```
+pub fn wallet_from_faucet_response(
+    status: u16,
+    body: &str,
+) -> Result<(Wallet, u64), XRPLFaucetException> {
+    match status {
+        200..=299 => {
+            let response: FaucetResponse = serde_json::from_str(body)?;
+            let wallet = Wallet::new(&response.account.secret, 0)?;
+
+            if wallet.classic_address != response.account.classic_address {
+                return Err(XRPLFaucetException::InvalidResponse);
+            }
+
+            Ok((wallet, response.balance))
+        }
+        429 => Err(XRPLFaucetException::RateLimited),
+        _ => Err(XRPLFaucetException::UnexpectedStatus { status }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SEED: &str = "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r";
+
+    fn faucet_body(classic_address: &str) -> String {
+        alloc::format!(
+            r#"{{"account":{{"xAddress":"T7jkn8zYC2NhPdcbVxkiEXZGy56YiEE4P7uXRgpy5j4Q6S1","classicAddress":"{}","secret":"{}"}},"amount":1000,"balance":1000}}"#,
+            classic_address,
+            SEED
+        )
+    }
+
+    #[test]
+    fn test_wallet_from_faucet_response() {
+        let classic_address = Wallet::new(SEED, 0).unwrap().classic_address.clone();
+        let (wallet, balance) =
+            wallet_from_faucet_response(200, &faucet_body(&classic_address)).unwrap();
+
+        assert_eq!(wallet.classic_address, classic_address);
+        assert_eq!(balance, 1000);
+    }
+
+    #[test]
+    fn test_faucet_errors() {
+        assert_eq!(
+            wallet_from_faucet_response(429, "").err(),
+            Some(XRPLFaucetException::RateLimited)
+        );
+        assert_eq!(
+            wallet_from_faucet_response(503, "").err(),
+            Some(XRPLFaucetException::UnexpectedStatus { status: 503 })
+        );
+        assert_eq!(
+            wallet_from_faucet_response(200, "{}").err(),
+            Some(XRPLFaucetException::InvalidResponse)
+        );
+        assert_eq!(
+            wallet_from_faucet_response(200, &faucet_body("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"))
+                .err(),
+            Some(XRPLFaucetException::InvalidResponse)
+        );
+    }
+
+    #[test]
+    fn test_serialize_request() {
+        let request = FaucetRequest {
+            destination: Some("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+            user_agent: Some("xrpl-rust"),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"destination":"rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh","userAgent":"xrpl-rust"}"#
+        );
+    }
+}
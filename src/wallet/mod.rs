@@ -1,5 +1,10 @@
 //! Methods for working with XRPL wallets.
 
+pub mod exceptions;
+pub mod faucet;
+#[cfg(feature = "models")]
+pub mod signing;
+
 use crate::constants::CryptoAlgorithm;
 use crate::core::addresscodec::classic_address_to_xaddress;
 use crate::core::addresscodec::exceptions::XRPLAddressCodecException;
@@ -7,18 +12,55 @@ use crate::core::keypairs::derive_classic_address;
 use crate::core::keypairs::derive_keypair;
 use crate::core::keypairs::exceptions::XRPLKeypairsException;
 use crate::core::keypairs::generate_seed;
+use crate::core::keypairs::get_algorithm_from_key;
+use crate::core::keypairs::is_valid_message;
+use crate::core::keypairs::sign;
+use crate::utils::hex::{from_hex, to_hex};
 use alloc::format;
 use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec;
+use alloc::vec::Vec;
 use zeroize::Zeroize;
 
+/// Prefix of messages signed with `Wallet::sign_message`. It
+/// separates them from transactions and payment channel claims,
+/// whose signing prefixes are 4 bytes, so that a signed message
+/// can never be submitted as one of those.
+pub const MESSAGE_SIGNING_PREFIX: &[u8] = b"XRPL Signed Message:\n";
+
+/// Returns the message prefixed with `MESSAGE_SIGNING_PREFIX`.
+fn _prefixed_message(message: &[u8]) -> Vec<u8> {
+    [MESSAGE_SIGNING_PREFIX, message].concat()
+}
+
+/// Verifies a signature of `Wallet::sign_message` on an
+/// arbitrary message for the hex encoded public key of either
+/// algorithm.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::wallet::{verify_message, Wallet};
+///
+/// let wallet = Wallet::create(None).unwrap();
+/// let signature: Vec<u8> = wallet.sign_message(b"login").unwrap();
+///
+/// assert!(verify_message(&wallet.public_key, b"login", &signature));
+/// assert!(!verify_message(&wallet.public_key, b"logout", &signature));
+/// ```
+pub fn verify_message(public_key: &str, message: &[u8], signature: &[u8]) -> bool {
+    is_valid_message(&_prefixed_message(message), &to_hex(signature), public_key)
+}
+
 /// The cryptographic keys needed to control an
 /// XRP Ledger account.
 ///
 /// See Cryptographic Keys:
 /// `<https://xrpl.org/cryptographic-keys.html>`
-struct Wallet {
+pub struct Wallet {
     /// The seed from which the public and private keys
     /// are derived.
     pub seed: String,
@@ -33,6 +75,8 @@ struct Wallet {
     /// The address that publicly identifies this wallet, as
     /// a base58 string.
     pub classic_address: String,
+    /// The cryptographic algorithm of the wallet's keys.
+    pub algorithm: CryptoAlgorithm,
     /// The next available sequence number to use for
     /// transactions from this wallet. Must be updated by the
     /// user. Increments on the ledger with every successful
@@ -57,17 +101,20 @@ impl Wallet {
     pub fn new(seed: &str, sequence: u64) -> Result<Self, XRPLKeypairsException> {
         let (public_key, private_key) = derive_keypair(seed, false)?;
         let classic_address = derive_classic_address(&public_key)?;
+        let algorithm = get_algorithm_from_key(&public_key)?;
 
         Ok(Wallet {
             seed: seed.into(),
             public_key,
             private_key,
             classic_address,
+            algorithm,
             sequence,
         })
     }
 
-    /// Generates a new seed and Wallet.
+    /// Generates a new seed and Wallet. Uses Ed25519 if
+    /// no algorithm is provided.
     pub fn create(
         crypto_algorithm: Option<CryptoAlgorithm>,
     ) -> Result<Self, XRPLKeypairsException> {
@@ -82,6 +129,19 @@ impl Wallet {
     ) -> Result<String, XRPLAddressCodecException> {
         classic_address_to_xaddress(&self.classic_address, tag, is_test_network)
     }
+
+    /// Signs an arbitrary message, e.g. for off-chain
+    /// authentication, and returns the raw signature bytes.
+    /// The message is prefixed with `MESSAGE_SIGNING_PREFIX`
+    /// before signing; use `verify_message` to verify it.
+    /// Only errors if `private_key` was replaced by an
+    /// invalid key.
+    pub fn sign_message(&self, message: &[u8]) -> Result<Vec<u8>, XRPLKeypairsException> {
+        Ok(from_hex(sign(
+            &_prefixed_message(message),
+            &self.private_key,
+        )?)?)
+    }
 }
 
 impl ToString for Wallet {
@@ -96,3 +156,65 @@ impl ToString for Wallet {
         string_list.join("-")
     }
 }
+
+#[cfg(test)]
+mod test_sign_message {
+    use super::*;
+
+    const MESSAGE: &[u8] = b"xrpl-rust off-chain authentication";
+
+    #[test]
+    fn test_sign_and_verify() {
+        for algorithm in [CryptoAlgorithm::ED25519, CryptoAlgorithm::SECP256K1] {
+            let wallet = Wallet::create(Some(algorithm)).unwrap();
+            let signature = wallet.sign_message(MESSAGE).unwrap();
+
+            assert!(verify_message(&wallet.public_key, MESSAGE, &signature));
+        }
+    }
+
+    #[test]
+    fn test_tampered_message() {
+        for algorithm in [CryptoAlgorithm::ED25519, CryptoAlgorithm::SECP256K1] {
+            let wallet = Wallet::create(Some(algorithm)).unwrap();
+            let signature = wallet.sign_message(MESSAGE).unwrap();
+
+            assert!(!verify_message(
+                &wallet.public_key,
+                b"xrpl-rust off-chain authenticatioN",
+                &signature
+            ));
+            assert!(!verify_message(
+                &Wallet::create(None).unwrap().public_key,
+                MESSAGE,
+                &signature
+            ));
+        }
+    }
+
+    #[test]
+    fn test_verify_invalid_public_key() {
+        let wallet = Wallet::create(None).unwrap();
+        let signature = wallet.sign_message(MESSAGE).unwrap();
+
+        assert!(!verify_message("", MESSAGE, &signature));
+        assert!(!verify_message("E", MESSAGE, &signature));
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        let wallet = Wallet::create(None).unwrap();
+        let signature = wallet.sign_message(MESSAGE).unwrap();
+
+        assert!(!is_valid_message(
+            MESSAGE,
+            &to_hex(&signature),
+            &wallet.public_key
+        ));
+        assert!(!verify_message(
+            &wallet.public_key,
+            MESSAGE,
+            &from_hex(sign(MESSAGE, &wallet.private_key).unwrap()).unwrap()
+        ));
+    }
+}
@@ -0,0 +1,276 @@
+//! Signing of transactions without network access.
+
+use crate::core::binarycodec::{encode, encode_for_signing};
+use crate::core::keypairs::sign;
+use crate::models::amount::XRPAmount;
+use crate::models::transactions::Transaction;
+use crate::utils::hex::from_hex;
+use crate::utils::HIGH_FEE_CEILING_DROPS;
+use crate::wallet::exceptions::XRPLSignException;
+use crate::wallet::Wallet;
+use alloc::string::{String, ToString};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Signs transactions with the keys of a wallet without network
+/// access. Nothing is autofilled, because the values are only
+/// known to a connected client: `fee`, `sequence` and
+/// `last_ledger_sequence` must already be set, otherwise a
+/// `MissingField` error names the first one that is not.
+///
+/// A fee above `HIGH_FEE_CEILING_DROPS` is rejected as a likely
+/// typo unless it is allowed with `allow_high_fee`. Transaction
+/// models are plain structs without builders, and
+/// `Model::get_errors` has no way to opt in, so the ceiling is
+/// enforced here, before a transaction turns into a `tx_blob`.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::transactions::{AccountSet, CommonFields};
+/// use xrpl::wallet::signing::OfflineSigner;
+/// use xrpl::wallet::Wallet;
+///
+/// let wallet = Wallet::create(None).unwrap();
+/// let account_set = AccountSet {
+///     common_fields: CommonFields {
+///         account: wallet.classic_address.clone().into(),
+///         fee: Some("5000000".into()),
+///         sequence: Some(1),
+///         last_ledger_sequence: Some(100),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+///
+/// assert!(OfflineSigner::new(&wallet).sign(&account_set).is_err());
+/// assert!(OfflineSigner::new(&wallet)
+///     .allow_high_fee()
+///     .sign(&account_set)
+///     .is_ok());
+/// ```
+pub struct OfflineSigner<'w> {
+    wallet: &'w Wallet,
+    allow_high_fee: bool,
+}
+
+impl<'w> OfflineSigner<'w> {
+    /// Create a signer using the keys of `wallet`.
+    pub fn new(wallet: &'w Wallet) -> Self {
+        Self {
+            wallet,
+            allow_high_fee: false,
+        }
+    }
+
+    /// Allow fees above `HIGH_FEE_CEILING_DROPS`.
+    pub fn allow_high_fee(mut self) -> Self {
+        self.allow_high_fee = true;
+        self
+    }
+
+    /// Sign a transaction and return the hex encoded `tx_blob`,
+    /// ready to be submitted by a connected machine. Errors on
+    /// pseudo-transactions, which are only created by validators.
+    pub fn sign<T>(&self, tx: &T) -> Result<String, XRPLSignException>
+    where
+        T: Transaction + Serialize,
+    {
+        let common_fields = match tx.common_fields() {
+            Some(common_fields) if !tx.is_pseudo_transaction() => common_fields,
+            _ => {
+                return Err(XRPLSignException::PseudoTransaction {
+                    found: tx.get_transaction_type().to_string(),
+                })
+            }
+        };
+        let fee = common_fields
+            .fee()
+            .ok_or(XRPLSignException::MissingField { field: "Fee" })?;
+        if common_fields.sequence().is_none() {
+            return Err(XRPLSignException::MissingField { field: "Sequence" });
+        }
+        if common_fields.last_ledger_sequence().is_none() {
+            return Err(XRPLSignException::MissingField {
+                field: "LastLedgerSequence",
+            });
+        }
+        if !self.allow_high_fee {
+            _check_fee_ceiling(fee)?;
+        }
+
+        let mut fields = serde_json::to_value(tx)?;
+        fields["SigningPubKey"] = Value::from(self.wallet.public_key.as_str());
+
+        let signing_data = from_hex(encode_for_signing(&fields)?)?;
+        fields["TxnSignature"] = Value::from(sign(&signing_data, &self.wallet.private_key)?);
+
+        Ok(encode(&fields)?)
+    }
+}
+
+/// Sign a transaction with the keys of `wallet` and return the
+/// hex encoded `tx_blob`. Shorthand for
+/// `OfflineSigner::new(wallet).sign(tx)`, see `OfflineSigner`.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::transactions::{AccountSet, CommonFields};
+/// use xrpl::wallet::signing::sign_offline;
+/// use xrpl::wallet::Wallet;
+///
+/// let wallet = Wallet::create(None).unwrap();
+/// let account_set = AccountSet {
+///     common_fields: CommonFields {
+///         account: wallet.classic_address.clone().into(),
+///         fee: Some("12".into()),
+///         sequence: Some(1),
+///         last_ledger_sequence: Some(100),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+///
+/// assert!(sign_offline(&account_set, &wallet).is_ok());
+/// ```
+pub fn sign_offline<T>(tx: &T, wallet: &Wallet) -> Result<String, XRPLSignException>
+where
+    T: Transaction + Serialize,
+{
+    OfflineSigner::new(wallet).sign(tx)
+}
+
+/// Errors if the `Fee` in drops exceeds `HIGH_FEE_CEILING_DROPS`.
+/// A fee that is not in drops is left for the binary codec to
+/// reject.
+fn _check_fee_ceiling(fee: &XRPAmount) -> Result<(), XRPLSignException> {
+    match fee.0.parse::<u64>().ok() {
+        Some(drops) if drops > HIGH_FEE_CEILING_DROPS => Err(XRPLSignException::FeeTooHigh {
+            max: HIGH_FEE_CEILING_DROPS,
+            found: drops.to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test_sign_offline {
+    use super::*;
+    use crate::constants::ACCOUNT_ZERO;
+    use crate::core::binarycodec::decode;
+    use crate::core::keypairs::is_valid_message;
+    use crate::models::transactions::{AccountSet, CommonFields, EnableAmendment, TransactionType};
+
+    const SEED: &str = "sEdTM1uX8pu2do5XvTnutH6HsouMaM2";
+
+    fn account_set<'a>(wallet: &'a Wallet, sequence: Option<u32>) -> AccountSet<'a> {
+        AccountSet {
+            common_fields: CommonFields {
+                account: wallet.classic_address.as_str().into(),
+                fee: Some("12".into()),
+                sequence,
+                last_ledger_sequence: Some(100),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_missing_sequence() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+
+        assert_eq!(
+            sign_offline(&account_set(&wallet, None), &wallet),
+            Err(XRPLSignException::MissingField { field: "Sequence" })
+        );
+    }
+
+    #[test]
+    fn test_missing_fee_and_last_ledger_sequence() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let mut account_set = account_set(&wallet, Some(1));
+        account_set.common_fields.last_ledger_sequence = None;
+
+        assert_eq!(
+            sign_offline(&account_set, &wallet),
+            Err(XRPLSignException::MissingField {
+                field: "LastLedgerSequence"
+            })
+        );
+
+        account_set.common_fields.fee = None;
+
+        assert_eq!(
+            sign_offline(&account_set, &wallet),
+            Err(XRPLSignException::MissingField { field: "Fee" })
+        );
+    }
+
+    #[test]
+    fn test_all_fields_set() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let tx_blob = sign_offline(&account_set(&wallet, Some(1)), &wallet).unwrap();
+        let signed = decode(&tx_blob).unwrap();
+        let signing_data = from_hex(&encode_for_signing(&signed).unwrap()).unwrap();
+
+        assert_eq!(signed["Sequence"], 1);
+        assert_eq!(signed["SigningPubKey"], wallet.public_key.as_str());
+        assert!(is_valid_message(
+            &signing_data,
+            signed["TxnSignature"].as_str().unwrap(),
+            &wallet.public_key,
+        ));
+    }
+
+    #[test]
+    fn test_high_fee() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let mut account_set = account_set(&wallet, Some(1));
+        account_set.common_fields.fee = Some("1000000000".into());
+
+        assert_eq!(
+            sign_offline(&account_set, &wallet),
+            Err(XRPLSignException::FeeTooHigh {
+                max: 1000000,
+                found: "1000000000".to_string(),
+            })
+        );
+
+        let tx_blob = OfflineSigner::new(&wallet)
+            .allow_high_fee()
+            .sign(&account_set)
+            .unwrap();
+
+        assert_eq!(decode(&tx_blob).unwrap()["Fee"], "1000000000");
+    }
+
+    #[test]
+    fn test_pseudo_transaction() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let enable_amendment = EnableAmendment {
+            transaction_type: TransactionType::EnableAmendment,
+            account: ACCOUNT_ZERO.into(),
+            fee: Some("0".into()),
+            sequence: Some(0),
+            signing_pub_key: Some("".into()),
+            source_tag: None,
+            txn_signature: None,
+            flags: None,
+            amendment: "42426C4D4F1009EE67080A9B7965B44656D7714D104A72F9B4369F97ABF044EE".into(),
+            ledger_sequence: 21225473,
+        };
+
+        assert_eq!(
+            sign_offline(&enable_amendment, &wallet),
+            Err(XRPLSignException::PseudoTransaction {
+                found: "EnableAmendment".to_string(),
+            })
+        );
+    }
+}
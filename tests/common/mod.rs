@@ -1,3 +1,8 @@
+#![allow(dead_code)]
+
+#[cfg(feature = "integration")]
+pub mod rippled;
+
 /// Setup common testing prerequisites here such as connecting a client
 /// to a server or creating required files/directories if needed.
 pub fn setup() {}
@@ -0,0 +1,140 @@
+//! Helpers for tests running against a local rippled in standalone mode.
+//!
+//! Start rippled with `rippled -a --start` and point the tests to it by
+//! setting `XRPL_RIPPLED_URL` (defaults to `http://127.0.0.1:5005`). In
+//! standalone mode all XRP is held by the genesis account, which is used
+//! to fund the accounts of the tests.
+
+use std::env;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+use serde_json::{json, Value};
+use xrpl::models::amount::Amount;
+use xrpl::models::transactions::{CommonFields, Payment};
+use xrpl::wallet::signing::sign_offline;
+use xrpl::wallet::Wallet;
+
+/// The JSON-RPC endpoint of rippled in standalone mode.
+pub const DEFAULT_RIPPLED_URL: &str = "http://127.0.0.1:5005";
+/// The seed of the genesis account `rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh`.
+pub const GENESIS_SEED: &str = "snoPBrXtMeMyMHUVTgbuqAfg1SUTb";
+/// The drops sent to the wallets created by `fund_wallet`.
+pub const FUNDING_DROPS: &str = "1000000000";
+
+/// Returns the URL of the rippled instance to test against.
+pub fn rippled_url() -> String {
+    env::var("XRPL_RIPPLED_URL").unwrap_or_else(|_| DEFAULT_RIPPLED_URL.to_string())
+}
+
+/// Returns the wallet of the genesis account holding all XRP.
+pub fn genesis_wallet() -> Wallet {
+    Wallet::new(GENESIS_SEED, 0).expect("the genesis seed is valid")
+}
+
+/// Returns a new unfunded wallet.
+pub fn new_wallet() -> Wallet {
+    Wallet::create(None).expect("a new wallet can be created")
+}
+
+/// Sends a request to rippled over JSON-RPC and returns the
+/// `result` of the response.
+///
+/// The request is built as JSON instead of from the request models,
+/// because the `serde` traits of the models can not be named here:
+/// with all targets and features, more than one `serde` version is
+/// in the dependency graph.
+pub fn request(method: &str, params: Value) -> Value {
+    let body = json!({ "method": method, "params": [params] }).to_string();
+    let url = rippled_url();
+    let host = url.trim_start_matches("http://").trim_end_matches('/');
+
+    // HTTP/1.0 keeps rippled from using a chunked response.
+    let mut stream = TcpStream::connect(host)
+        .unwrap_or_else(|err| panic!("rippled is not reachable at {}: {}", url, err));
+    write!(
+        stream,
+        "POST / HTTP/1.0\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+        host,
+        body.len(),
+        body
+    )
+    .expect("the request is sent");
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .expect("the response is read");
+    let (_, body) = response
+        .split_once("\r\n\r\n")
+        .expect("rippled responds with HTTP");
+    let response: Value = serde_json::from_str(body).expect("rippled responds with JSON");
+
+    response["result"].clone()
+}
+
+/// Closes the current ledger, so that submitted transactions
+/// are applied to a validated ledger.
+pub fn ledger_accept() {
+    let result = request("ledger_accept", json!({}));
+
+    assert_eq!(
+        result["status"], "success",
+        "ledger_accept failed: {}",
+        result
+    );
+}
+
+/// Returns the `result` of `account_info` for `account` in the
+/// current ledger.
+pub fn account_info(account: &str) -> Value {
+    let result = request(
+        "account_info",
+        json!({ "account": account, "ledger_index": "current" }),
+    );
+
+    assert_eq!(
+        result["status"], "success",
+        "account_info failed: {}",
+        result
+    );
+
+    result
+}
+
+/// Returns a new wallet funded with `FUNDING_DROPS` by the
+/// genesis account, after the funding payment is accepted
+/// into a ledger.
+pub fn fund_wallet() -> Wallet {
+    let genesis = genesis_wallet();
+    let wallet = new_wallet();
+    let genesis_info = account_info(&genesis.classic_address);
+    let ledger_current_index = genesis_info["ledger_current_index"]
+        .as_u64()
+        .expect("the current ledger has an index") as u32;
+    let sequence = genesis_info["account_data"]["Sequence"]
+        .as_u64()
+        .expect("the genesis account has a sequence") as u32;
+    let payment = Payment {
+        common_fields: CommonFields {
+            account: genesis.classic_address.as_str().into(),
+            fee: Some("10".into()),
+            sequence: Some(sequence),
+            last_ledger_sequence: Some(ledger_current_index + 20),
+            ..Default::default()
+        },
+        amount: Amount::XRPAmount(FUNDING_DROPS.into()),
+        destination: wallet.classic_address.as_str().into(),
+        ..Default::default()
+    };
+    let tx_blob = sign_offline(&payment, &genesis).expect("the genesis account can sign");
+    let result = request("submit", json!({ "tx_blob": tx_blob }));
+
+    assert_eq!(
+        result["engine_result"], "tesSUCCESS",
+        "funding failed: {}",
+        result
+    );
+    ledger_accept();
+
+    wallet
+}
@@ -0,0 +1,27 @@
+use xrpl::prelude::*;
+
+#[test]
+fn it_brings_common_types_into_scope() {
+    let offer_create = OfferCreate {
+        common_fields: CommonFields {
+            account: "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe".into(),
+            flags: Some(vec![OfferCreateFlag::TfPassive]),
+            ..Default::default()
+        },
+        taker_gets: Amount::XRPAmount(XRPAmount::from("1000000")),
+        taker_pays: Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+            "USD".into(),
+            "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+            "10".into(),
+        )),
+        ..Default::default()
+    };
+
+    assert!(offer_create.is_valid());
+    assert!(offer_create.has_flag(&Flag::OfferCreate(OfferCreateFlag::TfPassive)));
+    assert_eq!(
+        offer_create.get_transaction_type(),
+        TransactionType::OfferCreate
+    );
+    assert!(Wallet::create(None).is_ok());
+}
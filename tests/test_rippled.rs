@@ -0,0 +1,27 @@
+#![cfg(feature = "integration")]
+
+mod common;
+
+use common::rippled::{account_info, fund_wallet, genesis_wallet, new_wallet, FUNDING_DROPS};
+
+#[test]
+fn it_derives_the_genesis_account() {
+    assert_eq!(
+        genesis_wallet().classic_address,
+        "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"
+    );
+}
+
+#[test]
+fn it_creates_distinct_wallets() {
+    assert_ne!(new_wallet().classic_address, new_wallet().classic_address);
+}
+
+#[test]
+fn it_funds_a_wallet() {
+    let wallet = fund_wallet();
+    let account_data = &account_info(&wallet.classic_address)["account_data"];
+
+    assert_eq!(account_data["Account"], wallet.classic_address.as_str());
+    assert_eq!(account_data["Balance"], FUNDING_DROPS);
+}